@@ -0,0 +1,153 @@
+//! Element ids for ARIA wiring
+//!
+//! Triggers and the regions they control reference each other by id
+//! (`aria-controls`, `aria-labelledby`), so both ends need the same id on
+//! the server and the client. [`id`] derives one deterministically from a
+//! component scope and a caller key, and [`ControlledIds`] returns both
+//! ends' attributes already wired together.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::a11y::{self, ControlledIds};
+//!
+//! assert_eq!(a11y::id("accordion", "shipping"), "accordion-shipping");
+//! // Keys that lose characters when slugged get a hash of the key
+//! assert!(a11y::id("accordion", "Shipping & returns").starts_with("accordion-shipping-returns-"));
+//!
+//! let section = ControlledIds::new("accordion", "shipping");
+//! let trigger = section.disclosure_trigger_attributes(false);
+//! let panel = section.disclosure_panel_attributes(false);
+//!
+//! assert!(trigger.contains(&("aria-controls", "accordion-shipping-panel".to_string())));
+//! assert!(panel.contains(&("id", "accordion-shipping-panel".to_string())));
+//! assert!(panel.contains(&("aria-labelledby", "accordion-shipping-trigger".to_string())));
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+/// Derive an element id from a component scope and a key
+///
+/// The key is lowercased and every run of characters other than ASCII
+/// letters and digits becomes a single hyphen, so ids are stable across
+/// renders and valid in CSS selectors. Slugging loses information
+/// (`"a b"` and `"a-b"` slug the same), so when the slug differs from the
+/// key a hash of the original key is appended; keys with no ASCII letters
+/// or digits are just the hash. Distinct keys therefore get distinct ids,
+/// and keys must stay unique within a scope on the page. An empty scope
+/// becomes `id` so the result still starts with a letter.
+pub fn id(scope: &str, key: impl Display) -> String {
+    let key = key.to_string();
+    let slug = slugify(&key);
+    let key = if slug.is_empty() {
+        format!("{:08x}", fnv1a(&key))
+    } else if slug != key {
+        format!("{}-{:08x}", slug, fnv1a(&key))
+    } else {
+        slug
+    };
+    let scope = slugify(scope);
+    let scope = if scope.is_empty() { "id" } else { &scope };
+    format!("{}-{}", scope, key)
+}
+
+/// Ids for a trigger and the region it controls
+///
+/// The trigger is `{id}-trigger` and the region `{id}-panel`, where `id`
+/// comes from [`id`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ControlledIds {
+    trigger: String,
+    panel: String,
+}
+
+impl ControlledIds {
+    /// Create ids for a trigger and region in a component scope
+    pub fn new(scope: &str, key: impl Display) -> Self {
+        Self::from_base(id(scope, key))
+    }
+
+    /// Create ids from an existing base id
+    pub fn from_base(base: impl Into<String>) -> Self {
+        let base = base.into();
+        Self {
+            trigger: format!("{}-trigger", base),
+            panel: format!("{}-panel", base),
+        }
+    }
+
+    /// Get the trigger id
+    pub fn trigger_id(&self) -> &str {
+        &self.trigger
+    }
+
+    /// Get the controlled region id
+    pub fn panel_id(&self) -> &str {
+        &self.panel
+    }
+
+    /// Get attributes for the trigger: its id and `aria-controls`
+    pub fn trigger_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("id", self.trigger.clone()),
+            ("aria-controls", self.panel.clone()),
+        ]
+    }
+
+    /// Get attributes for the region: its id and `aria-labelledby`
+    pub fn panel_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("id", self.panel.clone()),
+            ("aria-labelledby", self.trigger.clone()),
+        ]
+    }
+
+    /// Get attributes for a disclosure or accordion section's button
+    pub fn disclosure_trigger_attributes(&self, expanded: bool) -> Vec<(&'static str, String)> {
+        let mut attrs = self.trigger_attributes();
+        attrs.push(("aria-expanded", expanded.to_string()));
+        attrs
+    }
+
+    /// Get attributes for a disclosure or accordion section's content
+    ///
+    /// Collapsed content is `hidden` so it leaves the accessibility tree.
+    pub fn disclosure_panel_attributes(&self, expanded: bool) -> Vec<(&'static str, String)> {
+        let mut attrs = self.panel_attributes();
+        attrs.push(("role", "region".to_string()));
+        if !expanded {
+            attrs.push(("hidden", "true".to_string()));
+        }
+        attrs
+    }
+}
+
+fn slugify(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    while slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// 32-bit FNV-1a, stable across platforms and releases
+fn fnv1a(value: &str) -> u32 {
+    value.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+#[path = "a11y_test.rs"]
+mod a11y_test;
@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use crate::a11y::{id, ControlledIds};
+
+    #[test]
+    fn test_id_slugs_key_and_scope() {
+        assert_eq!(id("tabs", "billing"), "tabs-billing");
+        assert_eq!(id("Date Picker", 42), "date-picker-42");
+
+        let shipping = id("accordion", "  Shipping & Returns! ");
+        assert!(shipping.starts_with("accordion-shipping-returns-"));
+        assert_eq!(shipping.len(), "accordion-shipping-returns-".len() + 8);
+    }
+
+    #[test]
+    fn test_id_keeps_lossy_keys_distinct() {
+        assert_ne!(id("tabs", "Größe"), id("tabs", "Grüße"));
+        assert_ne!(id("tabs", "a b"), id("tabs", "a-b"));
+        assert_ne!(id("tabs", "Billing"), id("tabs", "billing"));
+        assert_eq!(id("tabs", "a-b"), "tabs-a-b");
+    }
+
+    #[test]
+    fn test_id_with_empty_scope() {
+        assert_eq!(id("", "billing"), "id-billing");
+        assert_eq!(id(" & ", "billing"), "id-billing");
+        assert!(id("", "日本").starts_with("id-"));
+    }
+
+    #[test]
+    fn test_id_is_deterministic() {
+        assert_eq!(id("tabs", "Plans"), id("tabs", "Plans"));
+        assert_ne!(id("tabs", "plans"), id("accordion", "plans"));
+    }
+
+    #[test]
+    fn test_id_hashes_keys_without_ascii() {
+        let a = id("tabs", "日本");
+        let b = id("tabs", "中国");
+
+        assert!(a.starts_with("tabs-"));
+        assert_eq!(a.len(), "tabs-".len() + 8);
+        assert_ne!(a, b);
+        assert_eq!(a, id("tabs", "日本"));
+    }
+
+    #[test]
+    fn test_controlled_ids_wire_both_ends() {
+        let ids = ControlledIds::new("tabs", "billing");
+
+        assert_eq!(ids.trigger_id(), "tabs-billing-trigger");
+        assert_eq!(ids.panel_id(), "tabs-billing-panel");
+        assert_eq!(
+            ids.trigger_attributes(),
+            vec![
+                ("id", "tabs-billing-trigger".to_string()),
+                ("aria-controls", "tabs-billing-panel".to_string()),
+            ]
+        );
+        assert_eq!(
+            ids.panel_attributes(),
+            vec![
+                ("id", "tabs-billing-panel".to_string()),
+                ("aria-labelledby", "tabs-billing-trigger".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_controlled_ids_from_base() {
+        let ids = ControlledIds::from_base("filters");
+
+        assert_eq!(ids.trigger_id(), "filters-trigger");
+        assert_eq!(ids.panel_id(), "filters-panel");
+    }
+
+    #[test]
+    fn test_disclosure_attributes_follow_expanded() {
+        let ids = ControlledIds::new("accordion", "faq-1");
+
+        let open = ids.disclosure_trigger_attributes(true);
+        assert!(open.contains(&("aria-expanded", "true".to_string())));
+        assert!(open.contains(&("aria-controls", "accordion-faq-1-panel".to_string())));
+
+        let panel = ids.disclosure_panel_attributes(true);
+        assert!(panel.contains(&("role", "region".to_string())));
+        assert!(!panel.iter().any(|(name, _)| *name == "hidden"));
+
+        let closed = ids.disclosure_trigger_attributes(false);
+        assert!(closed.contains(&("aria-expanded", "false".to_string())));
+        assert!(ids
+            .disclosure_panel_attributes(false)
+            .contains(&("hidden", "true".to_string())));
+    }
+}
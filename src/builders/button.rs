@@ -4,11 +4,23 @@
 //! with any component library or framework.
 
 use crate::core::color::ColorProvider;
-use crate::core::{Color, Size};
+use crate::core::{Color, Density, Radius, RadiusProvider, Size, SizeProvider};
+use crate::descriptors::{ComponentDescriptor, Describe};
+use crate::patterns::{
+    FocusBehavior, FocusManagement, InteractionIntensity, InteractiveElement, InteractiveState,
+};
+use crate::presets::{Preset, PresetDefinition};
+#[cfg(feature = "std")]
+use crate::themes::{global_theme, SharedTheme};
+use crate::utils::{class_diff, ClassDiff, Visibility};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use serde::{Deserialize, Serialize};
 
 /// Button variant types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum ButtonVariant {
     Primary,
     Secondary,
@@ -17,6 +29,38 @@ pub enum ButtonVariant {
     Error,
     Ghost,
     Link,
+    /// Custom action intent registered on the theme
+    ///
+    /// Serializes by name but cannot be deserialized, since the name is a
+    /// `&'static str`.
+    Custom(&'static str),
+}
+
+/// Built-in variants, so deserializing does not require `'static` input
+#[derive(Deserialize)]
+#[serde(rename = "ButtonVariant")]
+enum BuiltinButtonVariant {
+    Primary,
+    Secondary,
+    Success,
+    Warning,
+    Error,
+    Ghost,
+    Link,
+}
+
+impl<'de> Deserialize<'de> for ButtonVariant {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match BuiltinButtonVariant::deserialize(deserializer)? {
+            BuiltinButtonVariant::Primary => ButtonVariant::Primary,
+            BuiltinButtonVariant::Secondary => ButtonVariant::Secondary,
+            BuiltinButtonVariant::Success => ButtonVariant::Success,
+            BuiltinButtonVariant::Warning => ButtonVariant::Warning,
+            BuiltinButtonVariant::Error => ButtonVariant::Error,
+            BuiltinButtonVariant::Ghost => ButtonVariant::Ghost,
+            BuiltinButtonVariant::Link => ButtonVariant::Link,
+        })
+    }
 }
 
 /// Button state types
@@ -46,13 +90,18 @@ pub enum ButtonState {
 ///     .full_width()
 ///     .classes();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ButtonStyles<C: ColorProvider> {
     variant: ButtonVariant,
     size: Size,
     state: ButtonState,
     full_width: bool,
     with_icon: bool,
+    icon_only: bool,
+    density: Density,
+    visibility: Visibility,
+    interaction: InteractionIntensity,
+    focus_behavior: FocusBehavior,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -66,11 +115,33 @@ impl<C: ColorProvider> ButtonStyles<C> {
             state: ButtonState::Default,
             full_width: false,
             with_icon: false,
+            icon_only: false,
+            density: color_provider.density(),
+            visibility: Visibility::Always,
+            interaction: InteractionIntensity::Standard,
+            focus_behavior: FocusBehavior::Standard,
             custom_classes: Vec::new(),
             color_provider,
         }
     }
 
+    /// Create a button styling utility starting from the theme's button
+    /// defaults
+    ///
+    /// Unset defaults keep the builder's own (primary, medium); setters
+    /// called afterwards still win.
+    pub fn for_theme(color_provider: C) -> Self {
+        let defaults = color_provider.component_defaults().button;
+        let mut styles = Self::new(color_provider);
+        if let Some(variant) = defaults.variant {
+            styles.variant = variant;
+        }
+        if let Some(size) = defaults.size {
+            styles.size = size;
+        }
+        styles
+    }
+
     /// Set primary variant (shorthand)
     pub fn primary(mut self) -> Self {
         self.variant = ButtonVariant::Primary;
@@ -113,6 +184,28 @@ impl<C: ColorProvider> ButtonStyles<C> {
         self
     }
 
+    /// Set a custom action intent registered on the theme
+    ///
+    /// Intents the theme does not know fall back to secondary styling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::button::ButtonStyles;
+    /// use jupiter_design_system::core::IntentEmphasis;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let colors = VibeColors::default().with_intent("publish", "teal-600", IntentEmphasis::Filled);
+    ///
+    /// let classes = ButtonStyles::new(colors).custom_intent("publish").classes();
+    /// assert!(classes.contains("bg-teal-600"));
+    /// assert!(classes.contains("hover:bg-teal-600/90"));
+    /// ```
+    pub fn custom_intent(mut self, name: &'static str) -> Self {
+        self.variant = ButtonVariant::Custom(name);
+        self
+    }
+
     /// Set variant explicitly
     pub fn variant(mut self, variant: ButtonVariant) -> Self {
         self.variant = variant;
@@ -206,6 +299,24 @@ impl<C: ColorProvider> ButtonStyles<C> {
         self
     }
 
+    /// Set density (defaults to the color provider's density)
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set density from string ("compact", "comfortable", "spacious")
+    pub fn density_str(mut self, density: &str) -> Self {
+        self.density = Density::from_str_or_default(density);
+        self
+    }
+
+    /// Show or hide per breakpoint, e.g. `hidden_below(Breakpoint::Tablet)`
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
     /// Set disabled state (shorthand)
     pub fn disabled(mut self) -> Self {
         self.state = ButtonState::Disabled;
@@ -277,6 +388,48 @@ impl<C: ColorProvider> ButtonStyles<C> {
         self
     }
 
+    /// Render a square button holding only an icon
+    ///
+    /// The width matches the control height so the button stays square at
+    /// every size. Icon-only buttons need an `aria-label`.
+    pub fn icon_only(mut self) -> Self {
+        self.icon_only = true;
+        self
+    }
+
+    // === Interaction Methods ===
+
+    /// Set the hover and press effects (standard by default)
+    ///
+    /// Effects come from `InteractiveElement`, so buttons, cards and
+    /// selection items with the same intensity react the same way. Link
+    /// buttons only change color.
+    pub fn interaction_intensity(mut self, intensity: InteractionIntensity) -> Self {
+        self.interaction = intensity;
+        self
+    }
+
+    /// Set gentle interaction (subtle lift and shadow)
+    pub fn gentle(self) -> Self {
+        self.interaction_intensity(InteractionIntensity::Gentle)
+    }
+
+    /// Set standard interaction
+    pub fn standard_interaction(self) -> Self {
+        self.interaction_intensity(InteractionIntensity::Standard)
+    }
+
+    /// Set prominent interaction (strong lift, shadow and press)
+    pub fn prominent(self) -> Self {
+        self.interaction_intensity(InteractionIntensity::Prominent)
+    }
+
+    /// Set the focus ring (standard by default)
+    pub fn focus_behavior(mut self, behavior: FocusBehavior) -> Self {
+        self.focus_behavior = behavior;
+        self
+    }
+
     /// Add a custom CSS class
     ///
     /// This allows you to add any custom Tailwind classes or other CSS classes
@@ -348,43 +501,125 @@ impl<C: ColorProvider> ButtonStyles<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         self.build()
     }
 
+    /// Compare the classes of this button with another configuration
+    pub fn diff(&self, other: &Self) -> ClassDiff {
+        class_diff(&self.classes(), &other.classes())
+    }
+
+    /// Get the color provider
+    pub fn color_provider(&self) -> &C {
+        &self.color_provider
+    }
+
+    /// Get the suggested HTML element for this button
+    pub fn suggested_element(&self) -> &'static str {
+        match self.variant {
+            ButtonVariant::Link => "a",
+            _ => "button",
+        }
+    }
+
     /// Build the final CSS classes string (alias for classes)
-    pub fn build(self) -> String {
-        let base_classes = self.get_base_classes();
-        let size_classes = self.get_size_classes();
-        let variant_classes = self.get_variant_classes();
-        let state_classes = self.get_state_classes();
+    pub fn build(&self) -> String {
+        let parts = self.parts();
+        parts
+            .iter()
+            .flat_map(|(_, classes)| classes.split_whitespace())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    /// Break the classes down by the decision that produced them
+    ///
+    /// Sources are "base", "size", "variant", "interaction", "focus",
+    /// "width", "icon", "visibility" and "custom"; decisions that add no classes are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::button::ButtonStyles;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let explanation = ButtonStyles::new(VibeColors::default())
+    ///     .primary()
+    ///     .large()
+    ///     .explain();
+    ///
+    /// let (source, classes) = &explanation[1];
+    /// assert_eq!(*source, "size");
+    /// assert!(classes.contains("px-6"));
+    /// ```
+    pub fn explain(&self) -> Vec<(&'static str, String)> {
+        self.parts()
+            .into_iter()
+            .map(|(source, classes)| (source, classes.into_owned()))
+            .collect()
+    }
+
+    /// Class groups by source; static fragments are borrowed, not allocated
+    fn parts(&self) -> Vec<(&'static str, Cow<'static, str>)> {
         let width_classes = if self.full_width { "w-full" } else { "" };
         let icon_classes = if self.with_icon { "space-x-2" } else { "" };
-        let custom_classes = self.custom_classes.join(" ");
 
-        format!(
-            "{base_classes} {size_classes} {variant_classes} {state_classes} {width_classes} {icon_classes} {custom_classes}"
-        )
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ")
+        vec![
+            ("base", Cow::Borrowed(self.get_base_classes())),
+            ("size", Cow::Owned(self.get_size_classes())),
+            ("variant", Cow::Owned(self.get_variant_classes())),
+            (
+                "interaction",
+                Cow::Owned(self.interaction_classes(self.state)),
+            ),
+            ("focus", Cow::Owned(self.focus_management().classes())),
+            ("width", Cow::Borrowed(width_classes)),
+            ("icon", Cow::Borrowed(icon_classes)),
+            (
+                "visibility",
+                Cow::Owned(
+                    self.visibility
+                        .classes_for_scale("inline-flex", &self.color_provider.breakpoint_scale()),
+                ),
+            ),
+            ("custom", Cow::Owned(self.custom_classes.join(" "))),
+        ]
+        .into_iter()
+        .filter(|(_, classes)| !classes.trim().is_empty())
+        .collect()
     }
 
     /// Get base button classes
-    fn get_base_classes(&self) -> String {
-        "inline-flex items-center justify-center font-medium transition-colors duration-200 disabled:opacity-50 disabled:cursor-not-allowed".to_string()
+    fn get_base_classes(&self) -> &'static str {
+        "inline-flex items-center justify-center font-medium disabled:opacity-50 disabled:cursor-not-allowed"
     }
 
     /// Get size-specific classes
     fn get_size_classes(&self) -> String {
-        match self.size {
-            Size::XSmall => "px-2 py-1 text-xs rounded",
-            Size::Small => "px-3 py-1.5 text-sm rounded",
-            Size::Medium => "px-4 py-2 text-sm rounded-md",
-            Size::Large => "px-6 py-3 text-base rounded-md",
-            Size::XLarge => "px-8 py-4 text-lg rounded-lg",
-        }
-        .to_string()
+        // Horizontal only: the control height sets the vertical size
+        const PADDING: [&str; 7] = ["px-1.5", "px-2", "px-3", "px-4", "px-6", "px-8", "px-10"];
+
+        let (index, text, radius) = match self.size {
+            Size::XSmall => (1, "text-xs", Radius::ControlSmall),
+            Size::Small => (2, "text-sm", Radius::ControlSmall),
+            Size::Medium => (3, "text-sm", Radius::Control),
+            Size::Large => (4, "text-base", Radius::Control),
+            Size::XLarge => (5, "text-lg", Radius::Surface),
+        };
+        let sizes = self.color_provider.size_scale();
+        let padding = if self.icon_only {
+            format!("{} p-0", sizes.width_class(self.size))
+        } else {
+            self.density.scale(&PADDING, index).to_string()
+        };
+        format!(
+            "{} {} {} {}",
+            sizes.control_height_class(self.size),
+            padding,
+            text,
+            self.color_provider.radius_scale().radius_class(radius)
+        )
     }
 
     /// Get variant-specific classes
@@ -398,33 +633,27 @@ impl<C: ColorProvider> ButtonStyles<C> {
                 format!(
                     "{} {} {}",
                     self.color_provider.bg_class(Color::Primary),
-                    self.color_provider.text_class(Color::TextInverse),
+                    self.on_fill(Color::Primary),
                     hover_bg
                 )
             }
-            ButtonVariant::Secondary => format!(
-                "{} {} {} {}",
-                self.color_provider.bg_class(Color::Surface),
-                self.color_provider.text_class(Color::TextPrimary),
-                self.color_provider.border_class(Color::Border),
-                "border"
-            ),
+            ButtonVariant::Secondary => self.secondary_variant_classes(),
             ButtonVariant::Success => format!(
                 "{} {} {}",
                 self.color_provider.bg_class(Color::Success),
-                self.color_provider.text_class(Color::TextInverse),
+                self.on_fill(Color::Success),
                 "hover:bg-green-600"
             ),
             ButtonVariant::Warning => format!(
                 "{} {} {}",
                 self.color_provider.bg_class(Color::Warning),
-                self.color_provider.text_class(Color::TextInverse),
+                self.on_fill(Color::Warning),
                 "hover:bg-amber-600"
             ),
             ButtonVariant::Error => format!(
                 "{} {} {}",
                 self.color_provider.bg_class(Color::Error),
-                self.color_provider.text_class(Color::TextInverse),
+                self.on_fill(Color::Error),
                 "hover:bg-red-600"
             ),
             ButtonVariant::Ghost => {
@@ -442,26 +671,151 @@ impl<C: ColorProvider> ButtonStyles<C> {
                 self.color_provider.text_class(Color::Primary),
                 "hover:underline"
             ),
+            ButtonVariant::Custom(name) => self
+                .color_provider
+                .custom_intent_classes(name)
+                .unwrap_or_else(|| self.secondary_variant_classes()),
+        }
+    }
+
+    /// Text class for a label on a filled background
+    fn on_fill(&self, background: Color) -> String {
+        self.color_provider
+            .text_class(self.color_provider.on_color(background))
+    }
+
+    fn secondary_variant_classes(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider.text_class(Color::TextPrimary),
+            self.color_provider.border_class(Color::Border),
+            "border"
+        )
+    }
+
+    /// Hover, press, cursor and state classes from `InteractiveElement`
+    fn interaction_classes(&self, state: ButtonState) -> String {
+        let state = match state {
+            ButtonState::Default => InteractiveState::Default,
+            ButtonState::Hover => InteractiveState::Hover,
+            ButtonState::Active => InteractiveState::Active,
+            ButtonState::Disabled => InteractiveState::Disabled,
+            ButtonState::Loading => InteractiveState::Loading,
+        };
+        let element = InteractiveElement::new(&self.color_provider)
+            .intensity(self.interaction)
+            .state(state);
+        match self.variant {
+            // Links only change color, so they keep a color transition
+            ButtonVariant::Link => format!("transition-colors duration-200 {}", element.classes()),
+            _ => element.hoverable().pressable().classes(),
         }
     }
 
-    /// Get state-specific classes
-    fn get_state_classes(&self) -> String {
+    /// Get focus management for the button
+    pub fn focus_management(&self) -> FocusManagement<&C> {
+        let focus = FocusManagement::new(&self.color_provider);
+        let focus = match self.variant {
+            ButtonVariant::Link => focus.link(),
+            _ => focus.button(),
+        };
+        focus.focus_behavior(self.focus_behavior)
+    }
+
+    /// Get only the classes a state adds on top of the default button
+    ///
+    /// Useful for frameworks that toggle state classes at runtime instead of
+    /// rebuilding the full class string. Hover and press effects are
+    /// `hover:` and `active:` variants present in the default classes, so
+    /// those states add nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::button::{ButtonState, ButtonStyles};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let button = ButtonStyles::new(VibeColors::default()).primary();
+    ///
+    /// assert_eq!(button.classes_for_state(ButtonState::Default), "");
+    /// assert_eq!(
+    ///     button.classes_for_state(ButtonState::Disabled),
+    ///     "cursor-not-allowed opacity-50 pointer-events-none"
+    /// );
+    /// assert_eq!(button.classes_for_state(ButtonState::Hover), "");
+    /// ```
+    pub fn classes_for_state(&self, state: ButtonState) -> String {
+        class_diff(
+            &self.interaction_classes(ButtonState::Default),
+            &self.interaction_classes(state),
+        )
+        .added
+        .join(" ")
+    }
+}
+
+impl<C: ColorProvider + Clone> Describe for ButtonStyles<C> {
+    fn describe(&self) -> ComponentDescriptor {
+        let element = self.suggested_element();
+        let mut descriptor = ComponentDescriptor::new(element, self.classes());
+
+        if element == "button" {
+            descriptor = descriptor.with_attribute("type", "button");
+        }
         match self.state {
-            ButtonState::Default => "".to_string(),
-            ButtonState::Hover => "hover:scale-105".to_string(),
-            ButtonState::Active => "active:scale-95".to_string(),
-            ButtonState::Disabled => "opacity-50 cursor-not-allowed".to_string(),
-            ButtonState::Loading => "cursor-wait".to_string(),
+            ButtonState::Disabled => descriptor.with_attribute("aria-disabled", "true"),
+            ButtonState::Loading => descriptor
+                .with_attribute("aria-disabled", "true")
+                .with_attribute("aria-busy", "true"),
+            _ => descriptor,
         }
     }
 }
 
+impl<C: ColorProvider> Preset<C> for ButtonStyles<C> {
+    const COMPONENT: &'static str = "button";
+
+    /// Options: "variant", "size", "state", "density", and the flags
+    /// "full_width", "with_icon" and "icon_only"
+    fn from_preset(color_provider: C, preset: &PresetDefinition) -> Self {
+        let mut styles = Self::for_theme(color_provider);
+        if let Some(variant) = preset.get("variant") {
+            styles = styles.variant_str(variant);
+        }
+        if let Some(size) = preset.get("size") {
+            styles = styles.size_str(size);
+        }
+        if let Some(state) = preset.get("state") {
+            styles = styles.state_str(state);
+        }
+        if let Some(density) = preset.get("density") {
+            styles = styles.density_str(density);
+        }
+        if preset.flag("full_width") {
+            styles = styles.full_width();
+        }
+        if preset.flag("with_icon") {
+            styles = styles.with_icon();
+        }
+        if preset.flag("icon_only") {
+            styles = styles.icon_only();
+        }
+        styles.custom_vec(preset.custom.clone())
+    }
+}
+
 /// Convenience function to create button styles
 pub fn button_styles<C: ColorProvider>(color_provider: C) -> ButtonStyles<C> {
     ButtonStyles::new(color_provider)
 }
 
+/// Convenience function to create button styles from the global theme
+#[cfg(feature = "std")]
+pub fn button_styles_default() -> ButtonStyles<SharedTheme> {
+    ButtonStyles::new(global_theme())
+}
+
 /// One-shot convenience function to create button classes from strings
 ///
 /// This completely replaces the need for ButtonUtils::classes() and similar utility functions.
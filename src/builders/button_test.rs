@@ -5,7 +5,9 @@ mod tests {
     use crate::builders::button::{
         button_classes_from_strings, button_styles, ButtonState, ButtonStyles, ButtonVariant,
     };
-    use crate::themes::VibeColors;
+    use crate::core::{Breakpoint, Density, RadiusScale, Size, SizeScale};
+    use crate::themes::{ButtonDefaults, ComponentDefaults, VibeColors};
+    use crate::utils::visibility::{hidden_below, only_on};
 
     fn create_test_colors() -> VibeColors {
         VibeColors::new()
@@ -20,8 +22,8 @@ mod tests {
         assert!(classes.contains("items-center"));
         assert!(classes.contains("justify-center"));
         assert!(classes.contains("font-medium"));
-        assert!(classes.contains("transition-colors"));
-        assert!(classes.contains("px-4 py-2")); // medium size
+        assert!(classes.contains("transition-all"));
+        assert!(classes.contains("px-4")); // medium size
         assert!(classes.contains("bg-jupiter-blue-500")); // primary variant
     }
 
@@ -36,7 +38,7 @@ mod tests {
             .classes();
 
         assert!(classes.contains("bg-green-500")); // success variant
-        assert!(classes.contains("px-6 py-3")); // large size
+        assert!(classes.contains("px-6")); // large size
         assert!(classes.contains("w-full")); // full width
         assert!(classes.contains("space-x-2")); // with icon
     }
@@ -48,7 +50,7 @@ mod tests {
         // Primary
         let primary = ButtonStyles::new(colors.clone()).primary().classes();
         assert!(primary.contains("bg-jupiter-blue-500"));
-        assert!(primary.contains("text-white"));
+        assert!(primary.contains("text-gray-900")); // white is under 4.5:1 on blue-500
 
         // Secondary
         let secondary = ButtonStyles::new(colors.clone()).secondary().classes();
@@ -59,17 +61,17 @@ mod tests {
         // Success
         let success = ButtonStyles::new(colors.clone()).success().classes();
         assert!(success.contains("bg-green-500"));
-        assert!(success.contains("text-white"));
+        assert!(success.contains("text-gray-900")); // white is under 3:1 on green-500
 
         // Warning
         let warning = ButtonStyles::new(colors.clone()).warning().classes();
         assert!(warning.contains("bg-amber-500"));
-        assert!(warning.contains("text-white"));
+        assert!(warning.contains("text-gray-900")); // white is under 3:1 on amber-500
 
         // Error
         let error = ButtonStyles::new(colors.clone()).error().classes();
         assert!(error.contains("bg-red-500"));
-        assert!(error.contains("text-white"));
+        assert!(error.contains("text-gray-900")); // white is under 4.5:1 on red-500
 
         // Ghost
         let ghost = ButtonStyles::new(colors.clone()).ghost().classes();
@@ -89,33 +91,39 @@ mod tests {
 
         // Extra small
         let xs = ButtonStyles::new(colors.clone()).extra_small().classes();
-        assert!(xs.contains("px-2 py-1"));
+        assert!(xs.contains("px-2"));
         assert!(xs.contains("text-xs"));
         assert!(xs.contains("rounded"));
 
         // Small
         let sm = ButtonStyles::new(colors.clone()).small().classes();
-        assert!(sm.contains("px-3 py-1.5"));
+        assert!(sm.contains("px-3"));
         assert!(sm.contains("text-sm"));
         assert!(sm.contains("rounded"));
 
         // Medium
         let md = ButtonStyles::new(colors.clone()).medium().classes();
-        assert!(md.contains("px-4 py-2"));
+        assert!(md.contains("px-4"));
         assert!(md.contains("text-sm"));
         assert!(md.contains("rounded-md"));
 
         // Large
         let lg = ButtonStyles::new(colors.clone()).large().classes();
-        assert!(lg.contains("px-6 py-3"));
+        assert!(lg.contains("px-6"));
         assert!(lg.contains("text-base"));
         assert!(lg.contains("rounded-md"));
 
         // Extra large
         let xl = ButtonStyles::new(colors.clone()).extra_large().classes();
-        assert!(xl.contains("px-8 py-4"));
+        assert!(xl.contains("px-8"));
         assert!(xl.contains("text-lg"));
         assert!(xl.contains("rounded-lg"));
+
+        // The control height sets the vertical size, so no vertical padding
+        for classes in [&xs, &sm, &md, &lg, &xl] {
+            assert!(classes.contains(" h-"));
+            assert!(!classes.contains("py-"));
+        }
     }
 
     #[test]
@@ -150,7 +158,7 @@ mod tests {
             .classes();
 
         assert!(classes.contains("bg-jupiter-blue-500"));
-        assert!(classes.contains("px-6 py-3"));
+        assert!(classes.contains("px-6"));
         assert!(classes.contains("w-full"));
     }
 
@@ -183,7 +191,7 @@ mod tests {
             assert!(variant_classes.contains("items-center"));
             assert!(variant_classes.contains("justify-center"));
             assert!(variant_classes.contains("font-medium"));
-            assert!(variant_classes.contains("transition-colors"));
+            assert!(variant_classes.contains("transition-"));
             assert!(variant_classes.contains("duration-200"));
             assert!(variant_classes.contains("disabled:opacity-50"));
             assert!(variant_classes.contains("disabled:cursor-not-allowed"));
@@ -273,7 +281,7 @@ mod tests {
 
         // Design system classes
         assert!(classes.contains("bg-jupiter-blue-500")); // primary
-        assert!(classes.contains("px-6 py-3")); // large
+        assert!(classes.contains("px-6")); // large
         assert!(classes.contains("w-full")); // full width
 
         // Custom classes
@@ -294,7 +302,7 @@ mod tests {
             .classes();
 
         assert!(classes.contains("bg-jupiter-blue-500"));
-        assert!(classes.contains("px-6 py-3"));
+        assert!(classes.contains("px-6"));
         assert!(classes.contains("shadow-xl"));
         assert!(classes.contains("transform"));
     }
@@ -373,36 +381,36 @@ mod tests {
 
         // Test standard sizes
         let xs = ButtonStyles::new(colors.clone()).size_str("xs").classes();
-        assert!(xs.contains("px-2 py-1"));
+        assert!(xs.contains("px-2"));
 
         let sm = ButtonStyles::new(colors.clone()).size_str("sm").classes();
-        assert!(sm.contains("px-3 py-1.5"));
+        assert!(sm.contains("px-3"));
 
         let md = ButtonStyles::new(colors.clone()).size_str("md").classes();
-        assert!(md.contains("px-4 py-2"));
+        assert!(md.contains("px-4"));
 
         let lg = ButtonStyles::new(colors.clone()).size_str("lg").classes();
-        assert!(lg.contains("px-6 py-3"));
+        assert!(lg.contains("px-6"));
 
         let xl = ButtonStyles::new(colors.clone()).size_str("xl").classes();
-        assert!(xl.contains("px-8 py-4"));
+        assert!(xl.contains("px-8"));
 
         // Test aliases
         let small = ButtonStyles::new(colors.clone())
             .size_str("small")
             .classes();
-        assert!(small.contains("px-3 py-1.5")); // maps to sm
+        assert!(small.contains("px-3")); // maps to sm
 
         let large = ButtonStyles::new(colors.clone())
             .size_str("large")
             .classes();
-        assert!(large.contains("px-6 py-3")); // maps to lg
+        assert!(large.contains("px-6")); // maps to lg
 
         // Test fallback
         let unknown = ButtonStyles::new(colors.clone())
             .size_str("unknown")
             .classes();
-        assert!(unknown.contains("px-4 py-2")); // fallback to medium
+        assert!(unknown.contains("px-4")); // fallback to medium
     }
 
     #[test]
@@ -459,7 +467,7 @@ mod tests {
         );
 
         assert!(classes.contains("bg-jupiter-blue-500")); // primary variant
-        assert!(classes.contains("px-6 py-3")); // large size
+        assert!(classes.contains("px-6")); // large size
         assert!(classes.contains("w-full")); // full width
         assert!(classes.contains("disabled:opacity-50")); // base class always present
         assert!(!classes.contains("cursor-wait")); // not loading
@@ -475,7 +483,7 @@ mod tests {
         );
 
         assert!(loading_classes.contains("bg-white")); // secondary variant
-        assert!(loading_classes.contains("px-3 py-1.5")); // small size
+        assert!(loading_classes.contains("px-3")); // small size
         assert!(loading_classes.contains("cursor-wait")); // loading state
         assert!(!loading_classes.contains("w-full")); // not full width
 
@@ -490,7 +498,7 @@ mod tests {
         );
 
         assert!(disabled_classes.contains("bg-green-500")); // success variant
-        assert!(disabled_classes.contains("px-4 py-2")); // medium size
+        assert!(disabled_classes.contains("px-4")); // medium size
         assert!(disabled_classes.contains("opacity-50")); // disabled state
         assert!(disabled_classes.contains("cursor-not-allowed")); // disabled cursor
 
@@ -505,7 +513,7 @@ mod tests {
         );
 
         assert!(alias_classes.contains("bg-white")); // secondary variant (from outline alias)
-        assert!(alias_classes.contains("px-8 py-4")); // extra large size
+        assert!(alias_classes.contains("px-8")); // extra large size
     }
 
     #[test]
@@ -522,9 +530,345 @@ mod tests {
             .classes();
 
         assert!(classes.contains("bg-jupiter-blue-500")); // primary variant
-        assert!(classes.contains("px-6 py-3")); // large size
+        assert!(classes.contains("px-6")); // large size
         assert!(classes.contains("hover:scale-105")); // hover state
         assert!(classes.contains("w-full")); // full width
         assert!(classes.contains("shadow-xl")); // custom class
     }
+
+    #[test]
+    fn test_button_styles_density() {
+        let colors = create_test_colors();
+
+        let comfortable = ButtonStyles::new(colors.clone()).medium().classes();
+        assert!(comfortable.contains("px-4"));
+
+        let compact = ButtonStyles::new(colors.clone())
+            .medium()
+            .density(Density::Compact)
+            .classes();
+        assert!(compact.contains("px-3"));
+        assert!(compact.contains("text-sm"));
+
+        let spacious = ButtonStyles::new(colors.clone())
+            .medium()
+            .density_str("spacious")
+            .classes();
+        assert!(spacious.contains("px-6"));
+
+        // Theme default applies without touching the call site
+        let themed = ButtonStyles::new(colors.with_density(Density::Compact))
+            .medium()
+            .classes();
+        assert_eq!(themed, compact);
+    }
+
+    #[test]
+    fn test_button_styles_radius_scale() {
+        let colors = create_test_colors();
+
+        let classes = ButtonStyles::new(colors.clone()).medium().classes();
+        assert!(classes.contains("rounded-md"));
+
+        let sharp = colors.with_radius(RadiusScale::sharp());
+        let classes = ButtonStyles::new(sharp.clone()).medium().classes();
+        assert!(classes.contains("rounded-sm"));
+        assert!(!classes.contains("rounded-md"));
+
+        let classes = ButtonStyles::new(sharp).extra_large().classes();
+        assert!(classes.contains("rounded"));
+        assert!(!classes.contains("rounded-lg"));
+    }
+
+    #[test]
+    fn test_button_classes_for_state() {
+        let button = ButtonStyles::new(create_test_colors()).primary().large();
+
+        assert_eq!(button.classes_for_state(ButtonState::Default), "");
+        assert_eq!(button.classes_for_state(ButtonState::Hover), "");
+        assert_eq!(
+            button.classes_for_state(ButtonState::Loading),
+            "cursor-wait opacity-75"
+        );
+
+        // The delta matches what the full build adds for that state
+        let diff = button.diff(&button.clone().loading());
+        assert_eq!(diff.added, vec!["cursor-wait", "opacity-75"]);
+        assert_eq!(diff.removed, vec!["cursor-pointer"]);
+    }
+
+    #[test]
+    fn test_button_styles_diff() {
+        let colors = create_test_colors();
+
+        let medium = ButtonStyles::new(colors.clone()).primary().medium();
+        let large = ButtonStyles::new(colors.clone()).primary().large();
+        let diff = medium.diff(&large);
+        assert!(diff.removed.contains(&"px-4".to_string()));
+        assert!(diff.added.contains(&"px-6".to_string()));
+        assert!(diff.added.contains(&"text-base".to_string()));
+
+        // Theme changes are reported the same way
+        let sharp = ButtonStyles::new(colors.with_radius(RadiusScale::sharp()))
+            .primary()
+            .medium();
+        let diff = medium.diff(&sharp);
+        assert_eq!(diff.removed, vec!["rounded-md"]);
+        assert_eq!(diff.added, vec!["rounded-sm"]);
+
+        assert!(medium.diff(&medium.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_suggested_element() {
+        let colors = VibeColors::default();
+
+        assert_eq!(button_styles(colors.clone()).suggested_element(), "button");
+        assert_eq!(button_styles(colors).link().suggested_element(), "a");
+    }
+
+    #[test]
+    fn test_visibility_keeps_inline_flex() {
+        let styles = button_styles(create_test_colors()).visibility(only_on(Breakpoint::Mobile));
+
+        assert!(styles
+            .explain()
+            .contains(&("visibility", "md:hidden".to_string())));
+
+        let classes = button_styles(create_test_colors())
+            .visibility(hidden_below(Breakpoint::Desktop))
+            .classes();
+        assert!(classes.contains("lg:inline-flex"));
+    }
+
+    #[test]
+    fn test_control_heights() {
+        let colors = create_test_colors();
+
+        assert!(button_styles(colors.clone())
+            .small()
+            .classes()
+            .contains("h-8"));
+        assert!(button_styles(colors.clone())
+            .medium()
+            .classes()
+            .contains("h-10"));
+        assert!(button_styles(colors.clone())
+            .large()
+            .classes()
+            .contains("h-12"));
+
+        // Density changes padding, not height
+        assert!(button_styles(colors)
+            .density(Density::Compact)
+            .classes()
+            .contains("h-10"));
+    }
+
+    #[test]
+    fn test_control_height_matches_input() {
+        use crate::builders::interactive::interactive_input;
+
+        let colors = create_test_colors();
+        let button = button_styles(colors.clone()).size(Size::Large).classes();
+        let input = interactive_input(colors)
+            .size(Size::Large)
+            .standard_style()
+            .build();
+
+        assert!(button.split_whitespace().any(|class| class == "h-12"));
+        assert!(input.split_whitespace().any(|class| class == "h-12"));
+    }
+
+    #[test]
+    fn test_theme_control_heights() {
+        let colors = create_test_colors().with_sizes(SizeScale::touch());
+
+        assert!(button_styles(colors).medium().classes().contains("h-12"));
+    }
+
+    #[test]
+    fn test_state_scales_follow_motion_tokens() {
+        use crate::core::MotionScale;
+
+        let colors = VibeColors::default().with_motion(MotionScale {
+            hover: 102,
+            arbitrary_scales: true,
+            ..MotionScale::default()
+        });
+        let classes = ButtonStyles::new(colors).classes();
+
+        assert!(classes.contains("hover:scale-[1.02]"));
+        assert!(classes.contains("active:scale-95"));
+    }
+
+    #[test]
+    fn test_icon_only_is_square() {
+        let classes = ButtonStyles::new(VibeColors::default())
+            .ghost()
+            .small()
+            .icon_only()
+            .classes();
+
+        assert!(classes.contains("h-8"));
+        assert!(classes.contains("w-8"));
+        assert!(classes.contains("p-0"));
+        assert!(!classes.contains("px-3"));
+    }
+
+    #[test]
+    fn test_custom_intent_variant() {
+        use crate::core::IntentEmphasis;
+        use crate::patterns::{action_semantics, ButtonPattern};
+        use crate::utils::ThemeLint;
+
+        let colors =
+            VibeColors::default().with_intent("archive", "slate-500", IntentEmphasis::Outline);
+
+        let button = ButtonStyles::new(colors.clone())
+            .custom_intent("archive")
+            .classes();
+        assert!(button.contains("text-slate-500"));
+        assert!(ThemeLint::new(&colors).is_clean(&button));
+
+        let semantics = action_semantics(colors.clone())
+            .custom_intent("archive")
+            .classes();
+        assert!(semantics.contains("border-slate-500"));
+
+        let pattern = ButtonPattern::new(colors.clone())
+            .custom_action("archive")
+            .classes();
+        assert!(pattern.contains("border-slate-500"));
+
+        // Unknown intents fall back to secondary styling
+        let unknown = ButtonStyles::new(colors.clone())
+            .custom_intent("publish")
+            .classes();
+        let secondary = ButtonStyles::new(colors).secondary().classes();
+        assert_eq!(unknown, secondary);
+    }
+
+    #[test]
+    fn test_for_theme_starts_from_theme_defaults() {
+        let colors = create_test_colors().with_component_defaults(ComponentDefaults {
+            button: ButtonDefaults {
+                variant: Some(ButtonVariant::Secondary),
+                size: Some(Size::Large),
+            },
+            ..ComponentDefaults::default()
+        });
+
+        let themed = ButtonStyles::for_theme(colors.clone());
+        assert_eq!(
+            themed.classes(),
+            ButtonStyles::new(colors.clone())
+                .secondary()
+                .large()
+                .classes()
+        );
+
+        // Setters still win over the defaults
+        let small = ButtonStyles::for_theme(colors.clone()).small().primary();
+        assert_eq!(
+            small.classes(),
+            ButtonStyles::new(colors.clone()).small().classes()
+        );
+
+        // `new` ignores the defaults
+        assert_eq!(
+            ButtonStyles::new(colors).classes(),
+            ButtonStyles::new(create_test_colors()).classes()
+        );
+    }
+
+    #[test]
+    fn test_for_theme_without_defaults_matches_new() {
+        let colors = create_test_colors();
+        assert_eq!(
+            ButtonStyles::for_theme(&colors).classes(),
+            ButtonStyles::new(&colors).classes()
+        );
+    }
+
+    #[test]
+    fn test_interaction_comes_from_interactive_element() {
+        use crate::patterns::{InteractionIntensity, InteractiveElement};
+
+        let colors = create_test_colors();
+        for intensity in [
+            InteractionIntensity::Gentle,
+            InteractionIntensity::Standard,
+            InteractionIntensity::Prominent,
+        ] {
+            let button = ButtonStyles::new(&colors).interaction_intensity(intensity);
+            let element = InteractiveElement::new(&colors)
+                .hoverable()
+                .pressable()
+                .intensity(intensity)
+                .classes();
+
+            assert!(button.explain().contains(&("interaction", element.clone())));
+        }
+
+        // Links only change color
+        let link = ButtonStyles::new(&colors).link().classes();
+        assert!(link.contains("transition-colors"));
+        assert!(!link.contains("hover:scale"));
+    }
+
+    #[test]
+    fn test_focus_ring_follows_focus_behavior() {
+        use crate::patterns::FocusBehavior;
+
+        let colors = create_test_colors();
+        let standard = ButtonStyles::new(&colors).classes();
+        assert!(standard.contains("focus:ring-2 focus:ring-offset-2"));
+        assert!(standard.contains("focus:ring-jupiter-blue-300"));
+
+        let prominent = ButtonStyles::new(&colors)
+            .focus_behavior(FocusBehavior::Prominent)
+            .classes();
+        assert!(prominent.contains("focus:ring-4"));
+
+        let none = ButtonStyles::new(&colors)
+            .focus_behavior(FocusBehavior::None)
+            .classes();
+        assert!(none.contains("focus:ring-0"));
+        assert!(!none.contains("focus:ring-2"));
+    }
+
+    #[test]
+    fn test_intensity_shorthands() {
+        use crate::patterns::InteractionIntensity;
+
+        let colors = create_test_colors();
+        let shorthands = [
+            (
+                ButtonStyles::new(&colors).gentle(),
+                InteractionIntensity::Gentle,
+            ),
+            (
+                ButtonStyles::new(&colors)
+                    .prominent()
+                    .standard_interaction(),
+                InteractionIntensity::Standard,
+            ),
+            (
+                ButtonStyles::new(&colors).prominent(),
+                InteractionIntensity::Prominent,
+            ),
+        ];
+
+        for (button, intensity) in shorthands {
+            assert_eq!(
+                button,
+                ButtonStyles::new(&colors).interaction_intensity(intensity)
+            );
+        }
+        assert!(ButtonStyles::new(&colors)
+            .prominent()
+            .classes()
+            .contains("active:scale-90"));
+    }
 }
@@ -0,0 +1,212 @@
+//! Callout styling utilities for the Jupiter Design System
+//!
+//! Provides classes for documentation-style callouts (note, tip, warning,
+//! danger) and block quotations: a left accent border in the semantic
+//! color, a tinted background, an icon slot, and paired title/body
+//! typography.
+
+use crate::builders::text::TextStyles;
+use crate::core::color::ColorProvider;
+use crate::core::Color;
+use crate::patterns::quote_typography;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Callout kinds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CalloutKind {
+    /// Neutral supplementary information
+    Note,
+    /// Helpful suggestion
+    Tip,
+    /// Something to be careful about
+    Warning,
+    /// Something that can break or lose data
+    Danger,
+    /// Block quotation
+    Quote,
+}
+
+/// Callout styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::callout::CalloutStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let callout = CalloutStyles::new(VibeColors::default()).warning();
+///
+/// let container_classes = callout.classes();
+/// let icon_classes = callout.icon_classes();
+/// let title_classes = callout.title_classes();
+/// let body_classes = callout.body_classes();
+/// assert_eq!(callout.suggested_element(), "aside");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalloutStyles<C: ColorProvider + Clone> {
+    kind: CalloutKind,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> CalloutStyles<C> {
+    /// Create a new callout styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            kind: CalloutKind::Note,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Kind Methods ===
+
+    /// Set note kind
+    pub fn note(mut self) -> Self {
+        self.kind = CalloutKind::Note;
+        self
+    }
+
+    /// Set tip kind
+    pub fn tip(mut self) -> Self {
+        self.kind = CalloutKind::Tip;
+        self
+    }
+
+    /// Set warning kind
+    pub fn warning(mut self) -> Self {
+        self.kind = CalloutKind::Warning;
+        self
+    }
+
+    /// Set danger kind
+    pub fn danger(mut self) -> Self {
+        self.kind = CalloutKind::Danger;
+        self
+    }
+
+    /// Set block quotation kind
+    pub fn quote(mut self) -> Self {
+        self.kind = CalloutKind::Quote;
+        self
+    }
+
+    /// Set kind explicitly
+    pub fn kind(mut self, kind: CalloutKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set kind from string
+    pub fn kind_str(mut self, kind: &str) -> Self {
+        self.kind = match kind {
+            "note" | "info" => CalloutKind::Note,
+            "tip" | "success" => CalloutKind::Tip,
+            "warning" | "caution" => CalloutKind::Warning,
+            "danger" | "error" => CalloutKind::Danger,
+            "quote" | "blockquote" => CalloutKind::Quote,
+            _ => CalloutKind::Note, // fallback
+        };
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the callout container
+    pub fn classes(&self) -> String {
+        let mut classes = vec![
+            format!(
+                "callout callout--{} flex gap-3 border-l-4 py-3 pl-4 pr-4",
+                self.kind_name()
+            ),
+            self.color_provider.border_class(self.accent_color()),
+        ];
+        if self.kind != CalloutKind::Quote {
+            classes.push(format!(
+                "rounded-r-md {}/10",
+                self.color_provider.bg_class(self.accent_color())
+            ));
+        }
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the icon slot
+    pub fn icon_classes(&self) -> String {
+        format!(
+            "callout-icon shrink-0 w-5 h-5 mt-0.5 {}",
+            self.color_provider.text_class(self.accent_color())
+        )
+    }
+
+    /// Build classes for the content column next to the icon
+    pub fn content_classes(&self) -> String {
+        "callout-content flex-1 min-w-0 space-y-1".to_string()
+    }
+
+    /// Build classes for the callout title
+    pub fn title_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .body()
+            .semibold()
+            .classes()
+    }
+
+    /// Build classes for the callout body
+    pub fn body_classes(&self) -> String {
+        match self.kind {
+            CalloutKind::Quote => quote_typography(self.color_provider.clone()).classes(),
+            _ => TextStyles::new(self.color_provider.clone())
+                .body_small()
+                .classes(),
+        }
+    }
+
+    /// Get the suggested HTML element for the container
+    pub fn suggested_element(&self) -> &'static str {
+        match self.kind {
+            CalloutKind::Quote => "blockquote",
+            _ => "aside",
+        }
+    }
+
+    fn accent_color(&self) -> Color {
+        match self.kind {
+            CalloutKind::Note => Color::Info,
+            CalloutKind::Tip => Color::Success,
+            CalloutKind::Warning => Color::Warning,
+            CalloutKind::Danger => Color::Error,
+            CalloutKind::Quote => Color::Border,
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self.kind {
+            CalloutKind::Note => "note",
+            CalloutKind::Tip => "tip",
+            CalloutKind::Warning => "warning",
+            CalloutKind::Danger => "danger",
+            CalloutKind::Quote => "quote",
+        }
+    }
+}
+
+/// Convenience function to create callout styles
+pub fn callout_styles<C: ColorProvider + Clone>(color_provider: C) -> CalloutStyles<C> {
+    CalloutStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "callout_test.rs"]
+mod callout_test;
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::callout::{callout_styles, CalloutKind, CalloutStyles};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_callout_accent_per_kind() {
+        let colors = VibeColors::default();
+
+        let note = callout_styles(colors.clone()).note().classes();
+        assert!(note.contains("border-l-4"));
+        assert!(note.contains("border-blue-500"));
+        assert!(note.contains("bg-blue-500/10"));
+
+        let tip = callout_styles(colors.clone()).tip().classes();
+        assert!(tip.contains("border-green-500"));
+
+        let warning = callout_styles(colors.clone()).warning().classes();
+        assert!(warning.contains("border-amber-500"));
+
+        let danger = callout_styles(colors).danger().classes();
+        assert!(danger.contains("border-red-500"));
+        assert!(danger.contains("callout--danger"));
+    }
+
+    #[test]
+    fn test_callout_icon_and_typography() {
+        let callout = callout_styles(VibeColors::default()).tip();
+
+        assert!(callout.icon_classes().contains("shrink-0"));
+        assert!(callout.icon_classes().contains("text-green-500"));
+        assert!(callout.classes().contains("gap-3"));
+        assert!(callout.title_classes().contains("font-semibold"));
+        assert!(!callout.body_classes().is_empty());
+    }
+
+    #[test]
+    fn test_quote_kind() {
+        let quote = callout_styles(VibeColors::default()).quote();
+
+        assert_eq!(quote.suggested_element(), "blockquote");
+        assert!(quote.classes().contains("border-gray-200"));
+        assert!(!quote.classes().contains("/10"));
+        assert!(quote.body_classes().contains("text-gray-600"));
+    }
+
+    #[test]
+    fn test_kind_str() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            CalloutStyles::new(colors.clone())
+                .kind_str("caution")
+                .classes(),
+            CalloutStyles::new(colors.clone())
+                .kind(CalloutKind::Warning)
+                .classes()
+        );
+        assert_eq!(
+            CalloutStyles::new(colors.clone())
+                .kind_str("invalid")
+                .classes(),
+            CalloutStyles::new(colors).note().classes()
+        );
+    }
+
+    #[test]
+    fn test_custom_classes() {
+        let classes = callout_styles(VibeColors::default())
+            .custom("my-6")
+            .classes();
+
+        assert!(classes.contains("my-6"));
+    }
+}
@@ -4,7 +4,27 @@
 //! with any component library or framework.
 
 use crate::core::color::ColorProvider;
-use crate::patterns::{CardElevation, CardInteraction, CardSpacing, CardSurface};
+use crate::core::{
+    BorderProvider, BorderWidth, Density, ElevationProvider, GlassTone, MotionProvider, Radius,
+    RadiusProvider, ScaleEffect,
+};
+use crate::descriptors::{ComponentDescriptor, Describe};
+use crate::patterns::card::{
+    branded_surface_classes, card_placeholder_classes, glass_surface_classes, loading_card_classes,
+    nested_card_classes,
+};
+use crate::patterns::{
+    CardElevation, CardInteraction, CardPart, CardSpacing, CardSurface, InteractionIntensity,
+    InteractiveElement,
+};
+use crate::presets::{Preset, PresetDefinition};
+#[cfg(feature = "std")]
+use crate::themes::{global_theme, SharedTheme};
+use crate::utils::{class_diff, ClassDiff, Visibility};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Card styling utility builder
 ///
@@ -24,13 +44,20 @@ use crate::patterns::{CardElevation, CardInteraction, CardSpacing, CardSurface};
 ///     .clickable_interaction()
 ///     .classes();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CardStyles<C: ColorProvider> {
     elevation: CardElevation,
     surface: CardSurface,
     spacing: CardSpacing,
     interaction: CardInteraction,
+    intensity: Option<InteractionIntensity>,
     selected: bool,
+    nested: bool,
+    loading: bool,
+    density: Density,
+    visibility: Visibility,
+    container_visibility: bool,
+    container_query: bool,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -43,12 +70,42 @@ impl<C: ColorProvider> CardStyles<C> {
             surface: CardSurface::Standard,
             spacing: CardSpacing::Standard,
             interaction: CardInteraction::Static,
+            intensity: None,
             selected: false,
+            nested: false,
+            loading: false,
+            density: color_provider.density(),
+            visibility: Visibility::Always,
+            container_visibility: false,
+            container_query: false,
             custom_classes: Vec::new(),
             color_provider,
         }
     }
 
+    /// Create a card styling utility starting from the theme's card
+    /// defaults
+    ///
+    /// Unset defaults keep the builder's own (subtle elevation, standard
+    /// surface and spacing, static); setters called afterwards still win.
+    pub fn for_theme(color_provider: C) -> Self {
+        let defaults = color_provider.component_defaults().card;
+        let mut styles = Self::new(color_provider);
+        if let Some(elevation) = defaults.elevation {
+            styles.elevation = elevation;
+        }
+        if let Some(surface) = defaults.surface {
+            styles.surface = surface;
+        }
+        if let Some(spacing) = defaults.spacing {
+            styles.spacing = spacing;
+        }
+        if let Some(interaction) = defaults.interaction {
+            styles.interaction = interaction;
+        }
+        styles
+    }
+
     // === Elevation Methods ===
 
     /// Set flat elevation (no shadow)
@@ -106,7 +163,7 @@ impl<C: ColorProvider> CardStyles<C> {
         self
     }
 
-    /// Set elevated surface (subtle background tint)
+    /// Set elevated surface (raised level of the surface ladder)
     pub fn elevated_surface(mut self) -> Self {
         self.surface = CardSurface::Elevated;
         self
@@ -124,6 +181,32 @@ impl<C: ColorProvider> CardStyles<C> {
         self
     }
 
+    /// Set glass morphism surface for light pages
+    ///
+    /// Tinted with the surface color and using primary text, where
+    /// `glass_surface()` is meant for dark or image backgrounds. Both fall
+    /// back to a nearly opaque background without `backdrop-filter`.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::card_styles;
+    /// use jupiter_design_system::core::GlassScale;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let colors = VibeColors::default().with_glass(GlassScale {
+    ///     blur: 24,
+    ///     ..GlassScale::default()
+    /// });
+    /// let classes = card_styles(colors).glass_light_surface().classes();
+    ///
+    /// assert!(classes.contains("text-gray-900"));
+    /// assert!(classes.contains("bg-white/90"));
+    /// assert!(classes.contains("supports-[backdrop-filter]:backdrop-blur-xl"));
+    /// ```
+    pub fn glass_light_surface(mut self) -> Self {
+        self.surface = CardSurface::GlassLight;
+        self
+    }
+
     /// Set dark surface
     pub fn dark_surface(mut self) -> Self {
         self.surface = CardSurface::Dark;
@@ -136,17 +219,27 @@ impl<C: ColorProvider> CardStyles<C> {
         self
     }
 
+    /// Set overlay surface (menus, popovers and modals)
+    pub fn overlay_surface(mut self) -> Self {
+        self.surface = CardSurface::Overlay;
+        self
+    }
+
     /// Set surface from string (convenience method)
     ///
     /// Maps common string surfaces to CardSurface enum.
-    /// Supports: "standard", "elevated", "branded", "glass", "dark", "transparent"
-    /// Also supports aliases: "white" -> Standard, "theme" -> Branded, "clear" -> Transparent
+    /// Supports: "standard", "elevated", "overlay", "branded", "glass", "glass-light", "dark",
+    /// "transparent"
+    /// Also supports aliases: "white" -> Standard, "theme" -> Branded, "clear" -> Transparent,
+    /// "popover"/"menu" -> Overlay
     pub fn surface_str(mut self, surface: &str) -> Self {
         self.surface = match surface {
             "standard" | "white" => CardSurface::Standard,
-            "elevated" => CardSurface::Elevated,
+            "elevated" | "raised" => CardSurface::Elevated,
+            "overlay" | "popover" | "menu" => CardSurface::Overlay,
             "branded" | "theme" => CardSurface::Branded,
             "glass" => CardSurface::Glass,
+            "glass-light" | "frosted" => CardSurface::GlassLight,
             "dark" => CardSurface::Dark,
             "transparent" | "clear" => CardSurface::Transparent,
             _ => CardSurface::Standard, // fallback
@@ -203,6 +296,45 @@ impl<C: ColorProvider> CardStyles<C> {
         self
     }
 
+    /// Set density (defaults to the color provider's density)
+    ///
+    /// Density shifts the spacing one step; `CardSpacing::None` is unaffected.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set density from string ("compact", "comfortable", "spacious")
+    pub fn density_str(mut self, density: &str) -> Self {
+        self.density = Density::from_str_or_default(density);
+        self
+    }
+
+    /// Show or hide per breakpoint, e.g. `hidden_below(Breakpoint::Tablet)`
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Resolve `visibility` against the nearest enclosing `@container`
+    /// instead of the viewport
+    ///
+    /// Uses the container variants from `Breakpoint::container_prefix`
+    /// (`@sm:` for tablet, `@lg:` for desktop), which are narrower than
+    /// the viewport widths. Without an enclosing container the card stays
+    /// visible at every width.
+    pub fn container_visibility(mut self) -> Self {
+        self.container_visibility = true;
+        self
+    }
+
+    /// Make the card a query container so its content can respond to the
+    /// card's own width with `@sm:`/`@lg:` variants
+    pub fn container_query(mut self) -> Self {
+        self.container_query = true;
+        self
+    }
+
     // === Interaction Methods ===
 
     /// Set static interaction (no interactions)
@@ -252,8 +384,99 @@ impl<C: ColorProvider> CardStyles<C> {
         self
     }
 
+    /// Set the hover and press effects of an interactive card
+    ///
+    /// Once set, hover, press and shadow classes come from
+    /// `InteractiveElement`, matching buttons and selection items with the
+    /// same intensity; the card keeps its own cursor and transition timing.
+    /// Static cards stay static.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "buttons")]
+    /// # {
+    /// use jupiter_design_system::builders::{ButtonStyles, CardStyles};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let colors = VibeColors::default();
+    /// let card = CardStyles::new(&colors).clickable_interaction().prominent().classes();
+    /// let button = ButtonStyles::new(&colors).prominent().classes();
+    ///
+    /// for class in ["hover:scale-110", "hover:shadow-lg", "active:scale-90"] {
+    ///     assert!(card.contains(class) && button.contains(class));
+    /// }
+    /// # }
+    /// ```
+    pub fn interaction_intensity(mut self, intensity: InteractionIntensity) -> Self {
+        self.intensity = Some(intensity);
+        self
+    }
+
+    /// Set gentle interaction (subtle lift and shadow)
+    pub fn gentle(self) -> Self {
+        self.interaction_intensity(InteractionIntensity::Gentle)
+    }
+
+    /// Set standard interaction
+    pub fn standard_interaction(self) -> Self {
+        self.interaction_intensity(InteractionIntensity::Standard)
+    }
+
+    /// Set prominent interaction (strong lift, shadow and press)
+    pub fn prominent(self) -> Self {
+        self.interaction_intensity(InteractionIntensity::Prominent)
+    }
+
     // === State Methods ===
 
+    /// Style the card for use inside another card
+    ///
+    /// Two stacked cards double up borders and shadows. A nested card drops
+    /// both and is tinted one step down the surface ladder instead, so a
+    /// `Surface` parent holds `Background`-tinted regions:
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::card::card_styles;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let colors = VibeColors::default();
+    /// let parent = card_styles(colors.clone()).classes();
+    /// let child = card_styles(colors).nested().compact_spacing().classes();
+    ///
+    /// assert!(parent.contains("bg-white"));
+    /// assert!(child.contains("bg-gray-50"));
+    /// assert!(child.contains("shadow-none"));
+    /// ```
+    pub fn nested(mut self) -> Self {
+        self.nested = true;
+        self
+    }
+
+    /// Show the card as a loading skeleton
+    ///
+    /// Mutes the surface, pulses the card and suppresses interaction, hover
+    /// and selection classes. Fill header, content and footer sections with
+    /// [`placeholder_classes`](Self::placeholder_classes) blocks.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::{card_header_styles, card_styles};
+    /// use jupiter_design_system::patterns::CardPart;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let colors = VibeColors::default();
+    /// let card = card_styles(colors.clone()).clickable_interaction().loading();
+    ///
+    /// let header_classes = card_header_styles(colors).classes();
+    /// let title_bar = &card.placeholder_classes(CardPart::Header)[0];
+    ///
+    /// assert!(card.classes().contains("pointer-events-none"));
+    /// assert!(!card.classes().contains("cursor-pointer"));
+    /// assert!(title_bar.contains("bg-gray-200"));
+    /// ```
+    pub fn loading(mut self) -> Self {
+        self.loading = true;
+        self
+    }
+
     /// Set selected state
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -296,137 +519,371 @@ impl<C: ColorProvider> CardStyles<C> {
     // === Build Methods ===
 
     /// Build the final CSS classes string
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         self.build()
     }
 
+    /// Compare the classes of this card with another configuration
+    pub fn diff(&self, other: &Self) -> ClassDiff {
+        class_diff(&self.classes(), &other.classes())
+    }
+
+    /// Get the suggested HTML element for this card
+    pub fn suggested_element(&self) -> &'static str {
+        self.interaction.suggested_element()
+    }
+
     /// Build the final CSS classes string (alias for classes)
-    pub fn build(self) -> String {
-        let mut all_classes = Vec::new();
-
-        // Base classes
-        all_classes.push("rounded-lg border transition-all duration-300".to_string());
-
-        // Elevation classes
-        let elevation_classes = match self.elevation {
-            CardElevation::Flat => "shadow-none",
-            CardElevation::Subtle => "shadow-sm",
-            CardElevation::Raised => "shadow-md",
-            CardElevation::Floating => "shadow-lg",
-            CardElevation::Modal => "shadow-2xl",
-        };
-        all_classes.push(elevation_classes.to_string());
+    pub fn build(&self) -> String {
+        let parts = self.parts();
+
+        // Join and clean up
+        let mut classes: Vec<&str> = parts
+            .iter()
+            .flat_map(|(_, classes)| classes.split_whitespace())
+            .collect();
+        classes.sort_unstable();
+        classes.dedup();
+        classes.join(" ")
+    }
+
+    /// Break the classes down by the decision that produced them
+    ///
+    /// Sources are "base", "elevation", "surface" (or "nested" in their
+    /// place), "loading", "spacing", "interaction", "selected",
+    /// "hover-elevation" and "custom"; decisions that add no classes are
+    /// omitted. A loading card drops "surface", "interaction", "selected"
+    /// and "hover-elevation".
+    pub fn explain(&self) -> Vec<(&'static str, String)> {
+        self.parts()
+            .into_iter()
+            .map(|(source, classes)| (source, classes.into_owned()))
+            .collect()
+    }
+
+    /// Class groups by source; static fragments are borrowed, not allocated
+    fn parts(&self) -> Vec<(&'static str, Cow<'static, str>)> {
+        let mut parts = Vec::new();
+
+        if self.nested {
+            // Nested cards replace border, shadow and surface with a tint
+            parts.push((
+                "nested",
+                Cow::Owned(nested_card_classes(&self.color_provider)),
+            ));
+        } else {
+            // Base classes
+            parts.push((
+                "base",
+                Cow::Owned(format!(
+                    "{} {} transition-all duration-300",
+                    self.color_provider
+                        .radius_scale()
+                        .radius_class(Radius::Surface),
+                    self.color_provider
+                        .border_scale()
+                        .border_width_class(BorderWidth::Default)
+                )),
+            ));
+
+            // Elevation classes
+            parts.push((
+                "elevation",
+                Cow::Owned(
+                    self.color_provider
+                        .elevation_scale()
+                        .shadow_class(self.elevation.into()),
+                ),
+            ));
+
+            // Surface classes
+            if !self.loading {
+                parts.push(("surface", self.get_surface_classes()));
+            }
+        }
 
-        // Surface classes
-        let surface_classes = self.get_surface_classes();
-        if !surface_classes.is_empty() {
-            all_classes.push(surface_classes);
+        // Loading skeleton (muted surface, no interaction)
+        if self.loading {
+            parts.push((
+                "loading",
+                Cow::Owned(loading_card_classes(&self.color_provider)),
+            ));
         }
 
         // Spacing classes
-        let spacing_classes = match self.spacing {
-            CardSpacing::None => "p-0",
-            CardSpacing::Compact => "p-3",
-            CardSpacing::Standard => "p-5",
-            CardSpacing::Comfortable => "p-6",
-            CardSpacing::Spacious => "p-8",
-        };
-        all_classes.push(spacing_classes.to_string());
+        let spacing_classes = self.spacing.padding_class(self.density);
+        parts.push(("spacing", Cow::Borrowed(spacing_classes)));
 
         // Interaction classes
-        let interaction_classes = self.get_interaction_classes();
-        if !interaction_classes.is_empty() {
-            all_classes.push(interaction_classes);
+        if !self.loading {
+            parts.push(("interaction", Cow::Owned(self.get_interaction_classes())));
         }
 
         // Selection state
-        if self.selected {
-            all_classes.push("ring-2 ring-offset-2".to_string());
-            all_classes.push(format!(
-                "ring-{}",
-                self.color_provider
-                    .resolve_color(crate::core::Color::Primary)
-                    .replace("bg-", "")
-                    .replace("-500", "-300")
-            ));
+        if self.selected && !self.loading {
+            parts.push(("selected", Cow::Owned(self.selected_classes())));
         }
 
         // Hover elevation effects for interactive cards
-        if matches!(
-            self.interaction,
-            CardInteraction::Hoverable | CardInteraction::Clickable
-        ) {
-            match self.elevation {
-                CardElevation::Subtle => all_classes.push("hover:shadow-md".to_string()),
-                CardElevation::Raised => all_classes.push("hover:shadow-lg".to_string()),
-                CardElevation::Floating => all_classes.push("hover:shadow-xl".to_string()),
-                _ => {}
-            }
+        parts.push((
+            "hover-elevation",
+            Cow::Owned(self.get_hover_elevation_classes()),
+        ));
+
+        // Responsive visibility
+        let visibility = if self.container_visibility {
+            self.visibility.container_classes("block")
+        } else {
+            self.visibility
+                .classes_for_scale("block", &self.color_provider.breakpoint_scale())
+        };
+        parts.push(("visibility", Cow::Owned(visibility)));
+
+        // Container query context
+        if self.container_query {
+            parts.push(("container", Cow::Borrowed("@container")));
         }
 
         // Custom classes
-        let custom_classes = self.custom_classes.join(" ");
-        if !custom_classes.is_empty() {
-            all_classes.push(custom_classes);
+        parts.push(("custom", Cow::Owned(self.custom_classes.join(" "))));
+
+        parts
+            .into_iter()
+            .filter(|(_, classes)| !classes.trim().is_empty())
+            .collect()
+    }
+
+    // === State Inspection Methods ===
+
+    /// Get only the hover classes for the current configuration
+    ///
+    /// Includes hover transforms from the interaction and the hover shadow
+    /// lift from the elevation scale.
+    pub fn hover_classes(&self) -> String {
+        if self.loading {
+            return String::new();
         }
 
-        // Join and clean up
-        let mut classes: Vec<String> = all_classes
-            .join(" ")
+        let mut classes: Vec<String> = self
+            .get_interaction_classes()
             .split_whitespace()
-            .map(|s| s.to_string())
+            .filter(|class| class.starts_with("hover:"))
+            .map(|class| class.to_string())
             .collect();
-        classes.sort();
-        classes.dedup();
+
+        let hover_elevation_classes = self.get_hover_elevation_classes();
+        if !hover_elevation_classes.is_empty() {
+            classes.push(hover_elevation_classes);
+        }
+
         classes.join(" ")
     }
 
+    /// Get placeholder blocks for one section of a loading card
+    ///
+    /// Returns one class string per block, shaped like the content the
+    /// section will hold.
+    pub fn placeholder_classes(&self, part: CardPart) -> Vec<String> {
+        card_placeholder_classes(&self.color_provider, part)
+    }
+
+    /// Get only the classes added when the card is selected
+    ///
+    /// Returned regardless of the `selected` flag so frameworks can toggle
+    /// them at runtime.
+    pub fn selected_classes(&self) -> String {
+        format!(
+            "ring-2 ring-offset-2 ring-{}",
+            self.color_provider
+                .resolve_color(crate::core::Color::Primary)
+                .replace("bg-", "")
+                .replace("-500", "-300")
+        )
+    }
+
+    /// Get hover shadow lift for interactive cards
+    ///
+    /// With an interaction intensity the shadow lift comes from the
+    /// interaction classes instead.
+    fn get_hover_elevation_classes(&self) -> String {
+        if self.intensity.is_none()
+            && !self.nested
+            && !self.loading
+            && matches!(
+                self.interaction,
+                CardInteraction::Hoverable | CardInteraction::Clickable
+            )
+            && matches!(
+                self.elevation,
+                CardElevation::Subtle | CardElevation::Raised | CardElevation::Floating
+            )
+        {
+            self.color_provider
+                .elevation_scale()
+                .hover_shadow_class(self.elevation.into())
+        } else {
+            String::new()
+        }
+    }
+
     /// Get surface-specific classes
-    fn get_surface_classes(&self) -> String {
+    fn get_surface_classes(&self) -> Cow<'static, str> {
         match self.surface {
-            CardSurface::Standard => format!(
+            CardSurface::Standard => Cow::Owned(format!(
                 "{} {} {}",
                 self.color_provider.bg_class(crate::core::Color::Surface),
-                self.color_provider.text_class(crate::core::Color::TextPrimary),
+                self.color_provider
+                    .text_class(crate::core::Color::TextPrimary),
                 self.color_provider.border_class(crate::core::Color::Border)
-            ),
-            CardSurface::Elevated => format!(
+            )),
+            CardSurface::Elevated => Cow::Owned(format!(
                 "{} {} {}",
-                self.color_provider.bg_class(crate::core::Color::Background),
-                self.color_provider.text_class(crate::core::Color::TextPrimary),
+                self.color_provider
+                    .bg_class(crate::core::Color::SurfaceRaised),
+                self.color_provider
+                    .text_class(crate::core::Color::TextPrimary),
                 self.color_provider.border_class(crate::core::Color::Border)
-            ),
-            CardSurface::Branded => {
-                "bg-gradient-to-br from-jupiter-navy-900/80 to-jupiter-blue-900/80 border-white/10 text-white".to_string()
-            },
+            )),
+            CardSurface::Overlay => Cow::Owned(format!(
+                "{} {} {}",
+                self.color_provider
+                    .bg_class(crate::core::Color::SurfaceOverlay),
+                self.color_provider
+                    .text_class(crate::core::Color::TextPrimary),
+                self.color_provider.border_class(crate::core::Color::Border)
+            )),
+            CardSurface::Branded => Cow::Owned(branded_surface_classes(&self.color_provider)),
             CardSurface::Glass => {
-                "bg-white/10 backdrop-blur-md border-white/20 text-white".to_string()
-            },
-            CardSurface::Dark => {
-                "bg-gray-900 border-gray-700 text-white".to_string()
-            },
-            CardSurface::Transparent => {
-                "bg-transparent border-transparent".to_string()
-            },
+                Cow::Owned(glass_surface_classes(&self.color_provider, GlassTone::Dark))
+            }
+            CardSurface::GlassLight => Cow::Owned(glass_surface_classes(
+                &self.color_provider,
+                GlassTone::Light,
+            )),
+            CardSurface::Dark => Cow::Borrowed("bg-gray-900 border-gray-700 text-white"),
+            CardSurface::Transparent => Cow::Borrowed("bg-transparent border-transparent"),
         }
     }
 
     /// Get interaction-specific classes
     fn get_interaction_classes(&self) -> String {
+        if let Some(intensity) = self.intensity {
+            return self.intensity_classes(intensity);
+        }
+
+        let motion = self.color_provider.motion_scale();
         match self.interaction {
-            CardInteraction::Static => "".to_string(),
-            CardInteraction::Hoverable => "hover:scale-101 hover:shadow-sm".to_string(),
-            CardInteraction::Clickable => {
-                "cursor-pointer hover:scale-105 active:scale-95 focus:outline-none focus:ring-2 focus:ring-offset-2".to_string()
-            },
-            CardInteraction::Selectable => {
-                "cursor-pointer hover:scale-101 focus:outline-none focus:ring-2 focus:ring-offset-2".to_string()
-            },
-            CardInteraction::Draggable => {
-                "cursor-move hover:scale-105 active:scale-95".to_string()
-            },
+            CardInteraction::Static => String::new(),
+            CardInteraction::Hoverable => format!(
+                "{} hover:shadow-sm",
+                motion.scale_effect_class(ScaleEffect::HoverSubtle)
+            ),
+            CardInteraction::Clickable => format!(
+                "cursor-pointer {} {} focus:outline-none focus:ring-2 focus:ring-offset-2",
+                motion.scale_effect_class(ScaleEffect::Hover),
+                motion.scale_effect_class(ScaleEffect::Press)
+            ),
+            CardInteraction::Selectable => format!(
+                "cursor-pointer {} focus:outline-none focus:ring-2 focus:ring-offset-2",
+                motion.scale_effect_class(ScaleEffect::HoverSubtle)
+            ),
+            CardInteraction::Draggable => format!(
+                "cursor-move {} {}",
+                motion.scale_effect_class(ScaleEffect::Hover),
+                motion.scale_effect_class(ScaleEffect::Press)
+            ),
         }
     }
+
+    /// Interaction classes from `InteractiveElement` at an intensity
+    fn intensity_classes(&self, intensity: InteractionIntensity) -> String {
+        let element = InteractiveElement::new(&self.color_provider).intensity(intensity);
+        let (cursor, element) = match self.interaction {
+            CardInteraction::Static => return String::new(),
+            CardInteraction::Hoverable => ("", element.hoverable()),
+            CardInteraction::Clickable | CardInteraction::Selectable => (
+                "cursor-pointer focus:outline-none focus:ring-2 focus:ring-offset-2",
+                element.hoverable().pressable(),
+            ),
+            CardInteraction::Draggable => ("cursor-move", element.hoverable().pressable()),
+        };
+        let effects = element.classes();
+        let effects = effects.split_whitespace().filter(|class| {
+            !["cursor-", "transition-", "duration-", "ease-"]
+                .iter()
+                .any(|prefix| class.starts_with(prefix))
+        });
+        core::iter::once(cursor)
+            .chain(effects)
+            .filter(|class| !class.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl<C: ColorProvider + Clone> Describe for CardStyles<C> {
+    fn describe(&self) -> ComponentDescriptor {
+        let descriptor = ComponentDescriptor::new(self.suggested_element(), self.classes());
+
+        if self.loading {
+            return match self.interaction {
+                CardInteraction::Clickable => descriptor
+                    .with_attribute("type", "button")
+                    .with_attribute("disabled", "true")
+                    .with_attribute("aria-busy", "true"),
+                _ => descriptor.with_attribute("aria-busy", "true"),
+            };
+        }
+
+        match self.interaction {
+            CardInteraction::Clickable => descriptor.with_attribute("type", "button"),
+            CardInteraction::Selectable => descriptor
+                .with_attribute("role", "option")
+                .with_attribute("tabindex", "0")
+                .with_attribute("aria-selected", self.selected.to_string()),
+            CardInteraction::Draggable => descriptor
+                .with_attribute("draggable", "true")
+                .with_attribute("tabindex", "0"),
+            CardInteraction::Static | CardInteraction::Hoverable => descriptor,
+        }
+    }
+}
+
+impl<C: ColorProvider> Preset<C> for CardStyles<C> {
+    const COMPONENT: &'static str = "card";
+
+    /// Options: "elevation", "surface", "spacing", "density", "interaction",
+    /// and the flags "container_query", "nested", "loading" and "selected"
+    fn from_preset(color_provider: C, preset: &PresetDefinition) -> Self {
+        let mut styles = Self::for_theme(color_provider);
+        if let Some(elevation) = preset.get("elevation") {
+            styles = styles.elevation_str(elevation);
+        }
+        if let Some(surface) = preset.get("surface") {
+            styles = styles.surface_str(surface);
+        }
+        if let Some(spacing) = preset.get("spacing") {
+            styles = styles.spacing_str(spacing);
+        }
+        if let Some(density) = preset.get("density") {
+            styles = styles.density_str(density);
+        }
+        if let Some(interaction) = preset.get("interaction") {
+            styles = styles.interaction_str(interaction);
+        }
+        if preset.flag("container_query") {
+            styles = styles.container_query();
+        }
+        if preset.flag("nested") {
+            styles = styles.nested();
+        }
+        if preset.flag("loading") {
+            styles = styles.loading();
+        }
+        styles
+            .selected(preset.flag("selected"))
+            .custom_vec(preset.custom.clone())
+    }
 }
 
 /// Convenience function to create card styles
@@ -434,6 +891,12 @@ pub fn card_styles<C: ColorProvider>(color_provider: C) -> CardStyles<C> {
     CardStyles::new(color_provider)
 }
 
+/// Convenience function to create card styles from the global theme
+#[cfg(feature = "std")]
+pub fn card_styles_default() -> CardStyles<SharedTheme> {
+    CardStyles::new(global_theme())
+}
+
 /// One-shot convenience function to create card classes from strings
 ///
 /// Perfect for component libraries that need to map string props to CSS classes.
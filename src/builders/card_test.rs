@@ -3,7 +3,12 @@
 #[cfg(test)]
 mod tests {
     use crate::builders::card::{card_classes_from_strings, card_styles, CardStyles};
-    use crate::themes::VibeColors;
+    use crate::core::{
+        BorderScale, Breakpoint, BreakpointScale, Density, ElevationScale, RadiusScale,
+    };
+    use crate::patterns::{CardInteraction, CardSpacing};
+    use crate::themes::{CardDefaults, ComponentDefaults, VibeColors};
+    use crate::utils::visibility::hidden_above;
 
     fn create_test_colors() -> VibeColors {
         VibeColors::new()
@@ -79,20 +84,22 @@ mod tests {
 
         // Elevated
         let elevated = CardStyles::new(colors.clone()).elevated_surface().classes();
-        assert!(elevated.contains("bg-gray-50"));
+        assert!(elevated.contains("bg-white"));
         assert!(elevated.contains("text-gray-900"));
 
         // Branded
         let branded = CardStyles::new(colors.clone()).branded_surface().classes();
         assert!(branded.contains("bg-gradient-to-br"));
-        assert!(branded.contains("from-jupiter-navy-900/80"));
-        assert!(branded.contains("text-white"));
+        assert!(branded.contains("from-jupiter-blue-500"));
+        assert!(branded.contains("to-jupiter-blue-700"));
+        assert!(branded.contains("text-gray-900"));
 
         // Glass
         let glass = CardStyles::new(colors.clone()).glass_surface().classes();
-        assert!(glass.contains("bg-white/10"));
-        assert!(glass.contains("backdrop-blur-md"));
+        assert!(glass.contains("supports-[backdrop-filter]:bg-white/10"));
+        assert!(glass.contains("supports-[backdrop-filter]:backdrop-blur-md"));
         assert!(glass.contains("border-white/20"));
+        assert!(glass.contains("text-white"));
 
         // Dark
         let dark = CardStyles::new(colors.clone()).dark_surface().classes();
@@ -150,7 +157,8 @@ mod tests {
         let hoverable = CardStyles::new(colors.clone())
             .hoverable_interaction()
             .classes();
-        assert!(hoverable.contains("hover:scale-101"));
+        assert!(hoverable.contains("hover:shadow-sm"));
+        assert!(!hoverable.contains("hover:scale-101"));
 
         // Clickable
         let clickable = CardStyles::new(colors.clone())
@@ -165,7 +173,7 @@ mod tests {
             .selectable_interaction()
             .classes();
         assert!(selectable.contains("cursor-pointer"));
-        assert!(selectable.contains("hover:scale-101"));
+        assert!(!selectable.contains("hover:scale"));
 
         // Draggable
         let draggable = CardStyles::new(colors.clone())
@@ -320,7 +328,7 @@ mod tests {
         let hover_alias = CardStyles::new(colors.clone())
             .interaction_str("hover")
             .classes();
-        assert!(hover_alias.contains("hover:scale-101"));
+        assert!(hover_alias.contains("hover:shadow-sm"));
 
         let click_alias = CardStyles::new(colors.clone())
             .interaction_str("click")
@@ -413,7 +421,7 @@ mod tests {
 
         assert!(selected_classes.contains("shadow-sm")); // subtle elevation
         assert!(selected_classes.contains("p-5")); // standard spacing
-        assert!(selected_classes.contains("hover:scale-101")); // hoverable interaction
+        assert!(selected_classes.contains("hover:shadow-sm")); // hoverable interaction
         assert!(selected_classes.contains("ring-jupiter-blue-300")); // selected ring
     }
 
@@ -475,4 +483,493 @@ mod tests {
         assert!(!classes.contains("  "));
         assert!(classes.contains("rounded-lg")); // base classes should still be there
     }
+
+    #[test]
+    fn test_card_styles_density() {
+        let colors = create_test_colors();
+
+        let compact = CardStyles::new(colors.clone())
+            .density(Density::Compact)
+            .classes();
+        assert!(compact.contains("p-3"));
+        assert!(!compact.contains("p-5"));
+
+        let spacious = CardStyles::new(colors.clone())
+            .spacious_spacing()
+            .density_str("spacious")
+            .classes();
+        assert!(spacious.contains("p-10"));
+
+        // No spacing stays unpadded at any density
+        let none = CardStyles::new(colors.with_density(Density::Spacious))
+            .no_spacing()
+            .classes();
+        assert!(none.contains("p-0"));
+    }
+
+    #[test]
+    fn test_card_styles_elevation_scale() {
+        let colors = create_test_colors();
+
+        let classes = CardStyles::new(colors.clone())
+            .raised_elevation()
+            .hoverable_interaction()
+            .classes();
+        assert!(classes.contains("shadow-md"));
+        assert!(classes.contains("hover:shadow-lg"));
+
+        // A flatter theme scale changes every card without touching call sites
+        let minimal = colors.with_elevation(ElevationScale::minimal());
+        let classes = CardStyles::new(minimal)
+            .raised_elevation()
+            .hoverable_interaction()
+            .classes();
+        assert!(classes.contains("shadow-sm"));
+        assert!(classes.contains("hover:shadow"));
+        assert!(!classes.contains("shadow-md"));
+    }
+
+    #[test]
+    fn test_card_styles_radius_scale() {
+        let colors = create_test_colors();
+
+        assert!(CardStyles::new(colors.clone())
+            .classes()
+            .contains("rounded-lg"));
+
+        let rounded = colors.with_radius(RadiusScale::rounded());
+        let classes = CardStyles::new(rounded).classes();
+        assert!(classes.contains("rounded-2xl"));
+        assert!(!classes.contains("rounded-lg"));
+    }
+
+    #[test]
+    fn test_card_state_inspection() {
+        let colors = create_test_colors();
+
+        let card = CardStyles::new(colors.clone())
+            .raised_elevation()
+            .clickable_interaction();
+        let hover = card.hover_classes();
+        assert!(hover.contains("hover:scale-105"));
+        assert!(hover.contains("hover:shadow-lg"));
+        assert!(!hover.contains("cursor-pointer"));
+        assert!(!hover.contains("active:scale-95"));
+
+        let selected = card.selected_classes();
+        assert!(selected.contains("ring-2"));
+        let unselected = card.classes();
+        assert!(!unselected.split_whitespace().any(|class| class == "ring-2"));
+        let selected_card = card.clone().is_selected().classes();
+        for class in selected.split_whitespace() {
+            assert!(selected_card.split_whitespace().any(|c| c == class));
+        }
+
+        let static_card = CardStyles::new(colors);
+        assert_eq!(static_card.hover_classes(), "");
+    }
+
+    #[test]
+    fn test_card_styles_explain() {
+        let card = CardStyles::new(create_test_colors())
+            .raised_elevation()
+            .hoverable_interaction()
+            .custom("my-card");
+
+        let explanation = card.explain();
+        let sources: Vec<&str> = explanation.iter().map(|(source, _)| *source).collect();
+        assert_eq!(
+            sources,
+            vec![
+                "base",
+                "elevation",
+                "surface",
+                "spacing",
+                "interaction",
+                "hover-elevation",
+                "custom"
+            ]
+        );
+        assert_eq!(explanation[1].1, "shadow-md");
+        assert_eq!(explanation[6].1, "my-card");
+
+        // Every explained class ends up in the built string
+        let classes = card.classes();
+        for (_, part) in explanation {
+            for class in part.split_whitespace() {
+                assert!(classes.split_whitespace().any(|c| c == class));
+            }
+        }
+    }
+
+    #[test]
+    fn test_suggested_element() {
+        let colors = VibeColors::default();
+
+        assert_eq!(card_styles(colors.clone()).suggested_element(), "article");
+        assert_eq!(
+            card_styles(colors.clone())
+                .clickable_interaction()
+                .suggested_element(),
+            "button"
+        );
+        assert_eq!(
+            card_styles(colors)
+                .selectable_interaction()
+                .suggested_element(),
+            "div"
+        );
+    }
+
+    #[test]
+    fn test_visibility() {
+        let classes = card_styles(VibeColors::default())
+            .visibility(hidden_above(Breakpoint::Tablet))
+            .classes();
+
+        assert!(classes.contains("lg:hidden"));
+    }
+
+    #[test]
+    fn test_container_query() {
+        let styles = card_styles(create_test_colors()).container_query();
+
+        assert!(styles.classes().contains("@container"));
+        assert!(!card_styles(create_test_colors())
+            .classes()
+            .contains("@container"));
+
+        // The card's own visibility stays on viewport breakpoints
+        let styles = styles.visibility(hidden_above(Breakpoint::Tablet));
+        let classes = styles.classes();
+        assert!(classes.split_whitespace().any(|c| c == "lg:hidden"));
+        assert!(!classes.contains("@lg:"));
+
+        // unless it opts into container visibility
+        let classes = styles.container_visibility().classes();
+        assert!(classes.split_whitespace().any(|c| c == "@lg:hidden"));
+        assert!(!classes.split_whitespace().any(|c| c == "lg:hidden"));
+    }
+
+    #[test]
+    fn test_visibility_uses_theme_breakpoints() {
+        let colors = create_test_colors().with_breakpoints(BreakpointScale {
+            desktop: 1200,
+            ..BreakpointScale::default()
+        });
+
+        let classes = card_styles(colors)
+            .visibility(hidden_above(Breakpoint::Tablet))
+            .classes();
+        assert!(classes.contains("min-[1200px]:hidden"));
+    }
+
+    #[test]
+    fn test_surfaces_follow_surface_ladder() {
+        let colors = VibeColors::with_overrides(|palette| {
+            palette.surface = "gray-800".to_string();
+            palette.surface_raised = "gray-700".to_string();
+            palette.surface_overlay = "gray-600".to_string();
+        });
+
+        let standard = CardStyles::new(colors.clone()).standard_surface().classes();
+        let elevated = CardStyles::new(colors.clone()).elevated_surface().classes();
+        let overlay = CardStyles::new(colors.clone())
+            .surface_str("popover")
+            .classes();
+
+        assert!(standard.contains("bg-gray-800"));
+        assert!(elevated.contains("bg-gray-700"));
+        assert!(overlay.contains("bg-gray-600"));
+    }
+
+    #[test]
+    fn test_border_scale_from_theme() {
+        let standard = CardStyles::new(VibeColors::default()).classes();
+        assert!(standard.split_whitespace().any(|class| class == "border"));
+
+        let bold =
+            CardStyles::new(VibeColors::default().with_borders(BorderScale::bold())).classes();
+        assert!(bold.split_whitespace().any(|class| class == "border-2"));
+        assert!(!bold.split_whitespace().any(|class| class == "border"));
+    }
+
+    #[test]
+    fn test_nested_card() {
+        let colors = create_test_colors();
+        let nested = card_styles(colors.clone())
+            .raised_elevation()
+            .hoverable_interaction()
+            .nested();
+        let classes = nested.classes();
+
+        assert!(classes.contains("border-0"));
+        assert!(classes.contains("shadow-none"));
+        assert!(classes.contains("bg-gray-50"));
+        assert!(classes.contains("rounded-md"));
+        assert!(!classes.contains("shadow-md"));
+        assert!(!classes.contains("rounded-lg"));
+        assert!(!classes.contains("hover:shadow-lg"));
+
+        let sources: Vec<&str> = nested
+            .explain()
+            .into_iter()
+            .map(|(source, _)| source)
+            .collect();
+        assert!(sources.contains(&"nested"));
+        assert!(!sources.contains(&"surface"));
+
+        // Nesting keeps the card's own spacing
+        assert!(card_styles(colors)
+            .nested()
+            .compact_spacing()
+            .classes()
+            .contains("p-3"));
+    }
+
+    #[test]
+    fn test_loading_skeleton() {
+        use crate::descriptors::Describe;
+        use crate::patterns::CardPart;
+
+        let colors = create_test_colors();
+        let card = card_styles(colors.clone())
+            .raised_elevation()
+            .clickable_interaction()
+            .loading();
+        let classes = card.classes();
+
+        assert!(classes.contains("motion-safe:animate-pulse"));
+        assert!(classes.contains("pointer-events-none"));
+        assert!(classes.contains("bg-gray-50"));
+        assert!(!classes.contains("bg-white"));
+        assert!(!classes.contains("cursor-pointer"));
+        assert!(!classes.contains("hover:shadow-lg"));
+        assert!(card.hover_classes().is_empty());
+
+        let descriptor = card.describe();
+        assert_eq!(descriptor.attribute("aria-busy"), Some("true"));
+        assert_eq!(descriptor.attribute("disabled"), Some("true"));
+
+        // Selection is hidden until the card has loaded
+        assert!(!card_styles(colors.clone())
+            .is_selected()
+            .loading()
+            .classes()
+            .contains("ring-2"));
+
+        // Placeholders are shaped like the section they stand in for
+        assert_eq!(card.placeholder_classes(CardPart::Header).len(), 2);
+        assert_eq!(card.placeholder_classes(CardPart::Content).len(), 3);
+        let footer = card.placeholder_classes(CardPart::Footer);
+        assert!(footer.iter().all(|block| block.contains("h-9")));
+        assert!(footer.iter().all(|block| block.contains("rounded-md")));
+    }
+
+    #[test]
+    fn test_glass_surfaces_follow_theme() {
+        use crate::core::{Color, GlassScale};
+
+        let colors = create_test_colors();
+
+        // Opaque fallback without backdrop-filter support
+        let dark = card_styles(colors.clone()).glass_surface().classes();
+        assert!(dark.contains("bg-gray-900/90"));
+        assert!(dark.contains("text-white"));
+
+        let light = card_styles(colors.clone())
+            .surface_str("glass-light")
+            .classes();
+        assert!(light.contains("bg-white/90"));
+        assert!(light.contains("supports-[backdrop-filter]:bg-white/70"));
+        assert!(light.contains("border-gray-200"));
+        assert!(light.contains("text-gray-900"));
+        assert!(!light.contains("text-white"));
+
+        let themed = colors.with_glass(GlassScale {
+            tint: Color::Primary,
+            tint_opacity: 15,
+            border_opacity: 30,
+            blur: 6,
+            ..GlassScale::default()
+        });
+        let themed_glass = card_styles(themed).glass_surface().classes();
+        assert!(themed_glass.contains("supports-[backdrop-filter]:bg-jupiter-blue-500/15"));
+        assert!(themed_glass.contains("border-jupiter-blue-500/30"));
+        assert!(themed_glass.contains("supports-[backdrop-filter]:backdrop-blur-[6px]"));
+    }
+
+    #[test]
+    fn test_branded_surface_follows_palette() {
+        let water = card_styles(VibeColors::water_wellness())
+            .branded_surface()
+            .classes();
+        assert!(water.contains("from-water-blue-500"));
+        assert!(water.contains("to-water-blue-700"));
+        assert!(!water.contains("jupiter"));
+
+        let brand = VibeColors::from_brand_hex("#7C3AED").unwrap();
+        let branded = card_styles(brand).branded_surface().classes();
+        assert!(branded.contains("from-[#7c3aed]"));
+        assert!(!branded.contains("navy"));
+    }
+
+    #[test]
+    fn test_hover_scales_are_valid_tailwind_classes() {
+        use crate::core::motion::is_valid_scale_class;
+        use crate::core::MotionScale;
+
+        let default_colors = create_test_colors();
+        let precise_colors =
+            create_test_colors().with_motion(MotionScale::default().with_arbitrary_scales());
+
+        for colors in [default_colors, precise_colors.clone()] {
+            for interaction in [
+                "static",
+                "hoverable",
+                "clickable",
+                "selectable",
+                "draggable",
+            ] {
+                let classes = card_styles(colors.clone())
+                    .interaction_str(interaction)
+                    .classes();
+                for class in classes.split_whitespace() {
+                    assert!(is_valid_scale_class(class), "{interaction}: {class}");
+                }
+            }
+        }
+
+        // Opting into arbitrary values restores the subtle lift
+        assert!(card_styles(precise_colors)
+            .hoverable_interaction()
+            .classes()
+            .contains("hover:scale-[1.01]"));
+    }
+
+    #[test]
+    fn test_branded_surface_text_reads_on_light_brands() {
+        let colors = VibeColors::with_overrides(|palette| {
+            palette.primary = "amber-400".to_string();
+            palette.interactive_active = "amber-600".to_string();
+        });
+
+        let branded = CardStyles::new(colors).branded_surface().classes();
+        assert!(branded.contains("from-amber-400"));
+        assert!(branded.contains("text-gray-900"));
+        assert!(branded.contains("border-gray-900/10"));
+        assert!(!branded.contains("text-white"));
+    }
+
+    #[test]
+    fn test_for_theme_starts_from_theme_defaults() {
+        let colors = create_test_colors().with_component_defaults(ComponentDefaults {
+            card: CardDefaults {
+                spacing: Some(CardSpacing::Comfortable),
+                interaction: Some(CardInteraction::Hoverable),
+                ..CardDefaults::default()
+            },
+            ..ComponentDefaults::default()
+        });
+
+        let themed = CardStyles::for_theme(&colors);
+        assert_eq!(
+            themed.classes(),
+            CardStyles::new(&colors)
+                .comfortable_spacing()
+                .hoverable_interaction()
+                .classes()
+        );
+
+        // Setters still win over the defaults
+        assert_eq!(
+            CardStyles::for_theme(&colors).compact_spacing().classes(),
+            CardStyles::new(&colors)
+                .compact_spacing()
+                .hoverable_interaction()
+                .classes()
+        );
+        assert_eq!(
+            CardStyles::new(&colors).classes(),
+            CardStyles::new(create_test_colors()).classes()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "buttons", feature = "selection"))]
+    fn test_interaction_intensity_matches_buttons_and_selection() {
+        use crate::builders::button::ButtonStyles;
+        use crate::builders::selection::SelectionStyles;
+
+        let colors = VibeColors::default();
+        let effects = |classes: &str| -> Vec<String> {
+            let mut effects: Vec<String> = classes
+                .split_whitespace()
+                .filter(|class| {
+                    ["hover:scale", "hover:shadow", "active:"]
+                        .iter()
+                        .any(|prefix| class.starts_with(prefix))
+                })
+                .map(|class| class.to_string())
+                .collect();
+            effects.sort();
+            effects
+        };
+
+        let card = CardStyles::new(&colors).clickable_interaction();
+        let button = ButtonStyles::new(&colors);
+        let selection = SelectionStyles::new(&colors).selected();
+
+        assert_eq!(
+            effects(&card.clone().gentle().classes()),
+            effects(&button.clone().gentle().classes())
+        );
+        assert_eq!(
+            effects(&card.clone().standard_interaction().classes()),
+            effects(&selection.clone().standard_interaction().item_classes())
+        );
+        assert_eq!(
+            effects(&card.clone().prominent().classes()),
+            effects(&button.prominent().classes())
+        );
+        // Prominent selection items keep their own floating elevation
+        let without_shadows = |classes: &str| -> Vec<String> {
+            effects(classes)
+                .into_iter()
+                .filter(|class| !class.starts_with("hover:shadow"))
+                .collect()
+        };
+        assert_eq!(
+            without_shadows(&card.prominent().classes()),
+            without_shadows(&selection.prominent_interaction().item_classes())
+        );
+    }
+
+    #[test]
+    fn test_interaction_intensity_keeps_card_cursor_and_timing() {
+        let colors = VibeColors::default();
+
+        let draggable = CardStyles::new(&colors)
+            .draggable_interaction()
+            .standard_interaction()
+            .classes();
+        assert!(draggable.contains("cursor-move"));
+        assert!(!draggable.contains("cursor-pointer"));
+        assert!(draggable.contains("duration-300"));
+        assert!(!draggable.contains("duration-200"));
+
+        let hoverable = CardStyles::new(&colors)
+            .hoverable_interaction()
+            .gentle()
+            .classes();
+        assert!(!hoverable.contains("cursor-"));
+        assert!(hoverable.contains("hover:shadow-sm"));
+        assert!(!hoverable.contains("hover:shadow-md"));
+
+        // Static cards stay static
+        assert_eq!(
+            CardStyles::new(&colors).prominent().classes(),
+            CardStyles::new(&colors).classes()
+        );
+    }
 }
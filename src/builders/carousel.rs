@@ -0,0 +1,360 @@
+//! Carousel styling utilities for the Jupiter Design System
+//!
+//! Provides classes for horizontally scrolling galleries: a scroll-snap
+//! track, slide sizing (one per view, peeking the next slide, or several
+//! per view per breakpoint), previous/next buttons overlaid on the track,
+//! and pagination dots. Slides are spaced with start padding balanced by a
+//! negative track margin, so `basis-1/3` fits exactly three slides.
+
+use crate::builders::button::ButtonStyles;
+use crate::builders::position::PositionStyles;
+use crate::builders::scroll::{ScrollSnapAlign, ScrollStyles};
+use crate::core::color::ColorProvider;
+use crate::core::{
+    Breakpoint, Color, Elevation, ElevationProvider, MotionDuration, MotionProvider,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// How slides are sized within the track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SlideSizing {
+    /// Slides fill the track exactly
+    Exact,
+    /// Slides leave part of the next slide visible as a swipe hint
+    PeekNext,
+}
+
+/// Carousel styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::carousel::CarouselStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// // Product gallery: one slide on phones, three from desktop up
+/// let gallery = CarouselStyles::new(VibeColors::default()).per_view(1).lg_per_view(3);
+///
+/// let track = gallery.track_classes();
+/// assert!(track.contains("snap-x snap-mandatory"));
+///
+/// let slide = gallery.slide_classes();
+/// assert!(slide.contains("basis-full lg:basis-1/3"));
+///
+/// assert!(gallery.dot_classes(true).contains("bg-jupiter-blue-500"));
+/// assert_eq!(gallery.dot_attributes(0, 5, true)[1], ("aria-label", "Go to slide 1 of 5".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CarouselStyles<C: ColorProvider + Clone> {
+    slides_per_view: Vec<(Breakpoint, u8)>,
+    sizing: SlideSizing,
+    align: ScrollSnapAlign,
+    gap: &'static str,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> CarouselStyles<C> {
+    /// Create a new carousel styling utility (one full-width slide per view)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            slides_per_view: Vec::new(),
+            sizing: SlideSizing::Exact,
+            align: ScrollSnapAlign::Start,
+            gap: "4",
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Sizing Methods ===
+
+    /// One slide filling the track at every breakpoint (hero banners)
+    pub fn full(mut self) -> Self {
+        self.slides_per_view.clear();
+        self.sizing = SlideSizing::Exact;
+        self
+    }
+
+    /// Leave part of the next slide visible so the track reads as swipeable
+    pub fn peek_next(mut self) -> Self {
+        self.sizing = SlideSizing::PeekNext;
+        self
+    }
+
+    /// Set slide sizing explicitly
+    pub fn sizing(mut self, sizing: SlideSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
+
+    /// Set the slides per view on the smallest screens
+    pub fn per_view(self, slides: u8) -> Self {
+        self.per_view_at(Breakpoint::Mobile, slides)
+    }
+
+    /// Set the slides per view from the tablet breakpoint up
+    pub fn md_per_view(self, slides: u8) -> Self {
+        self.per_view_at(Breakpoint::Tablet, slides)
+    }
+
+    /// Set the slides per view from the desktop breakpoint up
+    pub fn lg_per_view(self, slides: u8) -> Self {
+        self.per_view_at(Breakpoint::Desktop, slides)
+    }
+
+    /// Set the slides per view from the large breakpoint up
+    pub fn xl_per_view(self, slides: u8) -> Self {
+        self.per_view_at(Breakpoint::Large, slides)
+    }
+
+    /// Set the slides per view from a breakpoint up (clamped to 1-6)
+    pub fn per_view_at(mut self, breakpoint: Breakpoint, slides: u8) -> Self {
+        let slides = slides.clamp(1, 6);
+        match self
+            .slides_per_view
+            .iter_mut()
+            .find(|(existing, _)| *existing == breakpoint)
+        {
+            Some(entry) => entry.1 = slides,
+            None => self.slides_per_view.push((breakpoint, slides)),
+        }
+        self
+    }
+
+    /// Set the space between slides as a spacing scale value, e.g. "6"
+    pub fn gap(mut self, gap: &'static str) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    // === Alignment Methods ===
+
+    /// Snap slides to the start of the track
+    pub fn align_start(mut self) -> Self {
+        self.align = ScrollSnapAlign::Start;
+        self
+    }
+
+    /// Snap slides to the center of the track
+    pub fn align_center(mut self) -> Self {
+        self.align = ScrollSnapAlign::Center;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the track
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the carousel root
+    ///
+    /// The root clips the track's negative margin and anchors the
+    /// navigation buttons.
+    pub fn root_classes(&self) -> String {
+        "carousel relative overflow-hidden".to_string()
+    }
+
+    /// Build classes for the scrolling track
+    pub fn track_classes(&self) -> String {
+        let scroll = self.scroll_styles();
+        let mut classes = vec![
+            "carousel-track flex".to_string(),
+            format!("-ms-{}", self.gap),
+            scroll.classes(),
+        ];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for each slide
+    pub fn slide_classes(&self) -> String {
+        format!(
+            "carousel-slide min-w-0 shrink-0 grow-0 ps-{} {} {}",
+            self.gap,
+            self.scroll_styles().snap_item_classes(),
+            self.basis_classes()
+        )
+    }
+
+    /// Build classes for the previous (`true`) or next (`false`) button
+    ///
+    /// Buttons sit over the track's vertical center and fade out when
+    /// disabled at either end.
+    pub fn nav_button_classes(&self, previous: bool) -> String {
+        let position = if previous {
+            PositionStyles::new().center_start()
+        } else {
+            PositionStyles::new().center_end()
+        };
+        // Replace the button's dimmed disabled state with hiding it
+        let button = ButtonStyles::new(self.color_provider.clone())
+            .secondary()
+            .small()
+            .icon_only()
+            .classes()
+            .split_whitespace()
+            .filter(|class| !class.starts_with("disabled:"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!(
+            "carousel-nav carousel-nav--{} {} z-10 {} {} disabled:opacity-0 disabled:pointer-events-none",
+            if previous { "previous" } else { "next" },
+            position.classes(),
+            button,
+            self.color_provider
+                .elevation_scale()
+                .shadow_class(Elevation::Raised)
+        )
+    }
+
+    /// Get attributes for the previous (`true`) or next (`false`) button
+    pub fn nav_button_attributes(&self, previous: bool) -> Vec<(&'static str, String)> {
+        let label = if previous {
+            "Previous slide"
+        } else {
+            "Next slide"
+        };
+        vec![
+            ("type", "button".to_string()),
+            ("aria-label", label.to_string()),
+        ]
+    }
+
+    /// Build classes for the pagination row
+    pub fn pagination_classes(&self) -> String {
+        "carousel-pagination flex items-center justify-center gap-2 mt-4".to_string()
+    }
+
+    /// Build classes for a pagination dot
+    ///
+    /// The active dot stretches into a pill so the current position reads
+    /// without relying on color alone.
+    pub fn dot_classes(&self, active: bool) -> String {
+        let state = if active {
+            format!("w-6 {}", self.color_provider.bg_class(Color::Primary))
+        } else {
+            format!(
+                "w-2 {} hover:{}",
+                self.color_provider.bg_class(Color::Border),
+                self.color_provider.bg_class(Color::TextSecondary)
+            )
+        };
+        format!(
+            "carousel-dot h-2 rounded-full {} transition-all {} motion-reduce:transition-none focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-offset-2 focus-visible:ring-{}",
+            state,
+            self.color_provider
+                .motion_scale()
+                .duration_class(MotionDuration::Normal),
+            self.color_provider.resolve_color(Color::Primary)
+        )
+    }
+
+    /// Get attributes for the dot of slide `index` (zero-based)
+    pub fn dot_attributes(
+        &self,
+        index: usize,
+        total: usize,
+        active: bool,
+    ) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![
+            ("type", "button".to_string()),
+            (
+                "aria-label",
+                format!("Go to slide {} of {}", index + 1, total),
+            ),
+        ];
+        if active {
+            attrs.push(("aria-current", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Get attributes for the carousel root
+    pub fn root_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("role", "region".to_string()),
+            ("aria-roledescription", "carousel".to_string()),
+        ]
+    }
+
+    /// Get attributes for slide `index` (zero-based)
+    pub fn slide_attributes(&self, index: usize, total: usize) -> Vec<(&'static str, String)> {
+        vec![
+            ("role", "group".to_string()),
+            ("aria-roledescription", "slide".to_string()),
+            ("aria-label", format!("{} of {}", index + 1, total)),
+        ]
+    }
+
+    fn scroll_styles(&self) -> ScrollStyles<C> {
+        let scroll = ScrollStyles::new(self.color_provider.clone())
+            .horizontal()
+            .contain_overscroll()
+            .smooth()
+            .hidden_scrollbar();
+        match self.align {
+            ScrollSnapAlign::Center => scroll.snap_center(),
+            ScrollSnapAlign::End => scroll.snap_end(),
+            ScrollSnapAlign::Start => scroll.snap_start(),
+        }
+    }
+
+    fn basis_classes(&self) -> String {
+        let has_base = self
+            .slides_per_view
+            .iter()
+            .any(|(breakpoint, _)| *breakpoint == Breakpoint::Mobile);
+        let mut classes = Vec::new();
+        if !has_base {
+            classes.push(self.basis(1).to_string());
+        }
+
+        let breakpoints = self.color_provider.breakpoint_scale();
+        let mut slides = self.slides_per_view.clone();
+        slides.sort_by_key(|(breakpoint, _)| breakpoints.min_width(*breakpoint));
+        for (breakpoint, count) in slides {
+            classes.push(format!(
+                "{}{}",
+                breakpoints.prefix(breakpoint),
+                self.basis(count)
+            ));
+        }
+        classes.join(" ")
+    }
+
+    fn basis(&self, slides: u8) -> &'static str {
+        match (self.sizing, slides) {
+            (SlideSizing::Exact, 1) => "basis-full",
+            (SlideSizing::Exact, 2) => "basis-1/2",
+            (SlideSizing::Exact, 3) => "basis-1/3",
+            (SlideSizing::Exact, 4) => "basis-1/4",
+            (SlideSizing::Exact, 5) => "basis-1/5",
+            (SlideSizing::Exact, _) => "basis-1/6",
+            // About a sixth of a slide peeks in from the end
+            (SlideSizing::PeekNext, 1) => "basis-[85%]",
+            (SlideSizing::PeekNext, 2) => "basis-[46%]",
+            (SlideSizing::PeekNext, 3) => "basis-[31%]",
+            (SlideSizing::PeekNext, 4) => "basis-[24%]",
+            (SlideSizing::PeekNext, 5) => "basis-[19%]",
+            (SlideSizing::PeekNext, _) => "basis-[16%]",
+        }
+    }
+}
+
+/// Convenience function to create carousel styles
+pub fn carousel_styles<C: ColorProvider + Clone>(color_provider: C) -> CarouselStyles<C> {
+    CarouselStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "carousel_test.rs"]
+mod carousel_test;
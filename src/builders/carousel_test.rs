@@ -0,0 +1,108 @@
+//! Tests for carousel styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::carousel::{carousel_styles, CarouselStyles, SlideSizing};
+    use crate::core::Breakpoint;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_carousel_track_snaps_and_hides_scrollbar() {
+        let track = carousel_styles(VibeColors::default()).track_classes();
+
+        assert!(track.contains("carousel-track flex -ms-4"));
+        assert!(track.contains("overflow-x-auto"));
+        assert!(track.contains("snap-x snap-mandatory"));
+        assert!(track.contains("overscroll-contain"));
+        assert!(track.contains("[scrollbar-width:none]"));
+        assert!(track.contains("motion-reduce:scroll-auto"));
+    }
+
+    #[test]
+    fn test_slide_basis_per_breakpoint() {
+        let colors = VibeColors::default();
+
+        let full = CarouselStyles::new(colors.clone()).full().slide_classes();
+        assert!(full.contains("ps-4 snap-start basis-full"));
+
+        let multi = CarouselStyles::new(colors.clone())
+            .xl_per_view(4)
+            .md_per_view(2)
+            .per_view_at(Breakpoint::Desktop, 3)
+            .gap("6");
+        let slide = multi.slide_classes();
+        assert!(slide.contains("ps-6"));
+        assert!(slide.ends_with("basis-full md:basis-1/2 lg:basis-1/3 xl:basis-1/4"));
+        assert!(multi.track_classes().contains("-ms-6"));
+
+        let clamped = CarouselStyles::new(colors).per_view(9).slide_classes();
+        assert!(clamped.ends_with("basis-1/6"));
+    }
+
+    #[test]
+    fn test_peek_next_leaves_next_slide_visible() {
+        let colors = VibeColors::default();
+
+        let peek = CarouselStyles::new(colors.clone())
+            .peek_next()
+            .md_per_view(2)
+            .align_center();
+        let slide = peek.slide_classes();
+        assert!(slide.contains("snap-center"));
+        assert!(slide.ends_with("basis-[85%] md:basis-[46%]"));
+
+        let exact = CarouselStyles::new(colors)
+            .peek_next()
+            .sizing(SlideSizing::Exact);
+        assert!(exact.slide_classes().ends_with("basis-full"));
+    }
+
+    #[test]
+    fn test_nav_buttons_overlay_track() {
+        let carousel = carousel_styles(VibeColors::default());
+
+        let previous = carousel.nav_button_classes(true);
+        assert!(previous.contains("carousel-nav--previous"));
+        assert!(previous.contains("absolute top-1/2 -translate-y-1/2 start-2"));
+        assert!(previous.contains("z-10"));
+        assert!(previous.contains("disabled:opacity-0"));
+        assert!(!previous.contains("disabled:opacity-50"));
+
+        let next = carousel.nav_button_classes(false);
+        assert!(next.contains("end-2"));
+        assert!(carousel
+            .nav_button_attributes(false)
+            .contains(&("aria-label", "Next slide".to_string())));
+    }
+
+    #[test]
+    fn test_pagination_dots() {
+        let carousel = carousel_styles(VibeColors::default());
+
+        let active = carousel.dot_classes(true);
+        assert!(active.contains("w-6 bg-jupiter-blue-500"));
+        assert!(active.contains("duration-200"));
+
+        let inactive = carousel.dot_classes(false);
+        assert!(inactive.contains("w-2 bg-gray-200"));
+        assert!(inactive.contains("hover:bg-gray-600"));
+
+        assert!(carousel
+            .dot_attributes(2, 4, true)
+            .contains(&("aria-current", "true".to_string())));
+        assert_eq!(carousel.dot_attributes(2, 4, false).len(), 2);
+    }
+
+    #[test]
+    fn test_carousel_landmark_attributes() {
+        let carousel = carousel_styles(VibeColors::default());
+
+        assert!(carousel
+            .root_attributes()
+            .contains(&("aria-roledescription", "carousel".to_string())));
+        assert!(carousel
+            .slide_attributes(0, 3)
+            .contains(&("aria-label", "1 of 3".to_string())));
+        assert!(carousel.root_classes().contains("overflow-hidden"));
+    }
+}
@@ -0,0 +1,307 @@
+//! Chart container styling utilities for the Jupiter Design System
+//!
+//! Provides classes around embedded charts: a sized container the charting
+//! library draws into, grid, axis and label classes from the theme's
+//! `ChartPalette`, legend chips that toggle series, and a tooltip surface.
+//! Charting libraries keep drawing; the design system supplies the chrome
+//! so charts match the rest of the page.
+
+use crate::core::color::ColorProvider;
+use crate::core::{ChartPalette, Color, Elevation, ElevationProvider, Radius, RadiusProvider};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Chart size presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChartSize {
+    /// Inline trend line beside a metric, without axes
+    Sparkline,
+    /// Short chart for dashboard tiles
+    Compact,
+    /// Default chart height
+    Standard,
+    /// 16:9 chart that scales with its width
+    Wide,
+    /// 1:1 chart for pies and radars
+    Square,
+}
+
+/// Chart container styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::chart_container::ChartContainerStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let revenue = ChartContainerStyles::new(VibeColors::default()).wide().framed();
+/// assert!(revenue.container_classes().contains("aspect-video"));
+/// assert_eq!(revenue.grid_classes(), "stroke-gray-200 stroke-1");
+/// assert!(revenue.legend_swatch_classes(1).contains("bg-jupiter-orange-500"));
+/// assert!(revenue.tooltip_classes().contains("shadow-lg"));
+///
+/// let trend = ChartContainerStyles::new(VibeColors::default()).sparkline();
+/// assert!(trend.container_classes().contains("inline-block h-8 w-24"));
+/// assert!(trend.sparkline_line_classes().contains("stroke-jupiter-blue-500"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChartContainerStyles<C: ColorProvider> {
+    size: ChartSize,
+    framed: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> ChartContainerStyles<C> {
+    /// Create a new chart container styling utility (standard size)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            size: ChartSize::Standard,
+            framed: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Size Methods ===
+
+    /// Inline sparkline sized to sit beside a number
+    pub fn sparkline(self) -> Self {
+        self.size(ChartSize::Sparkline)
+    }
+
+    /// Short chart for dashboard tiles
+    pub fn compact(self) -> Self {
+        self.size(ChartSize::Compact)
+    }
+
+    /// Default chart height
+    pub fn standard(self) -> Self {
+        self.size(ChartSize::Standard)
+    }
+
+    /// 16:9 chart
+    pub fn wide(self) -> Self {
+        self.size(ChartSize::Wide)
+    }
+
+    /// 1:1 chart
+    pub fn square(self) -> Self {
+        self.size(ChartSize::Square)
+    }
+
+    /// Set size explicitly
+    pub fn size(mut self, size: ChartSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set size from string
+    pub fn size_str(self, size: &str) -> Self {
+        let size = match size {
+            "sparkline" | "spark" => ChartSize::Sparkline,
+            "compact" | "sm" => ChartSize::Compact,
+            "wide" | "video" => ChartSize::Wide,
+            "square" => ChartSize::Square,
+            _ => ChartSize::Standard, // fallback
+        };
+        self.size(size)
+    }
+
+    // === Frame Methods ===
+
+    /// Draw the chart on a bordered surface with padding
+    ///
+    /// Sparklines sit inline and ignore the frame.
+    pub fn framed(mut self) -> Self {
+        self.framed = true;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the container the chart is drawn into
+    pub fn container_classes(&self) -> String {
+        let size = match self.size {
+            ChartSize::Sparkline => "inline-block h-8 w-24 align-middle",
+            ChartSize::Compact => "w-full h-40",
+            ChartSize::Standard => "w-full h-72",
+            ChartSize::Wide => "w-full aspect-video",
+            ChartSize::Square => "w-full aspect-square",
+        };
+        let mut classes = vec![format!(
+            "chart chart--{} relative {}",
+            self.size_name(),
+            size
+        )];
+        if self.framed && self.size != ChartSize::Sparkline {
+            classes.push(format!(
+                "p-4 border {} {} {}",
+                self.color_provider.border_class(Color::Border),
+                self.color_provider.bg_class(Color::Surface),
+                self.color_provider
+                    .radius_scale()
+                    .radius_class(Radius::Surface)
+            ));
+        }
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the `svg` or `canvas` element filling the container
+    pub fn plot_classes(&self) -> String {
+        "chart-plot block w-full h-full overflow-visible".to_string()
+    }
+
+    /// Get the theme's chart palette
+    pub fn palette(&self) -> ChartPalette {
+        self.color_provider.chart_palette()
+    }
+
+    /// Build classes for grid lines
+    pub fn grid_classes(&self) -> String {
+        self.palette().grid_classes()
+    }
+
+    /// Build classes for axis lines and ticks
+    pub fn axis_classes(&self) -> String {
+        self.palette().axis_classes()
+    }
+
+    /// Build classes for axis and data labels
+    pub fn label_classes(&self) -> String {
+        self.palette().label_classes()
+    }
+
+    /// Build classes for the sparkline's trend line
+    ///
+    /// The stroke stays one width however the sparkline is scaled.
+    pub fn sparkline_line_classes(&self) -> String {
+        format!(
+            "chart-sparkline-line fill-none stroke-2 [vector-effect:non-scaling-stroke] {}",
+            self.palette().stroke_class(0)
+        )
+    }
+
+    /// Build classes for the area under the sparkline's trend line
+    pub fn sparkline_area_classes(&self) -> String {
+        format!(
+            "chart-sparkline-area stroke-none {}/10",
+            self.palette().fill_class(0)
+        )
+    }
+
+    /// Build classes for the legend row
+    pub fn legend_classes(&self) -> String {
+        "chart-legend flex flex-wrap items-center gap-x-2 gap-y-1 mt-3".to_string()
+    }
+
+    /// Build classes for a legend chip that toggles a series
+    ///
+    /// Hidden series stay in the legend, dimmed and struck through.
+    pub fn legend_chip_classes(&self, visible: bool) -> String {
+        let state = if visible {
+            ""
+        } else {
+            " opacity-50 line-through"
+        };
+        format!(
+            "chart-legend-chip inline-flex items-center gap-1.5 px-2 py-0.5 {} text-xs {} hover:{} focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-{}{}",
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill),
+            self.color_provider.text_class(Color::TextSecondary),
+            self.color_provider.bg_class(Color::Background),
+            self.color_provider.resolve_color(Color::Primary),
+            state
+        )
+    }
+
+    /// Build classes for the color swatch of the series at `index`
+    pub fn legend_swatch_classes(&self, index: usize) -> String {
+        format!(
+            "chart-legend-swatch h-2 w-2 shrink-0 {} {}",
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill),
+            self.palette().swatch_class(index)
+        )
+    }
+
+    /// Get attributes for a legend chip
+    pub fn legend_chip_attributes(&self, visible: bool) -> Vec<(&'static str, String)> {
+        vec![
+            ("type", "button".to_string()),
+            ("aria-pressed", visible.to_string()),
+        ]
+    }
+
+    /// Build classes for the tooltip surface
+    pub fn tooltip_classes(&self) -> String {
+        format!(
+            "chart-tooltip pointer-events-none z-50 px-3 py-2 text-xs border {} {} {} {}",
+            self.color_provider.border_class(Color::Border),
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Control),
+            self.color_provider
+                .elevation_scale()
+                .shadow_class(Elevation::Floating)
+        )
+    }
+
+    /// Build classes for the tooltip heading, e.g. the hovered date
+    pub fn tooltip_title_classes(&self) -> String {
+        format!(
+            "chart-tooltip-title mb-1 font-medium {}",
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    /// Build classes for a series row in the tooltip
+    pub fn tooltip_row_classes(&self) -> String {
+        format!(
+            "chart-tooltip-row flex items-center gap-2 {}",
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for a value in a tooltip row
+    pub fn tooltip_value_classes(&self) -> String {
+        format!(
+            "chart-tooltip-value ms-auto font-semibold tabular-nums {}",
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    fn size_name(&self) -> &'static str {
+        match self.size {
+            ChartSize::Sparkline => "sparkline",
+            ChartSize::Compact => "compact",
+            ChartSize::Standard => "standard",
+            ChartSize::Wide => "wide",
+            ChartSize::Square => "square",
+        }
+    }
+}
+
+/// Convenience function to create chart container styles
+pub fn chart_container_styles<C: ColorProvider>(color_provider: C) -> ChartContainerStyles<C> {
+    ChartContainerStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "chart_container_test.rs"]
+mod chart_container_test;
@@ -0,0 +1,105 @@
+//! Tests for chart container styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::chart_container::{
+        chart_container_styles, ChartContainerStyles, ChartSize,
+    };
+    use crate::core::ChartPalette;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_size_presets() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            chart_container_styles(colors.clone()).container_classes(),
+            "chart chart--standard relative w-full h-72"
+        );
+        assert!(ChartContainerStyles::new(colors.clone())
+            .compact()
+            .container_classes()
+            .contains("h-40"));
+        assert!(ChartContainerStyles::new(colors.clone())
+            .square()
+            .container_classes()
+            .contains("aspect-square"));
+        assert!(ChartContainerStyles::new(colors.clone())
+            .size(ChartSize::Wide)
+            .container_classes()
+            .contains("aspect-video"));
+        assert!(ChartContainerStyles::new(colors)
+            .size_str("spark")
+            .container_classes()
+            .contains("chart--sparkline"));
+    }
+
+    #[test]
+    fn test_framed_container() {
+        let colors = VibeColors::default();
+
+        let framed = ChartContainerStyles::new(colors.clone())
+            .framed()
+            .custom("my-chart")
+            .container_classes();
+        assert!(framed.contains("p-4 border border-gray-200 bg-white rounded-lg"));
+        assert!(framed.ends_with("my-chart"));
+
+        let sparkline = ChartContainerStyles::new(colors)
+            .sparkline()
+            .framed()
+            .container_classes();
+        assert!(!sparkline.contains("border"));
+    }
+
+    #[test]
+    fn test_chrome_follows_theme_palette() {
+        let colors = VibeColors::default().with_chart_palette(ChartPalette {
+            grid: "slate-100".to_string(),
+            categorical: vec!["emerald-500".to_string()],
+            ..ChartPalette::default()
+        });
+        let chart = ChartContainerStyles::new(colors);
+
+        assert_eq!(chart.grid_classes(), "stroke-slate-100 stroke-1");
+        assert_eq!(chart.axis_classes(), "stroke-gray-400 stroke-1");
+        assert!(chart.label_classes().contains("fill-gray-600"));
+        assert!(chart.legend_swatch_classes(3).contains("bg-emerald-500"));
+        assert!(chart
+            .sparkline_line_classes()
+            .contains("stroke-emerald-500"));
+        assert!(chart
+            .sparkline_area_classes()
+            .contains("fill-emerald-500/10"));
+    }
+
+    #[test]
+    fn test_legend_chips() {
+        let chart = ChartContainerStyles::new(VibeColors::default());
+
+        let visible = chart.legend_chip_classes(true);
+        assert!(visible.contains("rounded-full text-xs text-gray-600"));
+        assert!(!visible.contains("opacity-50"));
+        assert!(chart
+            .legend_chip_classes(false)
+            .contains("opacity-50 line-through"));
+        assert_eq!(
+            chart.legend_chip_attributes(false),
+            vec![
+                ("type", "button".to_string()),
+                ("aria-pressed", "false".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tooltip_surface() {
+        let chart = ChartContainerStyles::new(VibeColors::default());
+
+        let tooltip = chart.tooltip_classes();
+        assert!(tooltip.contains("pointer-events-none"));
+        assert!(tooltip.contains("bg-white"));
+        assert!(tooltip.contains("rounded-md shadow-lg"));
+        assert!(chart.tooltip_value_classes().contains("tabular-nums"));
+    }
+}
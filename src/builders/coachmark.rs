@@ -0,0 +1,274 @@
+//! Coach mark styling utilities for the Jupiter Design System
+//!
+//! Provides classes for onboarding and feature-highlight callouts: a dimmed
+//! page overlay with a spotlight around the highlighted element, an elevated
+//! callout card anchored to it with a pointer arrow, a step counter, and a
+//! next/skip action row.
+
+use crate::builders::button::ButtonStyles;
+use crate::builders::card::CardStyles;
+use crate::builders::position::{Placement, PositionStyles};
+use crate::builders::text::TextStyles;
+use crate::core::color::ColorProvider;
+use crate::core::Color;
+use crate::patterns::{FocusScope, InitialFocus};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Side of the highlighted element the callout is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoachmarkPlacement {
+    /// Above the target, arrow pointing down
+    Top,
+    /// Below the target, arrow pointing up
+    Bottom,
+    /// Left of the target, arrow pointing right
+    Left,
+    /// Right of the target, arrow pointing left
+    Right,
+}
+
+/// Coach mark styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::coachmark::CoachmarkStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let coachmark = CoachmarkStyles::new(VibeColors::default()).top();
+///
+/// let overlay_classes = coachmark.overlay_classes();
+/// let body_classes = coachmark.body_classes();
+/// let arrow_classes = coachmark.arrow_classes();
+/// let step = CoachmarkStyles::<VibeColors>::step_label(2, 5);
+/// assert_eq!(step, "2 of 5");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoachmarkStyles<C: ColorProvider + Clone> {
+    placement: CoachmarkPlacement,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> CoachmarkStyles<C> {
+    /// Create a new coach mark styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            placement: CoachmarkPlacement::Bottom,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Placement Methods ===
+
+    /// Place the callout above the target
+    pub fn top(mut self) -> Self {
+        self.placement = CoachmarkPlacement::Top;
+        self
+    }
+
+    /// Place the callout below the target
+    pub fn bottom(mut self) -> Self {
+        self.placement = CoachmarkPlacement::Bottom;
+        self
+    }
+
+    /// Place the callout left of the target
+    pub fn left(mut self) -> Self {
+        self.placement = CoachmarkPlacement::Left;
+        self
+    }
+
+    /// Place the callout right of the target
+    pub fn right(mut self) -> Self {
+        self.placement = CoachmarkPlacement::Right;
+        self
+    }
+
+    /// Set placement explicitly
+    pub fn placement(mut self, placement: CoachmarkPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set placement from string
+    pub fn placement_str(mut self, placement: &str) -> Self {
+        self.placement = match placement {
+            "top" | "above" => CoachmarkPlacement::Top,
+            "bottom" | "below" => CoachmarkPlacement::Bottom,
+            "left" | "start" => CoachmarkPlacement::Left,
+            "right" | "end" => CoachmarkPlacement::Right,
+            _ => CoachmarkPlacement::Bottom, // fallback
+        };
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the callout body
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the page-dim overlay
+    pub fn overlay_classes(&self) -> String {
+        "coachmark-overlay fixed inset-0 z-40 bg-black/50 transition-opacity duration-200 motion-reduce:transition-none"
+            .to_string()
+    }
+
+    /// Build classes for the spotlight drawn around the highlighted element
+    ///
+    /// The spotlight's spread shadow dims the rest of the page, so it can be
+    /// used instead of the overlay when the target must stay visible.
+    pub fn spotlight_classes(&self) -> String {
+        format!(
+            "coachmark-spotlight absolute z-40 rounded-lg pointer-events-none ring-2 ring-offset-2 shadow-[0_0_0_9999px_rgba(0,0,0,0.5)] ring-{}",
+            self.color_provider.resolve_color(Color::Primary)
+        )
+    }
+
+    /// Build classes for the anchor wrapper around the highlighted element
+    pub fn anchor_classes(&self) -> String {
+        "coachmark-anchor relative z-50".to_string()
+    }
+
+    /// Build classes for the callout card body
+    pub fn body_classes(&self) -> String {
+        let card = CardStyles::new(self.color_provider.clone())
+            .floating_elevation()
+            .overlay_surface()
+            .compact_spacing()
+            .classes();
+
+        let mut classes = vec![
+            format!(
+                "coachmark coachmark--{} absolute z-50 w-72 max-w-[calc(100vw-2rem)]",
+                self.placement_name()
+            ),
+            self.placement_classes().to_string(),
+            card,
+        ];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the pointer arrow
+    ///
+    /// The arrow is a rotated square sharing the card surface and border,
+    /// placed on the edge facing the target.
+    pub fn arrow_classes(&self) -> String {
+        // Placements are physical, so the arrow edge is too
+        let (edge, borders) = match self.placement {
+            CoachmarkPlacement::Top => (Placement::BottomCenter, "border-r border-b"),
+            CoachmarkPlacement::Bottom => (Placement::TopCenter, "border-l border-t"),
+            CoachmarkPlacement::Left => (Placement::CenterEnd, "border-t border-r"),
+            CoachmarkPlacement::Right => (Placement::CenterStart, "border-b border-l"),
+        };
+        let position = PositionStyles::new()
+            .placement(edge)
+            .offset("1.5")
+            .overhang()
+            .physical()
+            .classes();
+        format!(
+            "coachmark-arrow w-3 h-3 rotate-45 {} {} {} {}",
+            position,
+            borders,
+            self.color_provider.bg_class(Color::SurfaceOverlay),
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Build classes for the step-counter badge
+    pub fn step_badge_classes(&self) -> String {
+        format!(
+            "coachmark-step inline-flex items-center rounded-full px-2 py-0.5 text-xs font-semibold tabular-nums {} {}",
+            self.color_provider.bg_class(Color::Background),
+            self.color_provider.text_class(Color::Primary)
+        )
+    }
+
+    /// Build classes for the callout title
+    pub fn title_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .body()
+            .semibold()
+            .classes()
+    }
+
+    /// Build classes for the callout description
+    pub fn description_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .body_small()
+            .muted()
+            .classes()
+    }
+
+    /// Build classes for the next/skip action row
+    pub fn actions_classes(&self) -> String {
+        "coachmark-actions flex items-center justify-between gap-2 mt-4".to_string()
+    }
+
+    /// Build classes for the next (or finish) button
+    pub fn next_button_classes(&self) -> String {
+        ButtonStyles::new(self.color_provider.clone())
+            .primary()
+            .small()
+            .classes()
+    }
+
+    /// Build classes for the skip button
+    pub fn skip_button_classes(&self) -> String {
+        ButtonStyles::new(self.color_provider.clone())
+            .ghost()
+            .small()
+            .classes()
+    }
+
+    /// Get focus trap metadata for the callout
+    ///
+    /// The callout is modal while the overlay dims the page and opens on
+    /// the next button so keyboard users can step through the tour.
+    pub fn focus_scope(&self) -> FocusScope {
+        FocusScope::modal().initial_focus(InitialFocus::PrimaryAction)
+    }
+
+    /// Format the step counter text, e.g. "2 of 5"
+    pub fn step_label(step: usize, total: usize) -> String {
+        format!("{} of {}", step, total)
+    }
+
+    fn placement_name(&self) -> &'static str {
+        match self.placement {
+            CoachmarkPlacement::Top => "top",
+            CoachmarkPlacement::Bottom => "bottom",
+            CoachmarkPlacement::Left => "left",
+            CoachmarkPlacement::Right => "right",
+        }
+    }
+
+    fn placement_classes(&self) -> &'static str {
+        match self.placement {
+            CoachmarkPlacement::Top => "bottom-full left-1/2 -translate-x-1/2 mb-3",
+            CoachmarkPlacement::Bottom => "top-full left-1/2 -translate-x-1/2 mt-3",
+            CoachmarkPlacement::Left => "right-full top-1/2 -translate-y-1/2 mr-3",
+            CoachmarkPlacement::Right => "left-full top-1/2 -translate-y-1/2 ml-3",
+        }
+    }
+}
+
+/// Convenience function to create coach mark styles
+pub fn coachmark_styles<C: ColorProvider + Clone>(color_provider: C) -> CoachmarkStyles<C> {
+    CoachmarkStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "coachmark_test.rs"]
+mod coachmark_test;
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::coachmark::{coachmark_styles, CoachmarkPlacement, CoachmarkStyles};
+    use crate::patterns::InitialFocus;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_coachmark_body_is_elevated_card() {
+        let classes = coachmark_styles(VibeColors::default()).body_classes();
+
+        assert!(classes.contains("coachmark--bottom"));
+        assert!(classes.contains("top-full"));
+        assert!(classes.contains("shadow-lg"));
+        assert!(classes.contains("z-50"));
+    }
+
+    #[test]
+    fn test_coachmark_arrow_faces_target() {
+        let colors = VibeColors::default();
+
+        let top = CoachmarkStyles::new(colors.clone()).top();
+        assert!(top.body_classes().contains("bottom-full"));
+        assert!(top.arrow_classes().contains("-bottom-1.5"));
+        assert!(top.arrow_classes().contains("bg-white"));
+
+        let right = CoachmarkStyles::new(colors.clone()).placement(CoachmarkPlacement::Right);
+        assert!(right.body_classes().contains("left-full"));
+        assert!(right.arrow_classes().contains("-left-1.5"));
+
+        let fallback = CoachmarkStyles::new(colors).placement_str("invalid");
+        assert!(fallback.body_classes().contains("coachmark--bottom"));
+    }
+
+    #[test]
+    fn test_coachmark_overlay_and_spotlight() {
+        let coachmark = coachmark_styles(VibeColors::default());
+
+        assert!(coachmark.overlay_classes().contains("fixed inset-0"));
+        assert!(coachmark.overlay_classes().contains("bg-black/50"));
+        assert!(coachmark
+            .spotlight_classes()
+            .contains("ring-jupiter-blue-500"));
+        assert!(coachmark.anchor_classes().contains("z-50"));
+    }
+
+    #[test]
+    fn test_coachmark_step_and_actions() {
+        let coachmark = coachmark_styles(VibeColors::default());
+
+        assert!(coachmark.step_badge_classes().contains("tabular-nums"));
+        assert_eq!(CoachmarkStyles::<VibeColors>::step_label(1, 3), "1 of 3");
+        assert!(coachmark.actions_classes().contains("justify-between"));
+        assert!(coachmark
+            .next_button_classes()
+            .contains("bg-jupiter-blue-500"));
+        assert!(!coachmark
+            .skip_button_classes()
+            .contains("bg-jupiter-blue-500"));
+    }
+
+    #[test]
+    fn test_focus_scope() {
+        let scope = CoachmarkStyles::new(VibeColors::default()).focus_scope();
+
+        assert_eq!(scope.initial_focus, InitialFocus::PrimaryAction);
+        assert!(scope.trap);
+        assert!(scope
+            .container_attributes()
+            .contains(&("data-initial-focus", "primary-action".to_string())));
+    }
+}
@@ -0,0 +1,171 @@
+//! Detail list styling utilities for the Jupiter Design System
+//!
+//! Provides classes for key-value detail panels rendered as definition
+//! lists: a two-column term/description grid that stacks on mobile,
+//! optional row dividers, and a compact variant for sidebars.
+
+use crate::builders::text::TextStyles;
+use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth, Color, Density};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Detail list styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::detail_list::DetailListStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let details = DetailListStyles::new(VibeColors::default()).divided();
+///
+/// // <dl class={list}><div class={row}><dt class={term}/><dd class={description}/></div></dl>
+/// let list = details.classes();
+/// let row = details.row_classes();
+/// let term = details.term_classes();
+/// let description = details.description_classes();
+/// assert!(row.contains("sm:grid-cols-3"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DetailListStyles<C: ColorProvider + Clone> {
+    divided: bool,
+    compact: bool,
+    density: Density,
+    striped: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> DetailListStyles<C> {
+    /// Create a new detail list styling utility
+    ///
+    /// Density and striping default to the theme's data display tokens.
+    pub fn new(color_provider: C) -> Self {
+        let data = color_provider.data_display();
+        Self {
+            divided: false,
+            compact: false,
+            density: data.resolve_density(color_provider.density()),
+            striped: data.striped,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Variant Methods ===
+
+    /// Separate rows with dividers
+    pub fn divided(mut self) -> Self {
+        self.divided = true;
+        self
+    }
+
+    /// Use the compact variant for sidebars and narrow panels
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Set row density (defaults to the theme's data density)
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Stripe alternate rows (defaults to the theme's preference)
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the list
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the list (`dl`)
+    pub fn classes(&self) -> String {
+        let mut classes = vec!["detail-list".to_string()];
+        if self.divided {
+            classes.push(format!(
+                "{} {}",
+                self.color_provider
+                    .border_scale()
+                    .divide_class("y", BorderWidth::Default),
+                self.color_provider.border_class(Color::Border)
+            ));
+        }
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for a term/description row
+    ///
+    /// Striped rows get horizontal padding so text clears the stripe edge.
+    pub fn row_classes(&self) -> String {
+        const PADDING: [&str; 5] = ["py-1", "py-2", "py-3", "py-4", "py-5"];
+        const COMPACT_PADDING: [&str; 5] = ["py-0.5", "py-1", "py-1.5", "py-2", "py-3"];
+
+        let mut classes = vec![if self.compact {
+            format!(
+                "detail-list-row flex items-baseline justify-between gap-3 {}",
+                self.density.scale(&COMPACT_PADDING, 2)
+            )
+        } else {
+            format!(
+                "detail-list-row grid grid-cols-1 gap-1 {} sm:grid-cols-3 sm:gap-4",
+                self.density.scale(&PADDING, 2)
+            )
+        }];
+        if self.striped {
+            classes.push(format!(
+                "px-3 even:{}",
+                self.color_provider.bg_class(Color::Background)
+            ));
+        }
+        classes.join(" ")
+    }
+
+    /// Build classes for the term (`dt`)
+    pub fn term_classes(&self) -> String {
+        let term = TextStyles::new(self.color_provider.clone()).muted();
+        if self.compact {
+            term.caption().classes()
+        } else {
+            term.body_small().medium_weight().classes()
+        }
+    }
+
+    /// Build classes for the description (`dd`)
+    pub fn description_classes(&self) -> String {
+        let description = TextStyles::new(self.color_provider.clone()).body_small();
+        if self.compact {
+            description.right().classes()
+        } else {
+            format!("{} sm:col-span-2", description.classes())
+        }
+    }
+}
+
+/// Convenience function to create detail list styles
+pub fn detail_list_styles<C: ColorProvider + Clone>(color_provider: C) -> DetailListStyles<C> {
+    DetailListStyles::new(color_provider)
+}
+
+/// Convenience function to create compact sidebar detail list styles
+pub fn compact_detail_list_styles<C: ColorProvider + Clone>(
+    color_provider: C,
+) -> DetailListStyles<C> {
+    DetailListStyles::new(color_provider).compact()
+}
+
+#[cfg(test)]
+#[path = "detail_list_test.rs"]
+mod detail_list_test;
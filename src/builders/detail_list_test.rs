@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::detail_list::{compact_detail_list_styles, detail_list_styles};
+    use crate::core::{BorderScale, DataDisplayTokens, Density};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_rows_stack_on_mobile() {
+        let details = detail_list_styles(VibeColors::default());
+
+        assert!(details.row_classes().contains("grid-cols-1"));
+        assert!(details.row_classes().contains("sm:grid-cols-3"));
+        assert!(details.description_classes().contains("sm:col-span-2"));
+    }
+
+    #[test]
+    fn test_divided_rows() {
+        let colors = VibeColors::default();
+
+        assert!(!detail_list_styles(colors.clone())
+            .classes()
+            .contains("divide-y"));
+
+        let divided = detail_list_styles(colors).divided().classes();
+        assert!(divided.contains("divide-y"));
+        assert!(divided.contains("border-gray-200"));
+
+        let bold = detail_list_styles(VibeColors::default().with_borders(BorderScale::bold()))
+            .divided()
+            .classes();
+        assert!(bold.contains("divide-y-2"));
+    }
+
+    #[test]
+    fn test_term_and_description_typography() {
+        let details = detail_list_styles(VibeColors::default());
+
+        assert!(details.term_classes().contains("text-gray-600"));
+        assert!(details.term_classes().contains("font-medium"));
+        assert_ne!(details.term_classes(), details.description_classes());
+    }
+
+    #[test]
+    fn test_compact_variant() {
+        let details = compact_detail_list_styles(VibeColors::default());
+
+        assert!(details.row_classes().contains("justify-between"));
+        assert!(!details.row_classes().contains("sm:grid-cols-3"));
+        assert!(details.description_classes().contains("text-right"));
+    }
+
+    #[test]
+    fn test_custom_classes() {
+        let classes = detail_list_styles(VibeColors::default())
+            .custom("mt-6")
+            .classes();
+
+        assert!(classes.contains("mt-6"));
+    }
+
+    #[test]
+    fn test_theme_data_display_defaults() {
+        let admin = VibeColors::default().with_data_display(DataDisplayTokens {
+            density: Some(Density::Compact),
+            striped: true,
+        });
+
+        let details = detail_list_styles(admin.clone());
+        assert!(details.row_classes().contains("py-2"));
+        assert!(details.row_classes().contains("even:bg-gray-50"));
+
+        let compact = compact_detail_list_styles(admin.clone());
+        assert!(compact.row_classes().contains("py-1"));
+
+        let plain = detail_list_styles(admin)
+            .striped(false)
+            .density(Density::Comfortable);
+        assert!(plain.row_classes().contains("py-3"));
+        assert!(!plain.row_classes().contains("even:"));
+
+        let default = detail_list_styles(VibeColors::default());
+        assert!(default.row_classes().contains("py-3"));
+        assert!(!default.row_classes().contains("even:"));
+    }
+}
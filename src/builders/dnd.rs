@@ -0,0 +1,304 @@
+//! Drag-and-drop styling utilities for the Jupiter Design System
+//!
+//! Provides state classes for sortable lists and boards: the lifted item
+//! following the pointer, the ghost left at its origin, the placeholder
+//! marking where it will land, and drop targets that accept or reject it.
+//! The same states apply to cards, list rows and kanban columns, with
+//! corners and emphasis adjusted to each surface.
+
+use crate::core::color::ColorProvider;
+use crate::core::{
+    Color, Elevation, ElevationProvider, MotionDuration, MotionProvider, Radius, RadiusProvider,
+    ScaleEffect,
+};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Drag-and-drop states
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DragState {
+    /// At rest, ready to be picked up
+    Idle,
+    /// Picked up and following the pointer
+    Lifted,
+    /// Left at its origin while a copy is dragged
+    Ghost,
+    /// Marks where the dragged item will land
+    Placeholder,
+    /// Under the pointer and accepting the drop
+    DropTarget,
+    /// Under the pointer but refusing the drop
+    InvalidDrop,
+}
+
+impl DragState {
+    /// Name used in modifier classes and `data-drag-state`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DragState::Idle => "idle",
+            DragState::Lifted => "lifted",
+            DragState::Ghost => "ghost",
+            DragState::Placeholder => "placeholder",
+            DragState::DropTarget => "drop-target",
+            DragState::InvalidDrop => "invalid-drop",
+        }
+    }
+}
+
+/// Kind of element being dragged or dropped onto
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DragSurface {
+    /// Cards and tiles
+    Card,
+    /// List and table rows
+    Row,
+    /// Kanban columns and other large drop areas
+    Column,
+}
+
+/// Drag-and-drop styling utility builder
+///
+/// Apply the classes to the item or target itself, switching state as the
+/// drag progresses; the adapter owns the drag logic.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::dnd::DndStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let lifted = DndStyles::new(VibeColors::default()).card().lifted();
+/// assert!(lifted.classes().contains("shadow-xl scale-105 opacity-90"));
+///
+/// let column = DndStyles::new(VibeColors::default()).column().drop_target();
+/// assert!(column.classes().contains("ring-jupiter-blue-500"));
+///
+/// let refused = DndStyles::new(VibeColors::default()).row().invalid_drop();
+/// assert!(refused.classes().contains("cursor-no-drop"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DndStyles<C: ColorProvider> {
+    state: DragState,
+    surface: DragSurface,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> DndStyles<C> {
+    /// Create a new drag-and-drop styling utility (idle card)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            state: DragState::Idle,
+            surface: DragSurface::Card,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Surface Methods ===
+
+    /// Style a card or tile
+    pub fn card(mut self) -> Self {
+        self.surface = DragSurface::Card;
+        self
+    }
+
+    /// Style a list or table row
+    pub fn row(mut self) -> Self {
+        self.surface = DragSurface::Row;
+        self
+    }
+
+    /// Style a kanban column or other large drop area
+    pub fn column(mut self) -> Self {
+        self.surface = DragSurface::Column;
+        self
+    }
+
+    /// Set surface explicitly
+    pub fn surface(mut self, surface: DragSurface) -> Self {
+        self.surface = surface;
+        self
+    }
+
+    // === State Methods ===
+
+    /// Set idle state
+    pub fn idle(mut self) -> Self {
+        self.state = DragState::Idle;
+        self
+    }
+
+    /// Set lifted state
+    pub fn lifted(mut self) -> Self {
+        self.state = DragState::Lifted;
+        self
+    }
+
+    /// Set ghost state
+    pub fn ghost(mut self) -> Self {
+        self.state = DragState::Ghost;
+        self
+    }
+
+    /// Set placeholder state
+    pub fn placeholder(mut self) -> Self {
+        self.state = DragState::Placeholder;
+        self
+    }
+
+    /// Set drop-target state
+    pub fn drop_target(mut self) -> Self {
+        self.state = DragState::DropTarget;
+        self
+    }
+
+    /// Set invalid-drop state
+    pub fn invalid_drop(mut self) -> Self {
+        self.state = DragState::InvalidDrop;
+        self
+    }
+
+    /// Set state explicitly
+    pub fn state(mut self, state: DragState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set state from string
+    pub fn state_str(mut self, state: &str) -> Self {
+        self.state = match state {
+            "idle" => DragState::Idle,
+            "lifted" | "dragging" => DragState::Lifted,
+            "ghost" | "source" => DragState::Ghost,
+            "placeholder" => DragState::Placeholder,
+            "drop-target" | "over" => DragState::DropTarget,
+            "invalid-drop" | "invalid" | "rejected" => DragState::InvalidDrop,
+            _ => DragState::Idle, // fallback
+        };
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the item or target in its current state
+    pub fn classes(&self) -> String {
+        let motion = self.color_provider.motion_scale();
+        let mut classes = vec![
+            format!("dnd dnd--{}", self.state.as_str()),
+            format!(
+                "transition {} motion-reduce:transition-none",
+                motion.duration_class(MotionDuration::Fast)
+            ),
+        ];
+
+        let state_classes = match self.state {
+            DragState::Idle => String::new(),
+            // Rows stay flat so the lifted row lines up with its neighbours
+            DragState::Lifted => {
+                let scale = match self.surface {
+                    DragSurface::Row => None,
+                    DragSurface::Card | DragSurface::Column => {
+                        motion.scale_class(ScaleEffect::Hover)
+                    }
+                };
+                let mut lifted = vec![
+                    "relative z-50 cursor-grabbing".to_string(),
+                    self.color_provider
+                        .elevation_scale()
+                        .shadow_class(Elevation::Overlay),
+                ];
+                lifted.extend(scale);
+                lifted.push("opacity-90".to_string());
+                lifted.join(" ")
+            }
+            DragState::Ghost => "opacity-40".to_string(),
+            DragState::Placeholder => format!(
+                "border-2 border-dashed {} {} {}",
+                self.color_provider.border_class(Color::Border),
+                self.color_provider.bg_class(Color::Background),
+                self.radius()
+            ),
+            DragState::DropTarget => format!(
+                "{} {}/5 {}",
+                self.ring(),
+                self.color_provider.bg_class(Color::Primary),
+                self.ring_color(Color::Primary)
+            ),
+            DragState::InvalidDrop => format!(
+                "cursor-no-drop {} {}/5 {}",
+                self.ring(),
+                self.color_provider.bg_class(Color::Error),
+                self.ring_color(Color::Error)
+            ),
+        };
+        if !state_classes.is_empty() {
+            classes.push(state_classes);
+        }
+
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for a drag handle (grip icon button)
+    ///
+    /// `touch-none` keeps touch drags from scrolling the page.
+    pub fn handle_classes(&self) -> String {
+        format!(
+            "dnd-handle inline-flex items-center justify-center cursor-grab active:cursor-grabbing touch-none {} hover:{} focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-{}",
+            self.color_provider.text_class(Color::TextTertiary),
+            self.color_provider.text_class(Color::TextSecondary),
+            self.color_provider.resolve_color(Color::Primary)
+        )
+    }
+
+    /// Get attributes for the item or target
+    pub fn attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![("data-drag-state", self.state.as_str().to_string())];
+        if matches!(self.state, DragState::Ghost | DragState::Placeholder) {
+            attrs.push(("aria-hidden", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Rows sit inside a list, so their ring is inset to avoid overlapping
+    /// neighbours
+    fn ring(&self) -> &'static str {
+        match self.surface {
+            DragSurface::Row => "ring-2 ring-inset",
+            DragSurface::Card | DragSurface::Column => "ring-2",
+        }
+    }
+
+    fn ring_color(&self, color: Color) -> String {
+        format!("ring-{}", self.color_provider.resolve_color(color))
+    }
+
+    fn radius(&self) -> String {
+        let radius = match self.surface {
+            DragSurface::Row => Radius::Control,
+            DragSurface::Card | DragSurface::Column => Radius::Surface,
+        };
+        self.color_provider.radius_scale().radius_class(radius)
+    }
+}
+
+/// Convenience function to create drag-and-drop styles
+pub fn dnd_styles<C: ColorProvider>(color_provider: C) -> DndStyles<C> {
+    DndStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "dnd_test.rs"]
+mod dnd_test;
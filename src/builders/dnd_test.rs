@@ -0,0 +1,99 @@
+//! Tests for drag-and-drop styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::dnd::{dnd_styles, DndStyles, DragState, DragSurface};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_idle_has_only_base_classes() {
+        let classes = dnd_styles(VibeColors::default()).classes();
+
+        assert!(classes.contains("dnd dnd--idle"));
+        assert!(classes.contains("duration-150 motion-reduce:transition-none"));
+        assert!(!classes.contains("ring"));
+        assert!(!classes.contains("opacity"));
+    }
+
+    #[test]
+    fn test_lifted_scales_cards_but_not_rows() {
+        let colors = VibeColors::default();
+
+        let card = DndStyles::new(colors.clone()).lifted().classes();
+        assert!(card.contains("relative z-50 cursor-grabbing shadow-xl scale-105 opacity-90"));
+
+        let column = DndStyles::new(colors.clone()).column().lifted().classes();
+        assert!(column.contains("scale-105"));
+
+        let row = DndStyles::new(colors).row().lifted().classes();
+        assert!(row.contains("shadow-xl opacity-90"));
+        assert!(!row.contains("scale-"));
+    }
+
+    #[test]
+    fn test_ghost_and_placeholder() {
+        let colors = VibeColors::default();
+
+        let ghost = DndStyles::new(colors.clone()).ghost();
+        assert!(ghost.classes().contains("opacity-40"));
+        assert!(ghost
+            .attributes()
+            .contains(&("aria-hidden", "true".to_string())));
+
+        let card = DndStyles::new(colors.clone()).placeholder().classes();
+        assert!(card.contains("border-2 border-dashed border-gray-200 bg-gray-50 rounded-lg"));
+
+        let row = DndStyles::new(colors).row().placeholder().classes();
+        assert!(row.contains("rounded-md"));
+    }
+
+    #[test]
+    fn test_drop_targets() {
+        let colors = VibeColors::default();
+
+        let column = DndStyles::new(colors.clone()).column().drop_target();
+        let classes = column.classes();
+        assert!(classes.contains("ring-2 bg-jupiter-blue-500/5 ring-jupiter-blue-500"));
+        assert_eq!(
+            column.attributes(),
+            vec![("data-drag-state", "drop-target".to_string())]
+        );
+
+        let row = DndStyles::new(colors.clone()).row().drop_target().classes();
+        assert!(row.contains("ring-2 ring-inset"));
+
+        let invalid = DndStyles::new(colors).invalid_drop().classes();
+        assert!(invalid.contains("dnd--invalid-drop"));
+        assert!(invalid.contains("cursor-no-drop"));
+        assert!(invalid.contains("bg-red-500/5 ring-red-500"));
+    }
+
+    #[test]
+    fn test_state_str_and_surface() {
+        let colors = VibeColors::default();
+
+        let lifted = DndStyles::new(colors.clone()).state_str("dragging");
+        assert!(lifted.classes().contains("dnd--lifted"));
+
+        let rejected = DndStyles::new(colors.clone()).state_str("rejected");
+        assert!(rejected.classes().contains("dnd--invalid-drop"));
+
+        let fallback = DndStyles::new(colors.clone()).state_str("unknown");
+        assert!(fallback.classes().contains("dnd--idle"));
+
+        let row = DndStyles::new(colors)
+            .surface(DragSurface::Row)
+            .state(DragState::DropTarget)
+            .custom("my-row");
+        assert!(row.classes().contains("ring-inset"));
+        assert!(row.classes().ends_with("my-row"));
+    }
+
+    #[test]
+    fn test_handle_classes() {
+        let handle = dnd_styles(VibeColors::default()).handle_classes();
+
+        assert!(handle.contains("cursor-grab active:cursor-grabbing touch-none"));
+        assert!(handle.contains("focus-visible:ring-jupiter-blue-500"));
+    }
+}
@@ -0,0 +1,256 @@
+//! Faceted navigation styling utilities for the Jupiter Design System
+//!
+//! Provides classes for filter sidebars: collapsible facet groups, checkbox
+//! option lists with counts, show-more links, applied-filter chips, and a
+//! full-screen drawer variant for mobile layouts.
+
+use crate::builders::selection::{chip_selection_styles, SelectionStyles};
+use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth, Color};
+use crate::patterns::{FocusScope, SelectionState};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Facet sidebar styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::facet::FacetStyles;
+/// use jupiter_design_system::patterns::SelectionState;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let facets = FacetStyles::new(VibeColors::default());
+///
+/// let header_classes = facets.group_header_classes(true);
+/// let checkbox_classes = facets.checkbox_classes(SelectionState::Selected);
+/// let count_classes = facets.count_classes(true);
+///
+/// // Mobile variant rendered as a full-screen drawer
+/// let drawer_classes = FacetStyles::new(VibeColors::default())
+///     .drawer()
+///     .container_classes();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FacetStyles<C: ColorProvider + Clone> {
+    drawer: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> FacetStyles<C> {
+    /// Create a new facet styling utility (sidebar variant)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            drawer: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Variant Methods ===
+
+    /// Use the inline sidebar variant
+    pub fn sidebar(mut self) -> Self {
+        self.drawer = false;
+        self
+    }
+
+    /// Use the mobile full-screen drawer variant
+    pub fn drawer(mut self) -> Self {
+        self.drawer = true;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the facet container (sidebar or drawer)
+    pub fn container_classes(&self) -> String {
+        let mut classes = if self.drawer {
+            vec![
+                "facet-drawer fixed inset-0 z-50 flex flex-col overflow-y-auto".to_string(),
+                self.color_provider.bg_class(Color::Surface),
+            ]
+        } else {
+            vec!["facet-sidebar flex flex-col gap-4 w-64 shrink-0".to_string()]
+        };
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the drawer header (title and close button)
+    pub fn drawer_header_classes(&self) -> String {
+        format!(
+            "facet-drawer-header sticky top-0 flex items-center justify-between px-4 py-3 border-b {} {}",
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Build classes for the drawer footer (apply/reset actions)
+    pub fn drawer_footer_classes(&self) -> String {
+        format!(
+            "facet-drawer-footer sticky bottom-0 mt-auto flex items-center gap-3 px-4 py-3 {} {} {}",
+            self.color_provider
+                .border_scale()
+                .border_side_class("t", BorderWidth::Default),
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Build classes for a facet group wrapper
+    pub fn group_classes(&self) -> String {
+        let padding = if self.drawer { "px-4 pb-4" } else { "pb-4" };
+        format!(
+            "facet-group border-b {} {}",
+            padding,
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Build classes for a collapsible facet group header
+    pub fn group_header_classes(&self, expanded: bool) -> String {
+        let state = if expanded {
+            "facet-group-header--expanded"
+        } else {
+            "facet-group-header--collapsed"
+        };
+        format!(
+            "facet-group-header {} flex items-center justify-between w-full py-2 text-sm font-semibold cursor-pointer focus:outline-none focus:ring-2 {}",
+            state,
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    /// Build classes for the expand/collapse chevron
+    pub fn chevron_classes(&self, expanded: bool) -> String {
+        let rotation = if expanded { "rotate-180" } else { "rotate-0" };
+        format!(
+            "facet-chevron w-4 h-4 transition-transform duration-200 motion-reduce:transition-none {} {}",
+            rotation,
+            self.color_provider.text_class(Color::TextTertiary)
+        )
+    }
+
+    /// Build classes for a facet group body
+    pub fn group_body_classes(&self, expanded: bool) -> String {
+        if expanded {
+            "facet-group-body flex flex-col gap-2 pt-2".to_string()
+        } else {
+            "facet-group-body hidden".to_string()
+        }
+    }
+
+    /// Build classes for a checkbox option row
+    pub fn option_classes(&self, state: SelectionState) -> String {
+        let interaction = if matches!(state, SelectionState::Disabled) {
+            "cursor-not-allowed opacity-50"
+        } else {
+            "cursor-pointer"
+        };
+        format!(
+            "facet-option flex items-center gap-2 text-sm {} {}",
+            interaction,
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    /// Build classes for an option checkbox (supports the partial state)
+    pub fn checkbox_classes(&self, state: SelectionState) -> String {
+        let state_classes = match state {
+            SelectionState::Unselected => format!(
+                "{} {}",
+                self.color_provider.bg_class(Color::Surface),
+                self.color_provider.border_class(Color::Border)
+            ),
+            SelectionState::Selected | SelectionState::PartiallySelected => format!(
+                "{} {} {}",
+                self.color_provider.bg_class(Color::Primary),
+                self.color_provider.border_class(Color::Primary),
+                self.color_provider.text_class(Color::TextInverse)
+            ),
+            SelectionState::Disabled => format!(
+                "{} {}",
+                self.color_provider.bg_class(Color::InteractiveDisabled),
+                self.color_provider.border_class(Color::InteractiveDisabled)
+            ),
+        };
+        format!(
+            "facet-checkbox inline-flex items-center justify-center w-4 h-4 rounded border {}",
+            state_classes
+        )
+    }
+
+    /// Build classes for an option count badge
+    ///
+    /// Reuses the selection count badge so facet counts match other
+    /// selection components.
+    pub fn count_classes(&self, selected: bool) -> String {
+        let counts = SelectionStyles::new(self.color_provider.clone()).with_counts(true);
+        let counts = if selected {
+            counts.selected()
+        } else {
+            counts.unselected()
+        };
+        format!("facet-count ml-auto {}", counts.count_classes())
+    }
+
+    /// Build classes for the show-more/show-less link
+    pub fn show_more_classes(&self) -> String {
+        format!(
+            "facet-show-more self-start text-sm font-medium cursor-pointer hover:underline {}",
+            self.color_provider.text_class(Color::Primary)
+        )
+    }
+
+    /// Build classes for the applied-filters chip container
+    pub fn applied_filters_classes(&self) -> String {
+        format!(
+            "facet-applied {}",
+            chip_selection_styles(self.color_provider.clone())
+                .sm()
+                .container_classes()
+        )
+    }
+
+    /// Get focus trap metadata (drawer variant only)
+    pub fn focus_scope(&self) -> Option<FocusScope> {
+        if self.drawer {
+            Some(FocusScope::drawer())
+        } else {
+            None
+        }
+    }
+
+    /// Build classes for an applied-filter chip
+    pub fn applied_chip_classes(&self) -> String {
+        chip_selection_styles(self.color_provider.clone())
+            .sm()
+            .selected()
+            .item_classes()
+    }
+}
+
+/// Convenience function to create facet sidebar styles
+pub fn facet_styles<C: ColorProvider + Clone>(color_provider: C) -> FacetStyles<C> {
+    FacetStyles::new(color_provider)
+}
+
+/// Convenience function to create mobile facet drawer styles
+pub fn facet_drawer_styles<C: ColorProvider + Clone>(color_provider: C) -> FacetStyles<C> {
+    FacetStyles::new(color_provider).drawer()
+}
+
+#[cfg(test)]
+#[path = "facet_test.rs"]
+mod facet_test;
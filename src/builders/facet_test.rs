@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::facet::{facet_drawer_styles, facet_styles, FacetStyles};
+    use crate::patterns::{FocusScope, InitialFocus, SelectionState};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_facet_sidebar_and_drawer_containers() {
+        let colors = VibeColors::default();
+
+        let sidebar = facet_styles(colors.clone()).container_classes();
+        assert!(sidebar.contains("facet-sidebar"));
+        assert!(!sidebar.contains("fixed"));
+
+        let drawer = facet_drawer_styles(colors).container_classes();
+        assert!(drawer.contains("facet-drawer"));
+        assert!(drawer.contains("fixed"));
+        assert!(drawer.contains("inset-0"));
+        assert!(drawer.contains("bg-white"));
+    }
+
+    #[test]
+    fn test_facet_group_collapse() {
+        let facets = FacetStyles::new(VibeColors::default());
+
+        assert!(facets
+            .group_header_classes(true)
+            .contains("facet-group-header--expanded"));
+        assert!(facets.chevron_classes(true).contains("rotate-180"));
+        assert!(facets.chevron_classes(false).contains("rotate-0"));
+        assert!(facets.group_body_classes(false).contains("hidden"));
+        assert!(!facets.group_body_classes(true).contains("hidden"));
+    }
+
+    #[test]
+    fn test_facet_checkbox_states() {
+        let facets = FacetStyles::new(VibeColors::default());
+
+        let unselected = facets.checkbox_classes(SelectionState::Unselected);
+        assert!(unselected.contains("bg-white"));
+
+        let selected = facets.checkbox_classes(SelectionState::Selected);
+        assert!(selected.contains("bg-jupiter-blue-500"));
+
+        let partial = facets.checkbox_classes(SelectionState::PartiallySelected);
+        assert!(partial.contains("bg-jupiter-blue-500"));
+
+        let disabled = facets.option_classes(SelectionState::Disabled);
+        assert!(disabled.contains("cursor-not-allowed"));
+    }
+
+    #[test]
+    fn test_facet_counts_reuse_selection_counts() {
+        let facets = FacetStyles::new(VibeColors::default());
+
+        let selected = facets.count_classes(true);
+        assert!(selected.contains("rounded-full"));
+        assert!(selected.contains("bg-jupiter-blue-500"));
+
+        let unselected = facets.count_classes(false);
+        assert!(unselected.contains("bg-gray-50"));
+    }
+
+    #[test]
+    fn test_facet_show_more_and_applied_chips() {
+        let facets = FacetStyles::new(VibeColors::default()).custom("lg:w-72");
+
+        assert!(facets.container_classes().contains("lg:w-72"));
+        assert!(facets.show_more_classes().contains("hover:underline"));
+        assert!(facets.applied_filters_classes().contains("flex-wrap"));
+        assert!(facets.applied_chip_classes().contains("rounded-full"));
+    }
+
+    #[test]
+    fn test_focus_scope_only_for_drawer() {
+        let colors = VibeColors::default();
+
+        assert_eq!(facet_styles(colors.clone()).focus_scope(), None);
+
+        let scope = facet_drawer_styles(colors).focus_scope().unwrap();
+        assert_eq!(scope, FocusScope::drawer());
+        assert_eq!(scope.initial_focus, InitialFocus::Container);
+        assert!(scope.trap);
+        assert!(scope.restore_focus);
+    }
+}
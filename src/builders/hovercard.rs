@@ -0,0 +1,426 @@
+//! Hover card styling utilities for the Jupiter Design System
+//!
+//! Provides classes for rich hover previews such as a user profile shown
+//! when hovering an avatar: an elevated floating panel anchored to the
+//! trigger with a pointer arrow, an entrance transition that waits out a
+//! hover-intent delay, and the timing metadata adapters need to open and
+//! close the card without flicker.
+
+use crate::builders::card::CardStyles;
+use crate::builders::position::{Placement, PositionStyles};
+use crate::builders::text::TextStyles;
+use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth, Color, MotionDuration, MotionProvider};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Side of the trigger the hover card is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HovercardPlacement {
+    /// Above the trigger, arrow pointing down
+    Top,
+    /// Below the trigger, arrow pointing up
+    Bottom,
+    /// Left of the trigger, arrow pointing right
+    Left,
+    /// Right of the trigger, arrow pointing left
+    Right,
+}
+
+/// Hover-intent timing for opening and closing a hover card
+///
+/// The open delay keeps cards from flashing while the pointer crosses the
+/// page; the close delay gives the pointer time to travel from the trigger
+/// to the card. With `safe_polygon` set, adapters also keep the card open
+/// while the pointer moves inside the triangle between its position on
+/// leaving the trigger and the near edge of the card, so a diagonal path
+/// across other content does not dismiss it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HoverIntent {
+    /// Milliseconds the pointer must rest on the trigger before opening
+    pub open_delay_ms: u16,
+    /// Milliseconds to wait after the pointer leaves before closing
+    pub close_delay_ms: u16,
+    /// Keep the card open while the pointer travels toward it
+    pub safe_polygon: bool,
+}
+
+impl Default for HoverIntent {
+    fn default() -> Self {
+        Self {
+            open_delay_ms: 500,
+            close_delay_ms: 300,
+            safe_polygon: true,
+        }
+    }
+}
+
+impl HoverIntent {
+    /// Get the data attributes adapters read for hover-intent handling
+    pub fn attributes(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("data-open-delay", self.open_delay_ms.to_string()),
+            ("data-close-delay", self.close_delay_ms.to_string()),
+            ("data-safe-polygon", self.safe_polygon.to_string()),
+        ]
+    }
+}
+
+/// Hover card styling utility builder
+///
+/// The panel is closed until the adapter sets `data-state="open"` on it;
+/// the entrance transition then runs after the open delay. Keyboard users
+/// should get the same card on focus, and the card must not hold anything
+/// that cannot be reached another way.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::hovercard::HovercardStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let card = HovercardStyles::new(VibeColors::default()).top();
+///
+/// let panel_classes = card.panel_classes();
+/// assert!(panel_classes.contains("bottom-full"));
+/// assert!(panel_classes.contains("data-[state=open]:delay-500"));
+///
+/// let arrow_classes = card.arrow_classes();
+/// assert!(arrow_classes.contains("rotate-45"));
+///
+/// let intent = card.hover_intent();
+/// assert!(intent.safe_polygon);
+/// assert!(card.trigger_attributes(false).contains(&("data-state", "closed".to_string())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HovercardStyles<C: ColorProvider + Clone> {
+    placement: HovercardPlacement,
+    hover_intent: HoverIntent,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> HovercardStyles<C> {
+    /// Create a new hover card styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            placement: HovercardPlacement::Bottom,
+            hover_intent: HoverIntent::default(),
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Placement Methods ===
+
+    /// Place the card above the trigger
+    pub fn top(mut self) -> Self {
+        self.placement = HovercardPlacement::Top;
+        self
+    }
+
+    /// Place the card below the trigger
+    pub fn bottom(mut self) -> Self {
+        self.placement = HovercardPlacement::Bottom;
+        self
+    }
+
+    /// Place the card left of the trigger
+    pub fn left(mut self) -> Self {
+        self.placement = HovercardPlacement::Left;
+        self
+    }
+
+    /// Place the card right of the trigger
+    pub fn right(mut self) -> Self {
+        self.placement = HovercardPlacement::Right;
+        self
+    }
+
+    /// Set placement explicitly
+    pub fn placement(mut self, placement: HovercardPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set placement from string
+    pub fn placement_str(mut self, placement: &str) -> Self {
+        self.placement = match placement {
+            "top" | "above" => HovercardPlacement::Top,
+            "bottom" | "below" => HovercardPlacement::Bottom,
+            "left" | "start" => HovercardPlacement::Left,
+            "right" | "end" => HovercardPlacement::Right,
+            _ => HovercardPlacement::Bottom, // fallback
+        };
+        self
+    }
+
+    // === Timing Methods ===
+
+    /// Set how long the pointer rests on the trigger before the card opens
+    pub fn open_delay(mut self, ms: u16) -> Self {
+        self.hover_intent.open_delay_ms = ms;
+        self
+    }
+
+    /// Set how long the card stays open after the pointer leaves
+    pub fn close_delay(mut self, ms: u16) -> Self {
+        self.hover_intent.close_delay_ms = ms;
+        self
+    }
+
+    /// Enable or disable safe-polygon hover intent
+    pub fn safe_polygon(mut self, enabled: bool) -> Self {
+        self.hover_intent.safe_polygon = enabled;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the card panel
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the anchor wrapper around the trigger
+    pub fn anchor_classes(&self) -> String {
+        "hovercard-anchor relative inline-block".to_string()
+    }
+
+    /// Build classes for the floating card panel
+    ///
+    /// Closing is immediate; opening waits for the open delay so a pointer
+    /// passing over the trigger does not flash the card.
+    pub fn panel_classes(&self) -> String {
+        // The card's own transition would fight the entrance transition
+        let card = CardStyles::new(self.color_provider.clone())
+            .floating_elevation()
+            .overlay_surface()
+            .compact_spacing()
+            .classes()
+            .split_whitespace()
+            .filter(|class| !class.starts_with("transition") && !class.starts_with("duration-"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let motion = self.color_provider.motion_scale();
+
+        let mut classes = vec![
+            format!(
+                "hovercard hovercard--{} absolute z-50 w-80 max-w-[calc(100vw-2rem)]",
+                self.placement_name()
+            ),
+            self.placement_classes().to_string(),
+            card,
+            format!(
+                "invisible opacity-0 {} pointer-events-none transition-[opacity,transform,visibility] {} ease-out motion-reduce:transition-none",
+                self.hidden_offset(),
+                motion.duration_class(MotionDuration::Fast)
+            ),
+            format!(
+                "data-[state=open]:visible data-[state=open]:opacity-100 data-[state=open]:{} data-[state=open]:pointer-events-auto data-[state=open]:{}",
+                self.shown_offset(),
+                delay_class(self.hover_intent.open_delay_ms)
+            ),
+            self.bridge_classes().to_string(),
+        ];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the pointer arrow
+    ///
+    /// The arrow is a rotated square sharing the card surface and border,
+    /// placed on the edge facing the trigger.
+    pub fn arrow_classes(&self) -> String {
+        // Placements are physical, so the arrow edge is too
+        let (edge, borders) = match self.placement {
+            HovercardPlacement::Top => (Placement::BottomCenter, "border-r border-b"),
+            HovercardPlacement::Bottom => (Placement::TopCenter, "border-l border-t"),
+            HovercardPlacement::Left => (Placement::CenterEnd, "border-t border-r"),
+            HovercardPlacement::Right => (Placement::CenterStart, "border-b border-l"),
+        };
+        let position = PositionStyles::new()
+            .placement(edge)
+            .offset("1.5")
+            .overhang()
+            .physical()
+            .classes();
+        format!(
+            "hovercard-arrow w-3 h-3 rotate-45 {} {} {} {}",
+            position,
+            borders,
+            self.color_provider.bg_class(Color::SurfaceOverlay),
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Build classes for the avatar in a profile preview
+    pub fn avatar_classes(&self) -> String {
+        format!(
+            "hovercard-avatar w-12 h-12 shrink-0 rounded-full object-cover ring-2 ring-{}",
+            self.color_provider.resolve_color(Color::SurfaceOverlay)
+        )
+    }
+
+    /// Build classes for the card header (avatar beside title and subtitle)
+    pub fn header_classes(&self) -> String {
+        "hovercard-header flex items-start gap-3".to_string()
+    }
+
+    /// Build classes for the card title, e.g. a display name
+    pub fn title_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .body()
+            .semibold()
+            .truncate()
+            .classes()
+    }
+
+    /// Build classes for the subtitle, e.g. a handle or job title
+    pub fn subtitle_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .body_small()
+            .muted()
+            .truncate()
+            .classes()
+    }
+
+    /// Build classes for the card body text, e.g. a bio
+    pub fn description_classes(&self) -> String {
+        format!(
+            "mt-3 line-clamp-3 {}",
+            TextStyles::new(self.color_provider.clone())
+                .body_small()
+                .classes()
+        )
+    }
+
+    /// Build classes for the footer row of stats or actions
+    pub fn footer_classes(&self) -> String {
+        format!(
+            "hovercard-footer flex items-center gap-4 mt-3 pt-3 {} {}",
+            self.color_provider
+                .border_scale()
+                .border_side_class("t", BorderWidth::Default),
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Get the hover-intent timing for the card
+    pub fn hover_intent(&self) -> HoverIntent {
+        self.hover_intent
+    }
+
+    /// Get attributes for the trigger element
+    ///
+    /// Includes the hover-intent timing so adapters can attach one handler
+    /// to every trigger on the page.
+    pub fn trigger_attributes(&self, open: bool) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![
+            ("data-state", state_name(open).to_string()),
+            ("data-hovercard-trigger", "true".to_string()),
+        ];
+        attrs.extend(self.hover_intent.attributes());
+        attrs
+    }
+
+    /// Get attributes for the card panel
+    ///
+    /// Hover cards are supplementary, so the panel is a plain group rather
+    /// than a dialog and does not move focus when it opens.
+    pub fn panel_attributes(&self, open: bool) -> Vec<(&'static str, String)> {
+        vec![
+            ("data-state", state_name(open).to_string()),
+            ("data-placement", self.placement_name().to_string()),
+            ("role", "group".to_string()),
+        ]
+    }
+
+    fn placement_name(&self) -> &'static str {
+        match self.placement {
+            HovercardPlacement::Top => "top",
+            HovercardPlacement::Bottom => "bottom",
+            HovercardPlacement::Left => "left",
+            HovercardPlacement::Right => "right",
+        }
+    }
+
+    fn placement_classes(&self) -> &'static str {
+        match self.placement {
+            HovercardPlacement::Top => "bottom-full left-1/2 -translate-x-1/2 mb-3",
+            HovercardPlacement::Bottom => "top-full left-1/2 -translate-x-1/2 mt-3",
+            HovercardPlacement::Left => "right-full top-1/2 -translate-y-1/2 mr-3",
+            HovercardPlacement::Right => "left-full top-1/2 -translate-y-1/2 ml-3",
+        }
+    }
+
+    /// Nudge toward the trigger while closed, so opening slides outward
+    fn hidden_offset(&self) -> &'static str {
+        match self.placement {
+            HovercardPlacement::Top => "translate-y-1",
+            HovercardPlacement::Bottom => "-translate-y-1",
+            HovercardPlacement::Left => "translate-x-1",
+            HovercardPlacement::Right => "-translate-x-1",
+        }
+    }
+
+    /// Reset only the axis `hidden_offset` moved; the other axis centers
+    /// the card on the trigger
+    fn shown_offset(&self) -> &'static str {
+        match self.placement {
+            HovercardPlacement::Top | HovercardPlacement::Bottom => "translate-y-0",
+            HovercardPlacement::Left | HovercardPlacement::Right => "translate-x-0",
+        }
+    }
+
+    /// Invisible strip covering the gap to the trigger, so the pointer
+    /// never leaves a hover target on its way to the card
+    fn bridge_classes(&self) -> &'static str {
+        match self.placement {
+            HovercardPlacement::Top => {
+                "before:absolute before:content-[''] before:inset-x-0 before:top-full before:h-3"
+            }
+            HovercardPlacement::Bottom => {
+                "before:absolute before:content-[''] before:inset-x-0 before:bottom-full before:h-3"
+            }
+            HovercardPlacement::Left => {
+                "before:absolute before:content-[''] before:inset-y-0 before:left-full before:w-3"
+            }
+            HovercardPlacement::Right => {
+                "before:absolute before:content-[''] before:inset-y-0 before:right-full before:w-3"
+            }
+        }
+    }
+}
+
+fn state_name(open: bool) -> &'static str {
+    if open {
+        "open"
+    } else {
+        "closed"
+    }
+}
+
+/// Tailwind delay class, falling back to an arbitrary value off the scale
+fn delay_class(ms: u16) -> String {
+    const TAILWIND_DELAYS: [u16; 9] = [0, 75, 100, 150, 200, 300, 500, 700, 1000];
+    if TAILWIND_DELAYS.contains(&ms) {
+        format!("delay-{}", ms)
+    } else {
+        format!("delay-[{}ms]", ms)
+    }
+}
+
+/// Convenience function to create hover card styles
+pub fn hovercard_styles<C: ColorProvider + Clone>(color_provider: C) -> HovercardStyles<C> {
+    HovercardStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "hovercard_test.rs"]
+mod hovercard_test;
@@ -0,0 +1,94 @@
+//! Tests for hover card styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::hovercard::{hovercard_styles, HovercardPlacement, HovercardStyles};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_hovercard_panel_is_elevated_and_hidden_until_open() {
+        let classes = hovercard_styles(VibeColors::default()).panel_classes();
+
+        assert!(classes.contains("hovercard--bottom"));
+        assert!(classes.contains("top-full"));
+        assert!(classes.contains("shadow-lg"));
+        assert!(classes.contains("max-w-[calc(100vw-2rem)]"));
+        assert!(classes.split_whitespace().any(|class| class == "invisible"));
+        assert!(classes.contains("data-[state=open]:visible"));
+        assert!(classes.contains("motion-reduce:transition-none"));
+        assert!(!classes
+            .split_whitespace()
+            .any(|class| class == "transition-all"));
+        assert!(classes
+            .split_whitespace()
+            .any(|class| class == "duration-150"));
+    }
+
+    #[test]
+    fn test_hovercard_open_offset_keeps_centering_axis() {
+        let colors = VibeColors::default();
+
+        let top = HovercardStyles::new(colors.clone()).top().panel_classes();
+        assert!(top.contains("-translate-x-1/2"));
+        assert!(top.contains("data-[state=open]:translate-y-0"));
+        assert!(!top.contains("data-[state=open]:translate-x-0"));
+        assert!(top.contains("before:top-full"));
+
+        let right = HovercardStyles::new(colors)
+            .placement(HovercardPlacement::Right)
+            .panel_classes();
+        assert!(right.contains("-translate-y-1/2"));
+        assert!(right.contains("data-[state=open]:translate-x-0"));
+        assert!(right.contains("before:right-full"));
+    }
+
+    #[test]
+    fn test_hovercard_arrow_faces_trigger() {
+        let colors = VibeColors::default();
+
+        let top = HovercardStyles::new(colors.clone()).top();
+        assert!(top.arrow_classes().contains("-bottom-1.5"));
+        assert!(top.arrow_classes().contains("bg-white"));
+
+        let fallback = HovercardStyles::new(colors).placement_str("invalid");
+        assert!(fallback.panel_classes().contains("hovercard--bottom"));
+        assert!(fallback.arrow_classes().contains("-top-1.5"));
+    }
+
+    #[test]
+    fn test_hovercard_delay_and_hover_intent() {
+        let card = hovercard_styles(VibeColors::default())
+            .open_delay(400)
+            .close_delay(150)
+            .safe_polygon(false);
+
+        assert!(card
+            .panel_classes()
+            .contains("data-[state=open]:delay-[400ms]"));
+
+        let intent = card.hover_intent();
+        assert_eq!(intent.open_delay_ms, 400);
+        assert_eq!(intent.close_delay_ms, 150);
+        assert!(!intent.safe_polygon);
+
+        let trigger = card.trigger_attributes(true);
+        assert!(trigger.contains(&("data-state", "open".to_string())));
+        assert!(trigger.contains(&("data-close-delay", "150".to_string())));
+        assert!(trigger.contains(&("data-safe-polygon", "false".to_string())));
+
+        let panel = card.panel_attributes(false);
+        assert!(panel.contains(&("data-state", "closed".to_string())));
+        assert!(panel.contains(&("role", "group".to_string())));
+    }
+
+    #[test]
+    fn test_hovercard_profile_parts() {
+        let card = hovercard_styles(VibeColors::default());
+
+        assert!(card.avatar_classes().contains("rounded-full"));
+        assert!(card.title_classes().contains("truncate"));
+        assert!(card.subtitle_classes().contains("text-gray-600"));
+        assert!(card.description_classes().contains("line-clamp-3"));
+        assert!(card.footer_classes().contains("border-gray-200"));
+    }
+}
@@ -0,0 +1,429 @@
+//! Indicator styling utilities for the Jupiter Design System
+//!
+//! Provides classes for count bubbles and status dots attached to the
+//! corner of another element (an icon, avatar or nav item). The indicator
+//! is centered on the chosen corner of a `relative` anchor, pulled inward
+//! for circular anchors so it sits on the circle's edge, and ringed in the
+//! surface color so it stays legible over any content.
+
+use crate::core::color::ColorProvider;
+use crate::core::{Color, Radius, RadiusProvider};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// What the indicator shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IndicatorKind {
+    /// Bubble holding a number, e.g. unread messages
+    Count,
+    /// Small dot without text, e.g. presence or "something new"
+    Dot,
+}
+
+/// Corner of the anchor the indicator is centered on
+///
+/// Horizontal edges are logical, so indicators mirror in right-to-left
+/// layouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IndicatorCorner {
+    TopStart,
+    TopEnd,
+    BottomStart,
+    BottomEnd,
+}
+
+/// Indicator size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IndicatorSize {
+    /// For small icons and dense nav items
+    SM,
+    /// Default size
+    MD,
+    /// For large avatars
+    LG,
+}
+
+/// Indicator styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::indicator::IndicatorStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let unread = IndicatorStyles::new(VibeColors::default()).count().max_count(99);
+/// assert!(unread.classes().contains("bg-red-500"));
+/// assert!(unread.classes().contains("top-0 end-0"));
+/// assert_eq!(unread.label(120), Some("99+".to_string()));
+/// assert_eq!(unread.label(0), None);
+///
+/// let online = IndicatorStyles::new(VibeColors::default())
+///     .dot()
+///     .success()
+///     .bottom_end()
+///     .circular();
+/// assert!(online.classes().contains("bottom-[14%] end-[14%]"));
+/// assert!(online.classes().contains("bg-green-500"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndicatorStyles<C: ColorProvider> {
+    kind: IndicatorKind,
+    corner: IndicatorCorner,
+    size: IndicatorSize,
+    color: Color,
+    status: Option<String>,
+    circular: bool,
+    inline: bool,
+    max_count: u64,
+    show_zero: bool,
+    ping: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> IndicatorStyles<C> {
+    /// Create a new indicator styling utility (error count bubble, top-end)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            kind: IndicatorKind::Count,
+            corner: IndicatorCorner::TopEnd,
+            size: IndicatorSize::MD,
+            color: Color::Error,
+            status: None,
+            circular: false,
+            inline: false,
+            max_count: 99,
+            show_zero: false,
+            ping: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Kind Methods ===
+
+    /// Show a count bubble
+    pub fn count(mut self) -> Self {
+        self.kind = IndicatorKind::Count;
+        self
+    }
+
+    /// Show a status dot
+    pub fn dot(mut self) -> Self {
+        self.kind = IndicatorKind::Dot;
+        self
+    }
+
+    /// Set kind explicitly
+    pub fn kind(mut self, kind: IndicatorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    // === Placement Methods ===
+
+    /// Center on the top-start corner
+    pub fn top_start(self) -> Self {
+        self.corner(IndicatorCorner::TopStart)
+    }
+
+    /// Center on the top-end corner
+    pub fn top_end(self) -> Self {
+        self.corner(IndicatorCorner::TopEnd)
+    }
+
+    /// Center on the bottom-start corner
+    pub fn bottom_start(self) -> Self {
+        self.corner(IndicatorCorner::BottomStart)
+    }
+
+    /// Center on the bottom-end corner
+    pub fn bottom_end(self) -> Self {
+        self.corner(IndicatorCorner::BottomEnd)
+    }
+
+    /// Set corner explicitly
+    pub fn corner(mut self, corner: IndicatorCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Set corner from string, e.g. "top-end"
+    pub fn corner_str(mut self, corner: &str) -> Self {
+        self.corner = match corner {
+            "top-start" => IndicatorCorner::TopStart,
+            "top-end" => IndicatorCorner::TopEnd,
+            "bottom-start" => IndicatorCorner::BottomStart,
+            "bottom-end" => IndicatorCorner::BottomEnd,
+            _ => IndicatorCorner::TopEnd, // fallback
+        };
+        self
+    }
+
+    /// Pull the indicator onto the edge of a circular anchor such as an
+    /// avatar, instead of the corner of its bounding box
+    pub fn circular(mut self) -> Self {
+        self.circular = true;
+        self
+    }
+
+    /// Place the indicator in the text flow instead of on an anchor's
+    /// corner, e.g. a status dot beside a label
+    pub fn inline(mut self) -> Self {
+        self.inline = true;
+        self
+    }
+
+    // === Size Methods ===
+
+    /// Small indicator
+    pub fn sm(mut self) -> Self {
+        self.size = IndicatorSize::SM;
+        self
+    }
+
+    /// Medium indicator
+    pub fn md(mut self) -> Self {
+        self.size = IndicatorSize::MD;
+        self
+    }
+
+    /// Large indicator
+    pub fn lg(mut self) -> Self {
+        self.size = IndicatorSize::LG;
+        self
+    }
+
+    /// Set size explicitly
+    pub fn size(mut self, size: IndicatorSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    // === Color Methods ===
+
+    /// Error color (the default, for unread and alert counts)
+    pub fn error(self) -> Self {
+        self.color(Color::Error)
+    }
+
+    /// Success color, e.g. online presence
+    pub fn success(self) -> Self {
+        self.color(Color::Success)
+    }
+
+    /// Warning color, e.g. away presence
+    pub fn warning(self) -> Self {
+        self.color(Color::Warning)
+    }
+
+    /// Info color
+    pub fn info(self) -> Self {
+        self.color(Color::Info)
+    }
+
+    /// Primary brand color
+    pub fn primary(self) -> Self {
+        self.color(Color::Primary)
+    }
+
+    /// Muted color, e.g. offline presence
+    pub fn neutral(self) -> Self {
+        self.color(Color::InteractiveDisabled)
+    }
+
+    /// Set a semantic color explicitly
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self.status = None;
+        self
+    }
+
+    /// Use a theme-registered status color, e.g. "busy"
+    ///
+    /// Falls back to the semantic color when the theme has no such status.
+    pub fn status(mut self, name: impl Into<String>) -> Self {
+        self.status = Some(name.into());
+        self
+    }
+
+    // === Count Methods ===
+
+    /// Show counts above `max` as `max+` (99 by default)
+    pub fn max_count(mut self, max: u64) -> Self {
+        self.max_count = max;
+        self
+    }
+
+    /// Show the count bubble for a count of zero
+    pub fn show_zero(mut self, show: bool) -> Self {
+        self.show_zero = show;
+        self
+    }
+
+    /// Pulse a ring behind the indicator to draw attention
+    pub fn ping(mut self) -> Self {
+        self.ping = true;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the indicator
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the anchor the indicator is attached to
+    pub fn anchor_classes(&self) -> String {
+        "indicator-anchor relative inline-flex shrink-0".to_string()
+    }
+
+    /// Build classes for the indicator
+    pub fn classes(&self) -> String {
+        let size = match (self.kind, self.size) {
+            (IndicatorKind::Dot, IndicatorSize::SM) => "w-2 h-2",
+            (IndicatorKind::Dot, IndicatorSize::MD) => "w-2.5 h-2.5",
+            (IndicatorKind::Dot, IndicatorSize::LG) => "w-3 h-3",
+            (IndicatorKind::Count, IndicatorSize::SM) => "min-w-4 h-4 px-1 text-[0.625rem]",
+            (IndicatorKind::Count, IndicatorSize::MD) => "min-w-5 h-5 px-1.5 text-xs",
+            (IndicatorKind::Count, IndicatorSize::LG) => "min-w-6 h-6 px-2 text-sm",
+        };
+
+        let mut classes = vec![
+            format!("indicator indicator--{}", self.kind_name()),
+            self.position_classes(),
+            size.to_string(),
+            format!(
+                "{} bg-{} ring-2 ring-{} pointer-events-none",
+                self.color_provider
+                    .radius_scale()
+                    .radius_class(Radius::Pill),
+                self.resolved_color(),
+                self.color_provider.resolve_color(Color::Surface)
+            ),
+        ];
+        if self.kind == IndicatorKind::Count {
+            classes.push(format!(
+                "inline-flex items-center justify-center font-semibold leading-none tabular-nums whitespace-nowrap {}",
+                self.color_provider
+                    .text_class(self.color_provider.on_color_value(self.resolved_color()))
+            ));
+        }
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the ping layer, a sibling placed inside the
+    /// indicator
+    ///
+    /// Empty unless `ping` is set. The pulse is skipped for users who
+    /// prefer reduced motion.
+    pub fn ping_classes(&self) -> String {
+        if !self.ping {
+            return String::new();
+        }
+        format!(
+            "indicator-ping absolute inset-0 {} bg-{} opacity-75 motion-safe:animate-ping",
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill),
+            self.resolved_color()
+        )
+    }
+
+    /// Format a count for the bubble
+    ///
+    /// `None` means the bubble should not render: zero counts are hidden
+    /// unless `show_zero` is set. Counts above the maximum read `max+`.
+    pub fn label(&self, count: u64) -> Option<String> {
+        if count == 0 && !self.show_zero {
+            return None;
+        }
+        Some(if count > self.max_count {
+            format!("{}+", self.max_count)
+        } else {
+            count.to_string()
+        })
+    }
+
+    /// Whether the indicator should render for a count
+    ///
+    /// Dots always render; count bubbles follow `label`.
+    pub fn is_visible(&self, count: u64) -> bool {
+        match self.kind {
+            IndicatorKind::Dot => true,
+            IndicatorKind::Count => self.label(count).is_some(),
+        }
+    }
+
+    fn kind_name(&self) -> &'static str {
+        match self.kind {
+            IndicatorKind::Count => "count",
+            IndicatorKind::Dot => "dot",
+        }
+    }
+
+    fn resolved_color(&self) -> &str {
+        self.status
+            .as_deref()
+            .and_then(|name| self.color_provider.status_color(name))
+            .unwrap_or_else(|| self.color_provider.resolve_color(self.color))
+    }
+
+    /// Pin to the corner, then shift by half the indicator's size so it is
+    /// centered on the corner (mirrored in right-to-left layouts)
+    ///
+    /// Inline indicators stay in the flow and only anchor their ping layer.
+    fn position_classes(&self) -> String {
+        if self.inline {
+            return "relative inline-flex shrink-0".to_string();
+        }
+        let inset = if self.circular { "[14%]" } else { "0" };
+        let (vertical, horizontal, shift_y, shift_x) = match self.corner {
+            IndicatorCorner::TopStart => (
+                "top",
+                "start",
+                "-translate-y-1/2",
+                "-translate-x-1/2 rtl:translate-x-1/2",
+            ),
+            IndicatorCorner::TopEnd => (
+                "top",
+                "end",
+                "-translate-y-1/2",
+                "translate-x-1/2 rtl:-translate-x-1/2",
+            ),
+            IndicatorCorner::BottomStart => (
+                "bottom",
+                "start",
+                "translate-y-1/2",
+                "-translate-x-1/2 rtl:translate-x-1/2",
+            ),
+            IndicatorCorner::BottomEnd => (
+                "bottom",
+                "end",
+                "translate-y-1/2",
+                "translate-x-1/2 rtl:-translate-x-1/2",
+            ),
+        };
+        format!(
+            "absolute {}-{} {}-{} {} {}",
+            vertical, inset, horizontal, inset, shift_y, shift_x
+        )
+    }
+}
+
+/// Convenience function to create indicator styles
+pub fn indicator_styles<C: ColorProvider>(color_provider: C) -> IndicatorStyles<C> {
+    IndicatorStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "indicator_test.rs"]
+mod indicator_test;
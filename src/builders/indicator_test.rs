@@ -0,0 +1,146 @@
+//! Tests for indicator styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::indicator::{indicator_styles, IndicatorCorner, IndicatorStyles};
+    use crate::core::Color;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_count_bubble_defaults() {
+        let classes = indicator_styles(VibeColors::default()).classes();
+
+        assert!(classes.contains("indicator--count"));
+        assert!(classes.contains("absolute top-0 end-0 -translate-y-1/2 translate-x-1/2"));
+        assert!(classes.contains("rtl:-translate-x-1/2"));
+        assert!(classes.contains("min-w-5 h-5"));
+        assert!(classes.contains("bg-red-500"));
+        assert!(classes.contains("text-gray-900"));
+        assert!(classes.contains("ring-2 ring-white"));
+        assert!(classes.contains("tabular-nums"));
+    }
+
+    #[test]
+    fn test_dot_sizes_and_corners() {
+        let colors = VibeColors::default();
+
+        let dot = IndicatorStyles::new(colors.clone())
+            .dot()
+            .sm()
+            .bottom_start();
+        let classes = dot.classes();
+        assert!(classes.contains("indicator--dot"));
+        assert!(classes.contains("w-2 h-2"));
+        assert!(classes.contains("bottom-0 start-0 translate-y-1/2 -translate-x-1/2"));
+        assert!(!classes.contains("tabular-nums"));
+
+        let circular = IndicatorStyles::new(colors.clone())
+            .dot()
+            .lg()
+            .corner(IndicatorCorner::TopStart)
+            .circular();
+        assert!(circular.classes().contains("top-[14%] start-[14%]"));
+        assert!(circular.classes().contains("w-3 h-3"));
+
+        let fallback = IndicatorStyles::new(colors).corner_str("invalid");
+        assert!(fallback.classes().contains("top-0 end-0"));
+    }
+
+    #[test]
+    fn test_indicator_colors() {
+        let colors = VibeColors::default().with_status("busy", "purple-500");
+
+        let warning = IndicatorStyles::new(colors.clone()).dot().warning();
+        assert!(warning.classes().contains("bg-amber-500"));
+
+        let offline = IndicatorStyles::new(colors.clone()).dot().neutral();
+        assert!(offline.classes().contains("bg-gray-300"));
+
+        let busy = IndicatorStyles::new(colors.clone()).dot().status("busy");
+        assert!(busy.classes().contains("bg-purple-500"));
+
+        let unknown = IndicatorStyles::new(colors.clone())
+            .dot()
+            .success()
+            .status("unknown");
+        assert!(unknown.classes().contains("bg-green-500"));
+
+        let reset = IndicatorStyles::new(colors)
+            .status("busy")
+            .color(Color::Info);
+        assert!(!reset.classes().contains("purple-500"));
+    }
+
+    #[test]
+    fn test_count_labels() {
+        let colors = VibeColors::default();
+
+        let badge = indicator_styles(colors.clone());
+        assert_eq!(badge.label(0), None);
+        assert_eq!(badge.label(7), Some("7".to_string()));
+        assert_eq!(badge.label(100), Some("99+".to_string()));
+        assert!(!badge.is_visible(0));
+
+        let capped = indicator_styles(colors.clone())
+            .max_count(9)
+            .show_zero(true);
+        assert_eq!(capped.label(0), Some("0".to_string()));
+        assert_eq!(capped.label(10), Some("9+".to_string()));
+
+        let dot = indicator_styles(colors).dot();
+        assert!(dot.is_visible(0));
+    }
+
+    #[test]
+    fn test_ping_layer() {
+        let colors = VibeColors::default();
+
+        assert_eq!(indicator_styles(colors.clone()).ping_classes(), "");
+
+        let ping = indicator_styles(colors)
+            .dot()
+            .success()
+            .ping()
+            .ping_classes();
+        assert!(ping.contains("motion-safe:animate-ping"));
+        assert!(ping.contains("bg-green-500"));
+        assert!(ping.contains("absolute inset-0"));
+    }
+
+    #[test]
+    fn test_inline_dot_skips_corner_positioning() {
+        let dot = IndicatorStyles::new(VibeColors::default())
+            .dot()
+            .success()
+            .inline()
+            .ping();
+
+        let classes = dot.classes();
+        assert!(classes.contains("relative inline-flex shrink-0"));
+        assert!(!classes.contains("absolute"));
+        assert!(!classes.contains("translate"));
+        assert!(dot.ping_classes().contains("absolute inset-0"));
+    }
+
+    #[test]
+    fn test_count_text_reads_on_fill() {
+        let colors = VibeColors::default()
+            .with_status("busy", "yellow-400")
+            .with_status("offline", "gray-700");
+
+        let error = IndicatorStyles::new(colors.clone()).classes();
+        assert!(error.contains("text-gray-900"));
+
+        let offline = IndicatorStyles::new(colors.clone())
+            .status("offline")
+            .classes();
+        assert!(offline.contains("text-white"));
+
+        let warning = IndicatorStyles::new(colors.clone()).warning().classes();
+        assert!(warning.contains("text-gray-900"));
+
+        let busy = IndicatorStyles::new(colors).status("busy").classes();
+        assert!(busy.contains("bg-yellow-400"));
+        assert!(busy.contains("text-gray-900"));
+    }
+}
@@ -29,10 +29,14 @@
 //! ```
 
 use crate::core::color::ColorProvider;
-use crate::core::Color;
+use crate::core::{BorderProvider, BorderWidth, Color, Radius, RadiusProvider, Size, SizeProvider};
+use crate::descriptors::{ComponentDescriptor, Describe};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Base interactive component that can be specialized
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct InteractiveBase<C: ColorProvider> {
     base_classes: Vec<String>,
     hover_classes: Vec<String>,
@@ -82,11 +86,11 @@ impl<C: ColorProvider> InteractiveBase<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Base classes
-        all_classes.extend(self.base_classes);
+        all_classes.extend(self.base_classes.iter().cloned());
 
         // Hover classes
         if !self.hover_classes.is_empty() {
@@ -197,7 +201,7 @@ impl<C: ColorProvider> HoverBuilder<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         self.base.build()
     }
 }
@@ -267,7 +271,7 @@ impl<C: ColorProvider> FocusBuilder<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         self.base.build()
     }
 }
@@ -312,7 +316,7 @@ impl<C: ColorProvider> ActiveBuilder<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         self.base.build()
     }
 }
@@ -350,7 +354,7 @@ impl<C: ColorProvider> DisabledBuilder<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         self.base.build()
     }
 }
@@ -358,24 +362,43 @@ impl<C: ColorProvider> DisabledBuilder<C> {
 /// Specialized input builder
 pub struct InputBuilder<C: ColorProvider> {
     base: InteractiveBase<C>,
+    size: Size,
 }
 
 impl<C: ColorProvider> InputBuilder<C> {
     pub fn new(color_provider: C) -> Self {
         Self {
             base: InteractiveBase::new(color_provider),
+            size: Size::Medium,
         }
     }
 
+    /// Set the control size, matching buttons of the same size
+    ///
+    /// Call before `base_style` or `standard_style`, which read it.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
     /// Apply base input styles
     pub fn base_style(self) -> Self {
-        self.base_classes("w-full px-4 py-3 border rounded-md transition-colors focus:outline-none")
+        let base_classes = format!(
+            "w-full {} px-4 {} {} transition-colors focus:outline-none",
+            self.height_class(),
+            self.border_width_class(),
+            self.radius_class()
+        );
+        self.base_classes(&base_classes)
     }
 
     /// Apply standard input styles with theme colors
     pub fn standard_style(mut self) -> Self {
         let base_classes = format!(
-            "w-full px-4 py-3 rounded-md transition-colors focus:outline-none {} {}",
+            "w-full {} px-4 {} {} transition-colors focus:outline-none {} {}",
+            self.height_class(),
+            self.border_width_class(),
+            self.radius_class(),
             self.base.color_provider.border_class(Color::Border),
             self.base.color_provider.bg_class(Color::Surface)
         );
@@ -405,9 +428,36 @@ impl<C: ColorProvider> InputBuilder<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         self.base.build()
     }
+
+    fn radius_class(&self) -> String {
+        self.base
+            .color_provider
+            .radius_scale()
+            .radius_class(Radius::Control)
+    }
+
+    fn border_width_class(&self) -> String {
+        self.base
+            .color_provider
+            .border_scale()
+            .border_width_class(BorderWidth::Default)
+    }
+
+    fn height_class(&self) -> String {
+        self.base
+            .color_provider
+            .size_scale()
+            .control_height_class(self.size)
+    }
+}
+
+impl<C: ColorProvider> Describe for InputBuilder<C> {
+    fn describe(&self) -> ComponentDescriptor {
+        ComponentDescriptor::new("input", self.build()).with_attribute("type", "text")
+    }
 }
 
 /// Specialized button builder
@@ -494,7 +544,7 @@ impl<C: ColorProvider> ButtonBuilder<C> {
     }
 
     /// Build the final CSS classes string
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         self.base.build()
     }
 }
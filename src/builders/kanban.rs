@@ -0,0 +1,216 @@
+//! Kanban board styling utilities for the Jupiter Design System
+//!
+//! Provides classes for project boards: a horizontally scrolling row of
+//! fixed-width columns, each with a header, a vertically scrolling card
+//! list and a footer add button. Columns and cards take a drag state, so
+//! the drag-and-drop visuals from `DndStyles` apply without extra wiring.
+
+use crate::builders::button::ButtonStyles;
+use crate::builders::card::CardStyles;
+use crate::builders::dnd::{DndStyles, DragState};
+use crate::builders::scroll::ScrollStyles;
+use crate::core::color::ColorProvider;
+use crate::core::{Color, Radius, RadiusProvider};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Kanban board styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::dnd::DragState;
+/// use jupiter_design_system::builders::kanban::KanbanStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let board = KanbanStyles::new(VibeColors::default()).column_width("w-80");
+///
+/// assert!(board.board_classes().contains("overflow-x-auto"));
+/// assert!(board.column_classes(DragState::Idle).contains("w-80"));
+/// assert!(board.column_classes(DragState::DropTarget).contains("ring-jupiter-blue-500"));
+/// assert!(board.card_classes(DragState::Lifted).contains("shadow-xl"));
+/// assert!(board.add_button_classes().contains("justify-start"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KanbanStyles<C: ColorProvider + Clone> {
+    column_width: &'static str,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> KanbanStyles<C> {
+    /// Create a new kanban board styling utility (18rem columns)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            column_width: "w-72",
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Layout Methods ===
+
+    /// Set the column width class, e.g. "w-80"
+    pub fn column_width(mut self, width: &'static str) -> Self {
+        self.column_width = width;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the board
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the horizontally scrolling board
+    ///
+    /// Columns align to the top so short columns do not stretch to the
+    /// tallest one.
+    pub fn board_classes(&self) -> String {
+        let mut classes = vec![
+            "kanban flex items-start gap-4 pb-4 h-full".to_string(),
+            ScrollStyles::new(self.color_provider.clone())
+                .horizontal()
+                .contain_overscroll()
+                .thin_scrollbar()
+                .classes(),
+        ];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for a column in a drag state
+    pub fn column_classes(&self, state: DragState) -> String {
+        format!(
+            "kanban-column flex flex-col shrink-0 {} max-h-full {} {} {}",
+            self.column_width,
+            self.color_provider.bg_class(Color::Background),
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Surface),
+            self.dnd(state).column().classes()
+        )
+    }
+
+    /// Build classes for the column header
+    pub fn column_header_classes(&self) -> String {
+        "kanban-column-header flex items-center justify-between gap-2 px-3 pt-3 pb-2".to_string()
+    }
+
+    /// Build classes for the column title
+    pub fn column_title_classes(&self) -> String {
+        format!(
+            "kanban-column-title text-sm font-semibold truncate {}",
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    /// Build classes for the card count beside the column title
+    pub fn column_count_classes(&self) -> String {
+        format!(
+            "kanban-column-count shrink-0 px-2 py-0.5 text-xs font-medium tabular-nums {} {} {}",
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill),
+            self.color_provider.bg_class(Color::Border),
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for the scrolling card list
+    ///
+    /// The minimum height keeps an empty column a usable drop target.
+    pub fn card_list_classes(&self) -> String {
+        format!(
+            "kanban-cards flex flex-1 flex-col gap-2 min-h-16 px-2 pb-2 {}",
+            ScrollStyles::new(self.color_provider.clone())
+                .vertical()
+                .contain_overscroll()
+                .thin_scrollbar()
+                .classes()
+        )
+    }
+
+    /// Build classes for a card in a drag state
+    ///
+    /// Placeholders drop the card's surface so only the dashed outline
+    /// shows. The card's own transition and, while lifted, its shadow give
+    /// way to the drag visuals.
+    pub fn card_classes(&self, state: DragState) -> String {
+        let dnd = self.dnd(state).card().classes();
+        if state == DragState::Placeholder {
+            return format!("kanban-card min-h-16 {}", dnd);
+        }
+
+        let card = CardStyles::new(self.color_provider.clone())
+            .compact_spacing()
+            .draggable_interaction()
+            .classes();
+        let card = card
+            .split_whitespace()
+            .filter(|class| !class.starts_with("transition") && !class.starts_with("duration-"))
+            .filter(|class| state != DragState::Lifted || !class.starts_with("shadow"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("kanban-card {} {}", card, dnd)
+    }
+
+    /// Build classes for the column footer
+    pub fn column_footer_classes(&self) -> String {
+        "kanban-column-footer px-2 pb-2".to_string()
+    }
+
+    /// Build classes for the footer "add card" button
+    pub fn add_button_classes(&self) -> String {
+        let button = ButtonStyles::new(self.color_provider.clone())
+            .ghost()
+            .small()
+            .full_width()
+            .with_icon()
+            .classes()
+            .split_whitespace()
+            .filter(|class| *class != "justify-center")
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!(
+            "kanban-add {} justify-start {}",
+            button,
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Get attributes for a column in a drag state
+    pub fn column_attributes(&self, state: DragState) -> Vec<(&'static str, String)> {
+        self.dnd(state).attributes()
+    }
+
+    /// Get attributes for the card list
+    pub fn card_list_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![("role", "list".to_string())]
+    }
+
+    /// Get attributes for a card in a drag state
+    pub fn card_attributes(&self, state: DragState) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![("role", "listitem".to_string())];
+        attrs.extend(self.dnd(state).attributes());
+        attrs
+    }
+
+    fn dnd(&self, state: DragState) -> DndStyles<C> {
+        DndStyles::new(self.color_provider.clone()).state(state)
+    }
+}
+
+/// Convenience function to create kanban board styles
+pub fn kanban_styles<C: ColorProvider + Clone>(color_provider: C) -> KanbanStyles<C> {
+    KanbanStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "kanban_test.rs"]
+mod kanban_test;
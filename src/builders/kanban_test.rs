@@ -0,0 +1,88 @@
+//! Tests for kanban board styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::dnd::DragState;
+    use crate::builders::kanban::{kanban_styles, KanbanStyles};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_board_and_column_layout() {
+        let board = kanban_styles(VibeColors::default());
+
+        let classes = board.board_classes();
+        assert!(classes.contains("kanban flex items-start gap-4"));
+        assert!(classes.contains("overflow-x-auto"));
+
+        let column = board.column_classes(DragState::Idle);
+        assert!(column.contains(
+            "kanban-column flex flex-col shrink-0 w-72 max-h-full bg-gray-50 rounded-lg"
+        ));
+        assert!(column.contains("dnd--idle"));
+        assert!(!column.contains("ring-2"));
+
+        assert!(board.card_list_classes().contains("min-h-16"));
+        assert!(board.card_list_classes().contains("overflow-y-auto"));
+        assert!(board
+            .column_count_classes()
+            .contains("rounded-full bg-gray-200 text-gray-600"));
+    }
+
+    #[test]
+    fn test_column_drop_states() {
+        let board = KanbanStyles::new(VibeColors::default());
+
+        let over = board.column_classes(DragState::DropTarget);
+        assert!(over.contains("ring-2 bg-jupiter-blue-500/5 ring-jupiter-blue-500"));
+        assert_eq!(
+            board.column_attributes(DragState::InvalidDrop),
+            vec![("data-drag-state", "invalid-drop".to_string())]
+        );
+        assert!(board
+            .column_classes(DragState::InvalidDrop)
+            .contains("ring-red-500"));
+    }
+
+    #[test]
+    fn test_card_states() {
+        let board = KanbanStyles::new(VibeColors::default());
+
+        let idle = board.card_classes(DragState::Idle);
+        assert!(idle.contains("cursor-move"));
+        assert!(idle.contains("p-3"));
+        assert!(idle.contains("shadow-sm"));
+        assert!(!idle.contains("transition-all"));
+        assert!(!idle.contains("duration-300"));
+
+        let lifted = board.card_classes(DragState::Lifted);
+        assert!(lifted.contains("shadow-xl scale-105 opacity-90"));
+        assert!(!lifted.contains("shadow-sm"));
+
+        let placeholder = board.card_classes(DragState::Placeholder);
+        assert!(placeholder.contains("border-dashed"));
+        assert!(!placeholder.contains("bg-white"));
+
+        assert_eq!(
+            board.card_attributes(DragState::Ghost),
+            vec![
+                ("role", "listitem".to_string()),
+                ("data-drag-state", "ghost".to_string()),
+                ("aria-hidden", "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_footer_add_button() {
+        let board = KanbanStyles::new(VibeColors::default())
+            .column_width("w-80")
+            .custom("my-board");
+
+        let add = board.add_button_classes();
+        assert!(add.contains("w-full"));
+        assert!(add.contains("justify-start text-gray-600"));
+        assert!(!add.contains("justify-center"));
+        assert!(board.column_classes(DragState::Idle).contains("w-80"));
+        assert!(board.board_classes().ends_with("my-board"));
+    }
+}
@@ -0,0 +1,168 @@
+//! Labeled value styling utilities for the Jupiter Design System
+//!
+//! Provides coordinated classes for an overline label paired with a
+//! prominent value and an optional caption, as used on detail pages,
+//! stat tiles and summaries.
+
+use crate::builders::text::TextStyles;
+use crate::core::color::ColorProvider;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// How the label and value are arranged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LabeledValueOrientation {
+    /// Label above the value
+    Stacked,
+    /// Label left, value right on the same line
+    Horizontal,
+}
+
+/// Labeled value styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::labeled_value::LabeledValueStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let stat = LabeledValueStyles::new(VibeColors::default())
+///     .prominent()
+///     .numeric();
+///
+/// let container_classes = stat.classes();
+/// let label_classes = stat.label_classes();
+/// let value_classes = stat.value_classes();
+/// let caption_classes = stat.caption_classes();
+/// assert!(value_classes.contains("tabular-nums"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LabeledValueStyles<C: ColorProvider + Clone> {
+    orientation: LabeledValueOrientation,
+    prominent: bool,
+    numeric: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> LabeledValueStyles<C> {
+    /// Create a new labeled value styling utility (stacked)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            orientation: LabeledValueOrientation::Stacked,
+            prominent: false,
+            numeric: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Orientation Methods ===
+
+    /// Put the label above the value
+    pub fn stacked(mut self) -> Self {
+        self.orientation = LabeledValueOrientation::Stacked;
+        self
+    }
+
+    /// Put the label and value on one line
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = LabeledValueOrientation::Horizontal;
+        self
+    }
+
+    /// Set orientation explicitly
+    pub fn orientation(mut self, orientation: LabeledValueOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set orientation from string
+    pub fn orientation_str(mut self, orientation: &str) -> Self {
+        self.orientation = match orientation {
+            "stacked" | "vertical" => LabeledValueOrientation::Stacked,
+            "horizontal" | "inline" => LabeledValueOrientation::Horizontal,
+            _ => LabeledValueOrientation::Stacked, // fallback
+        };
+        self
+    }
+
+    // === Value Methods ===
+
+    /// Show the value at heading size (stat tiles, key figures)
+    pub fn prominent(mut self) -> Self {
+        self.prominent = true;
+        self
+    }
+
+    /// Render the value with tabular figures
+    pub fn numeric(mut self) -> Self {
+        self.numeric = true;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the container
+    pub fn classes(&self) -> String {
+        let layout = match self.orientation {
+            LabeledValueOrientation::Stacked => "flex flex-col gap-1",
+            LabeledValueOrientation::Horizontal => "flex items-baseline justify-between gap-4",
+        };
+        let mut classes = vec![format!("labeled-value {}", layout)];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the overline label
+    pub fn label_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .overline()
+            .muted()
+            .classes()
+    }
+
+    /// Build classes for the value
+    pub fn value_classes(&self) -> String {
+        let mut value = TextStyles::new(self.color_provider.clone());
+        value = if self.prominent {
+            value.heading()
+        } else {
+            value.body_large().semibold()
+        };
+        if self.numeric {
+            value = value.tabular_numbers();
+        }
+        if self.orientation == LabeledValueOrientation::Horizontal {
+            value = value.right();
+        }
+        value.classes()
+    }
+
+    /// Build classes for the optional caption below the value
+    pub fn caption_classes(&self) -> String {
+        TextStyles::new(self.color_provider.clone())
+            .caption()
+            .muted()
+            .classes()
+    }
+}
+
+/// Convenience function to create labeled value styles
+pub fn labeled_value_styles<C: ColorProvider + Clone>(color_provider: C) -> LabeledValueStyles<C> {
+    LabeledValueStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "labeled_value_test.rs"]
+mod labeled_value_test;
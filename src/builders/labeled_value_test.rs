@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::labeled_value::{labeled_value_styles, LabeledValueStyles};
+    use crate::builders::text::TextStyles;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_stacked_default() {
+        let styles = labeled_value_styles(VibeColors::default());
+
+        assert!(styles.classes().contains("flex-col"));
+        assert!(!styles.value_classes().contains("text-right"));
+    }
+
+    #[test]
+    fn test_horizontal_orientation() {
+        let styles = labeled_value_styles(VibeColors::default()).horizontal();
+
+        assert!(styles.classes().contains("justify-between"));
+        assert!(styles.classes().contains("items-baseline"));
+        assert!(styles.value_classes().contains("text-right"));
+    }
+
+    #[test]
+    fn test_label_uses_overline_typography() {
+        let colors = VibeColors::default();
+        let styles = labeled_value_styles(colors.clone());
+
+        assert_eq!(
+            styles.label_classes(),
+            TextStyles::new(colors).overline().muted().classes()
+        );
+    }
+
+    #[test]
+    fn test_prominent_and_numeric_value() {
+        let colors = VibeColors::default();
+        let standard = labeled_value_styles(colors.clone());
+        let prominent = labeled_value_styles(colors).prominent().numeric();
+
+        assert_ne!(standard.value_classes(), prominent.value_classes());
+        assert!(prominent.value_classes().contains("tabular-nums"));
+        assert!(!standard.value_classes().contains("tabular-nums"));
+    }
+
+    #[test]
+    fn test_orientation_str() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            LabeledValueStyles::new(colors.clone())
+                .orientation_str("inline")
+                .classes(),
+            LabeledValueStyles::new(colors.clone())
+                .horizontal()
+                .classes()
+        );
+        assert_eq!(
+            LabeledValueStyles::new(colors.clone())
+                .orientation_str("invalid")
+                .classes(),
+            LabeledValueStyles::new(colors).classes()
+        );
+    }
+}
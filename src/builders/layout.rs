@@ -4,7 +4,16 @@
 //! with any component library or framework.
 
 use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth};
+#[cfg(feature = "cards")]
+use crate::patterns::CardSpacing;
 use crate::patterns::{LayoutAlignment, LayoutDirection, LayoutDivider, LayoutSpacing};
+#[cfg(feature = "std")]
+use crate::themes::{global_theme, SharedTheme};
+use crate::utils::Visibility;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Layout styling utility builder
 ///
@@ -24,12 +33,16 @@ use crate::patterns::{LayoutAlignment, LayoutDirection, LayoutDivider, LayoutSpa
 ///     .alignment_between()
 ///     .classes();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LayoutStyles<C: ColorProvider> {
     divider: LayoutDivider,
     spacing: LayoutSpacing,
+    card_padding: Option<&'static str>,
     alignment: Option<LayoutAlignment>,
     direction: Option<LayoutDirection>,
+    visibility: Visibility,
+    container_visibility: bool,
+    container_query: bool,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -40,8 +53,12 @@ impl<C: ColorProvider> LayoutStyles<C> {
         Self {
             divider: LayoutDivider::None,
             spacing: LayoutSpacing::MD,
+            card_padding: None,
             alignment: None,
             direction: None,
+            visibility: Visibility::Always,
+            container_visibility: false,
+            container_query: false,
             custom_classes: Vec::new(),
             color_provider,
         }
@@ -123,6 +140,17 @@ impl<C: ColorProvider> LayoutStyles<C> {
         self
     }
 
+    /// Pad this section like a card with the given spacing
+    ///
+    /// Card sections (header, content, footer) then share the card's inset
+    /// and density, and `CardSpacing::None` removes their padding so media
+    /// and tables can run edge to edge. Pair with a `spacing_none()` card.
+    #[cfg(feature = "cards")]
+    pub fn card_spacing(mut self, spacing: CardSpacing) -> Self {
+        self.card_padding = Some(spacing.padding_class(self.color_provider.density()));
+        self
+    }
+
     // === Direction Methods ===
 
     /// Set vertical direction
@@ -175,6 +203,34 @@ impl<C: ColorProvider> LayoutStyles<C> {
         self
     }
 
+    // === Visibility Methods ===
+
+    /// Show or hide per breakpoint, e.g. `hidden_below(Breakpoint::Tablet)`
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    /// Resolve `visibility` against the nearest enclosing `@container`
+    /// instead of the viewport
+    ///
+    /// Uses the container variants from `Breakpoint::container_prefix`,
+    /// which are narrower than the viewport widths. Without an enclosing
+    /// container the layout stays visible at every width.
+    pub fn container_visibility(mut self) -> Self {
+        self.container_visibility = true;
+        self
+    }
+
+    // === Container Query Methods ===
+
+    /// Make the layout a query container so children can respond to its
+    /// width with `@sm:`/`@lg:` variants (see `Breakpoint::container_prefix`)
+    pub fn container_query(mut self) -> Self {
+        self.container_query = true;
+        self
+    }
+
     // === Custom Methods ===
 
     /// Add a custom CSS class
@@ -197,41 +253,35 @@ impl<C: ColorProvider> LayoutStyles<C> {
     // === Build Methods ===
 
     /// Build the final CSS classes string
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         self.build()
     }
 
     /// Build the final CSS classes string (alias for classes)
-    pub fn build(self) -> String {
+    pub fn build(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Divider classes
-        let divider_classes = match self.divider {
-            LayoutDivider::None => "",
-            LayoutDivider::Top => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-t {}", border_class)
-            }
-            LayoutDivider::Bottom => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-b {}", border_class)
-            }
-            LayoutDivider::Left => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-l {}", border_class)
-            }
-            LayoutDivider::Right => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-r {}", border_class)
-            }
+        let divider_side = match self.divider {
+            LayoutDivider::None => None,
+            LayoutDivider::Top => Some("t"),
+            LayoutDivider::Bottom => Some("b"),
+            LayoutDivider::Left => Some("l"),
+            LayoutDivider::Right => Some("r"),
         };
 
-        if !divider_classes.is_empty() {
-            all_classes.push(divider_classes.to_string());
+        if let Some(side) = divider_side {
+            all_classes.push(format!(
+                "{} {}",
+                self.color_provider
+                    .border_scale()
+                    .border_side_class(side, BorderWidth::Default),
+                self.color_provider.border_class(crate::core::Color::Border)
+            ));
         }
 
-        // Spacing classes
-        let spacing_classes = match self.spacing {
+        // Spacing classes (card sections follow their card's padding)
+        let spacing_classes = self.card_padding.unwrap_or(match self.spacing {
             LayoutSpacing::None => "",
             LayoutSpacing::XS => "p-1",
             LayoutSpacing::SM => "p-2",
@@ -239,7 +289,7 @@ impl<C: ColorProvider> LayoutStyles<C> {
             LayoutSpacing::LG => "p-6",
             LayoutSpacing::XL => "p-8",
             LayoutSpacing::XL2 => "p-12",
-        };
+        });
 
         if !spacing_classes.is_empty() {
             all_classes.push(spacing_classes.to_string());
@@ -267,6 +317,27 @@ impl<C: ColorProvider> LayoutStyles<C> {
             all_classes.push(alignment_classes.to_string());
         }
 
+        // Responsive visibility (restores flex when a direction is set)
+        let display = if self.direction.is_some() {
+            "flex"
+        } else {
+            "block"
+        };
+        let visibility_classes = if self.container_visibility {
+            self.visibility.container_classes(display)
+        } else {
+            self.visibility
+                .classes_for_scale(display, &self.color_provider.breakpoint_scale())
+        };
+        if !visibility_classes.is_empty() {
+            all_classes.push(visibility_classes);
+        }
+
+        // Container query context
+        if self.container_query {
+            all_classes.push("@container".to_string());
+        }
+
         // Custom classes
         let custom_classes = self.custom_classes.join(" ");
         if !custom_classes.is_empty() {
@@ -290,6 +361,12 @@ pub fn layout_styles<C: ColorProvider>(color_provider: C) -> LayoutStyles<C> {
     LayoutStyles::new(color_provider)
 }
 
+/// Convenience function to create layout styles from the global theme
+#[cfg(feature = "std")]
+pub fn layout_styles_default() -> LayoutStyles<SharedTheme> {
+    LayoutStyles::new(global_theme())
+}
+
 /// Convenience function to create card header layout styles
 pub fn card_header_styles<C: ColorProvider>(color_provider: C) -> LayoutStyles<C> {
     LayoutStyles::new(color_provider)
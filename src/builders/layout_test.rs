@@ -3,7 +3,9 @@ mod tests {
     use crate::builders::layout::{
         card_content_styles, card_footer_styles, card_header_styles, layout_styles, LayoutStyles,
     };
+    use crate::core::{Breakpoint, BreakpointScale};
     use crate::themes::VibeColors;
+    use crate::utils::visibility::hidden_below;
 
     #[test]
     fn test_layout_styles_new() {
@@ -307,9 +309,102 @@ mod tests {
             .divider_top()
             .spacing_sm();
 
-        let classes1 = styles.clone().classes();
+        let classes1 = styles.classes();
         let classes2 = styles.build();
 
         assert_eq!(classes1, classes2);
     }
+
+    #[test]
+    fn test_visibility_restores_layout_display() {
+        let colors = VibeColors::default();
+
+        let block = layout_styles(colors.clone())
+            .visibility(hidden_below(Breakpoint::Tablet))
+            .classes();
+        assert!(block.contains("md:block"));
+
+        let row = layout_styles(colors)
+            .direction_horizontal()
+            .visibility(hidden_below(Breakpoint::Tablet))
+            .classes();
+        assert!(row.contains("hidden"));
+        assert!(row.contains("md:flex"));
+        assert!(!row.contains("md:block"));
+    }
+
+    #[test]
+    fn test_container_query() {
+        let colors = VibeColors::default();
+
+        assert!(layout_styles(colors.clone())
+            .container_query()
+            .classes()
+            .contains("@container"));
+        assert!(!layout_styles(colors.clone())
+            .classes()
+            .contains("@container"));
+
+        // The layout's own visibility stays on viewport breakpoints
+        let layout = layout_styles(colors)
+            .direction_horizontal()
+            .visibility(hidden_below(Breakpoint::Desktop))
+            .container_query();
+        let classes = layout.classes();
+        assert!(classes.split_whitespace().any(|c| c == "lg:flex"));
+        assert!(!classes.contains("@lg:"));
+
+        // unless it opts into container visibility
+        let classes = layout.container_visibility().classes();
+        assert!(classes.split_whitespace().any(|c| c == "@lg:flex"));
+        assert!(!classes.split_whitespace().any(|c| c == "lg:flex"));
+    }
+
+    #[test]
+    fn test_theme_breakpoints() {
+        let colors = VibeColors::default().with_breakpoints(BreakpointScale {
+            tablet: 600,
+            ..BreakpointScale::default()
+        });
+
+        let classes = layout_styles(colors)
+            .visibility(hidden_below(Breakpoint::Tablet))
+            .classes();
+        assert!(classes.contains("min-[600px]:block"));
+        assert!(!classes.contains("md:block"));
+    }
+
+    #[test]
+    #[cfg(feature = "cards")]
+    fn test_card_sections_follow_card_spacing() {
+        use crate::builders::card::card_styles;
+        use crate::core::Density;
+        use crate::patterns::CardSpacing;
+
+        let colors = VibeColors::default();
+
+        let header = card_header_styles(colors.clone())
+            .card_spacing(CardSpacing::Standard)
+            .classes();
+        assert!(header.contains("p-5"));
+        assert!(!header.contains("p-4"));
+        assert!(header.contains("border-b"));
+
+        // No card spacing removes section padding but keeps dividers
+        let footer = card_footer_styles(colors.clone())
+            .card_spacing(CardSpacing::None)
+            .classes();
+        assert!(footer.contains("p-0"));
+        assert!(footer.contains("border-t"));
+
+        // Sections and cards agree on the inset, including density
+        let compact = colors.with_density(Density::Compact);
+        assert!(card_styles(compact.clone())
+            .classes()
+            .contains(CardSpacing::Standard.padding_class(Density::Compact)));
+        assert!(card_content_styles(compact)
+            .card_spacing(CardSpacing::Standard)
+            .classes()
+            .contains(CardSpacing::Standard.padding_class(Density::Compact)));
+    }
 }
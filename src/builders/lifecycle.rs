@@ -0,0 +1,180 @@
+//! Async action lifecycle for buttons
+//!
+//! Optimistic-UI buttons move through idle, loading, success and error
+//! phases. [`ButtonLifecycle`] wraps a configured [`ButtonStyles`] and
+//! returns the classes and attributes for each phase, plus how long the
+//! success and error phases should hold before returning to idle, so every
+//! async button behaves the same way.
+
+use crate::builders::button::ButtonStyles;
+use crate::core::color::ColorProvider;
+use crate::core::{Color, MotionDuration, MotionProvider};
+use crate::utils::{class_diff, ClassDiff};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Phases of an async action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ButtonPhase {
+    /// Waiting for the user
+    Idle,
+    /// Request in flight
+    Loading,
+    /// Request succeeded; shows a check state before returning to idle
+    Success,
+    /// Request failed; shakes before returning to idle
+    Error,
+}
+
+impl ButtonPhase {
+    /// Get the phase name used in `data-phase`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ButtonPhase::Idle => "idle",
+            ButtonPhase::Loading => "loading",
+            ButtonPhase::Success => "success",
+            ButtonPhase::Error => "error",
+        }
+    }
+}
+
+/// What changes when a button enters a phase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleDelta {
+    /// Classes to add and remove relative to the idle button
+    pub classes: ClassDiff,
+    /// Attributes to set while in the phase
+    pub attributes: Vec<(&'static str, String)>,
+    /// Milliseconds to stay in the phase before returning to idle, for
+    /// phases that return on their own
+    pub hold_ms: Option<u16>,
+}
+
+/// Phase-aware classes for an async action button
+///
+/// The error shake uses an arbitrary `animate-[button-shake_…]` value, so
+/// the app's stylesheet must define `@keyframes button-shake`. Its
+/// duration follows the theme's slow motion token and is skipped for users
+/// who prefer reduced motion.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::button::ButtonStyles;
+/// use jupiter_design_system::builders::lifecycle::{ButtonLifecycle, ButtonPhase};
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let save = ButtonLifecycle::new(ButtonStyles::new(VibeColors::default()).primary());
+///
+/// let loading = save.delta(ButtonPhase::Loading);
+/// assert!(loading.classes.added.contains(&"pointer-events-none".to_string()));
+/// assert!(loading.attributes.contains(&("aria-busy", "true".to_string())));
+///
+/// let success = save.delta(ButtonPhase::Success);
+/// assert!(success.classes.added.contains(&"bg-green-500".to_string()));
+/// assert!(success.classes.removed.contains(&"bg-jupiter-blue-500".to_string()));
+/// assert_eq!(success.hold_ms, Some(2000));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ButtonLifecycle<C: ColorProvider + Clone> {
+    button: ButtonStyles<C>,
+    success_hold_ms: u16,
+}
+
+impl<C: ColorProvider + Clone> ButtonLifecycle<C> {
+    /// Wrap a configured button
+    pub fn new(button: ButtonStyles<C>) -> Self {
+        Self {
+            button,
+            success_hold_ms: 2000,
+        }
+    }
+
+    /// Set how long the success state shows before returning to idle
+    pub fn success_hold(mut self, ms: u16) -> Self {
+        self.success_hold_ms = ms;
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Get the button styles for a phase
+    pub fn styles(&self, phase: ButtonPhase) -> ButtonStyles<C> {
+        let button = self.button.clone();
+        match phase {
+            ButtonPhase::Idle => button,
+            ButtonPhase::Loading => button.loading().custom("pointer-events-none"),
+            ButtonPhase::Success => button.success(),
+            ButtonPhase::Error => button.custom(format!(
+                "{} ring-2 ring-offset-2 ring-{}",
+                self.shake_class(),
+                self.button.color_provider().resolve_color(Color::Error)
+            )),
+        }
+    }
+
+    /// Get the full classes for a phase
+    pub fn classes(&self, phase: ButtonPhase) -> String {
+        self.styles(phase).classes()
+    }
+
+    /// Get the attributes for a phase
+    ///
+    /// Every phase sets `data-phase`; loading also marks the button busy
+    /// and disabled so repeated clicks are ignored.
+    pub fn attributes(&self, phase: ButtonPhase) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![("data-phase", phase.as_str().to_string())];
+        if phase == ButtonPhase::Loading {
+            attrs.push(("aria-busy", "true".to_string()));
+            attrs.push(("aria-disabled", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Get how long a phase holds before returning to idle
+    ///
+    /// Success holds for the configured time; error holds for the shake.
+    /// Idle and loading last until the app changes phase.
+    pub fn hold_ms(&self, phase: ButtonPhase) -> Option<u16> {
+        match phase {
+            ButtonPhase::Idle | ButtonPhase::Loading => None,
+            ButtonPhase::Success => Some(self.success_hold_ms),
+            ButtonPhase::Error => Some(self.shake_ms()),
+        }
+    }
+
+    /// Get everything that changes when entering a phase from idle
+    pub fn delta(&self, phase: ButtonPhase) -> LifecycleDelta {
+        LifecycleDelta {
+            classes: class_diff(&self.classes(ButtonPhase::Idle), &self.classes(phase)),
+            attributes: self.attributes(phase),
+            hold_ms: self.hold_ms(phase),
+        }
+    }
+
+    fn shake_ms(&self) -> u16 {
+        self.button
+            .color_provider()
+            .motion_scale()
+            .resolve_duration(MotionDuration::Slow)
+    }
+
+    fn shake_class(&self) -> String {
+        format!(
+            "motion-safe:animate-[button-shake_{}ms_ease-in-out]",
+            self.shake_ms()
+        )
+    }
+}
+
+/// Convenience function to create a lifecycle for a button
+pub fn button_lifecycle<C: ColorProvider + Clone>(button: ButtonStyles<C>) -> ButtonLifecycle<C> {
+    ButtonLifecycle::new(button)
+}
+
+#[cfg(test)]
+#[path = "lifecycle_test.rs"]
+mod lifecycle_test;
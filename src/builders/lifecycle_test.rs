@@ -0,0 +1,64 @@
+//! Tests for the button lifecycle utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::button::ButtonStyles;
+    use crate::builders::lifecycle::{button_lifecycle, ButtonPhase};
+    use crate::core::MotionScale;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_idle_matches_button() {
+        let button = ButtonStyles::new(VibeColors::default()).primary();
+        let lifecycle = button_lifecycle(button.clone());
+
+        assert_eq!(lifecycle.classes(ButtonPhase::Idle), button.classes());
+        let idle = lifecycle.delta(ButtonPhase::Idle);
+        assert!(idle.classes.is_empty());
+        assert_eq!(idle.attributes, vec![("data-phase", "idle".to_string())]);
+        assert_eq!(idle.hold_ms, None);
+    }
+
+    #[test]
+    fn test_loading_blocks_pointer_events() {
+        let lifecycle = button_lifecycle(ButtonStyles::new(VibeColors::default()).primary());
+
+        let loading = lifecycle.delta(ButtonPhase::Loading);
+        assert!(loading
+            .classes
+            .added
+            .contains(&"pointer-events-none".to_string()));
+        assert!(loading.classes.added.contains(&"cursor-wait".to_string()));
+        assert!(loading
+            .attributes
+            .contains(&("aria-disabled", "true".to_string())));
+        assert_eq!(loading.hold_ms, None);
+    }
+
+    #[test]
+    fn test_success_swaps_colors_and_holds() {
+        let lifecycle = button_lifecycle(ButtonStyles::new(VibeColors::default()).secondary())
+            .success_hold(1200);
+
+        let success = lifecycle.delta(ButtonPhase::Success);
+        assert!(success.classes.added.contains(&"bg-green-500".to_string()));
+        assert!(success.classes.removed.contains(&"bg-white".to_string()));
+        assert_eq!(success.hold_ms, Some(1200));
+    }
+
+    #[test]
+    fn test_error_shake_follows_motion_tokens() {
+        let lifecycle = button_lifecycle(ButtonStyles::new(VibeColors::default()).primary());
+        let error = lifecycle.classes(ButtonPhase::Error);
+        assert!(error.contains("motion-safe:animate-[button-shake_300ms_ease-in-out]"));
+        assert!(error.contains("ring-red-500"));
+        assert_eq!(lifecycle.hold_ms(ButtonPhase::Error), Some(300));
+
+        let relaxed = VibeColors::default().with_motion(MotionScale::relaxed());
+        let lifecycle = button_lifecycle(ButtonStyles::new(relaxed).primary());
+        assert!(lifecycle
+            .classes(ButtonPhase::Error)
+            .contains("animate-[button-shake_500ms_ease-in-out]"));
+        assert_eq!(lifecycle.hold_ms(ButtonPhase::Error), Some(500));
+    }
+}
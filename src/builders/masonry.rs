@@ -0,0 +1,289 @@
+//! Gallery layout utilities for the Jupiter Design System
+//!
+//! Provides classes for two editorial gallery layouts: a masonry flow built
+//! on CSS columns, where tiles of any height pack into responsive columns,
+//! and a featured grid where one large tile sits beside a block of small
+//! ones.
+
+use crate::core::color::ColorProvider;
+use crate::core::Breakpoint;
+use crate::patterns::LayoutSpacing;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Side of the featured grid the large tile sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FeaturedSide {
+    /// Large tile first, small tiles after it
+    Start,
+    /// Small tiles first, large tile after them
+    End,
+}
+
+/// Masonry layout utility builder
+///
+/// CSS columns fill top to bottom before moving to the next column, so
+/// tiles read down each column rather than across rows. Use it for
+/// galleries where order is loose; use a grid when order matters.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::masonry::MasonryStyles;
+/// use jupiter_design_system::patterns::LayoutSpacing;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let gallery = MasonryStyles::new(VibeColors::default())
+///     .columns(2)
+///     .lg_columns(4)
+///     .gap(LayoutSpacing::LG);
+///
+/// assert_eq!(gallery.classes(), "masonry columns-2 md:columns-2 lg:columns-4 gap-6");
+/// assert_eq!(gallery.item_classes(), "masonry-item break-inside-avoid mb-6");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MasonryStyles<C: ColorProvider> {
+    columns: Vec<(Breakpoint, u8)>,
+    gap: LayoutSpacing,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> MasonryStyles<C> {
+    /// Create a new masonry layout (1, 2 and 3 columns at mobile, tablet
+    /// and desktop)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            columns: vec![
+                (Breakpoint::Mobile, 1),
+                (Breakpoint::Tablet, 2),
+                (Breakpoint::Desktop, 3),
+            ],
+            gap: LayoutSpacing::MD,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Column Methods ===
+
+    /// Set the columns on the smallest screens
+    pub fn columns(self, cols: u8) -> Self {
+        self.columns_at(Breakpoint::Mobile, cols)
+    }
+
+    /// Set the columns from the tablet breakpoint up
+    pub fn md_columns(self, cols: u8) -> Self {
+        self.columns_at(Breakpoint::Tablet, cols)
+    }
+
+    /// Set the columns from the desktop breakpoint up
+    pub fn lg_columns(self, cols: u8) -> Self {
+        self.columns_at(Breakpoint::Desktop, cols)
+    }
+
+    /// Set the columns from the large breakpoint up
+    pub fn xl_columns(self, cols: u8) -> Self {
+        self.columns_at(Breakpoint::Large, cols)
+    }
+
+    /// Set the columns from a breakpoint up (clamped to 1-12)
+    pub fn columns_at(mut self, breakpoint: Breakpoint, cols: u8) -> Self {
+        let cols = cols.clamp(1, 12);
+        match self
+            .columns
+            .iter_mut()
+            .find(|(existing, _)| *existing == breakpoint)
+        {
+            Some(entry) => entry.1 = cols,
+            None => self.columns.push((breakpoint, cols)),
+        }
+        self
+    }
+
+    /// Set the space between columns and between stacked tiles
+    pub fn gap(mut self, gap: LayoutSpacing) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the masonry container
+    pub fn classes(&self) -> String {
+        let breakpoints = self.color_provider.breakpoint_scale();
+        let mut columns = self.columns.clone();
+        columns.sort_by_key(|(breakpoint, _)| breakpoints.min_width(*breakpoint));
+
+        let mut classes = vec!["masonry".to_string()];
+        classes.extend(columns.into_iter().map(|(breakpoint, cols)| {
+            format!("{}columns-{}", breakpoints.prefix(breakpoint), cols)
+        }));
+        classes.push(format!("gap-{}", self.gap.step()));
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for each tile
+    ///
+    /// Tiles never split across columns, and the bottom margin matches the
+    /// column gap so spacing is even in both directions.
+    pub fn item_classes(&self) -> String {
+        format!("masonry-item break-inside-avoid mb-{}", self.gap.step())
+    }
+}
+
+/// Featured gallery grid utility builder
+///
+/// One large tile spans two columns and two rows beside a block of small
+/// tiles. On phones the large tile spans the full width above two columns
+/// of small tiles.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::masonry::FeaturedGridStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let landing = FeaturedGridStyles::new(VibeColors::default()).small_tiles(4);
+///
+/// assert!(landing.classes().contains("md:grid-cols-4"));
+/// assert!(landing.featured_classes().contains("md:row-span-2"));
+/// assert_eq!(landing.tile_classes(), "featured-grid-tile col-span-1 min-h-0");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FeaturedGridStyles<C: ColorProvider> {
+    small_tiles: u8,
+    side: FeaturedSide,
+    gap: LayoutSpacing,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> FeaturedGridStyles<C> {
+    /// Create a new featured grid (large tile first, four small tiles)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            small_tiles: 4,
+            side: FeaturedSide::Start,
+            gap: LayoutSpacing::MD,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Layout Methods ===
+
+    /// Set how many small tiles sit beside the large one (rounded up to an
+    /// even number and clamped to 2-6, since they fill two rows)
+    pub fn small_tiles(mut self, count: u8) -> Self {
+        self.small_tiles = count.clamp(2, 6).div_ceil(2) * 2;
+        self
+    }
+
+    /// Put the large tile before the small ones
+    pub fn featured_start(mut self) -> Self {
+        self.side = FeaturedSide::Start;
+        self
+    }
+
+    /// Put the large tile after the small ones
+    pub fn featured_end(mut self) -> Self {
+        self.side = FeaturedSide::End;
+        self
+    }
+
+    /// Set the side explicitly
+    pub fn side(mut self, side: FeaturedSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Set the space between tiles
+    pub fn gap(mut self, gap: LayoutSpacing) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the grid container
+    ///
+    /// Dense flow lets small tiles fill the columns before an end-placed
+    /// large tile.
+    pub fn classes(&self) -> String {
+        let tablet = self
+            .color_provider
+            .breakpoint_scale()
+            .prefix(Breakpoint::Tablet);
+        let mut classes = vec![format!(
+            "featured-grid grid grid-cols-2 {}grid-cols-{} {}grid-rows-2 grid-flow-row-dense gap-{}",
+            tablet,
+            self.columns(),
+            tablet,
+            self.gap.step()
+        )];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the large tile
+    pub fn featured_classes(&self) -> String {
+        let tablet = self
+            .color_provider
+            .breakpoint_scale()
+            .prefix(Breakpoint::Tablet);
+        let mut classes = format!(
+            "featured-grid-featured col-span-2 {}row-span-2 min-h-0",
+            tablet
+        );
+        if self.side == FeaturedSide::End {
+            classes.push_str(&format!(" {}col-start-{}", tablet, self.columns() - 1));
+        }
+        classes
+    }
+
+    /// Build classes for each small tile
+    pub fn tile_classes(&self) -> String {
+        "featured-grid-tile col-span-1 min-h-0".to_string()
+    }
+
+    /// Columns from the tablet breakpoint up: two for the large tile, plus
+    /// one for every two small tiles
+    fn columns(&self) -> u8 {
+        2 + self.small_tiles / 2
+    }
+}
+
+/// Convenience function to create masonry styles
+pub fn masonry_styles<C: ColorProvider>(color_provider: C) -> MasonryStyles<C> {
+    MasonryStyles::new(color_provider)
+}
+
+/// Convenience function to create featured grid styles
+pub fn featured_grid_styles<C: ColorProvider>(color_provider: C) -> FeaturedGridStyles<C> {
+    FeaturedGridStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "masonry_test.rs"]
+mod masonry_test;
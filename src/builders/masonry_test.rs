@@ -0,0 +1,90 @@
+//! Tests for gallery layout utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::masonry::{
+        featured_grid_styles, masonry_styles, FeaturedGridStyles, FeaturedSide, MasonryStyles,
+    };
+    use crate::core::{Breakpoint, BreakpointScale};
+    use crate::patterns::LayoutSpacing;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_masonry_defaults() {
+        let masonry = masonry_styles(VibeColors::default());
+
+        assert_eq!(
+            masonry.classes(),
+            "masonry columns-1 md:columns-2 lg:columns-3 gap-4"
+        );
+        assert_eq!(
+            masonry.item_classes(),
+            "masonry-item break-inside-avoid mb-4"
+        );
+    }
+
+    #[test]
+    fn test_masonry_columns_sorted_and_clamped() {
+        let masonry = MasonryStyles::new(VibeColors::default())
+            .xl_columns(20)
+            .columns_at(Breakpoint::Tablet, 3)
+            .gap(LayoutSpacing::SM)
+            .custom("px-4");
+
+        assert_eq!(
+            masonry.classes(),
+            "masonry columns-1 md:columns-3 lg:columns-3 xl:columns-12 gap-2 px-4"
+        );
+        assert!(masonry.item_classes().ends_with("mb-2"));
+    }
+
+    #[test]
+    fn test_masonry_follows_theme_breakpoints() {
+        let colors = VibeColors::default().with_breakpoints(BreakpointScale {
+            tablet: 600,
+            ..BreakpointScale::default()
+        });
+        let classes = masonry_styles(colors).classes();
+
+        assert!(classes.contains("min-[600px]:columns-2"));
+    }
+
+    #[test]
+    fn test_featured_grid_columns_follow_tile_count() {
+        let colors = VibeColors::default();
+
+        let four = featured_grid_styles(colors.clone());
+        assert!(four
+            .classes()
+            .contains("grid-cols-2 md:grid-cols-4 md:grid-rows-2"));
+        assert!(four.classes().contains("grid-flow-row-dense"));
+        assert_eq!(
+            four.featured_classes(),
+            "featured-grid-featured col-span-2 md:row-span-2 min-h-0"
+        );
+
+        let two = FeaturedGridStyles::new(colors.clone()).small_tiles(1);
+        assert!(two.classes().contains("md:grid-cols-3"));
+
+        let six = FeaturedGridStyles::new(colors).small_tiles(5);
+        assert!(six.classes().contains("md:grid-cols-5"));
+    }
+
+    #[test]
+    fn test_featured_grid_end_placement() {
+        let colors = VibeColors::default();
+
+        let end = FeaturedGridStyles::new(colors.clone())
+            .featured_end()
+            .gap(LayoutSpacing::LG);
+        assert!(end.featured_classes().ends_with("md:col-start-3"));
+        assert!(end.classes().contains("gap-6"));
+
+        let start = FeaturedGridStyles::new(colors).side(FeaturedSide::Start);
+        assert!(!start.featured_classes().contains("col-start"));
+        assert_eq!(
+            start.tile_classes(),
+            "featured-grid-tile col-span-1 min-h-0"
+        );
+    }
+}
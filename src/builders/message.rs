@@ -0,0 +1,270 @@
+//! Chat message styling utilities for the Jupiter Design System
+//!
+//! Provides classes for chat UIs: sent and received bubbles, system messages,
+//! timestamps, and the spacing rules for consecutive messages from the same
+//! author.
+
+use crate::builders::text::TextStyles;
+use crate::core::color::ColorProvider;
+use crate::core::Color;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Who a message is from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MessageDirection {
+    /// Sent by the current user (brand surface, trailing side)
+    Sent,
+    /// Received from another participant (neutral surface, leading side)
+    Received,
+    /// System notice (centered, no bubble)
+    System,
+}
+
+/// Position of a message within a run from the same author
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MessageGroupPosition {
+    /// Standalone message
+    Single,
+    /// First message of a group
+    First,
+    /// Message between the first and last of a group
+    Middle,
+    /// Last message of a group (carries the tail)
+    Last,
+}
+
+/// Chat message styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::message::MessageStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let message = MessageStyles::new(VibeColors::default()).sent().last();
+///
+/// let row_classes = message.row_classes();
+/// let bubble_classes = message.bubble_classes();
+/// let timestamp_classes = message.timestamp_classes();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MessageStyles<C: ColorProvider + Clone> {
+    direction: MessageDirection,
+    position: MessageGroupPosition,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> MessageStyles<C> {
+    /// Create a new message styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            direction: MessageDirection::Received,
+            position: MessageGroupPosition::Single,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Direction Methods ===
+
+    /// Message sent by the current user
+    pub fn sent(mut self) -> Self {
+        self.direction = MessageDirection::Sent;
+        self
+    }
+
+    /// Message received from another participant
+    pub fn received(mut self) -> Self {
+        self.direction = MessageDirection::Received;
+        self
+    }
+
+    /// System notice
+    pub fn system(mut self) -> Self {
+        self.direction = MessageDirection::System;
+        self
+    }
+
+    /// Set direction explicitly
+    pub fn direction(mut self, direction: MessageDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set direction from string
+    pub fn direction_str(mut self, direction: &str) -> Self {
+        self.direction = match direction {
+            "sent" | "outgoing" | "self" => MessageDirection::Sent,
+            "received" | "incoming" => MessageDirection::Received,
+            "system" | "notice" => MessageDirection::System,
+            _ => MessageDirection::Received, // fallback
+        };
+        self
+    }
+
+    // === Grouping Methods ===
+
+    /// Standalone message
+    pub fn single(mut self) -> Self {
+        self.position = MessageGroupPosition::Single;
+        self
+    }
+
+    /// First message of a group
+    pub fn first(mut self) -> Self {
+        self.position = MessageGroupPosition::First;
+        self
+    }
+
+    /// Middle message of a group
+    pub fn middle(mut self) -> Self {
+        self.position = MessageGroupPosition::Middle;
+        self
+    }
+
+    /// Last message of a group
+    pub fn last(mut self) -> Self {
+        self.position = MessageGroupPosition::Last;
+        self
+    }
+
+    /// Set group position explicitly
+    pub fn group_position(mut self, position: MessageGroupPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the bubble
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the message row (alignment and group spacing)
+    ///
+    /// Messages that start a group get full spacing; follow-up messages from
+    /// the same author sit tight against the previous bubble.
+    pub fn row_classes(&self) -> String {
+        let alignment = match self.direction {
+            MessageDirection::Sent => "items-end",
+            MessageDirection::Received => "items-start",
+            MessageDirection::System => "items-center",
+        };
+        let spacing = match self.position {
+            MessageGroupPosition::Single | MessageGroupPosition::First => "mt-4",
+            MessageGroupPosition::Middle | MessageGroupPosition::Last => "mt-0.5",
+        };
+        format!(
+            "message-row flex flex-col w-full first:mt-0 {} {}",
+            alignment, spacing
+        )
+    }
+
+    /// Build classes for the message bubble
+    pub fn bubble_classes(&self) -> String {
+        let mut classes = Vec::new();
+
+        match self.direction {
+            MessageDirection::Sent => {
+                classes.push("message-bubble message-bubble--sent".to_string());
+                classes.push(self.bubble_base_classes().to_string());
+                classes.push(self.color_provider.bg_class(Color::Primary));
+            }
+            MessageDirection::Received => {
+                classes.push("message-bubble message-bubble--received".to_string());
+                classes.push(self.bubble_base_classes().to_string());
+                classes.push(self.color_provider.bg_class(Color::Background));
+            }
+            MessageDirection::System => {
+                classes.push("message-system px-3 py-1 rounded-full text-center".to_string());
+                classes.push(self.color_provider.bg_class(Color::Background));
+            }
+        }
+
+        classes.push(self.text_classes());
+        classes.push(self.radius_classes().to_string());
+        classes.extend(self.custom_classes.iter().cloned());
+
+        classes
+            .into_iter()
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Build classes for the message text
+    pub fn text_classes(&self) -> String {
+        let text = TextStyles::new(self.color_provider.clone());
+        match self.direction {
+            MessageDirection::Sent => text.body_small().white().classes(),
+            MessageDirection::Received => text.body_small().classes(),
+            MessageDirection::System => text.caption().extra_small().muted().classes(),
+        }
+    }
+
+    /// Build classes for the timestamp caption
+    pub fn timestamp_classes(&self) -> String {
+        let text = TextStyles::new(self.color_provider.clone())
+            .caption()
+            .extra_small()
+            .normal()
+            .muted();
+        let text = match self.direction {
+            MessageDirection::Sent => text.right(),
+            MessageDirection::Received => text.left(),
+            MessageDirection::System => text.center(),
+        };
+        format!(
+            "message-timestamp mt-1 px-1 tabular-nums {}",
+            text.classes()
+        )
+    }
+
+    fn bubble_base_classes(&self) -> &'static str {
+        "max-w-[75%] px-3.5 py-2 break-words whitespace-pre-wrap"
+    }
+
+    /// Bubbles are fully rounded except on the author's side, where corners
+    /// touching another bubble in the group are flattened and the last one
+    /// carries the tail.
+    fn radius_classes(&self) -> &'static str {
+        match (self.direction, self.position) {
+            (MessageDirection::System, _) => "",
+            (MessageDirection::Sent, MessageGroupPosition::Single) => "rounded-2xl rounded-br-sm",
+            (MessageDirection::Sent, MessageGroupPosition::First) => "rounded-2xl rounded-br-md",
+            (MessageDirection::Sent, MessageGroupPosition::Middle) => "rounded-2xl rounded-r-md",
+            (MessageDirection::Sent, MessageGroupPosition::Last) => {
+                "rounded-2xl rounded-tr-md rounded-br-sm"
+            }
+            (MessageDirection::Received, MessageGroupPosition::Single) => {
+                "rounded-2xl rounded-bl-sm"
+            }
+            (MessageDirection::Received, MessageGroupPosition::First) => {
+                "rounded-2xl rounded-bl-md"
+            }
+            (MessageDirection::Received, MessageGroupPosition::Middle) => {
+                "rounded-2xl rounded-l-md"
+            }
+            (MessageDirection::Received, MessageGroupPosition::Last) => {
+                "rounded-2xl rounded-tl-md rounded-bl-sm"
+            }
+        }
+    }
+}
+
+/// Convenience function to create message styles
+pub fn message_styles<C: ColorProvider + Clone>(color_provider: C) -> MessageStyles<C> {
+    MessageStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "message_test.rs"]
+mod message_test;
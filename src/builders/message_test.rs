@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::message::{message_styles, MessageGroupPosition, MessageStyles};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_sent_bubble_uses_brand_surface() {
+        let message = message_styles(VibeColors::default()).sent();
+
+        let classes = message.bubble_classes();
+        assert!(classes.contains("message-bubble--sent"));
+        assert!(classes.contains("bg-jupiter-blue-500"));
+        assert!(classes.contains("text-white"));
+        assert!(message.row_classes().contains("items-end"));
+    }
+
+    #[test]
+    fn test_received_bubble_uses_neutral_surface() {
+        let message = message_styles(VibeColors::default()).received();
+
+        let classes = message.bubble_classes();
+        assert!(classes.contains("message-bubble--received"));
+        assert!(classes.contains("bg-gray-50"));
+        assert!(classes.contains("text-gray-900"));
+        assert!(message.row_classes().contains("items-start"));
+    }
+
+    #[test]
+    fn test_tail_side_flattening() {
+        let colors = VibeColors::default();
+
+        let sent = MessageStyles::new(colors.clone()).sent().single();
+        assert!(sent.bubble_classes().contains("rounded-br-sm"));
+
+        let sent_middle = MessageStyles::new(colors.clone()).sent().middle();
+        assert!(sent_middle.bubble_classes().contains("rounded-r-md"));
+
+        let received_last = MessageStyles::new(colors)
+            .received()
+            .group_position(MessageGroupPosition::Last);
+        let classes = received_last.bubble_classes();
+        assert!(classes.contains("rounded-tl-md"));
+        assert!(classes.contains("rounded-bl-sm"));
+    }
+
+    #[test]
+    fn test_grouped_spacing() {
+        let colors = VibeColors::default();
+
+        let first = MessageStyles::new(colors.clone()).first();
+        assert!(first.row_classes().contains("mt-4"));
+
+        let last = MessageStyles::new(colors).last();
+        assert!(last.row_classes().contains("mt-0.5"));
+    }
+
+    #[test]
+    fn test_system_message_and_timestamps() {
+        let colors = VibeColors::default();
+
+        let system = MessageStyles::new(colors.clone()).system();
+        let classes = system.bubble_classes();
+        assert!(classes.contains("message-system"));
+        assert!(classes.contains("rounded-full"));
+        assert!(!classes.contains("rounded-2xl"));
+        assert!(system.row_classes().contains("items-center"));
+        assert!(system.timestamp_classes().contains("text-center"));
+
+        let sent = MessageStyles::new(colors.clone()).direction_str("outgoing");
+        assert!(sent.timestamp_classes().contains("text-right"));
+        assert!(sent.timestamp_classes().contains("text-gray-600"));
+
+        let fallback = MessageStyles::new(colors).direction_str("invalid");
+        assert!(fallback.timestamp_classes().contains("text-left"));
+    }
+}
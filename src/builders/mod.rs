@@ -6,42 +6,153 @@
 //! The builders are pure styling utilities that generate CSS classes without
 //! being tied to any specific component implementation.
 
+#[cfg(feature = "buttons")]
 pub mod button;
+#[cfg(feature = "typography")]
+pub mod callout;
+#[cfg(feature = "cards")]
 pub mod card;
+#[cfg(feature = "buttons")]
+pub mod carousel;
+pub mod chart_container;
+#[cfg(all(feature = "buttons", feature = "cards", feature = "typography"))]
+pub mod coachmark;
+#[cfg(feature = "typography")]
+pub mod detail_list;
+pub mod dnd;
+#[cfg(feature = "selection")]
+pub mod facet;
+#[cfg(all(feature = "cards", feature = "typography"))]
+pub mod hovercard;
+pub mod indicator;
+#[cfg(feature = "buttons")]
 pub mod interactive;
+#[cfg(all(feature = "buttons", feature = "cards"))]
+pub mod kanban;
+#[cfg(feature = "typography")]
+pub mod labeled_value;
+#[cfg(feature = "layout")]
 pub mod layout;
+#[cfg(feature = "buttons")]
+pub mod lifecycle;
+#[cfg(feature = "layout")]
+pub mod masonry;
+#[cfg(feature = "typography")]
+pub mod message;
+pub mod position;
+#[cfg(feature = "product")]
 pub mod product;
+pub mod scroll;
+#[cfg(all(feature = "buttons", feature = "selection"))]
+pub mod search;
+#[cfg(feature = "selection")]
 pub mod selection;
+#[cfg(feature = "layout")]
+pub mod split_pane;
+#[cfg(feature = "states")]
 pub mod state;
+#[cfg(feature = "states")]
+pub mod status_indicator;
+pub mod table;
+#[cfg(feature = "typography")]
 pub mod text;
+#[cfg(feature = "selection")]
+pub mod tree;
+pub mod upload;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "typography"))]
 mod text_test;
 
 // Re-export commonly used items
+#[cfg(all(feature = "std", feature = "buttons"))]
+pub use button::button_styles_default;
+#[cfg(feature = "buttons")]
 pub use button::{
     button_classes_from_strings, button_styles, ButtonState, ButtonStyles, ButtonVariant,
 };
+#[cfg(feature = "typography")]
+pub use callout::{callout_styles, CalloutKind, CalloutStyles};
+#[cfg(all(feature = "std", feature = "cards"))]
+pub use card::card_styles_default;
+#[cfg(feature = "cards")]
 pub use card::{card_classes_from_strings, card_styles, CardStyles};
+#[cfg(feature = "buttons")]
+pub use carousel::{carousel_styles, CarouselStyles, SlideSizing};
+pub use chart_container::{chart_container_styles, ChartContainerStyles, ChartSize};
+#[cfg(all(feature = "buttons", feature = "cards", feature = "typography"))]
+pub use coachmark::{coachmark_styles, CoachmarkPlacement, CoachmarkStyles};
+#[cfg(feature = "typography")]
+pub use detail_list::{compact_detail_list_styles, detail_list_styles, DetailListStyles};
+pub use dnd::{dnd_styles, DndStyles, DragState, DragSurface};
+#[cfg(feature = "selection")]
+pub use facet::{facet_drawer_styles, facet_styles, FacetStyles};
+#[cfg(all(feature = "cards", feature = "typography"))]
+pub use hovercard::{hovercard_styles, HoverIntent, HovercardPlacement, HovercardStyles};
+pub use indicator::{
+    indicator_styles, IndicatorCorner, IndicatorKind, IndicatorSize, IndicatorStyles,
+};
+#[cfg(feature = "buttons")]
 pub use interactive::{
     interactive_button, interactive_element, interactive_input, ButtonBuilder, InputBuilder,
 };
+#[cfg(all(feature = "buttons", feature = "cards"))]
+pub use kanban::{kanban_styles, KanbanStyles};
+#[cfg(feature = "typography")]
+pub use labeled_value::{labeled_value_styles, LabeledValueOrientation, LabeledValueStyles};
+#[cfg(all(feature = "std", feature = "layout"))]
+pub use layout::layout_styles_default;
+#[cfg(feature = "layout")]
 pub use layout::{
     card_content_styles, card_footer_styles, card_header_styles, layout_styles, LayoutStyles,
 };
+#[cfg(feature = "buttons")]
+pub use lifecycle::{button_lifecycle, ButtonLifecycle, ButtonPhase, LifecycleDelta};
+#[cfg(feature = "layout")]
+pub use masonry::{
+    featured_grid_styles, masonry_styles, FeaturedGridStyles, FeaturedSide, MasonryStyles,
+};
+#[cfg(feature = "typography")]
+pub use message::{message_styles, MessageDirection, MessageGroupPosition, MessageStyles};
+pub use position::{position_styles, Placement, PositionMode, PositionStyles};
+#[cfg(feature = "product")]
 pub use product::{
-    featured_product_styles, product_preview_styles, product_showcase_styles, product_styles,
-    product_tile_styles, ProductBuilder,
+    featured_product_styles, product_preview_styles, product_quick_view_styles,
+    product_showcase_styles, product_styles, product_tile_styles, ProductBuilder, ProductClasses,
+    ProductQuickViewClasses,
+};
+pub use scroll::{
+    carousel_scroll_styles, overlay_scroll_styles, scroll_styles, ScrollAxis, ScrollSnapAlign,
+    ScrollStyles, ScrollbarStyle,
 };
+#[cfg(all(feature = "buttons", feature = "selection"))]
+pub use search::{search_bar_styles, SearchBarStyles};
+#[cfg(all(feature = "std", feature = "selection"))]
+pub use selection::selection_styles_default;
+#[cfg(feature = "selection")]
 pub use selection::{
-    chip_selection_styles, filter_selection_styles, selection_classes_from_strings,
-    selection_styles, tab_selection_styles, SelectionStyles,
+    chip_selection_styles, filter_selection_styles, segmented_control_styles,
+    selection_classes_from_strings, selection_styles, tab_selection_styles, SelectionStyles,
 };
+#[cfg(feature = "layout")]
+pub use split_pane::{split_pane_styles, SplitOrientation, SplitPaneSide, SplitPaneStyles};
+#[cfg(all(feature = "std", feature = "states"))]
+pub use state::state_styles_default;
+#[cfg(feature = "states")]
 pub use state::{
-    empty_state_styles, error_state_styles, loading_state_styles, state_classes_from_strings,
-    state_styles, success_state_styles, StateStyles,
+    empty_state_styles, error_page_styles, error_state_styles, loading_state_styles,
+    state_classes_from_strings, state_styles, success_state_styles, ErrorPageClasses,
+    ErrorPageStyles, StateParts, StateStyles,
 };
+#[cfg(feature = "states")]
+pub use status_indicator::{status_indicator_styles, ServiceStatus, StatusIndicatorStyles};
+pub use table::{table_styles, TableStyles};
+#[cfg(all(feature = "std", feature = "typography"))]
+pub use text::text_styles_default;
+#[cfg(feature = "typography")]
 pub use text::{
     text_clamp_style, text_classes_from_strings, text_element_from_hierarchy, text_styles,
     TextStyles,
 };
+#[cfg(feature = "selection")]
+pub use tree::{checkbox_tree_styles, tree_styles, TreeStyles};
+pub use upload::{compact_dropzone_styles, dropzone_styles, DropzoneState, DropzoneStyles};
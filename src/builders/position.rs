@@ -0,0 +1,235 @@
+//! Position styling utilities for the Jupiter Design System
+//!
+//! Provides classes for pinning an element inside a positioned parent at a
+//! semantic placement (badges in a corner, arrows on an edge, overlays in
+//! the middle). Horizontal edges are logical by default (`start`/`end`), so
+//! placements mirror automatically in right-to-left layouts.
+
+use alloc::string::{String, ToString};
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Where an element is pinned within its positioned parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Placement {
+    /// Top corner on the start side (top-left in left-to-right layouts)
+    TopStart,
+    /// Middle of the top edge
+    TopCenter,
+    /// Top corner on the end side (badges, close buttons)
+    TopEnd,
+    /// Middle of the start edge
+    CenterStart,
+    /// Centered on both axes (overlays, spinners)
+    Center,
+    /// Middle of the end edge
+    CenterEnd,
+    /// Bottom corner on the start side
+    BottomStart,
+    /// Middle of the bottom edge
+    BottomCenter,
+    /// Bottom corner on the end side
+    BottomEnd,
+}
+
+/// CSS positioning scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PositionMode {
+    /// Relative to the nearest positioned ancestor
+    Absolute,
+    /// Relative to the viewport
+    Fixed,
+}
+
+/// Position styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::position::PositionStyles;
+///
+/// assert_eq!(PositionStyles::new().top_end().classes(), "absolute top-2 end-2");
+///
+/// // Arrow hanging off the bottom edge
+/// assert_eq!(
+///     PositionStyles::new().bottom_center().offset("1.5").overhang().classes(),
+///     "absolute -bottom-1.5 left-1/2 -translate-x-1/2"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PositionStyles {
+    placement: Placement,
+    mode: PositionMode,
+    offset: &'static str,
+    overhang: bool,
+    logical: bool,
+}
+
+impl Default for PositionStyles {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PositionStyles {
+    /// Create a new position styling utility (absolute, top-start, offset 2)
+    pub fn new() -> Self {
+        Self {
+            placement: Placement::TopStart,
+            mode: PositionMode::Absolute,
+            offset: "2",
+            overhang: false,
+            logical: true,
+        }
+    }
+
+    // === Placement Methods ===
+
+    /// Set placement explicitly
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set placement from string, e.g. "top-end" or "overlay-center"
+    pub fn placement_str(mut self, placement: &str) -> Self {
+        self.placement = match placement {
+            "top-start" => Placement::TopStart,
+            "top-center" | "top" => Placement::TopCenter,
+            "top-end" => Placement::TopEnd,
+            "center-start" | "start" => Placement::CenterStart,
+            "center" | "overlay-center" => Placement::Center,
+            "center-end" | "end" => Placement::CenterEnd,
+            "bottom-start" => Placement::BottomStart,
+            "bottom-center" | "bottom" => Placement::BottomCenter,
+            "bottom-end" => Placement::BottomEnd,
+            _ => Placement::TopStart, // fallback
+        };
+        self
+    }
+
+    /// Pin to the top-start corner
+    pub fn top_start(self) -> Self {
+        self.placement(Placement::TopStart)
+    }
+
+    /// Pin to the middle of the top edge
+    pub fn top_center(self) -> Self {
+        self.placement(Placement::TopCenter)
+    }
+
+    /// Pin to the top-end corner
+    pub fn top_end(self) -> Self {
+        self.placement(Placement::TopEnd)
+    }
+
+    /// Pin to the middle of the start edge
+    pub fn center_start(self) -> Self {
+        self.placement(Placement::CenterStart)
+    }
+
+    /// Center on both axes
+    pub fn overlay_center(self) -> Self {
+        self.placement(Placement::Center)
+    }
+
+    /// Pin to the middle of the end edge
+    pub fn center_end(self) -> Self {
+        self.placement(Placement::CenterEnd)
+    }
+
+    /// Pin to the bottom-start corner
+    pub fn bottom_start(self) -> Self {
+        self.placement(Placement::BottomStart)
+    }
+
+    /// Pin to the middle of the bottom edge
+    pub fn bottom_center(self) -> Self {
+        self.placement(Placement::BottomCenter)
+    }
+
+    /// Pin to the bottom-end corner
+    pub fn bottom_end(self) -> Self {
+        self.placement(Placement::BottomEnd)
+    }
+
+    // === Modifier Methods ===
+
+    /// Position relative to the viewport (toasts, floating actions)
+    pub fn fixed(mut self) -> Self {
+        self.mode = PositionMode::Fixed;
+        self
+    }
+
+    /// Set the distance from the edges as a spacing scale value, e.g. "4"
+    pub fn offset(mut self, offset: &'static str) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Push the element past the edge by the offset (arrows, notification dots)
+    pub fn overhang(mut self) -> Self {
+        self.overhang = true;
+        self
+    }
+
+    /// Use physical `left`/`right` edges instead of logical `start`/`end`
+    ///
+    /// For elements placed next to physically positioned content.
+    pub fn physical(mut self) -> Self {
+        self.logical = false;
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build the positioning classes
+    pub fn classes(&self) -> String {
+        let mode = match self.mode {
+            PositionMode::Absolute => "absolute",
+            PositionMode::Fixed => "fixed",
+        };
+        let (start, end) = if self.logical {
+            ("start", "end")
+        } else {
+            ("left", "right")
+        };
+
+        let (vertical, horizontal) = match self.placement {
+            Placement::TopStart => (Some("top"), Some(start)),
+            Placement::TopCenter => (Some("top"), None),
+            Placement::TopEnd => (Some("top"), Some(end)),
+            Placement::CenterStart => (None, Some(start)),
+            Placement::Center => (None, None),
+            Placement::CenterEnd => (None, Some(end)),
+            Placement::BottomStart => (Some("bottom"), Some(start)),
+            Placement::BottomCenter => (Some("bottom"), None),
+            Placement::BottomEnd => (Some("bottom"), Some(end)),
+        };
+
+        let mut classes = vec![mode.to_string()];
+        classes.push(match vertical {
+            Some(edge) => self.edge_class(edge),
+            None => "top-1/2 -translate-y-1/2".to_string(),
+        });
+        classes.push(match horizontal {
+            Some(edge) => self.edge_class(edge),
+            None => "left-1/2 -translate-x-1/2".to_string(),
+        });
+        classes.join(" ")
+    }
+
+    fn edge_class(&self, edge: &str) -> String {
+        let sign = if self.overhang { "-" } else { "" };
+        format!("{}{}-{}", sign, edge, self.offset)
+    }
+}
+
+/// Convenience function to create position styles
+pub fn position_styles() -> PositionStyles {
+    PositionStyles::new()
+}
+
+#[cfg(test)]
+#[path = "position_test.rs"]
+mod position_test;
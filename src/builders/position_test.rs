@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::position::{position_styles, Placement, PositionStyles};
+
+    #[test]
+    fn test_corner_placements_are_logical() {
+        assert_eq!(
+            position_styles().top_start().classes(),
+            "absolute top-2 start-2"
+        );
+        assert_eq!(
+            position_styles().bottom_end().classes(),
+            "absolute bottom-2 end-2"
+        );
+    }
+
+    #[test]
+    fn test_centered_placements() {
+        assert_eq!(
+            position_styles().top_center().classes(),
+            "absolute top-2 left-1/2 -translate-x-1/2"
+        );
+        assert_eq!(
+            position_styles().center_end().classes(),
+            "absolute top-1/2 -translate-y-1/2 end-2"
+        );
+        assert_eq!(
+            position_styles().overlay_center().classes(),
+            "absolute top-1/2 -translate-y-1/2 left-1/2 -translate-x-1/2"
+        );
+    }
+
+    #[test]
+    fn test_modifiers() {
+        assert_eq!(
+            position_styles().top_end().offset("4").fixed().classes(),
+            "fixed top-4 end-4"
+        );
+        assert_eq!(
+            position_styles().top_end().offset("1").overhang().classes(),
+            "absolute -top-1 -end-1"
+        );
+        assert_eq!(
+            position_styles().center_start().physical().classes(),
+            "absolute top-1/2 -translate-y-1/2 left-2"
+        );
+    }
+
+    #[test]
+    fn test_placement_str() {
+        assert_eq!(
+            PositionStyles::new().placement_str("overlay-center"),
+            PositionStyles::new().placement(Placement::Center)
+        );
+        assert_eq!(
+            PositionStyles::new().placement_str("bottom"),
+            PositionStyles::new().bottom_center()
+        );
+        assert_eq!(
+            PositionStyles::new().placement_str("invalid"),
+            PositionStyles::new()
+        );
+    }
+}
@@ -1,12 +1,25 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
 /// Product Builder - Chainable API for product component CSS generation
 ///
 /// This builder provides a fluent interface for creating product component
 /// classes based on Jupiter Design System patterns.
-use crate::core::color::ColorProvider;
+use crate::builders::button::ButtonStyles;
+use crate::builders::position::PositionStyles;
+use crate::core::color::{Color, ColorProvider};
+use crate::core::{
+    BorderProvider, BorderWidth, Breakpoint, Elevation, ElevationProvider, Radius, RadiusProvider,
+    Size, SurfaceLevel,
+};
 use crate::patterns::product::*;
+use crate::patterns::typography::{
+    TypographyColor, TypographyHierarchy, TypographyPattern, TypographyWeight,
+};
 
 /// Builder for product component CSS classes
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProductBuilder<C: ColorProvider> {
     pattern: ProductCardPattern,
     colors: C,
@@ -123,6 +136,12 @@ impl<C: ColorProvider> ProductBuilder<C> {
         self
     }
 
+    /// Pulse the availability badge when stock is limited
+    pub fn pulse_limited_badge(mut self) -> Self {
+        self.pattern = self.pattern.pulse_limited(true);
+        self
+    }
+
     /// Set prominence to subtle
     pub fn subtle(mut self) -> Self {
         self.pattern = self.pattern.prominence(ProductProminence::Subtle);
@@ -279,6 +298,13 @@ impl<C: ColorProvider> ProductBuilder<C> {
         self
     }
 
+    /// Mark the product as in the wishlist (adds the wishlist action)
+    pub fn favorited(self) -> Self {
+        let mut builder = self.wishlist_action();
+        builder.pattern = builder.pattern.favorited(true);
+        builder
+    }
+
     /// Add action to share
     pub fn share_action(mut self) -> Self {
         let mut actions = self.pattern.actions.clone();
@@ -414,8 +440,8 @@ impl<C: ColorProvider> ProductBuilder<C> {
     }
 
     /// Generate CSS classes for product component
-    pub fn classes(self) -> String {
-        let mut classes = self.pattern.classes(self.colors);
+    pub fn classes(&self) -> String {
+        let mut classes = self.pattern.classes(&self.colors);
 
         // Add custom classes
         if !self.custom_classes.is_empty() {
@@ -427,33 +453,86 @@ impl<C: ColorProvider> ProductBuilder<C> {
     }
 
     /// Generate CSS classes for product container
-    pub fn container_classes(self) -> String {
-        let base_classes = self.pattern.classes(self.colors);
+    ///
+    /// List items lay out as a row: thumbnail, content column, then the
+    /// trailing price and actions column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::product_styles;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let row = product_styles(VibeColors::default()).list_item();
+    /// assert!(row.container_classes().contains("flex-row"));
+    /// assert!(row.image_classes().contains("shrink-0"));
+    /// assert!(row.info_classes().contains("flex-1"));
+    /// assert!(row.trailing_classes().contains("items-end"));
+    /// ```
+    pub fn container_classes(&self) -> String {
+        let base_classes = self.pattern.classes(&self.colors);
         let padding = self.pattern.suggested_container_padding();
-        let spacing = self.pattern.suggested_spacing();
+        let layout = self.pattern.suggested_layout();
 
-        format!("{} {} {}", base_classes, padding, spacing)
+        format!("{} {} {}", base_classes, padding, layout)
     }
 
     /// Generate CSS classes for product image
-    pub fn image_classes(self) -> String {
+    pub fn image_classes(&self) -> String {
         let base_classes = "product-image";
         let aspect_ratio = self.pattern.suggested_image_aspect_ratio();
         let sizes = self.pattern.suggested_image_sizes();
 
-        format!("{} {} {}", base_classes, aspect_ratio, sizes)
+        if self.pattern.is_horizontal() {
+            format!(
+                "{} {} {} shrink-0 object-cover",
+                base_classes, aspect_ratio, sizes
+            )
+        } else {
+            format!("{} {} {}", base_classes, aspect_ratio, sizes)
+        }
     }
 
     /// Generate CSS classes for product info section
-    pub fn info_classes(self) -> String {
+    pub fn info_classes(&self) -> String {
         let base_classes = "product-info";
         let spacing = self.pattern.suggested_spacing();
 
-        format!("{} {}", base_classes, spacing)
+        if self.pattern.is_horizontal() {
+            format!("{} flex-1 min-w-0 {}", base_classes, spacing)
+        } else {
+            format!("{} {}", base_classes, spacing)
+        }
+    }
+
+    /// Generate CSS classes for the trailing column of a list item
+    ///
+    /// Holds the price and actions, right-aligned so they line up across
+    /// rows. Vertical cards have no trailing column and get an empty string.
+    pub fn trailing_classes(&self) -> String {
+        if self.pattern.is_horizontal() {
+            String::from(
+                "product-trailing flex flex-col items-end justify-between gap-2 shrink-0 self-stretch text-right",
+            )
+        } else {
+            String::new()
+        }
+    }
+
+    /// Generate CSS classes for a list wrapping product rows, with
+    /// dividers between rows
+    pub fn list_classes(&self) -> String {
+        format!(
+            "product-list {} divide-{}",
+            self.colors
+                .border_scale()
+                .divide_class("y", BorderWidth::Default),
+            self.colors.resolve_color(Color::Border)
+        )
     }
 
     /// Generate CSS classes for product actions
-    pub fn actions_classes(self) -> String {
+    pub fn actions_classes(&self) -> String {
         let base_classes = "product-actions";
         let spacing = match self.pattern.display {
             ProductDisplayPattern::Tile => "gap-2",
@@ -465,11 +544,319 @@ impl<C: ColorProvider> ProductBuilder<C> {
     }
 
     /// Generate CSS classes for product badges
-    pub fn badges_classes(self) -> String {
+    pub fn badges_classes(&self) -> String {
         let base_classes = "product-badges";
-        let positioning = "absolute top-2 right-2 flex flex-col gap-1";
+        let positioning = PositionStyles::new().top_end().classes();
+
+        format!("{} {} flex flex-col gap-1", base_classes, positioning)
+    }
+
+    /// Generate CSS classes for the product title
+    ///
+    /// The level comes from [`ProductCardPattern::title_hierarchy`], so the
+    /// title grows with the display pattern and prominence. Body-sized
+    /// titles use medium weight to stand apart from the description.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::product_styles;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let tile = product_styles(VibeColors::default()).tile();
+    /// assert!(tile.title_classes().contains("text-base"));
+    ///
+    /// let hero = product_styles(VibeColors::default()).featured().hero();
+    /// assert!(hero.title_classes().contains("text-3xl"));
+    /// ```
+    pub fn title_classes(&self) -> String {
+        let hierarchy = self.pattern.title_hierarchy();
+        let is_heading = matches!(
+            hierarchy,
+            TypographyHierarchy::Title
+                | TypographyHierarchy::Heading
+                | TypographyHierarchy::Subheading
+                | TypographyHierarchy::H4
+        );
+        let mut typography = self.typography(hierarchy, TypographyColor::Auto);
+        if !is_heading {
+            typography = typography.weight(TypographyWeight::Medium);
+        }
+
+        format!("product-title line-clamp-2 {}", typography.classes())
+    }
+
+    /// Generate CSS classes for the product description
+    pub fn description_classes(&self) -> String {
+        let typography =
+            self.typography(self.pattern.description_hierarchy(), TypographyColor::Muted);
+
+        format!("product-description {}", typography.classes())
+    }
+
+    /// Generate CSS classes for product meta text such as brand or SKU
+    pub fn meta_classes(&self) -> String {
+        let typography = self.typography(self.pattern.meta_hierarchy(), TypographyColor::Auto);
 
-        format!("{} {}", base_classes, positioning)
+        format!("product-meta {}", typography.classes())
+    }
+
+    /// Typography for a text part, switching to inverse text on hero
+    /// gradients and disabled text for unavailable products
+    fn typography(
+        &self,
+        hierarchy: TypographyHierarchy,
+        color: TypographyColor,
+    ) -> TypographyPattern<&C> {
+        let color = if self.pattern.availability != ProductAvailabilityState::Available {
+            TypographyColor::Disabled
+        } else if self.pattern.prominence == ProductProminence::Hero {
+            TypographyColor::White
+        } else {
+            color
+        };
+
+        TypographyPattern::new(&self.colors)
+            .hierarchy(hierarchy)
+            .color(color)
+    }
+
+    /// Generate CSS classes for the product price
+    ///
+    /// Sale prices use the error color; compare-at and discount patterns
+    /// lay the current and compare-at prices out on one baseline.
+    pub fn price_classes(&self) -> String {
+        let color = match self.pattern.price_pattern {
+            ProductPricePattern::OnSale => Color::Error,
+            _ => Color::TextPrimary,
+        };
+        let layout = match self.pattern.price_pattern {
+            ProductPricePattern::WithCompare | ProductPricePattern::WithDiscount => {
+                " inline-flex items-baseline gap-2"
+            }
+            _ => "",
+        };
+
+        format!(
+            "product-price font-semibold tabular-nums {}{}",
+            self.colors.text_class(color),
+            layout
+        )
+    }
+
+    /// Get the price parts and `aria-label` template for the price pattern
+    pub fn price_semantic_info(&self) -> PriceSemanticInfo {
+        self.pattern.price_pattern.semantic_info()
+    }
+
+    /// Generate every part's classes in one pass
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::product_styles;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let product = product_styles(VibeColors::default()).tile();
+    /// let parts = product.build_parts();
+    ///
+    /// assert_eq!(parts.container, product.container_classes());
+    /// assert!(parts.title.contains("product-title"));
+    /// assert!(parts.price.contains("product-price"));
+    /// ```
+    pub fn build_parts(&self) -> ProductClasses {
+        ProductClasses {
+            container: self.container_classes(),
+            image: self.image_classes(),
+            info: self.info_classes(),
+            trailing: self.trailing_classes(),
+            actions: self.actions_classes(),
+            badges: self.badges_classes(),
+            price: self.price_classes(),
+            title: self.title_classes(),
+            description: self.description_classes(),
+            meta: self.meta_classes(),
+        }
+    }
+
+    /// Get button styles for a product action
+    ///
+    /// Add to cart is the primary action, quick view and compare are
+    /// secondary, view details is a link, and wishlist and share are
+    /// icon-only ghost buttons. Buttons are sized to the display pattern,
+    /// and add to cart is disabled while the product cannot be bought.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::product_styles;
+    /// use jupiter_design_system::patterns::ProductActionType;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let product = product_styles(VibeColors::default()).tile();
+    ///
+    /// let add = product.action_button_styles(ProductActionType::AddToCart).classes();
+    /// assert!(add.contains("bg-jupiter-blue-500"));
+    /// assert!(add.contains("h-8"));
+    ///
+    /// let wishlist = product.action_button_styles(ProductActionType::Wishlist).classes();
+    /// assert!(wishlist.contains("w-8"));
+    /// ```
+    pub fn action_button_styles(&self, action: ProductActionType) -> ButtonStyles<C>
+    where
+        C: Clone,
+    {
+        let size = match self.pattern.display {
+            ProductDisplayPattern::Preview => Size::XSmall,
+            ProductDisplayPattern::Tile | ProductDisplayPattern::ListItem => Size::Small,
+            ProductDisplayPattern::Featured => Size::Medium,
+            ProductDisplayPattern::Showcase => Size::Large,
+        };
+        let mut button = ButtonStyles::new(self.colors.clone()).size(size);
+
+        button = match action {
+            ProductActionType::AddToCart => button.primary(),
+            ProductActionType::QuickView | ProductActionType::Compare => button.secondary(),
+            ProductActionType::ViewDetails => button.link(),
+            ProductActionType::Wishlist | ProductActionType::Share => button.ghost().icon_only(),
+        };
+
+        let purchasable = matches!(
+            self.pattern.availability,
+            ProductAvailabilityState::Available
+                | ProductAvailabilityState::Limited
+                | ProductAvailabilityState::Backorder
+        );
+        match self.pattern.interaction_state {
+            ProductInteractionState::Disabled => button.disabled(),
+            ProductInteractionState::Loading if action == ProductActionType::AddToCart => {
+                button.loading()
+            }
+            _ if action == ProductActionType::AddToCart && !purchasable => button.disabled(),
+            _ => button,
+        }
+    }
+
+    /// Generate CSS classes for one badge inside the badge stack
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::product_styles;
+    /// use jupiter_design_system::patterns::ProductBadgeType;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let product = product_styles(VibeColors::default()).sale_badge();
+    /// for badge in &product.pattern().badges {
+    ///     let classes = product.badge_classes(badge);
+    ///     assert!(classes.contains("product-badge"));
+    /// }
+    ///
+    /// let custom = ProductBadgeType::Custom("Eco".to_string());
+    /// assert_eq!(custom.suggested_label(), "Eco");
+    /// ```
+    pub fn badge_classes(&self, badge: &ProductBadgeType) -> String {
+        self.pattern.badge_classes(&self.colors, badge)
+    }
+
+    /// Generate classes for a quick-view dialog showing this product
+    ///
+    /// The panel sits on the overlay surface at modal elevation, with the
+    /// gallery and info in two columns from the tablet breakpoint up. Pair
+    /// the panel with `FocusScope::modal()` attributes, and label the
+    /// icon-only close button.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::product_quick_view_styles;
+    /// use jupiter_design_system::patterns::FocusScope;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let quick_view = product_quick_view_styles(VibeColors::default());
+    /// let parts = quick_view.quick_view_parts();
+    ///
+    /// assert!(parts.backdrop.contains("fixed inset-0"));
+    /// assert!(parts.layout.contains("md:grid-cols-2"));
+    /// assert!(parts.close_button.contains("absolute"));
+    ///
+    /// let attrs = FocusScope::modal().container_attributes();
+    /// assert!(attrs.contains(&("aria-modal", "true".to_string())));
+    /// ```
+    pub fn quick_view_parts(&self) -> ProductQuickViewClasses
+    where
+        C: Clone,
+    {
+        let breakpoints = self.colors.breakpoint_scale();
+        let tablet = breakpoints.prefix(Breakpoint::Tablet);
+        let radius = self.colors.radius_scale();
+
+        let backdrop = format!(
+            "product-quick-view-backdrop fixed inset-0 z-50 flex items-center justify-center p-4 {}/50",
+            self.colors.bg_class(Color::Foreground)
+        );
+        let panel = format!(
+            "product-quick-view relative w-full max-w-4xl max-h-[90vh] overflow-y-auto p-6 {} {} {} {}",
+            self.colors.surface_class(SurfaceLevel::Overlay),
+            self.colors.text_class(Color::TextPrimary),
+            self.colors.elevation_scale().shadow_class(Elevation::Modal),
+            radius.radius_class(Radius::Surface)
+        );
+        let layout = format!(
+            "product-quick-view-layout grid grid-cols-1 gap-6 {}grid-cols-2",
+            tablet
+        );
+        let gallery = format!(
+            "product-quick-view-gallery w-full object-cover {} {}",
+            self.pattern.suggested_image_aspect_ratio(),
+            radius.radius_class(Radius::Control)
+        );
+        let close_button = format!(
+            "{} {}",
+            ButtonStyles::new(self.colors.clone())
+                .ghost()
+                .small()
+                .icon_only()
+                .classes(),
+            PositionStyles::new().top_end().classes()
+        );
+        let actions = format!(
+            "product-quick-view-actions flex flex-wrap items-center gap-3 pt-4 {} {}",
+            self.colors
+                .border_scale()
+                .border_side_class("t", BorderWidth::Default),
+            self.colors.border_class(Color::Border)
+        );
+
+        ProductQuickViewClasses {
+            backdrop,
+            panel,
+            layout,
+            gallery,
+            info: format!(
+                "product-info flex flex-col {}",
+                self.pattern.suggested_spacing()
+            ),
+            title: self.title_classes(),
+            price: self.price_classes(),
+            close_button,
+            actions,
+        }
+    }
+
+    /// Generate CSS classes for the availability badge
+    pub fn availability_badge_classes(&self) -> String {
+        self.pattern.availability_badge_classes(&self.colors)
+    }
+
+    /// Get suggested availability badge label
+    pub fn availability_label(&self) -> &'static str {
+        self.pattern.suggested_availability_label()
+    }
+
+    /// Generate wishlist toggle classes (None without a wishlist action)
+    pub fn wishlist_parts(&self) -> Option<WishlistParts> {
+        self.pattern.wishlist_parts(&self.colors)
     }
 
     /// Get pattern configuration
@@ -488,6 +875,54 @@ impl<C: ColorProvider> ProductBuilder<C> {
     }
 }
 
+/// Class set for every part of a product card
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProductClasses {
+    /// Outer container (same as `ProductBuilder::container_classes`)
+    pub container: String,
+    /// Product image
+    pub image: String,
+    /// Info section holding the title and price
+    pub info: String,
+    /// Trailing price and actions column (empty unless horizontal)
+    pub trailing: String,
+    /// Actions row
+    pub actions: String,
+    /// Badge stack over the image
+    pub badges: String,
+    /// Current price
+    pub price: String,
+    /// Product name
+    pub title: String,
+    /// Product description
+    pub description: String,
+    /// Meta text such as brand or SKU
+    pub meta: String,
+}
+
+/// Class set for a product quick-view dialog
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProductQuickViewClasses {
+    /// Full-screen backdrop that centers the panel
+    pub backdrop: String,
+    /// Dialog panel
+    pub panel: String,
+    /// Two-column gallery and info grid
+    pub layout: String,
+    /// Main gallery image
+    pub gallery: String,
+    /// Info column
+    pub info: String,
+    /// Product name
+    pub title: String,
+    /// Current price
+    pub price: String,
+    /// Icon-only close button in the panel corner
+    pub close_button: String,
+    /// Action row below the info
+    pub actions: String,
+}
+
 /// Convenience function for creating product component CSS classes
 pub fn product_styles<C: ColorProvider>(colors: C) -> ProductBuilder<C> {
     ProductBuilder::new(colors)
@@ -508,7 +943,19 @@ pub fn product_showcase_styles<C: ColorProvider>(colors: C) -> ProductBuilder<C>
     ProductBuilder::new(colors).showcase().detailed_info()
 }
 
+/// Convenience function for creating product quick-view CSS classes
+///
+/// A showcase product with extended info, for use with
+/// [`ProductBuilder::quick_view_parts`].
+pub fn product_quick_view_styles<C: ColorProvider>(colors: C) -> ProductBuilder<C> {
+    ProductBuilder::new(colors).showcase().extended_info()
+}
+
 /// Convenience function for creating product preview CSS classes
 pub fn product_preview_styles<C: ColorProvider>(colors: C) -> ProductBuilder<C> {
     ProductBuilder::new(colors).preview().minimal_info()
 }
+
+#[cfg(test)]
+#[path = "product_test.rs"]
+mod product_test;
@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::builders::product::{
+        featured_product_styles, product_preview_styles, product_quick_view_styles,
+        product_showcase_styles, product_styles, product_tile_styles, ProductBuilder,
+    };
+    use crate::core::BorderScale;
     use crate::patterns::product::*;
     use crate::themes::VibeColors;
 
@@ -139,6 +143,18 @@ mod tests {
 
         let hero = ProductBuilder::new(colors.clone()).hero();
         assert_eq!(hero.pattern().prominence, ProductProminence::Hero);
+
+        // Hero products use the theme's brand gradient
+        let hero_classes = hero.classes();
+        assert!(hero_classes.contains("from-jupiter-blue-500"));
+        assert!(hero_classes.contains("text-gray-900"));
+
+        let rebranded = VibeColors::with_overrides(|palette| {
+            palette.primary = "purple-600".to_string();
+            palette.interactive_active = "purple-800".to_string();
+        });
+        let rebranded_hero = ProductBuilder::new(rebranded).hero().classes();
+        assert!(rebranded_hero.contains("from-purple-600 to-purple-800"));
     }
 
     #[test]
@@ -467,7 +483,7 @@ mod tests {
         assert!(classes.contains("product-badges"));
         assert!(classes.contains("absolute"));
         assert!(classes.contains("top-2"));
-        assert!(classes.contains("right-2"));
+        assert!(classes.contains("end-2"));
         assert!(classes.contains("flex"));
         assert!(classes.contains("flex-col"));
         assert!(classes.contains("gap-1"));
@@ -544,7 +560,7 @@ mod tests {
     fn test_pattern_suggestions() {
         let colors = VibeColors::default();
 
-        let pattern = ProductBuilder::new(colors).featured().pattern();
+        let pattern = ProductBuilder::new(colors).featured().pattern().clone();
 
         assert_eq!(pattern.suggested_image_aspect_ratio(), "aspect-[4/3]");
         assert_eq!(pattern.suggested_image_sizes(), "h-64 w-64");
@@ -600,21 +616,32 @@ mod tests {
 
         let standard = ProductBuilder::new(colors.clone())
             .standard_image()
-            .pattern();
+            .pattern()
+            .clone();
         assert_eq!(standard.suggested_image_aspect_ratio(), "aspect-[4/3]");
 
-        let square = ProductBuilder::new(colors.clone()).square_image().pattern();
+        let square = ProductBuilder::new(colors.clone())
+            .square_image()
+            .pattern()
+            .clone();
         assert_eq!(square.suggested_image_aspect_ratio(), "aspect-square");
 
-        let wide = ProductBuilder::new(colors.clone()).wide_image().pattern();
+        let wide = ProductBuilder::new(colors.clone())
+            .wide_image()
+            .pattern()
+            .clone();
         assert_eq!(wide.suggested_image_aspect_ratio(), "aspect-[16/9]");
 
         let portrait = ProductBuilder::new(colors.clone())
             .portrait_image()
-            .pattern();
+            .pattern()
+            .clone();
         assert_eq!(portrait.suggested_image_aspect_ratio(), "aspect-[3/4]");
 
-        let circle = ProductBuilder::new(colors.clone()).circle_image().pattern();
+        let circle = ProductBuilder::new(colors.clone())
+            .circle_image()
+            .pattern()
+            .clone();
         assert_eq!(
             circle.suggested_image_aspect_ratio(),
             "aspect-square rounded-full"
@@ -638,4 +665,468 @@ mod tests {
             .actions_classes();
         assert!(standard_classes.contains("gap-3"));
     }
+
+    #[test]
+    fn test_wishlist_parts_requires_action() {
+        let colors = VibeColors::default();
+
+        assert!(ProductBuilder::new(colors.clone())
+            .wishlist_parts()
+            .is_none());
+        assert!(ProductBuilder::new(colors)
+            .wishlist_action()
+            .wishlist_parts()
+            .is_some());
+    }
+
+    #[test]
+    fn test_wishlist_parts_selected_and_unselected() {
+        let colors = VibeColors::default();
+
+        let unselected = ProductBuilder::new(colors.clone())
+            .wishlist_action()
+            .wishlist_parts()
+            .unwrap();
+        assert!(unselected.icon.contains("fill-none"));
+        assert!(unselected.icon.contains("text-gray-600"));
+        assert!(unselected.button.contains("hover:scale-110"));
+        assert!(unselected.button.contains("motion-reduce:transform-none"));
+
+        let selected = ProductBuilder::new(colors)
+            .favorited()
+            .wishlist_parts()
+            .unwrap();
+        assert!(selected.icon.contains("fill-current"));
+        assert!(selected.icon.contains("text-jupiter-orange-500"));
+        assert!(selected.count.contains("text-jupiter-orange-500"));
+        assert!(selected.count.contains("tabular-nums"));
+    }
+
+    #[test]
+    fn test_wishlist_parts_disabled_card() {
+        let colors = VibeColors::default();
+
+        let parts = ProductBuilder::new(colors)
+            .favorited()
+            .disabled()
+            .wishlist_parts()
+            .unwrap();
+        assert!(parts.button.contains("cursor-not-allowed"));
+        assert!(!parts.button.contains("hover:scale-110"));
+    }
+
+    #[test]
+    fn test_availability_badge_colors() {
+        let colors = VibeColors::default();
+
+        let available = ProductBuilder::new(colors.clone()).availability_badge_classes();
+        assert!(available.contains("availability-badge"));
+        assert!(available.contains("bg-green-500"));
+
+        let limited = ProductBuilder::new(colors.clone())
+            .limited()
+            .availability_badge_classes();
+        assert!(limited.contains("bg-amber-500"));
+        assert!(!limited.contains("animate-pulse"));
+
+        let backorder = ProductBuilder::new(colors.clone())
+            .backorder()
+            .availability_badge_classes();
+        assert!(backorder.contains("bg-blue-500"));
+
+        let out_of_stock = ProductBuilder::new(colors.clone())
+            .out_of_stock()
+            .availability_badge_classes();
+        assert!(out_of_stock.contains("bg-red-500"));
+
+        let discontinued = ProductBuilder::new(colors)
+            .discontinued()
+            .availability_badge_classes();
+        assert!(discontinued.contains("bg-gray-300"));
+        assert!(discontinued.contains("text-gray-600"));
+    }
+
+    #[test]
+    fn test_availability_badge_pulse_only_for_limited() {
+        let colors = VibeColors::default();
+
+        let limited = ProductBuilder::new(colors.clone())
+            .limited()
+            .pulse_limited_badge()
+            .availability_badge_classes();
+        assert!(limited.contains("motion-safe:animate-pulse"));
+
+        let available = ProductBuilder::new(colors)
+            .pulse_limited_badge()
+            .availability_badge_classes();
+        assert!(!available.contains("animate-pulse"));
+    }
+
+    #[test]
+    fn test_availability_labels() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            ProductBuilder::new(colors.clone()).availability_label(),
+            "In Stock"
+        );
+        assert_eq!(
+            ProductBuilder::new(colors.clone())
+                .limited()
+                .availability_label(),
+            "Only a Few Left"
+        );
+        assert_eq!(
+            ProductBuilder::new(colors)
+                .out_of_stock()
+                .availability_label(),
+            "Out of Stock"
+        );
+    }
+
+    #[test]
+    fn test_build_parts_matches_individual_getters() {
+        let colors = VibeColors::default();
+        let product = ProductBuilder::new(colors).featured().prominent();
+
+        // Getters borrow, so one builder serves every part
+        let parts = product.build_parts();
+        assert_eq!(parts.container, product.container_classes());
+        assert_eq!(parts.image, product.image_classes());
+        assert_eq!(parts.info, product.info_classes());
+        assert_eq!(parts.actions, product.actions_classes());
+        assert_eq!(parts.badges, product.badges_classes());
+        assert_eq!(parts.title, product.title_classes());
+        assert_eq!(parts.price, product.price_classes());
+        assert_eq!(parts.description, product.description_classes());
+        assert_eq!(parts.meta, product.meta_classes());
+        assert_eq!(product.classes(), product.classes());
+    }
+
+    #[test]
+    fn test_price_classes_follow_price_pattern() {
+        let colors = VibeColors::default();
+
+        let standard = ProductBuilder::new(colors.clone()).price_classes();
+        assert!(standard.contains("tabular-nums"));
+        assert!(standard.contains("text-gray-900"));
+
+        let sale = ProductBuilder::new(colors.clone())
+            .price_on_sale()
+            .price_classes();
+        assert!(sale.contains("text-red-500"));
+
+        let compare = ProductBuilder::new(colors)
+            .price_with_compare()
+            .price_classes();
+        assert!(compare.contains("items-baseline"));
+    }
+
+    #[test]
+    fn test_title_scales_with_display_and_prominence() {
+        let colors = VibeColors::default();
+
+        let preview = ProductBuilder::new(colors.clone())
+            .preview()
+            .title_classes();
+        assert!(preview.contains("text-base"));
+        assert!(preview.contains("font-medium"));
+
+        let list = ProductBuilder::new(colors.clone())
+            .list_item()
+            .title_classes();
+        assert!(list.contains("text-lg"));
+
+        let showcase = ProductBuilder::new(colors.clone())
+            .showcase()
+            .title_classes();
+        assert!(showcase.contains("text-2xl"));
+        assert!(showcase.contains("font-bold"));
+
+        let subtle = ProductBuilder::new(colors.clone())
+            .list_item()
+            .subtle()
+            .title_classes();
+        assert!(subtle.contains("text-base"));
+
+        // Hero showcase tops out at the largest level
+        let hero = ProductBuilder::new(colors)
+            .showcase()
+            .hero()
+            .title_classes();
+        assert!(hero.contains("text-4xl"));
+    }
+
+    #[test]
+    fn test_description_and_meta_typography() {
+        let colors = VibeColors::default();
+
+        let tile = ProductBuilder::new(colors.clone()).tile();
+        assert!(tile.description_classes().contains("text-sm"));
+        assert!(tile.description_classes().contains("text-gray-600"));
+        assert!(tile.meta_classes().contains("product-meta"));
+        assert!(tile.meta_classes().contains("font-medium"));
+
+        let showcase = ProductBuilder::new(colors).showcase().prominent();
+        assert!(showcase.description_classes().contains("text-lg"));
+    }
+
+    #[test]
+    fn test_text_colors_follow_card_surface() {
+        let colors = VibeColors::default();
+
+        let hero = ProductBuilder::new(colors.clone()).hero();
+        assert!(hero.title_classes().contains("text-white"));
+        assert!(hero.description_classes().contains("text-white"));
+
+        let sold_out = ProductBuilder::new(colors).out_of_stock();
+        assert!(!sold_out.title_classes().contains("text-gray-900"));
+        assert!(sold_out.description_classes().contains("text-gray-300"));
+    }
+
+    #[test]
+    fn test_action_button_variants() {
+        let product = ProductBuilder::new(VibeColors::default()).featured();
+
+        let add = product.action_button_styles(ProductActionType::AddToCart);
+        assert!(add.classes().contains("bg-jupiter-blue-500"));
+
+        let quick_view = product
+            .action_button_styles(ProductActionType::QuickView)
+            .classes();
+        assert!(quick_view.contains("border-gray-200"));
+
+        let details = product
+            .action_button_styles(ProductActionType::ViewDetails)
+            .classes();
+        assert!(details.contains("hover:underline"));
+
+        let wishlist = product
+            .action_button_styles(ProductActionType::Wishlist)
+            .classes();
+        assert!(wishlist.contains("bg-transparent"));
+        assert!(wishlist.contains("w-10"));
+        assert!(wishlist.contains("p-0"));
+        assert!(ProductActionType::Wishlist.is_icon_only());
+        assert_eq!(
+            ProductActionType::Wishlist.suggested_label(),
+            "Add to Wishlist"
+        );
+    }
+
+    #[test]
+    fn test_action_buttons_sized_to_display() {
+        let colors = VibeColors::default();
+        let height = |product: ProductBuilder<VibeColors>| {
+            product
+                .action_button_styles(ProductActionType::AddToCart)
+                .classes()
+        };
+
+        assert!(height(ProductBuilder::new(colors.clone()).preview()).contains("h-7"));
+        assert!(height(ProductBuilder::new(colors.clone()).tile()).contains("h-8"));
+        assert!(height(ProductBuilder::new(colors.clone()).featured()).contains("h-10"));
+        assert!(height(ProductBuilder::new(colors).showcase()).contains("h-12"));
+    }
+
+    #[test]
+    fn test_add_to_cart_follows_availability() {
+        let colors = VibeColors::default();
+
+        let sold_out = ProductBuilder::new(colors.clone())
+            .out_of_stock()
+            .action_button_styles(ProductActionType::AddToCart)
+            .classes();
+        assert!(sold_out
+            .split_whitespace()
+            .any(|c| c == "cursor-not-allowed"));
+
+        // Other actions stay usable on sold-out products
+        let quick_view = ProductBuilder::new(colors.clone())
+            .out_of_stock()
+            .action_button_styles(ProductActionType::QuickView)
+            .classes();
+        assert!(!quick_view
+            .split_whitespace()
+            .any(|c| c == "cursor-not-allowed"));
+
+        let adding = ProductBuilder::new(colors)
+            .loading()
+            .action_button_styles(ProductActionType::AddToCart)
+            .classes();
+        assert!(adding.contains("cursor-wait"));
+    }
+
+    #[test]
+    fn test_badge_classes_by_type() {
+        let product = ProductBuilder::new(VibeColors::default());
+
+        assert!(product
+            .badge_classes(&ProductBadgeType::Sale)
+            .contains("bg-red-500"));
+        assert!(product
+            .badge_classes(&ProductBadgeType::New)
+            .contains("bg-green-500"));
+        assert!(product
+            .badge_classes(&ProductBadgeType::OutOfStock)
+            .contains("text-gray-600"));
+
+        let custom = ProductBadgeType::Custom("Organic".to_string());
+        let classes = product.badge_classes(&custom);
+        assert!(classes.contains("bg-white"));
+        assert!(classes.contains("border-gray-200"));
+        assert_eq!(custom.suggested_label(), "Organic");
+        assert_eq!(ProductBadgeType::OutOfStock.suggested_label(), "Sold Out");
+    }
+
+    #[test]
+    fn test_badge_classes_follow_provider() {
+        let water = ProductBuilder::new(VibeColors::water_wellness());
+        let default = ProductBuilder::new(VibeColors::default());
+
+        assert_ne!(
+            water.badge_classes(&ProductBadgeType::Featured),
+            default.badge_classes(&ProductBadgeType::Featured)
+        );
+    }
+
+    #[test]
+    fn test_list_item_is_horizontal() {
+        let colors = VibeColors::default();
+
+        let row = ProductBuilder::new(colors.clone()).list_item();
+        let container = row.container_classes();
+        assert!(container.contains("flex-row"));
+        assert!(!container.contains("space-y-3"));
+        assert!(row.image_classes().contains("h-24 w-24"));
+        assert!(row.info_classes().contains("min-w-0"));
+        assert!(row.trailing_classes().contains("text-right"));
+        assert!(row.list_classes().contains("divide-gray-200"));
+        assert!(row.list_classes().contains("divide-y "));
+
+        // Dividers follow the theme's border scale
+        let bold =
+            ProductBuilder::new(colors.clone().with_borders(BorderScale::bold())).list_item();
+        assert!(bold.list_classes().contains("divide-y-2"));
+
+        // Vertical cards keep stacking and have no trailing column
+        let tile = ProductBuilder::new(colors).tile();
+        assert!(tile.container_classes().contains("space-y-2"));
+        assert!(!tile.image_classes().contains("shrink-0"));
+        assert_eq!(tile.trailing_classes(), "");
+        assert_eq!(tile.build_parts().trailing, "");
+    }
+
+    #[test]
+    fn test_list_item_thumbnail_width_by_prominence() {
+        let colors = VibeColors::default();
+        let image = |product: ProductBuilder<VibeColors>| product.list_item().image_classes();
+
+        assert!(image(ProductBuilder::new(colors.clone()).subtle()).contains("w-16"));
+        assert!(image(ProductBuilder::new(colors.clone())).contains("w-24"));
+        assert!(image(ProductBuilder::new(colors.clone()).prominent()).contains("w-32"));
+        assert!(image(ProductBuilder::new(colors).hero()).contains("w-40"));
+    }
+
+    #[test]
+    fn test_interaction_states_are_visible() {
+        let colors = VibeColors::default();
+        let has = |classes: &str, class: &str| classes.split_whitespace().any(|c| c == class);
+
+        let default = ProductBuilder::new(colors.clone()).classes();
+        assert!(has(&default, "focus:ring-2"));
+        assert!(!has(&default, "ring-2"));
+
+        let focused = ProductBuilder::new(colors.clone()).focused().classes();
+        assert!(has(&focused, "ring-2"));
+        assert!(has(&focused, "ring-jupiter-blue-300"));
+        assert!(has(&focused, "outline-none"));
+
+        let selected = ProductBuilder::new(colors.clone()).selected().classes();
+        assert!(has(&selected, "ring-2"));
+        assert!(has(&selected, "focus:ring-2"));
+
+        let loading = ProductBuilder::new(colors.clone()).loading().classes();
+        assert!(has(&loading, "motion-safe:animate-pulse"));
+        assert!(has(&loading, "pointer-events-none"));
+
+        let disabled = ProductBuilder::new(colors).disabled().classes();
+        assert!(has(&disabled, "opacity-50"));
+        assert!(has(&disabled, "cursor-not-allowed"));
+    }
+
+    #[test]
+    fn test_quick_view_parts() {
+        let quick_view = product_quick_view_styles(VibeColors::default());
+        assert_eq!(
+            quick_view.pattern().display,
+            ProductDisplayPattern::Showcase
+        );
+
+        let parts = quick_view.quick_view_parts();
+        assert!(parts.backdrop.contains("bg-gray-900/50"));
+        assert!(parts.panel.contains("max-w-4xl"));
+        assert!(parts.panel.contains("shadow-2xl"));
+        assert!(parts.panel.contains("bg-white"));
+        assert!(parts.layout.contains("grid-cols-1"));
+        assert!(parts.layout.contains("md:grid-cols-2"));
+        assert!(parts.gallery.contains("w-full"));
+        assert!(parts.close_button.contains("w-8"));
+        assert!(parts.close_button.contains("absolute"));
+        assert!(parts.actions.contains("border-t"));
+        assert_eq!(parts.title, quick_view.title_classes());
+        assert_eq!(parts.price, quick_view.price_classes());
+    }
+
+    #[test]
+    fn test_quick_view_follows_breakpoint_scale() {
+        use crate::core::BreakpointScale;
+
+        let colors = VibeColors::default().with_breakpoints(BreakpointScale {
+            tablet: 700,
+            ..BreakpointScale::default()
+        });
+        let parts = product_quick_view_styles(colors).quick_view_parts();
+        assert!(parts.layout.contains("min-[700px]:grid-cols-2"));
+    }
+
+    #[test]
+    fn test_quick_view_follows_border_scale() {
+        let parts =
+            product_quick_view_styles(VibeColors::default().with_borders(BorderScale::bold()))
+                .quick_view_parts();
+        assert!(parts.actions.contains("border-t-2"));
+    }
+
+    #[test]
+    fn test_price_semantic_info() {
+        let colors = VibeColors::default();
+
+        let standard = ProductBuilder::new(colors.clone()).price_semantic_info();
+        assert!(!standard.compare_at && !standard.range);
+        assert_eq!(standard.placeholders(), vec!["price"]);
+        assert_eq!(standard.aria_label(&[("price", "$20")]), "Price: $20");
+
+        let range = ProductBuilder::new(colors.clone())
+            .price_range()
+            .price_semantic_info();
+        assert!(range.range);
+        assert_eq!(range.placeholders(), vec!["min", "max"]);
+        assert_eq!(
+            range.aria_label(&[("min", "$10"), ("max", "$30")]),
+            "Price: from $10 to $30"
+        );
+
+        let sale = ProductBuilder::new(colors)
+            .price_on_sale()
+            .price_semantic_info();
+        assert!(sale.on_sale && sale.compare_at);
+        assert_eq!(sale.placeholders(), vec!["price", "compare_at"]);
+
+        // Missing values stay visible
+        assert_eq!(
+            sale.aria_label(&[("price", "$15")]),
+            "Sale price: $15, was {compare_at}"
+        );
+    }
 }
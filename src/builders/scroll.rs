@@ -0,0 +1,313 @@
+//! Scroll styling utilities for the Jupiter Design System
+//!
+//! Provides classes for scroll containers: overflow axis, snap scrolling
+//! for carousels, overscroll containment for modals and drawers, thin or
+//! branded scrollbars, and scroll margins for anchored headings.
+
+use crate::core::color::ColorProvider;
+use crate::core::Color;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Axis a container scrolls along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScrollAxis {
+    /// Scroll vertically, clip horizontally
+    Vertical,
+    /// Scroll horizontally, clip vertically
+    Horizontal,
+    /// Scroll in both directions
+    Both,
+    /// Clip without scrolling
+    None,
+}
+
+/// Where snap items come to rest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScrollSnapAlign {
+    Start,
+    Center,
+    End,
+}
+
+/// Scrollbar appearance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScrollbarStyle {
+    /// Browser default scrollbar
+    Default,
+    /// Thin neutral scrollbar
+    Thin,
+    /// Thin scrollbar with a primary-colored thumb
+    Branded,
+    /// Scrollbar hidden (content still scrolls)
+    Hidden,
+}
+
+/// Scroll styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::scroll::ScrollStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// // Carousel track with centered slides
+/// let track = ScrollStyles::new(VibeColors::default())
+///     .horizontal()
+///     .snap_center()
+///     .hidden_scrollbar();
+///
+/// let track_classes = track.classes();
+/// let slide_classes = track.snap_item_classes();
+/// assert!(track_classes.contains("snap-x"));
+/// assert_eq!(slide_classes, "snap-center");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScrollStyles<C: ColorProvider> {
+    axis: ScrollAxis,
+    snap: Option<ScrollSnapAlign>,
+    snap_mandatory: bool,
+    contain_overscroll: bool,
+    smooth: bool,
+    scrollbar: ScrollbarStyle,
+    anchor_offset: &'static str,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> ScrollStyles<C> {
+    /// Create a new scroll styling utility (vertical, default scrollbar)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            axis: ScrollAxis::Vertical,
+            snap: None,
+            snap_mandatory: true,
+            contain_overscroll: false,
+            smooth: false,
+            scrollbar: ScrollbarStyle::Default,
+            anchor_offset: "20",
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Axis Methods ===
+
+    /// Scroll vertically
+    pub fn vertical(mut self) -> Self {
+        self.axis = ScrollAxis::Vertical;
+        self
+    }
+
+    /// Scroll horizontally
+    pub fn horizontal(mut self) -> Self {
+        self.axis = ScrollAxis::Horizontal;
+        self
+    }
+
+    /// Scroll in both directions
+    pub fn both(mut self) -> Self {
+        self.axis = ScrollAxis::Both;
+        self
+    }
+
+    /// Clip overflow without scrolling
+    pub fn clip(mut self) -> Self {
+        self.axis = ScrollAxis::None;
+        self
+    }
+
+    /// Set axis from string
+    pub fn axis_str(mut self, axis: &str) -> Self {
+        self.axis = match axis {
+            "vertical" | "y" => ScrollAxis::Vertical,
+            "horizontal" | "x" => ScrollAxis::Horizontal,
+            "both" => ScrollAxis::Both,
+            "none" | "clip" => ScrollAxis::None,
+            _ => ScrollAxis::Vertical, // fallback
+        };
+        self
+    }
+
+    // === Snap Methods ===
+
+    /// Snap items to their start edge
+    pub fn snap_start(mut self) -> Self {
+        self.snap = Some(ScrollSnapAlign::Start);
+        self
+    }
+
+    /// Snap items to the center (carousels)
+    pub fn snap_center(mut self) -> Self {
+        self.snap = Some(ScrollSnapAlign::Center);
+        self
+    }
+
+    /// Snap items to their end edge
+    pub fn snap_end(mut self) -> Self {
+        self.snap = Some(ScrollSnapAlign::End);
+        self
+    }
+
+    /// Only snap when scrolling stops near an item
+    pub fn snap_proximity(mut self) -> Self {
+        self.snap_mandatory = false;
+        self
+    }
+
+    // === Behavior Methods ===
+
+    /// Stop scroll chaining to the page (modals, drawers, menus)
+    pub fn contain_overscroll(mut self) -> Self {
+        self.contain_overscroll = true;
+        self
+    }
+
+    /// Animate programmatic and anchor scrolling
+    pub fn smooth(mut self) -> Self {
+        self.smooth = true;
+        self
+    }
+
+    // === Scrollbar Methods ===
+
+    /// Use a thin neutral scrollbar
+    pub fn thin_scrollbar(mut self) -> Self {
+        self.scrollbar = ScrollbarStyle::Thin;
+        self
+    }
+
+    /// Use a thin scrollbar with a primary-colored thumb
+    pub fn branded_scrollbar(mut self) -> Self {
+        self.scrollbar = ScrollbarStyle::Branded;
+        self
+    }
+
+    /// Hide the scrollbar while keeping content scrollable
+    pub fn hidden_scrollbar(mut self) -> Self {
+        self.scrollbar = ScrollbarStyle::Hidden;
+        self
+    }
+
+    // === Anchor Methods ===
+
+    /// Set the scroll margin for anchored headings, e.g. the sticky header height
+    pub fn anchor_offset(mut self, offset: &'static str) -> Self {
+        self.anchor_offset = offset;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the scroll container
+    pub fn classes(&self) -> String {
+        let mut classes = vec![match self.axis {
+            ScrollAxis::Vertical => "overflow-y-auto overflow-x-hidden",
+            ScrollAxis::Horizontal => "overflow-x-auto overflow-y-hidden",
+            ScrollAxis::Both => "overflow-auto",
+            ScrollAxis::None => "overflow-hidden",
+        }
+        .to_string()];
+
+        if self.snap.is_some() {
+            let axis = match self.axis {
+                ScrollAxis::Horizontal => "snap-x",
+                ScrollAxis::Both => "snap-both",
+                _ => "snap-y",
+            };
+            let strictness = if self.snap_mandatory {
+                "snap-mandatory"
+            } else {
+                "snap-proximity"
+            };
+            classes.push(format!("{} {}", axis, strictness));
+        }
+
+        if self.contain_overscroll {
+            classes.push("overscroll-contain".to_string());
+        }
+        if self.smooth {
+            classes.push("scroll-smooth motion-reduce:scroll-auto".to_string());
+        }
+
+        let scrollbar = self.scrollbar_classes();
+        if !scrollbar.is_empty() {
+            classes.push(scrollbar);
+        }
+
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for each snap item (empty without snapping)
+    pub fn snap_item_classes(&self) -> String {
+        match self.snap {
+            Some(ScrollSnapAlign::Start) => "snap-start",
+            Some(ScrollSnapAlign::Center) => "snap-center",
+            Some(ScrollSnapAlign::End) => "snap-end",
+            None => "",
+        }
+        .to_string()
+    }
+
+    /// Build classes for headings and other anchor targets
+    pub fn anchor_classes(&self) -> String {
+        format!("scroll-mt-{}", self.anchor_offset)
+    }
+
+    fn scrollbar_classes(&self) -> String {
+        let thin = "[scrollbar-width:thin] [&::-webkit-scrollbar]:w-1.5 [&::-webkit-scrollbar]:h-1.5 [&::-webkit-scrollbar-track]:bg-transparent [&::-webkit-scrollbar-thumb]:rounded-full";
+        match self.scrollbar {
+            ScrollbarStyle::Default => String::new(),
+            ScrollbarStyle::Thin => format!(
+                "{} [&::-webkit-scrollbar-thumb]:{}",
+                thin,
+                self.color_provider.bg_class(Color::Border)
+            ),
+            ScrollbarStyle::Branded => format!(
+                "{} [&::-webkit-scrollbar-thumb]:{}",
+                thin,
+                self.color_provider.bg_class(Color::Primary)
+            ),
+            ScrollbarStyle::Hidden => {
+                "[scrollbar-width:none] [&::-webkit-scrollbar]:hidden".to_string()
+            }
+        }
+    }
+}
+
+/// Convenience function to create scroll styles
+pub fn scroll_styles<C: ColorProvider>(color_provider: C) -> ScrollStyles<C> {
+    ScrollStyles::new(color_provider)
+}
+
+/// Convenience function to create carousel track scroll styles
+pub fn carousel_scroll_styles<C: ColorProvider>(color_provider: C) -> ScrollStyles<C> {
+    ScrollStyles::new(color_provider)
+        .horizontal()
+        .snap_center()
+        .contain_overscroll()
+        .hidden_scrollbar()
+}
+
+/// Convenience function to create modal/drawer body scroll styles
+pub fn overlay_scroll_styles<C: ColorProvider>(color_provider: C) -> ScrollStyles<C> {
+    ScrollStyles::new(color_provider)
+        .vertical()
+        .contain_overscroll()
+        .thin_scrollbar()
+}
+
+#[cfg(test)]
+#[path = "scroll_test.rs"]
+mod scroll_test;
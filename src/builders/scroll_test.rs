@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::scroll::{
+        carousel_scroll_styles, overlay_scroll_styles, scroll_styles, ScrollStyles,
+    };
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_overflow_axis() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            scroll_styles(colors.clone()).classes(),
+            "overflow-y-auto overflow-x-hidden"
+        );
+        assert!(scroll_styles(colors.clone())
+            .horizontal()
+            .classes()
+            .contains("overflow-x-auto"));
+        assert_eq!(
+            scroll_styles(colors.clone()).both().classes(),
+            "overflow-auto"
+        );
+        assert_eq!(scroll_styles(colors).clip().classes(), "overflow-hidden");
+    }
+
+    #[test]
+    fn test_snap_scrolling() {
+        let colors = VibeColors::default();
+
+        let carousel = carousel_scroll_styles(colors.clone());
+        assert!(carousel.classes().contains("snap-x snap-mandatory"));
+        assert_eq!(carousel.snap_item_classes(), "snap-center");
+
+        let list = scroll_styles(colors.clone()).snap_start().snap_proximity();
+        assert!(list.classes().contains("snap-y snap-proximity"));
+        assert_eq!(list.snap_item_classes(), "snap-start");
+
+        assert_eq!(scroll_styles(colors).snap_item_classes(), "");
+    }
+
+    #[test]
+    fn test_overscroll_and_smooth() {
+        let colors = VibeColors::default();
+
+        assert!(overlay_scroll_styles(colors.clone())
+            .classes()
+            .contains("overscroll-contain"));
+        assert!(scroll_styles(colors)
+            .smooth()
+            .classes()
+            .contains("motion-reduce:scroll-auto"));
+    }
+
+    #[test]
+    fn test_scrollbar_styles() {
+        let colors = VibeColors::default();
+
+        let thin = scroll_styles(colors.clone()).thin_scrollbar().classes();
+        assert!(thin.contains("[scrollbar-width:thin]"));
+        assert!(thin.contains("[&::-webkit-scrollbar-thumb]:bg-gray-200"));
+
+        let branded = scroll_styles(colors.clone()).branded_scrollbar().classes();
+        assert!(branded.contains("[&::-webkit-scrollbar-thumb]:bg-jupiter-blue-500"));
+
+        let hidden = scroll_styles(colors).hidden_scrollbar().classes();
+        assert!(hidden.contains("[scrollbar-width:none]"));
+        assert!(hidden.contains("[&::-webkit-scrollbar]:hidden"));
+    }
+
+    #[test]
+    fn test_anchor_classes() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            scroll_styles(colors.clone()).anchor_classes(),
+            "scroll-mt-20"
+        );
+        assert_eq!(
+            scroll_styles(colors).anchor_offset("16").anchor_classes(),
+            "scroll-mt-16"
+        );
+    }
+
+    #[test]
+    fn test_axis_str() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            ScrollStyles::new(colors.clone()).axis_str("x").classes(),
+            ScrollStyles::new(colors.clone()).horizontal().classes()
+        );
+        assert_eq!(
+            ScrollStyles::new(colors.clone())
+                .axis_str("invalid")
+                .classes(),
+            ScrollStyles::new(colors).classes()
+        );
+    }
+}
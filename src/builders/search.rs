@@ -0,0 +1,261 @@
+//! Search bar styling utilities for the Jupiter Design System
+//!
+//! Provides a chainable API for building the classes of a composite search
+//! header: the search field itself, an attached filters row, and a summary of
+//! the currently active filters.
+
+use crate::builders::button::ButtonStyles;
+use crate::builders::selection::{chip_selection_styles, SelectionStyles};
+use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth, Color, Radius, RadiusProvider, Size, SizeProvider};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Search bar styling utility builder
+///
+/// Each part of the search header has its own class getter so list pages can
+/// share one search design regardless of how they render the markup.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::search::SearchBarStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let search = SearchBarStyles::new(VibeColors::default())
+///     .large()
+///     .with_submit(true)
+///     .with_filters(true);
+///
+/// let input_classes = search.input_classes();
+/// let chip_classes = search.filter_chip_classes(true);
+/// let clear_all_classes = search.clear_all_classes();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchBarStyles<C: ColorProvider + Clone> {
+    size: Size,
+    show_submit: bool,
+    show_filters: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> SearchBarStyles<C> {
+    /// Create a new search bar styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            size: Size::Medium,
+            show_submit: false,
+            show_filters: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Size Methods ===
+
+    /// Set small size
+    pub fn small(mut self) -> Self {
+        self.size = Size::Small;
+        self
+    }
+
+    /// Set medium size
+    pub fn medium(mut self) -> Self {
+        self.size = Size::Medium;
+        self
+    }
+
+    /// Set large size
+    pub fn large(mut self) -> Self {
+        self.size = Size::Large;
+        self
+    }
+
+    /// Set size from string
+    pub fn size_str(mut self, size: &str) -> Self {
+        self.size = match size {
+            "sm" | "small" => Size::Small,
+            "md" | "medium" => Size::Medium,
+            "lg" | "large" => Size::Large,
+            _ => Size::Medium, // fallback
+        };
+        self
+    }
+
+    // === Feature Methods ===
+
+    /// Attach a submit button to the search field
+    pub fn with_submit(mut self, show_submit: bool) -> Self {
+        self.show_submit = show_submit;
+        self
+    }
+
+    /// Show the filters row below the search field
+    pub fn with_filters(mut self, show_filters: bool) -> Self {
+        self.show_filters = show_filters;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the outer search header container
+    pub fn container_classes(&self) -> String {
+        let mut classes = vec!["search-bar flex flex-col w-full".to_string()];
+        classes.push(if self.show_filters { "gap-3" } else { "gap-0" }.to_string());
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the field row (input plus optional submit button)
+    pub fn field_classes(&self) -> String {
+        "search-field relative flex items-stretch w-full".to_string()
+    }
+
+    /// Build classes for the leading search icon
+    pub fn leading_icon_classes(&self) -> String {
+        let (offset, size) = match self.size {
+            Size::XSmall | Size::Small => ("start-2.5", "w-4 h-4"),
+            Size::Medium => ("start-3", "w-5 h-5"),
+            Size::Large | Size::XLarge => ("start-4", "w-6 h-6"),
+        };
+        format!(
+            "search-icon absolute top-1/2 -translate-y-1/2 pointer-events-none {} {} {}",
+            offset,
+            size,
+            self.color_provider.text_class(Color::TextTertiary)
+        )
+    }
+
+    /// Build classes for the search input
+    pub fn input_classes(&self) -> String {
+        let size_classes = match self.size {
+            Size::XSmall | Size::Small => "ps-8 pe-8 text-sm",
+            Size::Medium => "ps-10 pe-10 text-sm",
+            Size::Large | Size::XLarge => "ps-12 pe-12 text-base",
+        };
+        let radius = self
+            .color_provider
+            .radius_scale()
+            .radius_class(Radius::Control);
+        let radius = if self.show_submit {
+            format!("{radius} rounded-e-none")
+        } else {
+            radius
+        };
+
+        format!(
+            "search-input flex-1 w-full {} {} {} {} {} {} {} focus:outline-none focus:ring-2 focus:{} transition-colors duration-200",
+            self.color_provider
+                .border_scale()
+                .border_width_class(BorderWidth::Default),
+            self.color_provider.size_scale().control_height_class(self.size),
+            size_classes,
+            radius,
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider.text_class(Color::TextPrimary),
+            self.color_provider.border_class(Color::Border),
+            self.color_provider.border_class(Color::Primary),
+        )
+    }
+
+    /// Build classes for the trailing clear button inside the input
+    pub fn clear_button_classes(&self) -> String {
+        let offset = if self.show_submit {
+            match self.size {
+                Size::XSmall | Size::Small => "end-20",
+                Size::Medium => "end-24",
+                Size::Large | Size::XLarge => "end-28",
+            }
+        } else {
+            "end-2"
+        };
+
+        format!(
+            "search-clear absolute top-1/2 -translate-y-1/2 {} inline-flex items-center justify-center rounded-full p-1 cursor-pointer {} hover:{} focus:outline-none focus:ring-2",
+            offset,
+            self.color_provider.text_class(Color::TextTertiary),
+            self.color_provider.text_class(Color::TextPrimary),
+        )
+    }
+
+    /// Build classes for the attached submit button
+    pub fn submit_button_classes(&self) -> String {
+        let button = ButtonStyles::new(self.color_provider.clone())
+            .primary()
+            .size(self.size);
+        format!("search-submit rounded-s-none -ms-px {}", button.classes())
+    }
+
+    /// Build classes for the filters row container
+    pub fn filters_row_classes(&self) -> String {
+        if !self.show_filters {
+            return "hidden".to_string();
+        }
+        format!("search-filters {}", self.filter_chips().container_classes())
+    }
+
+    /// Build classes for an individual filter chip
+    pub fn filter_chip_classes(&self, selected: bool) -> String {
+        let chips = self.filter_chips();
+        let chips = if selected {
+            chips.selected()
+        } else {
+            chips.unselected()
+        };
+        chips.item_classes()
+    }
+
+    /// Build classes for the active-filters summary area
+    pub fn active_filters_classes(&self) -> String {
+        format!(
+            "search-active-filters flex flex-wrap items-center gap-2 text-sm {}",
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for a chip in the active-filters summary
+    pub fn active_filter_chip_classes(&self) -> String {
+        chip_selection_styles(self.color_provider.clone())
+            .xs()
+            .selected()
+            .item_classes()
+    }
+
+    /// Build classes for the clear-all action in the active-filters summary
+    pub fn clear_all_classes(&self) -> String {
+        format!(
+            "search-clear-all text-sm font-medium cursor-pointer underline-offset-2 hover:underline {}",
+            self.color_provider.text_class(Color::Primary)
+        )
+    }
+
+    fn filter_chips(&self) -> SelectionStyles<C> {
+        let chips = chip_selection_styles(self.color_provider.clone());
+        match self.size {
+            Size::XSmall | Size::Small => chips.xs(),
+            Size::Medium => chips.sm(),
+            Size::Large | Size::XLarge => chips.md(),
+        }
+    }
+}
+
+/// Convenience function to create search bar styles
+pub fn search_bar_styles<C: ColorProvider + Clone>(color_provider: C) -> SearchBarStyles<C> {
+    SearchBarStyles::new(color_provider)
+        .with_submit(true)
+        .with_filters(true)
+}
+
+#[cfg(test)]
+#[path = "search_test.rs"]
+mod search_test;
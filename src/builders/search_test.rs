@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::search::{search_bar_styles, SearchBarStyles};
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_search_bar_defaults() {
+        let search = SearchBarStyles::new(VibeColors::default());
+
+        assert!(search.container_classes().contains("search-bar"));
+        assert!(search.input_classes().contains("rounded-md"));
+        assert!(search.input_classes().contains("ps-10"));
+        assert!(search.clear_button_classes().contains("end-2"));
+        assert_eq!(search.filters_row_classes(), "hidden");
+    }
+
+    #[test]
+    fn test_search_bar_with_submit() {
+        let search = SearchBarStyles::new(VibeColors::default()).with_submit(true);
+
+        let input = search.input_classes();
+        assert!(input.contains("rounded-md"));
+        assert!(input.contains("rounded-e-none"));
+        assert!(search.clear_button_classes().contains("end-24"));
+
+        let submit = search.submit_button_classes();
+        assert!(submit.contains("search-submit"));
+        assert!(submit.contains("rounded-s-none"));
+        assert!(submit.contains("bg-jupiter-blue-500"));
+    }
+
+    #[test]
+    fn test_search_bar_sizes() {
+        let colors = VibeColors::default();
+
+        let small = SearchBarStyles::new(colors.clone()).small();
+        assert!(small.input_classes().contains("h-8"));
+        assert!(small.leading_icon_classes().contains("w-4"));
+
+        let large = SearchBarStyles::new(colors.clone()).size_str("lg");
+        assert!(large.input_classes().contains("h-12"));
+        assert!(large.leading_icon_classes().contains("w-6"));
+
+        let fallback = SearchBarStyles::new(colors).size_str("invalid");
+        assert!(fallback.input_classes().contains("h-10"));
+
+        // The control height sets the vertical size, so no vertical padding
+        for size in [small, large] {
+            assert!(!size
+                .input_classes()
+                .split_whitespace()
+                .any(|class| class.starts_with("py-")));
+        }
+    }
+
+    #[test]
+    fn test_search_bar_filters_use_chips() {
+        let search = search_bar_styles(VibeColors::default());
+
+        let row = search.filters_row_classes();
+        assert!(row.contains("search-filters"));
+        assert!(row.contains("flex-wrap"));
+
+        let selected = search.filter_chip_classes(true);
+        assert!(selected.contains("rounded-full"));
+        assert!(selected.contains("bg-jupiter-blue-500"));
+
+        let unselected = search.filter_chip_classes(false);
+        assert!(unselected.contains("bg-white"));
+    }
+
+    #[test]
+    fn test_search_bar_active_filters_summary() {
+        let search = search_bar_styles(VibeColors::default());
+
+        assert!(search
+            .active_filters_classes()
+            .contains("search-active-filters"));
+        assert!(search.active_filter_chip_classes().contains("text-xs"));
+
+        let clear_all = search.clear_all_classes();
+        assert!(clear_all.contains("hover:underline"));
+        assert!(clear_all.contains("text-jupiter-blue-500"));
+    }
+}
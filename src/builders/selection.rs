@@ -3,11 +3,25 @@
 //! Provides a chainable API for building selection CSS classes that can be used
 //! with any component library or framework.
 
+use crate::a11y::ControlledIds;
 use crate::core::color::ColorProvider;
+use crate::core::{
+    BorderProvider, BorderWidth, Breakpoint, Color, Density, Elevation, ElevationProvider, Radius,
+    RadiusProvider, Size, SizeProvider,
+};
+use crate::patterns::selection::{segment_state_classes, segment_track_classes};
 use crate::patterns::{
-    SelectionBehavior, SelectionDisplay, SelectionInteraction, SelectionLayout, SelectionSize,
-    SelectionState,
+    ChipLeading, CompositeRole, CountPosition, FocusBehavior, FocusManagement, InteractiveElement,
+    InteractiveState, SelectionBehavior, SelectionDisplay, SelectionInteraction, SelectionLayout,
+    SelectionSize, SelectionState, TabStyle,
 };
+#[cfg(feature = "std")]
+use crate::themes::{global_theme, SharedTheme};
+use crate::utils::{class_diff, ClassDiff};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Selection styling utility builder
 ///
@@ -33,16 +47,28 @@ use crate::patterns::{
 ///     .md()
 ///     .item_classes();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SelectionStyles<C: ColorProvider> {
     behavior: SelectionBehavior,
     state: SelectionState,
+    selected_color: Option<Color>,
     display: SelectionDisplay,
     layout: SelectionLayout,
     size: SelectionSize,
     interaction: SelectionInteraction,
+    focus_behavior: FocusBehavior,
     show_counts: bool,
+    count_position: CountPosition,
+    max_count: Option<u64>,
+    compact_counts: bool,
+    count_max_width: Option<u8>,
     show_clear_all: bool,
+    show_check_icon: bool,
+    dismissible: bool,
+    chip_leading: Option<ChipLeading>,
+    tab_style: TabStyle,
+    grid_columns: Vec<(Breakpoint, u8)>,
+    density: Density,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -53,12 +79,24 @@ impl<C: ColorProvider> SelectionStyles<C> {
         Self {
             behavior: SelectionBehavior::Single,
             state: SelectionState::Unselected,
+            selected_color: None,
             display: SelectionDisplay::Button,
             layout: SelectionLayout::Horizontal,
             size: SelectionSize::MD,
             interaction: SelectionInteraction::Standard,
+            focus_behavior: FocusBehavior::Standard,
             show_counts: false,
+            count_position: CountPosition::Inline,
+            max_count: None,
+            compact_counts: false,
+            count_max_width: None,
             show_clear_all: false,
+            show_check_icon: false,
+            dismissible: false,
+            chip_leading: None,
+            tab_style: TabStyle::Underline,
+            grid_columns: Vec::new(),
+            density: color_provider.density(),
             custom_classes: Vec::new(),
             color_provider,
         }
@@ -116,6 +154,30 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
+    /// Fill selected items with another color token, e.g. `Color::Accent`
+    /// for storefront filters
+    ///
+    /// Overrides the theme's selection tokens for this group; text switches
+    /// to whichever reads on the fill. Tabs and segments keep their own
+    /// indicator styling.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::selection::SelectionStyles;
+    /// use jupiter_design_system::core::Color;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let filter = SelectionStyles::new(VibeColors::default())
+    ///     .selected()
+    ///     .selected_color(Color::Accent);
+    ///
+    /// assert!(filter.item_classes().contains("bg-jupiter-orange-500"));
+    /// assert!(!filter.item_classes().contains("bg-jupiter-blue-500"));
+    /// ```
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.selected_color = Some(color);
+        self
+    }
+
     // === Display Methods ===
 
     /// Set button display style
@@ -130,6 +192,13 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
+    /// Set chip display with a remove button
+    pub fn dismissible_chip(mut self) -> Self {
+        self.display = SelectionDisplay::Chip;
+        self.dismissible = true;
+        self
+    }
+
     /// Set list item display style
     pub fn list_item_display(mut self) -> Self {
         self.display = SelectionDisplay::ListItem;
@@ -148,6 +217,44 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
+    /// Set segmented control display style
+    pub fn segment_display(mut self) -> Self {
+        self.display = SelectionDisplay::Segment;
+        self
+    }
+
+    // === Tab Style Methods ===
+
+    /// Set underline tab indicator (default)
+    pub fn underline_tabs(mut self) -> Self {
+        self.tab_style = TabStyle::Underline;
+        self
+    }
+
+    /// Set animated underline tab indicator
+    pub fn animated_underline_tabs(mut self) -> Self {
+        self.tab_style = TabStyle::AnimatedUnderline;
+        self
+    }
+
+    /// Set pill tab indicator
+    pub fn pill_tabs(mut self) -> Self {
+        self.tab_style = TabStyle::Pill;
+        self
+    }
+
+    /// Set enclosed (boxed) tabs
+    pub fn enclosed_tabs(mut self) -> Self {
+        self.tab_style = TabStyle::Enclosed;
+        self
+    }
+
+    /// Set tab indicator style explicitly
+    pub fn tab_style(mut self, tab_style: TabStyle) -> Self {
+        self.tab_style = tab_style;
+        self
+    }
+
     // === Layout Methods ===
 
     /// Set horizontal layout
@@ -162,9 +269,52 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
-    /// Set grid layout
-    pub fn grid_layout(mut self) -> Self {
+    /// Set grid layout with a fixed number of columns (1-12)
+    pub fn grid_layout(self, cols: u8) -> Self {
+        self.grid_cols(cols)
+    }
+
+    /// Set grid layout that fits as many columns as the width allows
+    pub fn auto_grid_layout(mut self) -> Self {
         self.layout = SelectionLayout::Grid;
+        self.grid_columns.clear();
+        self
+    }
+
+    // === Grid Column Methods ===
+
+    /// Set grid layout with `cols` columns on the smallest screens
+    pub fn grid_cols(self, cols: u8) -> Self {
+        self.cols_at(Breakpoint::Mobile, cols)
+    }
+
+    /// Set the grid columns from the tablet breakpoint up
+    pub fn md_cols(self, cols: u8) -> Self {
+        self.cols_at(Breakpoint::Tablet, cols)
+    }
+
+    /// Set the grid columns from the desktop breakpoint up
+    pub fn lg_cols(self, cols: u8) -> Self {
+        self.cols_at(Breakpoint::Desktop, cols)
+    }
+
+    /// Set the grid columns from the large breakpoint up
+    pub fn xl_cols(self, cols: u8) -> Self {
+        self.cols_at(Breakpoint::Large, cols)
+    }
+
+    /// Set the grid columns from a breakpoint up (clamped to 1-12)
+    pub fn cols_at(mut self, breakpoint: Breakpoint, cols: u8) -> Self {
+        self.layout = SelectionLayout::Grid;
+        let cols = cols.clamp(1, 12);
+        match self
+            .grid_columns
+            .iter_mut()
+            .find(|(existing, _)| *existing == breakpoint)
+        {
+            Some(entry) => entry.1 = cols,
+            None => self.grid_columns.push((breakpoint, cols)),
+        }
         self
     }
 
@@ -232,6 +382,15 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
+    /// Set the focus ring of items outside tab lists and listboxes
+    /// (standard by default)
+    ///
+    /// Tab and listbox items always show the composite focus-visible ring.
+    pub fn focus_behavior(mut self, behavior: FocusBehavior) -> Self {
+        self.focus_behavior = behavior;
+        self
+    }
+
     // === Feature Methods ===
 
     /// Show counts for selection items
@@ -246,6 +405,56 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
+    /// Reserve a leading slot for an icon or avatar before chip labels
+    pub fn with_chip_leading(mut self, leading: ChipLeading) -> Self {
+        self.chip_leading = Some(leading);
+        self
+    }
+
+    /// Reserve an icon slot for a check mark on selected items
+    pub fn with_check_icon(mut self, show_check_icon: bool) -> Self {
+        self.show_check_icon = show_check_icon;
+        self
+    }
+
+    // === Count Methods ===
+
+    /// Place count badges after the label (default)
+    pub fn inline_counts(mut self) -> Self {
+        self.count_position = CountPosition::Inline;
+        self
+    }
+
+    /// Pin count badges to the item's top-right corner
+    pub fn corner_counts(mut self) -> Self {
+        self.count_position = CountPosition::Corner;
+        self
+    }
+
+    /// Set count badge position explicitly
+    pub fn count_position(mut self, position: CountPosition) -> Self {
+        self.count_position = position;
+        self
+    }
+
+    /// Show counts above `max` as `max+` (e.g. "99+")
+    pub fn max_count(mut self, max: u64) -> Self {
+        self.max_count = Some(max);
+        self
+    }
+
+    /// Abbreviate large counts (e.g. "1.2k", "10k", "3M")
+    pub fn compact_counts(mut self, compact: bool) -> Self {
+        self.compact_counts = compact;
+        self
+    }
+
+    /// Truncate count badges wider than `chars` characters
+    pub fn count_max_width(mut self, chars: u8) -> Self {
+        self.count_max_width = Some(chars);
+        self
+    }
+
     // === String Convenience Methods ===
 
     /// Set behavior from string
@@ -280,11 +489,24 @@ impl<C: ColorProvider> SelectionStyles<C> {
             "list" | "list-item" => SelectionDisplay::ListItem,
             "card" => SelectionDisplay::Card,
             "tab" => SelectionDisplay::Tab,
+            "segment" | "segmented" => SelectionDisplay::Segment,
             _ => SelectionDisplay::Button, // fallback
         };
         self
     }
 
+    /// Set tab indicator style from string
+    pub fn tab_style_str(mut self, tab_style: &str) -> Self {
+        self.tab_style = match tab_style {
+            "underline" | "line" => TabStyle::Underline,
+            "animated" | "animated-underline" => TabStyle::AnimatedUnderline,
+            "pill" | "pills" => TabStyle::Pill,
+            "enclosed" | "boxed" => TabStyle::Enclosed,
+            _ => TabStyle::Underline, // fallback
+        };
+        self
+    }
+
     /// Set layout from string
     pub fn layout_str(mut self, layout: &str) -> Self {
         self.layout = match layout {
@@ -311,6 +533,18 @@ impl<C: ColorProvider> SelectionStyles<C> {
         self
     }
 
+    /// Set density (defaults to the color provider's density)
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set density from string ("compact", "comfortable", "spacious")
+    pub fn density_str(mut self, density: &str) -> Self {
+        self.density = Density::from_str_or_default(density);
+        self
+    }
+
     /// Set interaction from string
     pub fn interaction_str(mut self, interaction: &str) -> Self {
         self.interaction = match interaction {
@@ -344,31 +578,42 @@ impl<C: ColorProvider> SelectionStyles<C> {
     // === Build Methods ===
 
     /// Build container classes for the selection group
-    pub fn container_classes(self) -> String {
+    pub fn container_classes(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Base selection classes
         all_classes.push("selection-pattern".to_string());
 
-        // Layout classes
-        let layout_classes = match self.layout {
-            SelectionLayout::Horizontal => "flex flex-row gap-2 items-center",
-            SelectionLayout::Vertical => "flex flex-col gap-2",
-            SelectionLayout::Grid => "grid grid-cols-auto gap-2",
-            SelectionLayout::Dropdown => "relative",
-            SelectionLayout::Inline => "flex flex-wrap gap-2 items-center",
-        };
-        all_classes.push(layout_classes.to_string());
-
-        // Size-based spacing
-        let spacing_classes = match self.size {
-            SelectionSize::XS => "gap-1",
-            SelectionSize::SM => "gap-1.5",
-            SelectionSize::MD => "gap-2",
-            SelectionSize::LG => "gap-3",
-            SelectionSize::XL => "gap-4",
-        };
-        all_classes.push(spacing_classes.to_string());
+        if self.display == SelectionDisplay::Segment {
+            // The track sets its own layout and spacing
+            all_classes.push(segment_track_classes(&self.color_provider));
+        } else {
+            // Layout classes
+            let layout_classes = match self.layout {
+                SelectionLayout::Horizontal => "flex flex-row gap-2 items-center",
+                SelectionLayout::Vertical => "flex flex-col gap-2",
+                SelectionLayout::Grid => "grid gap-2",
+                SelectionLayout::Dropdown => "relative",
+                SelectionLayout::Inline => "flex flex-wrap gap-2 items-center",
+            };
+            all_classes.push(layout_classes.to_string());
+            if self.layout == SelectionLayout::Grid {
+                all_classes.push(self.grid_column_classes());
+            }
+            if self.display == SelectionDisplay::Tab {
+                all_classes.push(
+                    self.tab_style
+                        .list_classes(&self.color_provider, self.is_vertical()),
+                );
+            }
+
+            // Size-based spacing
+            const GAP: [&str; 7] = [
+                "gap-0.5", "gap-1", "gap-1.5", "gap-2", "gap-3", "gap-4", "gap-6",
+            ];
+            let spacing_classes = self.density.scale(&GAP, self.size_index());
+            all_classes.push(spacing_classes.to_string());
+        }
 
         // Custom classes
         let custom_classes = self.custom_classes.join(" ");
@@ -396,29 +641,108 @@ impl<C: ColorProvider> SelectionStyles<C> {
 
         // Display style classes
         let display_classes = match self.display {
-            SelectionDisplay::Button => "inline-flex items-center justify-center font-medium rounded-md transition-all duration-200",
-            SelectionDisplay::Chip => "inline-flex items-center rounded-full transition-all duration-200",
-            SelectionDisplay::ListItem => "flex items-center w-full px-3 py-2 transition-all duration-200",
-            SelectionDisplay::Card => "flex flex-col items-center p-4 rounded-lg border transition-all duration-200",
-            SelectionDisplay::Tab => "flex items-center px-4 py-2 border-b-2 transition-all duration-200",
+            SelectionDisplay::Button => {
+                "inline-flex items-center justify-center font-medium transition-all duration-200"
+            }
+            SelectionDisplay::Chip => "inline-flex items-center transition-all duration-200",
+            SelectionDisplay::ListItem => {
+                "flex items-center w-full px-3 py-2 transition-all duration-200"
+            }
+            SelectionDisplay::Card => "flex flex-col items-center p-4 transition-all duration-200",
+            SelectionDisplay::Tab => "flex items-center px-4 py-2 transition-all duration-200",
+            SelectionDisplay::Segment => {
+                "flex-1 basis-0 inline-flex items-center justify-center font-medium whitespace-nowrap transition-all duration-200"
+            }
         };
         all_classes.push(display_classes.to_string());
 
-        // Size classes
-        let size_classes = match (self.display, self.size) {
-            (SelectionDisplay::Button, SelectionSize::XS) => "px-2 py-1 text-xs",
-            (SelectionDisplay::Button, SelectionSize::SM) => "px-3 py-1.5 text-sm",
-            (SelectionDisplay::Button, SelectionSize::MD) => "px-4 py-2 text-base",
-            (SelectionDisplay::Button, SelectionSize::LG) => "px-6 py-3 text-lg",
-            (SelectionDisplay::Button, SelectionSize::XL) => "px-8 py-4 text-xl",
-            (SelectionDisplay::Chip, SelectionSize::XS) => "px-2 py-0.5 text-xs",
-            (SelectionDisplay::Chip, SelectionSize::SM) => "px-3 py-1 text-sm",
-            (SelectionDisplay::Chip, SelectionSize::MD) => "px-3 py-1.5 text-base",
-            (SelectionDisplay::Chip, SelectionSize::LG) => "px-4 py-2 text-lg",
-            (SelectionDisplay::Chip, SelectionSize::XL) => "px-6 py-3 text-xl",
-            _ => "px-4 py-2 text-base", // fallback
+        // Border width classes
+        let borders = self.color_provider.border_scale();
+        match self.display {
+            SelectionDisplay::Card => {
+                all_classes.push(borders.border_width_class(BorderWidth::Default))
+            }
+            SelectionDisplay::Tab => all_classes.push(
+                self.tab_style
+                    .item_classes(&self.color_provider, self.is_vertical()),
+            ),
+            _ => {}
+        }
+
+        // Radius classes
+        let radius = match self.display {
+            SelectionDisplay::Button => Some(Radius::Control),
+            SelectionDisplay::Chip => Some(Radius::Pill),
+            SelectionDisplay::Card => Some(Radius::Surface),
+            SelectionDisplay::Segment => Some(Radius::ControlSmall),
+            SelectionDisplay::ListItem | SelectionDisplay::Tab => None,
         };
-        all_classes.push(size_classes.to_string());
+        if let Some(radius) = radius {
+            all_classes.push(self.color_provider.radius_scale().radius_class(radius));
+        }
+
+        // Size classes; controls with a fixed height only pad horizontally
+        const BUTTON_PADDING: [&str; 7] =
+            ["px-1.5", "px-2", "px-3", "px-4", "px-6", "px-8", "px-10"];
+        const CHIP_PADDING: [&str; 7] = ["px-1.5", "px-2", "px-3", "px-3", "px-4", "px-6", "px-8"];
+        const FALLBACK_PADDING: [&str; 7] = [
+            "px-1.5 py-0.5",
+            "px-2 py-1",
+            "px-3 py-1.5",
+            "px-4 py-2",
+            "px-6 py-3",
+            "px-8 py-4",
+            "px-10 py-5",
+        ];
+        let text_classes = match self.size {
+            SelectionSize::XS => "text-xs",
+            SelectionSize::SM => "text-sm",
+            SelectionSize::MD => "text-base",
+            SelectionSize::LG => "text-lg",
+            SelectionSize::XL => "text-xl",
+        };
+        let control_height = self
+            .color_provider
+            .size_scale()
+            .control_height_class(self.control_size());
+        let size_classes = match self.display {
+            SelectionDisplay::Button => format!(
+                "{} {} {}",
+                control_height,
+                self.density.scale(&BUTTON_PADDING, self.size_index()),
+                text_classes
+            ),
+            SelectionDisplay::Chip => format!(
+                "{} {} {}",
+                control_height,
+                self.density.scale(&CHIP_PADDING, self.size_index()),
+                text_classes
+            ),
+            // One step down so the segments fit inside the padded track
+            SelectionDisplay::Segment => format!(
+                "{} {} {}",
+                self.color_provider
+                    .size_scale()
+                    .control_height_class(self.segment_size()),
+                self.density.scale(&CHIP_PADDING, self.size_index()),
+                self.text_size_class()
+            ),
+            _ => format!("{} text-base", self.density.scale(&FALLBACK_PADDING, 3)), // fallback
+        };
+        all_classes.push(size_classes);
+
+        // Anchor for corner count badges
+        if self.show_counts && self.count_position == CountPosition::Corner {
+            all_classes.push("relative".to_string());
+        }
+
+        // Icon slot spacing
+        if self.show_check_icon {
+            const ICON_GAP: [&str; 7] = [
+                "gap-0.5", "gap-1", "gap-1", "gap-1.5", "gap-2", "gap-2", "gap-3",
+            ];
+            all_classes.push(self.density.scale(&ICON_GAP, self.size_index()).to_string());
+        }
 
         // State classes
         let state_classes = self.get_state_classes();
@@ -432,6 +756,17 @@ impl<C: ColorProvider> SelectionStyles<C> {
             all_classes.push(interaction_classes);
         }
 
+        // Focus ring: composite items of a tab list or listbox show it on
+        // focus-visible, other items follow the focus behavior
+        let focus = self.focus_management();
+        let focus_classes = match focus.composite_role() {
+            Some(_) => focus.item_classes(),
+            None => focus.classes(),
+        };
+        if !focus_classes.is_empty() {
+            all_classes.push(focus_classes);
+        }
+
         // Join and clean up
         let mut classes: Vec<String> = all_classes
             .join(" ")
@@ -443,6 +778,297 @@ impl<C: ColorProvider> SelectionStyles<C> {
         classes.join(" ")
     }
 
+    /// Get the suggested HTML element for a selection item
+    ///
+    /// Items inside a dropdown are list entries; every other display is a
+    /// `button` so it is focusable and activatable without extra wiring.
+    pub fn suggested_element(&self) -> &'static str {
+        match self.layout {
+            SelectionLayout::Dropdown => "li",
+            _ => match self.display {
+                SelectionDisplay::ListItem => "li",
+                _ => "button",
+            },
+        }
+    }
+
+    /// Get the ARIA role a selection item needs on top of its element
+    ///
+    /// Tabs and listbox options have no native element of their own, so
+    /// they are expressed as a role on the suggested element.
+    pub fn suggested_role(&self) -> Option<&'static str> {
+        match (self.display, self.layout) {
+            (SelectionDisplay::Tab, _) => Some("tab"),
+            (SelectionDisplay::Segment, _) => Some("radio"),
+            (_, SelectionLayout::Dropdown) | (SelectionDisplay::ListItem, _) => Some("option"),
+            _ => None,
+        }
+    }
+
+    /// Get the composite widget the selection group forms, if any
+    ///
+    /// Tab lists and listboxes take a single Tab stop and move focus
+    /// between items with the arrow keys.
+    pub fn composite_role(&self) -> Option<CompositeRole> {
+        match self.suggested_role() {
+            Some("tab") => Some(CompositeRole::Tablist),
+            Some("option") => Some(CompositeRole::Listbox),
+            _ => None,
+        }
+    }
+
+    /// Get focus management for the selection group's composite widget
+    ///
+    /// Tab lists follow the group's layout; listboxes are always vertical.
+    /// Without a composite role it describes no container or item focus.
+    pub fn focus_management(&self) -> FocusManagement<&C> {
+        let focus = FocusManagement::new(&self.color_provider);
+        let focus = match self.composite_role() {
+            Some(CompositeRole::Tablist) => focus
+                .composite(CompositeRole::Tablist)
+                .with_orientation(self.is_vertical()),
+            Some(role) => focus.composite(role),
+            None => focus,
+        };
+        focus.focus_behavior(self.focus_behavior)
+    }
+
+    /// Get the ARIA attributes for the selection group container
+    ///
+    /// Listboxes that allow several selected options also report
+    /// `aria-multiselectable`.
+    pub fn container_aria_attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = self.focus_management().container_attributes();
+        if self.composite_role() == Some(CompositeRole::Listbox)
+            && matches!(
+                self.behavior,
+                SelectionBehavior::Multiple | SelectionBehavior::Toggle
+            )
+        {
+            attrs.push(("aria-multiselectable", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Get the focus attributes for a selection item
+    ///
+    /// `active` marks the item that owns the group's Tab stop. The role
+    /// comes from `item_aria_attributes`, so it is not repeated here.
+    pub fn item_focus_attributes(&self, active: bool) -> Vec<(&'static str, String)> {
+        self.focus_management()
+            .item_attributes(active)
+            .into_iter()
+            .filter(|(name, _)| *name != "role")
+            .collect()
+    }
+
+    /// Build classes for a chip, including remove-button and leading-slot spacing
+    ///
+    /// Dismissible chips tighten their end padding around the remove
+    /// button; chips with an avatar tighten their start padding so the
+    /// avatar sits close to the rounded edge.
+    pub fn chip_classes(&self) -> String {
+        const END_PADDING: [&str; 7] =
+            ["pr-0.5", "pr-0.5", "pr-1", "pr-1", "pr-1.5", "pr-2", "pr-3"];
+        const START_PADDING: [&str; 7] =
+            ["pl-0.5", "pl-0.5", "pl-1", "pl-1", "pl-1.5", "pl-2", "pl-3"];
+        const SLOT_GAP: [&str; 7] = [
+            "gap-0.5", "gap-1", "gap-1", "gap-1.5", "gap-2", "gap-2", "gap-3",
+        ];
+
+        let mut classes = vec![self.item_classes()];
+        if self.dismissible || self.chip_leading.is_some() {
+            classes.push(self.density.scale(&SLOT_GAP, self.size_index()).to_string());
+        }
+        if self.dismissible {
+            classes.push(
+                self.density
+                    .scale(&END_PADDING, self.size_index())
+                    .to_string(),
+            );
+        }
+        if self.chip_leading == Some(ChipLeading::Avatar) {
+            classes.push(
+                self.density
+                    .scale(&START_PADDING, self.size_index())
+                    .to_string(),
+            );
+        }
+        classes.join(" ")
+    }
+
+    /// Get classes for the remove button inside a dismissible chip
+    ///
+    /// Empty unless the chip is dismissible. The visible button stays small,
+    /// while a transparent overlay extends its hit area past the icon.
+    pub fn remove_button_classes(&self) -> String {
+        if !self.dismissible {
+            return String::new();
+        }
+
+        let size = match self.size {
+            SelectionSize::XS => "w-3.5 h-3.5",
+            SelectionSize::SM => "w-4 h-4",
+            SelectionSize::MD => "w-5 h-5",
+            SelectionSize::LG => "w-6 h-6",
+            SelectionSize::XL => "w-7 h-7",
+        };
+        let hover = match self.state {
+            SelectionState::Selected => format!(
+                "hover:{}/20",
+                self.color_provider.bg_class(self.selected_colors().1)
+            ),
+            SelectionState::Disabled => "pointer-events-none opacity-50".to_string(),
+            _ => format!(
+                "hover:{}",
+                self.color_provider.bg_class(crate::core::Color::Border)
+            ),
+        };
+
+        format!(
+            "selection-chip-remove relative inline-flex items-center justify-center shrink-0 {} {} text-current opacity-70 hover:opacity-100 {} after:absolute after:-inset-1.5 after:content-[''] focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-{} transition-colors duration-150",
+            size,
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill),
+            hover,
+            self.color_provider.resolve_color(crate::core::Color::Primary)
+        )
+    }
+
+    /// Get classes for the leading icon or avatar slot of a chip
+    ///
+    /// Empty unless `with_chip_leading` is set.
+    pub fn leading_slot_classes(&self) -> String {
+        let Some(leading) = self.chip_leading else {
+            return String::new();
+        };
+
+        let size = match (leading, self.size) {
+            (ChipLeading::Icon, SelectionSize::XS | SelectionSize::SM) => "w-3 h-3",
+            (ChipLeading::Icon, SelectionSize::MD) => "w-4 h-4",
+            (ChipLeading::Icon, SelectionSize::LG | SelectionSize::XL) => "w-5 h-5",
+            (ChipLeading::Avatar, SelectionSize::XS | SelectionSize::SM) => "w-4 h-4",
+            (ChipLeading::Avatar, SelectionSize::MD) => "w-5 h-5",
+            (ChipLeading::Avatar, SelectionSize::LG | SelectionSize::XL) => "w-6 h-6",
+        };
+        match leading {
+            ChipLeading::Icon => format!("selection-chip-icon shrink-0 {}", size),
+            ChipLeading::Avatar => format!(
+                "selection-chip-avatar shrink-0 object-cover {} {}",
+                size,
+                self.color_provider
+                    .radius_scale()
+                    .radius_class(Radius::Pill)
+            ),
+        }
+    }
+
+    /// Get the ARIA attributes for a selection item
+    ///
+    /// Items with a role report their state through the matching attribute
+    /// (`aria-selected` for tabs and options, `aria-checked` for radios);
+    /// plain buttons that can be selected report it as `aria-pressed`.
+    pub fn item_aria_attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = Vec::new();
+
+        match self.suggested_role() {
+            Some(role @ ("tab" | "option")) => {
+                attrs.push(("role", role.to_string()));
+                attrs.push((
+                    "aria-selected",
+                    (self.state == SelectionState::Selected).to_string(),
+                ));
+            }
+            Some(role) => {
+                attrs.push(("role", role.to_string()));
+                attrs.push(("aria-checked", self.state.aria_checked().to_string()));
+            }
+            None if self.behavior != SelectionBehavior::None => {
+                attrs.push(("aria-pressed", self.state.aria_checked().to_string()));
+            }
+            None => {}
+        }
+
+        if self.state == SelectionState::Disabled {
+            attrs.push(("aria-disabled", "true".to_string()));
+        }
+
+        attrs
+    }
+
+    /// Get every attribute for a tab, wired to its panel
+    ///
+    /// Combines the tab's id and `aria-controls` with its role, selection
+    /// state and roving focus. `active` marks the tab that owns the tab
+    /// list's Tab stop.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::a11y::ControlledIds;
+    /// use jupiter_design_system::builders::selection::SelectionStyles;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let ids = ControlledIds::new("tabs", "billing");
+    /// let tab = SelectionStyles::new(VibeColors::default()).tab_display().selected();
+    ///
+    /// let attrs = tab.tab_attributes(&ids, true);
+    /// assert!(attrs.contains(&("aria-controls", "tabs-billing-panel".to_string())));
+    /// assert!(attrs.contains(&("aria-selected", "true".to_string())));
+    ///
+    /// let panel = tab.tab_panel_attributes(&ids);
+    /// assert!(panel.contains(&("aria-labelledby", "tabs-billing-trigger".to_string())));
+    /// ```
+    pub fn tab_attributes(&self, ids: &ControlledIds, active: bool) -> Vec<(&'static str, String)> {
+        let mut attrs = ids.trigger_attributes();
+        attrs.extend(self.item_aria_attributes());
+        attrs.extend(self.item_focus_attributes(active));
+        attrs
+    }
+
+    /// Get attributes for the panel a tab controls
+    ///
+    /// The panel itself takes focus so content without focusable elements
+    /// is still reachable; panels of unselected tabs are `hidden`.
+    pub fn tab_panel_attributes(&self, ids: &ControlledIds) -> Vec<(&'static str, String)> {
+        let mut attrs = ids.panel_attributes();
+        attrs.push(("role", "tabpanel".to_string()));
+        attrs.push(("tabindex", "0".to_string()));
+        if self.state != SelectionState::Selected {
+            attrs.push(("hidden", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Get classes for the check mark icon slot
+    ///
+    /// Empty unless `with_check_icon(true)` is set. The slot is hidden while
+    /// the item is not selected.
+    pub fn check_icon_classes(&self) -> String {
+        if !self.show_check_icon {
+            return String::new();
+        }
+
+        match self.state {
+            SelectionState::Selected | SelectionState::PartiallySelected => {
+                let size = match self.size {
+                    SelectionSize::XS | SelectionSize::SM => "w-3 h-3",
+                    SelectionSize::MD => "w-4 h-4",
+                    SelectionSize::LG | SelectionSize::XL => "w-5 h-5",
+                };
+                format!(
+                    "selection-check inline-flex shrink-0 items-center justify-center {}",
+                    size
+                )
+            }
+            _ => "selection-check hidden".to_string(),
+        }
+    }
+
+    /// Compare the item classes of this selection with another configuration
+    pub fn diff(&self, other: &Self) -> ClassDiff {
+        class_diff(&self.item_classes(), &other.item_classes())
+    }
+
     /// Get count badge classes
     pub fn count_classes(&self) -> String {
         if !self.show_counts {
@@ -450,16 +1076,32 @@ impl<C: ColorProvider> SelectionStyles<C> {
         }
 
         let mut classes = Vec::new();
-        classes.push("ml-2 px-2 py-0.5 text-xs rounded-full".to_string());
+        let placement = match self.count_position {
+            CountPosition::Inline => "ml-2 px-2 py-0.5 text-xs".to_string(),
+            CountPosition::Corner => format!(
+                "absolute -top-2 -right-2 inline-flex items-center justify-center min-w-5 h-5 px-1 text-[0.625rem] leading-none ring-2 ring-{}",
+                self.color_provider.resolve_color(crate::core::Color::Surface)
+            ),
+        };
+        classes.push(format!(
+            "{} tabular-nums whitespace-nowrap {}",
+            placement,
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill)
+        ));
+        if let Some(chars) = self.count_max_width {
+            classes.push(format!("max-w-[{}ch] truncate", chars));
+        }
 
         // Color based on state
         match self.state {
             SelectionState::Selected => {
+                let (fill, text, _) = self.selected_colors();
                 classes.push(format!(
                     "{} {}",
-                    self.color_provider.bg_class(crate::core::Color::Primary),
-                    self.color_provider
-                        .text_class(crate::core::Color::TextInverse)
+                    self.color_provider.bg_class(fill),
+                    self.color_provider.text_class(text)
                 ));
             }
             _ => {
@@ -475,7 +1117,153 @@ impl<C: ColorProvider> SelectionStyles<C> {
         classes.join(" ")
     }
 
+    /// Format a count for display in the count badge
+    ///
+    /// Applies `max_count` ("99+") and `compact_counts` ("1.2k") so the
+    /// badge text stays short no matter how many results there are.
+    pub fn count_label(&self, count: u64) -> String {
+        match self.max_count {
+            Some(max) if count > max => format!("{}+", self.format_count(max)),
+            _ => self.format_count(count),
+        }
+    }
+
+    /// Get classes for the clear-all control of the group
+    ///
+    /// Empty unless `with_clear_all(true)` is set.
+    pub fn clear_all_classes(&self) -> String {
+        if !self.show_clear_all {
+            return String::new();
+        }
+
+        format!(
+            "selection-clear-all inline-flex items-center {} font-medium underline-offset-2 hover:underline {} hover:{} focus-visible:outline-none focus-visible:ring-2 focus-visible:ring-{} {}",
+            self.text_size_class(),
+            self.color_provider.text_class(crate::core::Color::Primary),
+            self.color_provider
+                .text_class(crate::core::Color::InteractiveHover),
+            self.color_provider.resolve_color(crate::core::Color::Primary),
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::ControlSmall)
+        )
+    }
+
+    /// Get classes for the label naming the group
+    pub fn group_label_classes(&self) -> String {
+        format!(
+            "selection-group-label block {} font-semibold {}",
+            self.text_size_class(),
+            self.color_provider
+                .text_class(crate::core::Color::TextPrimary)
+        )
+    }
+
+    /// Get classes for the summary of the current selection (e.g. "3 selected")
+    pub fn selected_summary_classes(&self) -> String {
+        format!(
+            "selection-summary text-xs tabular-nums {}",
+            self.color_provider
+                .text_class(crate::core::Color::TextSecondary)
+        )
+    }
+
+    fn grid_column_classes(&self) -> String {
+        let has_base = self
+            .grid_columns
+            .iter()
+            .any(|(breakpoint, _)| *breakpoint == Breakpoint::Mobile);
+        let mut classes = Vec::new();
+        if !has_base {
+            classes.push("grid-cols-[repeat(auto-fill,minmax(10rem,1fr))]".to_string());
+        }
+
+        let breakpoints = self.color_provider.breakpoint_scale();
+        let mut columns = self.grid_columns.clone();
+        columns.sort_by_key(|(breakpoint, _)| breakpoints.min_width(*breakpoint));
+        for (breakpoint, cols) in columns {
+            classes.push(format!(
+                "{}grid-cols-{}",
+                breakpoints.prefix(breakpoint),
+                cols
+            ));
+        }
+        classes.join(" ")
+    }
+
+    fn format_count(&self, count: u64) -> String {
+        if !self.compact_counts || count < 1_000 {
+            return count.to_string();
+        }
+
+        let (unit, suffix) = match count {
+            0..=999_999 => (1_000, "k"),
+            1_000_000..=999_999_999 => (1_000_000, "M"),
+            _ => (1_000_000_000, "B"),
+        };
+        let whole = count / unit;
+        let tenth = count % unit * 10 / unit;
+        if whole < 10 && tenth > 0 {
+            format!("{}.{}{}", whole, tenth, suffix)
+        } else {
+            format!("{}{}", whole, suffix)
+        }
+    }
+
+    fn text_size_class(&self) -> &'static str {
+        match self.size {
+            SelectionSize::XS | SelectionSize::SM => "text-xs",
+            SelectionSize::MD => "text-sm",
+            SelectionSize::LG | SelectionSize::XL => "text-base",
+        }
+    }
+
+    /// Position of the size on the density spacing scales
+    fn control_size(&self) -> Size {
+        match self.size {
+            SelectionSize::XS => Size::XSmall,
+            SelectionSize::SM => Size::Small,
+            SelectionSize::MD => Size::Medium,
+            SelectionSize::LG => Size::Large,
+            SelectionSize::XL => Size::XLarge,
+        }
+    }
+
+    fn segment_size(&self) -> Size {
+        match self.size {
+            SelectionSize::XS | SelectionSize::SM => Size::XSmall,
+            SelectionSize::MD => Size::Small,
+            SelectionSize::LG => Size::Medium,
+            SelectionSize::XL => Size::Large,
+        }
+    }
+
+    fn size_index(&self) -> usize {
+        match self.size {
+            SelectionSize::XS => 1,
+            SelectionSize::SM => 2,
+            SelectionSize::MD => 3,
+            SelectionSize::LG => 4,
+            SelectionSize::XL => 5,
+        }
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.layout == SelectionLayout::Vertical
+    }
+
     fn get_state_classes(&self) -> String {
+        if self.display == SelectionDisplay::Tab {
+            return self.tab_style.state_classes(
+                &self.color_provider,
+                self.state,
+                self.is_vertical(),
+            );
+        }
+        if self.display == SelectionDisplay::Segment {
+            return segment_state_classes(&self.color_provider, self.state);
+        }
+
         match self.state {
             SelectionState::Unselected => format!(
                 "{} {} {}",
@@ -484,21 +1272,34 @@ impl<C: ColorProvider> SelectionStyles<C> {
                     .text_class(crate::core::Color::TextPrimary),
                 self.color_provider.border_class(crate::core::Color::Border)
             ),
-            SelectionState::Selected => format!(
-                "{} {} {}",
-                self.color_provider.bg_class(crate::core::Color::Primary),
+            // Toggles look pressed in rather than merely highlighted
+            SelectionState::Selected if self.behavior == SelectionBehavior::Toggle => format!(
+                "{} {} {} shadow-inner",
                 self.color_provider
-                    .text_class(crate::core::Color::TextInverse),
+                    .bg_class(crate::core::Color::InteractiveActive),
                 self.color_provider
-                    .border_class(crate::core::Color::Primary)
-            ),
-            SelectionState::PartiallySelected => format!(
-                "{} {} {}",
-                self.color_provider.bg_class(crate::core::Color::Background),
-                self.color_provider.text_class(crate::core::Color::Primary),
+                    .text_class(crate::core::Color::TextInverse),
                 self.color_provider
-                    .border_class(crate::core::Color::Primary)
+                    .border_class(crate::core::Color::InteractiveActive)
             ),
+            SelectionState::Selected => {
+                let (fill, text, border) = self.selected_colors();
+                format!(
+                    "{} {} {}",
+                    self.color_provider.bg_class(fill),
+                    self.color_provider.text_class(text),
+                    self.color_provider.border_class(border)
+                )
+            }
+            SelectionState::PartiallySelected => {
+                let (fill, _, border) = self.selected_colors();
+                format!(
+                    "{} {} {}",
+                    self.color_provider.bg_class(crate::core::Color::Background),
+                    self.color_provider.text_class(fill),
+                    self.color_provider.border_class(border)
+                )
+            }
             SelectionState::Disabled => format!(
                 "{} {} {}",
                 self.color_provider
@@ -511,41 +1312,70 @@ impl<C: ColorProvider> SelectionStyles<C> {
         }
     }
 
+    /// Fill, text and border of selected items
+    ///
+    /// The builder's `selected_color` wins over the theme's selection
+    /// tokens, which win over the primary color.
+    fn selected_colors(&self) -> (Color, Color, Color) {
+        let tokens = self.color_provider.selection_tokens();
+        if let Some(fill) = self.selected_color {
+            return (fill, self.color_provider.on_color(fill), fill);
+        }
+        let fill = tokens.selected_bg.unwrap_or(Color::Primary);
+        let text = tokens
+            .selected_text
+            .unwrap_or_else(|| self.color_provider.on_color(fill));
+        (fill, text, tokens.selected_border.unwrap_or(fill))
+    }
+
+    /// Hover and press effects from `InteractiveElement`, plus hover
+    /// colors for unselected items
     fn get_interaction_classes(&self) -> String {
-        if matches!(self.state, SelectionState::Disabled) {
-            return "cursor-not-allowed".to_string();
+        let element = InteractiveElement::new(&self.color_provider)
+            .intensity(self.interaction.intensity())
+            .state(match self.state {
+                SelectionState::Disabled => InteractiveState::Disabled,
+                _ => InteractiveState::Default,
+            });
+        if self.display == SelectionDisplay::Segment {
+            // Segments sit in a fixed track, so they never scale
+            return match self.state {
+                SelectionState::Disabled => element.classes(),
+                _ => "cursor-pointer".to_string(),
+            };
         }
 
+        let element = element.hoverable().pressable().classes();
         let mut classes = Vec::new();
-        classes.push("cursor-pointer".to_string());
-
-        match self.interaction {
-            SelectionInteraction::Subtle => {
-                classes.push("hover:opacity-80".to_string());
-            }
-            SelectionInteraction::Standard => {
-                if matches!(self.state, SelectionState::Unselected) {
-                    classes.push(format!(
-                        "hover:{} hover:{}",
-                        self.color_provider.bg_class(crate::core::Color::Background),
-                        self.color_provider
-                            .border_class(crate::core::Color::Interactive)
-                    ));
-                }
-                classes.push("hover:scale-105 active:scale-95".to_string());
-            }
-            SelectionInteraction::Prominent => {
-                if matches!(self.state, SelectionState::Unselected) {
-                    classes.push(format!(
-                        "hover:{} hover:{}",
-                        self.color_provider
-                            .bg_class(crate::core::Color::Interactive),
-                        self.color_provider
-                            .text_class(crate::core::Color::TextInverse)
-                    ));
-                }
-                classes
-                    .push("hover:scale-110 active:scale-90 shadow-lg hover:shadow-xl".to_string());
+        if self.interaction == SelectionInteraction::Prominent
+            && self.state != SelectionState::Disabled
+        {
+            // Prominent items float at rest and lift one step from there
+            let elevation = self.color_provider.elevation_scale();
+            classes.extend(
+                element
+                    .split_whitespace()
+                    .filter(|class| !class.starts_with("hover:shadow"))
+                    .map(String::from),
+            );
+            classes.push(elevation.shadow_class(Elevation::Floating));
+            classes.push(elevation.hover_shadow_class(Elevation::Floating));
+        } else {
+            classes.push(element);
+        }
+        if self.state == SelectionState::Unselected {
+            match self.interaction {
+                SelectionInteraction::Subtle => {}
+                SelectionInteraction::Standard => classes.push(format!(
+                    "hover:{} hover:{}",
+                    self.color_provider.bg_class(Color::Background),
+                    self.color_provider.border_class(Color::Interactive)
+                )),
+                SelectionInteraction::Prominent => classes.push(format!(
+                    "hover:{} hover:{}",
+                    self.color_provider.bg_class(Color::Interactive),
+                    self.color_provider.text_class(Color::TextInverse)
+                )),
             }
         }
 
@@ -558,6 +1388,12 @@ pub fn selection_styles<C: ColorProvider>(color_provider: C) -> SelectionStyles<
     SelectionStyles::new(color_provider)
 }
 
+/// Convenience function to create selection styles from the global theme
+#[cfg(feature = "std")]
+pub fn selection_styles_default() -> SelectionStyles<SharedTheme> {
+    SelectionStyles::new(global_theme())
+}
+
 /// Convenience function to create filter selection styles
 pub fn filter_selection_styles<C: ColorProvider>(color_provider: C) -> SelectionStyles<C> {
     SelectionStyles::new(color_provider)
@@ -587,6 +1423,19 @@ pub fn tab_selection_styles<C: ColorProvider>(color_provider: C) -> SelectionSty
         .standard_interaction()
 }
 
+/// Convenience function to create segmented control styles
+///
+/// A single-selection row of equal-width segments on an inset track, with
+/// the selected segment raised onto the surface color. Use the size
+/// methods (`sm()`, `lg()`, ...) to scale the whole control.
+pub fn segmented_control_styles<C: ColorProvider>(color_provider: C) -> SelectionStyles<C> {
+    SelectionStyles::new(color_provider)
+        .single_selection()
+        .segment_display()
+        .horizontal_layout()
+        .subtle_interaction()
+}
+
 /// One-shot convenience function to create selection classes from strings
 pub fn selection_classes_from_strings<C: ColorProvider + Clone>(
     color_provider: C,
@@ -607,7 +1456,7 @@ pub fn selection_classes_from_strings<C: ColorProvider + Clone>(
         .interaction_str(interaction)
         .with_counts(show_counts);
 
-    (builder.clone().container_classes(), builder.item_classes())
+    (builder.container_classes(), builder.item_classes())
 }
 
 #[cfg(test)]
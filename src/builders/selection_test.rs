@@ -1,15 +1,21 @@
 #[cfg(test)]
 mod tests {
+    use crate::a11y::ControlledIds;
     use crate::builders::selection::{
-        chip_selection_styles, filter_selection_styles, selection_classes_from_strings,
-        selection_styles, tab_selection_styles, SelectionStyles,
+        chip_selection_styles, filter_selection_styles, segmented_control_styles,
+        selection_classes_from_strings, selection_styles, tab_selection_styles, SelectionStyles,
     };
+    use crate::core::{
+        BorderScale, Breakpoint, BreakpointScale, Color, Density, ElevationScale, RadiusScale,
+        SelectionTokens, SizeScale,
+    };
+    use crate::patterns::{ChipLeading, CompositeRole, CountPosition, TabStyle};
     use crate::themes::VibeColors;
 
     #[test]
     fn test_selection_styles_new() {
         let styles = SelectionStyles::new(VibeColors::default());
-        let container_classes = styles.clone().container_classes();
+        let container_classes = styles.container_classes();
         let item_classes = styles.item_classes();
 
         // Default should be single selection, button display, horizontal layout
@@ -22,7 +28,7 @@ mod tests {
         assert!(item_classes.contains("inline-flex"));
         assert!(item_classes.contains("rounded-md"));
         assert!(item_classes.contains("px-4"));
-        assert!(item_classes.contains("py-2"));
+        assert!(!item_classes.contains("py-"));
     }
 
     #[test]
@@ -66,7 +72,7 @@ mod tests {
             .selected()
             .item_classes();
         assert!(item_classes.contains("bg-jupiter-blue-500"));
-        assert!(item_classes.contains("text-white"));
+        assert!(item_classes.contains("text-gray-900"));
         assert!(item_classes.contains("border-jupiter-blue-500"));
 
         // Test partially selected state
@@ -146,9 +152,10 @@ mod tests {
 
         // Test grid layout
         let container_classes = SelectionStyles::new(colors.clone())
-            .grid_layout()
+            .grid_layout(3)
             .container_classes();
         assert!(container_classes.contains("grid"));
+        assert!(container_classes.contains("grid-cols-3"));
 
         // Test dropdown layout
         let container_classes = SelectionStyles::new(colors.clone())
@@ -169,45 +176,45 @@ mod tests {
 
         // Test XS size
         let classes = SelectionStyles::new(colors.clone()).xs();
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("gap-1"));
         assert!(item_classes.contains("px-2"));
-        assert!(item_classes.contains("py-1"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-xs"));
 
         // Test SM size
         let classes = SelectionStyles::new(colors.clone()).sm();
         let item_classes = classes.item_classes();
         assert!(item_classes.contains("px-3"));
-        assert!(item_classes.contains("py-1.5"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-sm"));
 
         // Test MD size (default)
         let classes = SelectionStyles::new(colors.clone()).md();
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("gap-2"));
         assert!(item_classes.contains("px-4"));
-        assert!(item_classes.contains("py-2"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-base"));
 
         // Test LG size
         let classes = SelectionStyles::new(colors.clone()).lg();
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("gap-3"));
         assert!(item_classes.contains("px-6"));
-        assert!(item_classes.contains("py-3"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-lg"));
 
         // Test XL size
         let classes = SelectionStyles::new(colors.clone()).xl();
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("gap-4"));
         assert!(item_classes.contains("px-8"));
-        assert!(item_classes.contains("py-4"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-xl"));
     }
 
@@ -221,7 +228,7 @@ mod tests {
             .unselected()
             .item_classes();
         assert!(item_classes.contains("cursor-pointer"));
-        assert!(item_classes.contains("hover:opacity-80"));
+        assert!(item_classes.contains("hover:shadow-sm"));
 
         // Test standard interaction
         let item_classes = SelectionStyles::new(colors.clone())
@@ -342,21 +349,21 @@ mod tests {
 
         // Test filter_selection_styles function
         let classes = filter_selection_styles(colors.clone());
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("flex-row"));
         assert!(item_classes.contains("rounded-md"));
 
         // Test chip_selection_styles function
         let classes = chip_selection_styles(colors.clone());
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("flex-wrap"));
         assert!(item_classes.contains("rounded-full"));
 
         // Test tab_selection_styles function
         let classes = tab_selection_styles(colors.clone());
-        let container_classes = classes.clone().container_classes();
+        let container_classes = classes.container_classes();
         let item_classes = classes.item_classes();
         assert!(container_classes.contains("flex-row"));
         assert!(item_classes.contains("border-b-2"));
@@ -383,9 +390,9 @@ mod tests {
 
         assert!(item_classes.contains("selection-item"));
         assert!(item_classes.contains("bg-jupiter-blue-500"));
-        assert!(item_classes.contains("text-white"));
+        assert!(item_classes.contains("text-gray-900"));
         assert!(item_classes.contains("px-6"));
-        assert!(item_classes.contains("py-3"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-lg"));
         assert!(item_classes.contains("cursor-pointer"));
     }
@@ -400,7 +407,7 @@ mod tests {
             .with_counts(true)
             .count_classes();
         assert!(count_classes.contains("bg-jupiter-blue-500"));
-        assert!(count_classes.contains("text-white"));
+        assert!(count_classes.contains("text-gray-900"));
 
         // Test count classes with unselected state
         let count_classes = SelectionStyles::new(colors.clone())
@@ -421,7 +428,7 @@ mod tests {
             .xs()
             .item_classes();
         assert!(xs_classes.contains("px-2"));
-        assert!(xs_classes.contains("py-0.5"));
+        assert!(!xs_classes.contains("py-"));
         assert!(xs_classes.contains("text-xs"));
 
         let lg_classes = SelectionStyles::new(colors.clone())
@@ -429,7 +436,7 @@ mod tests {
             .lg()
             .item_classes();
         assert!(lg_classes.contains("px-4"));
-        assert!(lg_classes.contains("py-2"));
+        assert!(!lg_classes.contains("py-"));
         assert!(lg_classes.contains("text-lg"));
     }
 
@@ -461,7 +468,7 @@ mod tests {
             .custom("rounded-xl")
             .custom_classes("shadow-md border-2");
 
-        let container_classes = styles.clone().container_classes();
+        let container_classes = styles.container_classes();
         let item_classes = styles.selected().item_classes();
 
         assert!(container_classes.contains("selection-pattern"));
@@ -474,7 +481,7 @@ mod tests {
         assert!(item_classes.contains("selection-item"));
         assert!(item_classes.contains("rounded-full"));
         assert!(item_classes.contains("px-4"));
-        assert!(item_classes.contains("py-2"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("text-lg"));
         assert!(item_classes.contains("bg-jupiter-blue-500"));
         assert!(item_classes.contains("cursor-pointer"));
@@ -510,7 +517,7 @@ mod tests {
             .size_str("invalid")
             .interaction_str("invalid");
 
-        let container_classes = styles.clone().container_classes();
+        let container_classes = styles.container_classes();
         let item_classes = styles.item_classes();
 
         // Should fallback to single selection, unselected, button, horizontal, md, standard
@@ -518,7 +525,715 @@ mod tests {
         assert!(container_classes.contains("gap-2"));
         assert!(item_classes.contains("rounded-md"));
         assert!(item_classes.contains("px-4"));
-        assert!(item_classes.contains("py-2"));
+        assert!(!item_classes.contains("py-"));
         assert!(item_classes.contains("bg-white"));
     }
+
+    #[test]
+    fn test_selection_density() {
+        let colors = VibeColors::default();
+
+        let compact = SelectionStyles::new(colors.clone()).density(Density::Compact);
+        assert!(compact.item_classes().contains("px-3"));
+        assert!(compact.container_classes().contains("gap-1.5"));
+
+        let chips = chip_selection_styles(colors.with_density(Density::Spacious)).sm();
+        let classes = chips.item_classes();
+        assert!(classes.contains("px-3"));
+        assert!(classes.contains("text-sm"));
+    }
+
+    #[test]
+    fn test_selection_prominent_elevation() {
+        let colors = VibeColors::default();
+
+        let classes = SelectionStyles::new(colors.clone())
+            .prominent_interaction()
+            .item_classes();
+        assert!(classes.contains("shadow-lg"));
+        assert!(classes.contains("hover:shadow-xl"));
+        assert!(!classes.contains("hover:shadow-lg"));
+
+        let classes = SelectionStyles::new(colors.with_elevation(ElevationScale::minimal()))
+            .prominent_interaction()
+            .item_classes();
+        assert!(classes.contains("hover:shadow-md"));
+        assert!(!classes.contains("shadow-lg"));
+    }
+
+    #[test]
+    fn test_selection_radius_scale() {
+        let sharp = VibeColors::default().with_radius(RadiusScale::sharp());
+
+        let button = SelectionStyles::new(sharp.clone()).item_classes();
+        assert!(button.contains("rounded-sm"));
+        assert!(!button.contains("rounded-md"));
+
+        // Chips stay pill-shaped
+        let chip = chip_selection_styles(sharp).item_classes();
+        assert!(chip.contains("rounded-full"));
+    }
+
+    #[test]
+    fn test_suggested_element_and_role() {
+        let colors = VibeColors::default();
+
+        let chip = chip_selection_styles(colors.clone());
+        assert_eq!(chip.suggested_element(), "button");
+        assert_eq!(chip.suggested_role(), None);
+
+        let tab = tab_selection_styles(colors.clone());
+        assert_eq!(tab.suggested_element(), "button");
+        assert_eq!(tab.suggested_role(), Some("tab"));
+
+        let option = selection_styles(colors).dropdown_layout();
+        assert_eq!(option.suggested_element(), "li");
+        assert_eq!(option.suggested_role(), Some("option"));
+    }
+
+    #[test]
+    fn test_control_heights() {
+        let colors = VibeColors::default();
+
+        let button = selection_styles(colors.clone())
+            .button_display()
+            .md()
+            .item_classes();
+        assert!(button.split_whitespace().any(|class| class == "h-10"));
+
+        let chip = chip_selection_styles(colors.clone()).sm().item_classes();
+        assert!(chip.split_whitespace().any(|class| class == "h-8"));
+
+        let touch = selection_styles(colors.with_sizes(SizeScale::touch()))
+            .button_display()
+            .md()
+            .item_classes();
+        assert!(touch.split_whitespace().any(|class| class == "h-12"));
+    }
+
+    #[test]
+    fn test_border_scale_from_theme() {
+        let bold = VibeColors::default().with_borders(BorderScale::bold());
+
+        let tab = tab_selection_styles(VibeColors::default()).item_classes();
+        assert!(tab.contains("border-b-2"));
+
+        let bold_tab = tab_selection_styles(bold.clone()).item_classes();
+        assert!(bold_tab.contains("border-b-4"));
+
+        let card = SelectionStyles::new(bold).card_display().item_classes();
+        assert!(card.split_whitespace().any(|class| class == "border-2"));
+    }
+
+    #[test]
+    fn test_group_parts() {
+        let group = chip_selection_styles(VibeColors::default());
+
+        let clear_all = group.clear_all_classes();
+        assert!(clear_all.contains("selection-clear-all"));
+        assert!(clear_all.contains("text-jupiter-blue-500"));
+        assert!(clear_all.contains("hover:text-jupiter-blue-600"));
+
+        let label = group.group_label_classes();
+        assert!(label.contains("font-semibold"));
+        assert!(label.contains("text-gray-900"));
+
+        let summary = group.selected_summary_classes();
+        assert!(summary.contains("tabular-nums"));
+        assert!(summary.contains("text-gray-600"));
+    }
+
+    #[test]
+    fn test_clear_all_requires_opt_in() {
+        let group = SelectionStyles::new(VibeColors::default());
+        assert!(group.clear_all_classes().is_empty());
+
+        let large = group.with_clear_all(true).lg();
+        assert!(large.clear_all_classes().contains("text-base"));
+        assert!(large.group_label_classes().contains("text-base"));
+    }
+
+    fn grid_col_classes(classes: &str) -> Vec<&str> {
+        classes
+            .split_whitespace()
+            .filter(|class| class.contains("grid-cols-"))
+            .collect()
+    }
+
+    fn is_valid_grid_cols(class: &str) -> bool {
+        let utility = class.rsplit(':').next().unwrap();
+        let value = utility.trim_start_matches("grid-cols-");
+        match value.parse::<u8>() {
+            Ok(cols) => (1..=12).contains(&cols),
+            Err(_) => value == "[repeat(auto-fill,minmax(10rem,1fr))]",
+        }
+    }
+
+    #[test]
+    fn test_responsive_grid_columns() {
+        let classes = SelectionStyles::new(VibeColors::default())
+            .lg_cols(6)
+            .grid_cols(2)
+            .md_cols(4)
+            .container_classes();
+
+        let mut cols = grid_col_classes(&classes);
+        cols.sort();
+        assert_eq!(
+            cols,
+            vec!["grid-cols-2", "lg:grid-cols-6", "md:grid-cols-4"]
+        );
+        assert!(classes.split_whitespace().any(|class| class == "grid"));
+    }
+
+    #[test]
+    fn test_grid_produces_only_valid_column_classes() {
+        let colors = VibeColors::default();
+        let configurations = vec![
+            SelectionStyles::new(colors.clone()).grid_layout(3),
+            SelectionStyles::new(colors.clone()).auto_grid_layout(),
+            SelectionStyles::new(colors.clone()).layout_str("grid"),
+            SelectionStyles::new(colors.clone())
+                .grid_cols(0)
+                .md_cols(40),
+            SelectionStyles::new(colors.clone())
+                .grid_cols(1)
+                .cols_at(Breakpoint::Large, 8),
+        ];
+
+        for styles in configurations {
+            let classes = styles.container_classes();
+            let cols = grid_col_classes(&classes);
+            assert!(!cols.is_empty(), "{classes}");
+            assert!(!classes.contains("grid-cols-auto"));
+            for class in cols {
+                assert!(is_valid_grid_cols(class), "{class}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_columns_follow_theme_breakpoints() {
+        let colors = VibeColors::default().with_breakpoints(BreakpointScale {
+            tablet: 600,
+            ..BreakpointScale::default()
+        });
+        let classes = SelectionStyles::new(colors)
+            .grid_cols(1)
+            .md_cols(3)
+            .container_classes();
+
+        assert!(classes.contains("min-[600px]:grid-cols-3"));
+    }
+
+    #[test]
+    fn test_underline_tabs() {
+        let colors = VibeColors::default();
+        let active = tab_selection_styles(colors.clone()).selected();
+        let inactive = tab_selection_styles(colors.clone());
+
+        let active_classes = active.item_classes();
+        assert!(active_classes.contains("border-b-2"));
+        assert!(active_classes.contains("border-jupiter-blue-500"));
+        assert!(active_classes.contains("text-jupiter-blue-500"));
+        assert!(!active_classes.contains("bg-jupiter-blue-500"));
+        assert!(inactive.item_classes().contains("border-transparent"));
+
+        let list = inactive.container_classes();
+        assert!(list.split_whitespace().any(|class| class == "border-b"));
+    }
+
+    #[test]
+    fn test_tab_style_variants() {
+        let colors = VibeColors::default();
+
+        let pill = tab_selection_styles(colors.clone())
+            .pill_tabs()
+            .selected()
+            .item_classes();
+        assert!(pill.contains("rounded-full"));
+        assert!(pill.contains("bg-jupiter-blue-500"));
+        assert!(!pill.contains("border-b-2"));
+
+        let animated = tab_selection_styles(colors.clone()).animated_underline_tabs();
+        assert!(animated
+            .clone()
+            .selected()
+            .item_classes()
+            .contains("after:scale-x-100"));
+        assert!(animated.item_classes().contains("after:scale-x-0"));
+
+        let enclosed = tab_selection_styles(colors.clone())
+            .tab_style(TabStyle::Enclosed)
+            .selected()
+            .item_classes();
+        assert!(enclosed.contains("rounded-b-none"));
+        assert!(enclosed.contains("border-b-transparent"));
+
+        let from_str = tab_selection_styles(colors.clone()).tab_style_str("boxed");
+        assert_eq!(
+            from_str.item_classes(),
+            tab_selection_styles(colors).enclosed_tabs().item_classes()
+        );
+    }
+
+    #[test]
+    fn test_vertical_tabs() {
+        let colors = VibeColors::default();
+        let vertical = tab_selection_styles(colors.clone()).vertical_layout();
+
+        let item = vertical.clone().selected().item_classes();
+        assert!(item.contains("border-l-2"));
+        assert!(!item.contains("border-b-2"));
+        assert!(vertical
+            .clone()
+            .container_classes()
+            .split_whitespace()
+            .any(|class| class == "border-l"));
+
+        let animated = vertical.animated_underline_tabs().selected().item_classes();
+        assert!(animated.contains("after:w-0.5"));
+        assert!(animated.contains("after:scale-y-100"));
+    }
+
+    #[test]
+    fn test_segmented_control_styles() {
+        let colors = VibeColors::default();
+        let segmented = segmented_control_styles(colors.clone());
+
+        let container = segmented.container_classes();
+        assert!(container.contains("inline-flex"));
+        assert!(container.contains("p-1"));
+        assert!(container.contains("gap-1"));
+        assert!(container.contains("bg-gray-50"));
+        assert!(!container.contains("gap-2"));
+
+        let selected = segmented.clone().selected().item_classes();
+        assert!(selected.contains("flex-1"));
+        assert!(selected.contains("basis-0"));
+        assert!(selected.contains("bg-white"));
+        assert!(selected.contains("shadow-sm"));
+        assert!(selected.contains("text-gray-900"));
+        assert!(!selected.contains("scale"));
+
+        let unselected = segmented.item_classes();
+        assert!(unselected.contains("bg-transparent"));
+        assert!(unselected.contains("text-gray-600"));
+        assert!(!unselected.contains("shadow-sm"));
+
+        assert_eq!(segmented.suggested_role(), Some("radio"));
+        assert_eq!(
+            selection_styles(colors.clone())
+                .display_str("segmented")
+                .item_classes(),
+            selection_styles(colors).segment_display().item_classes()
+        );
+    }
+
+    #[test]
+    fn test_segmented_control_sizes() {
+        let colors = VibeColors::default();
+        let small = segmented_control_styles(colors.clone()).sm().item_classes();
+        let large = segmented_control_styles(colors).lg().item_classes();
+
+        assert!(small.contains("text-xs"));
+        assert!(large.contains("text-base"));
+        assert_ne!(small, large);
+    }
+
+    #[test]
+    fn test_toggle_pressed_appearance() {
+        let colors = VibeColors::default();
+        let toggle = selection_styles(colors.clone())
+            .toggle_selection()
+            .selected()
+            .item_classes();
+        let multiple = selection_styles(colors)
+            .multiple_selection()
+            .selected()
+            .item_classes();
+
+        assert_ne!(toggle, multiple);
+        assert!(toggle.contains("shadow-inner"));
+        assert!(toggle.contains("bg-jupiter-blue-700"));
+        assert!(!multiple.contains("shadow-inner"));
+    }
+
+    #[test]
+    fn test_item_aria_attributes() {
+        let colors = VibeColors::default();
+
+        let toggle = selection_styles(colors.clone())
+            .toggle_selection()
+            .selected()
+            .item_aria_attributes();
+        assert_eq!(toggle, vec![("aria-pressed", "true".to_string())]);
+
+        let tab = tab_selection_styles(colors.clone()).item_aria_attributes();
+        assert!(tab.contains(&("role", "tab".to_string())));
+        assert!(tab.contains(&("aria-selected", "false".to_string())));
+
+        let segment = segmented_control_styles(colors.clone())
+            .selected()
+            .item_aria_attributes();
+        assert!(segment.contains(&("role", "radio".to_string())));
+        assert!(segment.contains(&("aria-checked", "true".to_string())));
+
+        let disabled = selection_styles(colors.clone())
+            .disabled()
+            .item_aria_attributes();
+        assert!(disabled.contains(&("aria-disabled", "true".to_string())));
+
+        assert!(selection_styles(colors)
+            .no_selection()
+            .item_aria_attributes()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_selected_color_overrides_primary() {
+        let colors = VibeColors::default();
+
+        let accent = chip_selection_styles(colors.clone())
+            .selected()
+            .selected_color(Color::Accent)
+            .with_counts(true);
+        let classes = accent.item_classes();
+        assert!(classes.contains("bg-jupiter-orange-500"));
+        assert!(classes.contains("border-jupiter-orange-500"));
+        assert!(!classes.contains("jupiter-blue-500 "));
+        assert!(accent.count_classes().contains("bg-jupiter-orange-500"));
+
+        let partial = selection_styles(colors.clone())
+            .partially_selected()
+            .selected_color(Color::Success)
+            .item_classes();
+        assert!(partial.contains("text-green-500"));
+        assert!(partial.contains("border-green-500"));
+
+        // Text follows the fill's contrast
+        let warning = selection_styles(colors.clone())
+            .selected()
+            .selected_color(Color::Warning)
+            .item_classes();
+        assert!(warning.contains("text-gray-900"));
+
+        // Default selection still fills with primary, with dark text
+        let primary = selection_styles(colors).selected().item_classes();
+        assert!(primary.contains("bg-jupiter-blue-500"));
+        assert!(primary.contains("text-gray-900"));
+    }
+
+    #[test]
+    fn test_theme_selection_tokens() {
+        let colors = VibeColors::default().with_selection(SelectionTokens {
+            selected_bg: Some(Color::Accent),
+            selected_text: Some(Color::TextInverse),
+            selected_border: Some(Color::Border),
+        });
+
+        let item = selection_styles(colors.clone()).selected().item_classes();
+        assert!(item.contains("bg-jupiter-orange-500"));
+        assert!(item.contains("text-white"));
+        assert!(item.contains("border-gray-200"));
+
+        // Builder overrides win over the theme
+        let tab_like = selection_styles(colors.clone())
+            .selected()
+            .selected_color(Color::Primary)
+            .item_classes();
+        assert!(tab_like.contains("bg-jupiter-blue-500"));
+        assert!(tab_like.contains("border-jupiter-blue-500"));
+
+        // Tabs keep their own indicator
+        let tab = tab_selection_styles(colors).selected().item_classes();
+        assert!(!tab.contains("jupiter-orange"));
+    }
+
+    #[test]
+    fn test_tab_attributes_wire_panel() {
+        let colors = VibeColors::default();
+        let ids = ControlledIds::new("tabs", "usage");
+
+        let selected = tab_selection_styles(colors.clone()).selected();
+        let tab = selected.tab_attributes(&ids, true);
+        assert!(tab.contains(&("id", "tabs-usage-trigger".to_string())));
+        assert!(tab.contains(&("aria-controls", "tabs-usage-panel".to_string())));
+        assert!(tab.contains(&("role", "tab".to_string())));
+        assert!(tab.contains(&("aria-selected", "true".to_string())));
+        assert!(tab.contains(&("tabindex", "0".to_string())));
+        assert_eq!(tab.iter().filter(|(name, _)| *name == "role").count(), 1);
+
+        let panel = selected.tab_panel_attributes(&ids);
+        assert!(panel.contains(&("id", "tabs-usage-panel".to_string())));
+        assert!(panel.contains(&("aria-labelledby", "tabs-usage-trigger".to_string())));
+        assert!(panel.contains(&("role", "tabpanel".to_string())));
+        assert!(!panel.iter().any(|(name, _)| *name == "hidden"));
+
+        let unselected = tab_selection_styles(colors);
+        assert!(unselected
+            .tab_attributes(&ids, false)
+            .contains(&("tabindex", "-1".to_string())));
+        assert!(unselected
+            .tab_panel_attributes(&ids)
+            .contains(&("hidden", "true".to_string())));
+    }
+
+    #[test]
+    fn test_check_icon_slot() {
+        let colors = VibeColors::default();
+        assert_eq!(
+            selection_styles(colors.clone())
+                .selected()
+                .check_icon_classes(),
+            ""
+        );
+
+        let with_icon = selection_styles(colors).with_check_icon(true);
+        assert!(with_icon.item_classes().contains("gap-1.5"));
+        assert_eq!(with_icon.check_icon_classes(), "selection-check hidden");
+        assert!(with_icon
+            .clone()
+            .selected()
+            .check_icon_classes()
+            .contains("w-4 h-4"));
+        assert!(with_icon
+            .sm()
+            .selected()
+            .check_icon_classes()
+            .contains("w-3 h-3"));
+    }
+
+    #[test]
+    fn test_dismissible_chip() {
+        let colors = VibeColors::default();
+        let plain = chip_selection_styles(colors.clone());
+        assert_eq!(plain.remove_button_classes(), "");
+        assert_eq!(plain.chip_classes(), plain.item_classes());
+
+        let chip = chip_selection_styles(colors).dismissible_chip();
+        assert!(chip.chip_classes().contains("pr-1"));
+
+        let remove = chip.remove_button_classes();
+        assert!(remove.contains("w-5 h-5"));
+        assert!(remove.contains("hover:bg-gray-200"));
+        assert!(remove.contains("focus-visible:ring-jupiter-blue-500"));
+        assert!(remove.contains("after:-inset-1.5"));
+
+        assert!(chip
+            .clone()
+            .selected()
+            .remove_button_classes()
+            .contains("hover:bg-gray-900/20"));
+        assert!(chip.xs().remove_button_classes().contains("w-3.5 h-3.5"));
+    }
+
+    #[test]
+    fn test_chip_leading_slot() {
+        let colors = VibeColors::default();
+        assert_eq!(
+            chip_selection_styles(colors.clone()).leading_slot_classes(),
+            ""
+        );
+
+        let icon = chip_selection_styles(colors.clone()).with_chip_leading(ChipLeading::Icon);
+        assert!(icon.leading_slot_classes().contains("w-4 h-4"));
+        assert!(icon.chip_classes().contains("gap-1.5"));
+        assert!(!icon.chip_classes().contains("pl-1"));
+
+        let avatar = chip_selection_styles(colors).with_chip_leading(ChipLeading::Avatar);
+        assert!(avatar.leading_slot_classes().contains("rounded-full"));
+        assert!(avatar.chip_classes().contains("pl-1"));
+    }
+
+    #[test]
+    fn test_count_label_overflow() {
+        let colors = VibeColors::default();
+        let plain = selection_styles(colors.clone()).with_counts(true);
+        assert_eq!(plain.count_label(10_000), "10000");
+
+        let capped = plain.clone().max_count(99);
+        assert_eq!(capped.count_label(42), "42");
+        assert_eq!(capped.count_label(99), "99");
+        assert_eq!(capped.count_label(100), "99+");
+
+        let compact = plain.compact_counts(true);
+        assert_eq!(compact.count_label(999), "999");
+        assert_eq!(compact.count_label(1_000), "1k");
+        assert_eq!(compact.count_label(1_250), "1.2k");
+        assert_eq!(compact.count_label(10_000), "10k");
+        assert_eq!(compact.count_label(2_500_000), "2.5M");
+        assert_eq!(
+            compact.clone().max_count(9_999).count_label(12_000),
+            "9.9k+"
+        );
+    }
+
+    #[test]
+    fn test_count_position_and_truncation() {
+        let colors = VibeColors::default();
+        let inline = selection_styles(colors.clone()).with_counts(true);
+        assert!(inline.count_classes().contains("tabular-nums"));
+        assert!(!inline.count_classes().contains("truncate"));
+        assert!(!inline.item_classes().contains("relative"));
+
+        let corner = inline.clone().corner_counts();
+        let classes = corner.count_classes();
+        assert!(classes.contains("absolute -top-2 -right-2"));
+        assert!(classes.contains("ring-white"));
+        assert!(!classes.contains("ml-2"));
+        assert!(corner.item_classes().contains("relative"));
+        assert_eq!(
+            corner.count_classes(),
+            inline
+                .clone()
+                .count_position(CountPosition::Corner)
+                .count_classes()
+        );
+
+        let truncated = inline.count_max_width(4).count_classes();
+        assert!(truncated.contains("max-w-[4ch] truncate"));
+    }
+
+    #[test]
+    fn test_interaction_scales_follow_motion_tokens() {
+        use crate::core::motion::is_valid_scale_class;
+        use crate::core::MotionScale;
+
+        let colors = VibeColors::default().with_motion(MotionScale {
+            hover: 110,
+            press: 97,
+            ..MotionScale::default()
+        });
+        let item_classes = SelectionStyles::new(colors)
+            .standard_interaction()
+            .unselected()
+            .item_classes();
+
+        assert!(item_classes.contains("hover:scale-110"));
+        // 97% is not in Tailwind's default theme and snaps to 95%
+        assert!(item_classes.contains("active:scale-95"));
+        assert!(item_classes.split_whitespace().all(is_valid_scale_class));
+    }
+
+    #[test]
+    fn test_class_getters_borrow() {
+        let styles = selection_styles(VibeColors::default())
+            .multiple_selection()
+            .chip_display();
+
+        // Getters borrow, so the same builder renders repeatedly without clones
+        let first = styles.container_classes();
+        assert_eq!(first, styles.container_classes());
+        assert_eq!(styles.item_classes(), styles.item_classes());
+        assert_eq!(styles.chip_classes(), styles.chip_classes());
+    }
+
+    #[test]
+    fn test_composite_focus() {
+        let colors = VibeColors::default();
+
+        let tabs = tab_selection_styles(colors.clone());
+        assert_eq!(tabs.composite_role(), Some(CompositeRole::Tablist));
+        let container = tabs.container_aria_attributes();
+        assert!(container.contains(&("role", "tablist".to_string())));
+        assert!(container.contains(&("aria-orientation", "horizontal".to_string())));
+        assert_eq!(
+            tabs.item_focus_attributes(true),
+            vec![("tabindex", "0".to_string())]
+        );
+        assert_eq!(
+            tabs.item_focus_attributes(false),
+            vec![("tabindex", "-1".to_string())]
+        );
+        assert!(tabs
+            .item_classes()
+            .split_whitespace()
+            .any(|class| class == "focus-visible:ring-jupiter-blue-300"));
+
+        let vertical_tabs = tab_selection_styles(colors.clone()).vertical_layout();
+        assert!(vertical_tabs
+            .container_aria_attributes()
+            .contains(&("aria-orientation", "vertical".to_string())));
+
+        let listbox = SelectionStyles::new(colors.clone())
+            .multiple_selection()
+            .list_item_display();
+        assert_eq!(listbox.composite_role(), Some(CompositeRole::Listbox));
+        let container = listbox.container_aria_attributes();
+        assert!(container.contains(&("role", "listbox".to_string())));
+        assert!(container.contains(&("aria-orientation", "vertical".to_string())));
+        assert!(container.contains(&("aria-multiselectable", "true".to_string())));
+
+        let buttons = SelectionStyles::new(colors).button_display();
+        assert_eq!(buttons.composite_role(), None);
+        assert!(buttons.container_aria_attributes().is_empty());
+        assert!(buttons.item_focus_attributes(true).is_empty());
+        assert!(!buttons.item_classes().contains("focus-visible:"));
+    }
+
+    #[test]
+    fn test_interaction_matches_button_intensity() {
+        use crate::patterns::{InteractionIntensity, InteractiveElement};
+
+        let colors = VibeColors::default();
+        let cases = [
+            (
+                SelectionStyles::new(&colors).subtle_interaction(),
+                InteractionIntensity::Gentle,
+            ),
+            (
+                SelectionStyles::new(&colors).standard_interaction(),
+                InteractionIntensity::Standard,
+            ),
+            (
+                SelectionStyles::new(&colors).prominent_interaction(),
+                InteractionIntensity::Prominent,
+            ),
+        ];
+
+        for (selection, intensity) in cases {
+            let classes = selection.selected().item_classes();
+            let element = InteractiveElement::new(&colors)
+                .hoverable()
+                .pressable()
+                .intensity(intensity)
+                .classes();
+            // Prominent items replace the hover shadow with their own elevation
+            for class in element.split_whitespace().filter(|class| {
+                intensity != InteractionIntensity::Prominent || !class.starts_with("hover:shadow")
+            }) {
+                assert!(
+                    classes.split_whitespace().any(|c| c == class),
+                    "{} missing from {}",
+                    class,
+                    classes
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_item_focus_behavior() {
+        use crate::patterns::FocusBehavior;
+
+        let colors = VibeColors::default();
+        let chips = SelectionStyles::new(&colors).chip_display().item_classes();
+        assert!(chips.contains("focus:ring-2"));
+
+        let subtle = SelectionStyles::new(&colors)
+            .chip_display()
+            .focus_behavior(FocusBehavior::Subtle)
+            .item_classes();
+        assert!(subtle.contains("focus:ring-1"));
+        assert!(!subtle.contains("focus:ring-2"));
+
+        // Tabs keep the composite focus-visible ring
+        let tabs = SelectionStyles::new(&colors)
+            .tab_display()
+            .focus_behavior(FocusBehavior::Subtle)
+            .item_classes();
+        assert!(tabs.contains("focus-visible:ring-2"));
+        assert!(!tabs.contains("focus:ring-1"));
+    }
 }
@@ -0,0 +1,275 @@
+//! Split pane styling utilities for the Jupiter Design System
+//!
+//! Provides classes for editor-like layouts where two panes share a
+//! container and a handle between them resizes the split. The adapter owns
+//! the pane size (as a flex basis on the start pane) and pointer or
+//! keyboard resizing; the design system styles the panes, the handle's
+//! hit area and states, minimum pane sizes, and collapsed panes.
+
+use crate::core::color::ColorProvider;
+use crate::core::{Color, MotionDuration, MotionProvider};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Direction the panes are laid out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SplitOrientation {
+    /// Panes side by side, resized by a vertical handle
+    Horizontal,
+    /// Panes stacked, resized by a horizontal handle
+    Vertical,
+}
+
+/// One of the two panes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SplitPaneSide {
+    /// Left (or top) pane, mirrored in right-to-left layouts
+    Start,
+    /// Right (or bottom) pane
+    End,
+}
+
+/// Split pane styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::split_pane::{SplitPaneSide, SplitPaneStyles};
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let editor = SplitPaneStyles::new(VibeColors::default()).min_size("64");
+///
+/// assert!(editor.root_classes().contains("flex-row"));
+/// assert!(editor.pane_classes(SplitPaneSide::Start).contains("min-w-64"));
+/// assert!(editor.handle_classes().contains("cursor-col-resize"));
+/// assert!(editor
+///     .handle_attributes(30, 20, 80)
+///     .contains(&("aria-orientation", "vertical".to_string())));
+///
+/// let collapsed = editor.collapse(SplitPaneSide::Start);
+/// assert!(collapsed.pane_classes(SplitPaneSide::Start).contains("invisible"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplitPaneStyles<C: ColorProvider> {
+    orientation: SplitOrientation,
+    min_size: Option<&'static str>,
+    collapsed: Option<SplitPaneSide>,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> SplitPaneStyles<C> {
+    /// Create a new split pane styling utility (side-by-side panes)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            orientation: SplitOrientation::Horizontal,
+            min_size: None,
+            collapsed: None,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Orientation Methods ===
+
+    /// Lay panes out side by side
+    pub fn horizontal(mut self) -> Self {
+        self.orientation = SplitOrientation::Horizontal;
+        self
+    }
+
+    /// Stack panes on top of each other
+    pub fn vertical(mut self) -> Self {
+        self.orientation = SplitOrientation::Vertical;
+        self
+    }
+
+    /// Set orientation explicitly
+    pub fn orientation(mut self, orientation: SplitOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    // === Size Methods ===
+
+    /// Set the minimum pane size as a spacing scale value, e.g. "64"
+    ///
+    /// Defaults to "48" (12rem) for side-by-side panes and "24" (6rem) for
+    /// stacked panes. Adapters should stop resizing at the same size.
+    pub fn min_size(mut self, size: &'static str) -> Self {
+        self.min_size = Some(size);
+        self
+    }
+
+    // === Collapse Methods ===
+
+    /// Collapse a pane so the other fills the container
+    pub fn collapse(mut self, side: SplitPaneSide) -> Self {
+        self.collapsed = Some(side);
+        self
+    }
+
+    /// Show both panes
+    pub fn expand(mut self) -> Self {
+        self.collapsed = None;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the root
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the container holding both panes and the handle
+    pub fn root_classes(&self) -> String {
+        let direction = match self.orientation {
+            SplitOrientation::Horizontal => "flex-row",
+            SplitOrientation::Vertical => "flex-col",
+        };
+        let mut classes = vec![format!(
+            "split-pane split-pane--{} flex {} w-full h-full overflow-hidden",
+            self.orientation_name(),
+            direction
+        )];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for a pane
+    ///
+    /// The start pane keeps the size the adapter gives it and the end pane
+    /// fills the rest. A collapsed pane shrinks to nothing and is hidden
+    /// from focus and assistive technology, while the other pane fills the
+    /// container.
+    pub fn pane_classes(&self, side: SplitPaneSide) -> String {
+        let axis = self.axis();
+        if self.collapsed == Some(side) {
+            return format!(
+                "split-pane-pane split-pane-pane--collapsed shrink-0 basis-0 {}-0 min-{}-0 overflow-hidden invisible",
+                axis, axis
+            );
+        }
+
+        let sizing = if self.collapsed.is_some() || side == SplitPaneSide::End {
+            "flex-1"
+        } else {
+            "shrink-0"
+        };
+        format!(
+            "split-pane-pane relative {} min-{}-{} overflow-auto",
+            sizing,
+            axis,
+            self.resolved_min_size()
+        )
+    }
+
+    /// Build classes for the resize handle
+    ///
+    /// The visible line is one pixel wide; a transparent pseudo-element
+    /// widens the pointer hit area without shifting the layout. Adapters
+    /// set `data-dragging="true"` while a resize is in progress.
+    pub fn handle_classes(&self) -> String {
+        let (line, cursor, hit_area) = match self.orientation {
+            SplitOrientation::Horizontal => (
+                "w-px self-stretch",
+                "cursor-col-resize",
+                "before:inset-y-0 before:-inset-x-1.5",
+            ),
+            SplitOrientation::Vertical => (
+                "h-px w-full",
+                "cursor-row-resize",
+                "before:inset-x-0 before:-inset-y-1.5",
+            ),
+        };
+        let active = self.color_provider.bg_class(Color::Primary);
+        let mut classes = vec![format!(
+            "split-pane-handle relative z-10 shrink-0 {} {} touch-none select-none {} before:absolute {} before:content-['']",
+            line,
+            cursor,
+            self.color_provider.bg_class(Color::Border),
+            hit_area
+        )];
+        classes.push(format!(
+            "hover:{} active:{} data-[dragging=true]:{} transition-colors {} motion-reduce:transition-none",
+            active,
+            active,
+            active,
+            self.color_provider
+                .motion_scale()
+                .duration_class(MotionDuration::Fast)
+        ));
+        classes.push(format!(
+            "focus-visible:outline-none focus-visible:{} focus-visible:ring-2 focus-visible:ring-{}",
+            active,
+            self.color_provider.resolve_color(Color::Primary)
+        ));
+        if self.collapsed.is_some() {
+            classes.push("split-pane-handle--collapsed".to_string());
+        }
+        classes.join(" ")
+    }
+
+    /// Get attributes for the resize handle
+    ///
+    /// Values are the start pane's share of the container in percent. The
+    /// handle is a focusable separator, so arrow keys can resize it; its
+    /// orientation is the line's, across the split direction. A collapsed
+    /// pane widens the range to include it.
+    pub fn handle_attributes(&self, value: u8, min: u8, max: u8) -> Vec<(&'static str, String)> {
+        let (value, min, max) = match self.collapsed {
+            Some(SplitPaneSide::Start) => (0, 0, max),
+            Some(SplitPaneSide::End) => (100, min, 100),
+            None => (value.clamp(min, max), min, max),
+        };
+        let line = match self.orientation {
+            SplitOrientation::Horizontal => "vertical",
+            SplitOrientation::Vertical => "horizontal",
+        };
+        vec![
+            ("role", "separator".to_string()),
+            ("tabindex", "0".to_string()),
+            ("aria-orientation", line.to_string()),
+            ("aria-valuenow", value.to_string()),
+            ("aria-valuemin", min.to_string()),
+            ("aria-valuemax", max.to_string()),
+        ]
+    }
+
+    fn orientation_name(&self) -> &'static str {
+        match self.orientation {
+            SplitOrientation::Horizontal => "horizontal",
+            SplitOrientation::Vertical => "vertical",
+        }
+    }
+
+    /// Size axis the panes share
+    fn axis(&self) -> &'static str {
+        match self.orientation {
+            SplitOrientation::Horizontal => "w",
+            SplitOrientation::Vertical => "h",
+        }
+    }
+
+    fn resolved_min_size(&self) -> &'static str {
+        self.min_size.unwrap_or(match self.orientation {
+            SplitOrientation::Horizontal => "48",
+            SplitOrientation::Vertical => "24",
+        })
+    }
+}
+
+/// Convenience function to create split pane styles
+pub fn split_pane_styles<C: ColorProvider>(color_provider: C) -> SplitPaneStyles<C> {
+    SplitPaneStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "split_pane_test.rs"]
+mod split_pane_test;
@@ -0,0 +1,102 @@
+//! Tests for split pane styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::split_pane::{
+        split_pane_styles, SplitOrientation, SplitPaneSide, SplitPaneStyles,
+    };
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_horizontal_panes() {
+        let split = split_pane_styles(VibeColors::default());
+
+        assert_eq!(
+            split.root_classes(),
+            "split-pane split-pane--horizontal flex flex-row w-full h-full overflow-hidden"
+        );
+        assert_eq!(
+            split.pane_classes(SplitPaneSide::Start),
+            "split-pane-pane relative shrink-0 min-w-48 overflow-auto"
+        );
+        assert_eq!(
+            split.pane_classes(SplitPaneSide::End),
+            "split-pane-pane relative flex-1 min-w-48 overflow-auto"
+        );
+
+        let handle = split.handle_classes();
+        assert!(handle.contains("w-px self-stretch cursor-col-resize"));
+        assert!(handle.contains("bg-gray-200"));
+        assert!(handle.contains("before:inset-y-0 before:-inset-x-1.5"));
+        assert!(handle.contains("hover:bg-jupiter-blue-500"));
+        assert!(handle.contains("data-[dragging=true]:bg-jupiter-blue-500"));
+        assert!(handle.contains("focus-visible:ring-jupiter-blue-500"));
+    }
+
+    #[test]
+    fn test_vertical_panes() {
+        let split = SplitPaneStyles::new(VibeColors::default()).vertical();
+
+        assert!(split.root_classes().contains("flex-col"));
+        assert!(split.pane_classes(SplitPaneSide::End).contains("min-h-24"));
+        assert!(split
+            .handle_classes()
+            .contains("h-px w-full cursor-row-resize"));
+        assert!(split
+            .handle_attributes(50, 10, 90)
+            .contains(&("aria-orientation", "horizontal".to_string())));
+
+        let custom = SplitPaneStyles::new(VibeColors::default())
+            .orientation(SplitOrientation::Vertical)
+            .min_size("40")
+            .custom("editor");
+        assert!(custom
+            .pane_classes(SplitPaneSide::Start)
+            .contains("min-h-40"));
+        assert!(custom.root_classes().ends_with("editor"));
+    }
+
+    #[test]
+    fn test_collapsed_pane() {
+        let split = SplitPaneStyles::new(VibeColors::default()).collapse(SplitPaneSide::Start);
+
+        let start = split.pane_classes(SplitPaneSide::Start);
+        assert!(start.contains("split-pane-pane--collapsed"));
+        assert!(start.contains("basis-0 w-0 min-w-0 overflow-hidden invisible"));
+
+        let end = split.pane_classes(SplitPaneSide::End);
+        assert!(end.contains("flex-1"));
+        assert!(split
+            .handle_classes()
+            .contains("split-pane-handle--collapsed"));
+        let attrs = split.handle_attributes(40, 20, 80);
+        assert!(attrs.contains(&("aria-valuenow", "0".to_string())));
+        assert!(attrs.contains(&("aria-valuemin", "0".to_string())));
+
+        let expanded = split.expand();
+        assert!(!expanded
+            .pane_classes(SplitPaneSide::Start)
+            .contains("invisible"));
+    }
+
+    #[test]
+    fn test_handle_attributes() {
+        let split = SplitPaneStyles::new(VibeColors::default());
+
+        assert_eq!(
+            split.handle_attributes(95, 20, 80),
+            vec![
+                ("role", "separator".to_string()),
+                ("tabindex", "0".to_string()),
+                ("aria-orientation", "vertical".to_string()),
+                ("aria-valuenow", "80".to_string()),
+                ("aria-valuemin", "20".to_string()),
+                ("aria-valuemax", "80".to_string()),
+            ]
+        );
+        assert!(split
+            .collapse(SplitPaneSide::End)
+            .handle_attributes(50, 20, 80)
+            .contains(&("aria-valuenow", "100".to_string())));
+    }
+}
@@ -3,10 +3,20 @@
 //! Provides a chainable API for building state CSS classes and configuration
 //! that can be used with any component library or framework.
 
+use crate::builders::button::ButtonStyles;
+use crate::builders::text::TextStyles;
 use crate::core::color::ColorProvider;
+use crate::core::{Color, MotionDuration, MotionProvider, Radius, RadiusProvider};
 use crate::patterns::{
-    LoadingVariant, StateActionRequirement, StateAlignment, StateIntent, StateProminence, StateSize,
+    Announcer, LoadingVariant, StateActionRequirement, StateAlignment, StateDisplay, StateIntent,
+    StateMotion, StateProminence, StateSize,
 };
+#[cfg(feature = "std")]
+use crate::themes::{global_theme, SharedTheme};
+use crate::utils::{class_diff, ClassDiff};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 /// State styling utility builder
 ///
@@ -27,14 +37,17 @@ use crate::patterns::{
 ///     .md()
 ///     .classes();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StateStyles<C: ColorProvider> {
     intent: StateIntent,
     prominence: StateProminence,
     size: StateSize,
+    display: StateDisplay,
+    motion: StateMotion,
     alignment: StateAlignment,
     action_requirement: StateActionRequirement,
     loading_variant: Option<LoadingVariant>,
+    status: Option<String>,
     fullscreen: bool,
     custom_classes: Vec<String>,
     color_provider: C,
@@ -47,9 +60,12 @@ impl<C: ColorProvider> StateStyles<C> {
             intent: StateIntent::Informational,
             prominence: StateProminence::Standard,
             size: StateSize::MD,
+            display: StateDisplay::Section,
+            motion: StateMotion::Fade,
             alignment: StateAlignment::Center,
             action_requirement: StateActionRequirement::None,
             loading_variant: None,
+            status: None,
             fullscreen: false,
             custom_classes: Vec::new(),
             color_provider,
@@ -94,6 +110,16 @@ impl<C: ColorProvider> StateStyles<C> {
         self
     }
 
+    /// Color the state with a named status from the palette
+    ///
+    /// Takes a custom status registered on the palette (e.g. "pending") or
+    /// one of "success", "warning", "error" and "info". The status colors
+    /// replace the intent colors; unknown statuses keep the intent colors.
+    pub fn status(mut self, name: impl Into<String>) -> Self {
+        self.status = Some(name.into());
+        self
+    }
+
     // === Prominence Methods ===
 
     /// Set subtle prominence
@@ -146,6 +172,52 @@ impl<C: ColorProvider> StateStyles<C> {
         self
     }
 
+    // === Display Methods ===
+
+    /// Show the state as a full section block (default)
+    pub fn section(mut self) -> Self {
+        self.display = StateDisplay::Section;
+        self
+    }
+
+    /// Show the state as a compact line with the icon beside the text
+    pub fn inline(mut self) -> Self {
+        self.display = StateDisplay::Inline;
+        self
+    }
+
+    /// Show the state as a full-width banner with the icon beside the text
+    pub fn banner(mut self) -> Self {
+        self.display = StateDisplay::Banner;
+        self
+    }
+
+    /// Set display mode explicitly
+    pub fn display(mut self, display: StateDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    // === Motion Methods ===
+
+    /// Fade the state in and out (default)
+    pub fn fade(mut self) -> Self {
+        self.motion = StateMotion::Fade;
+        self
+    }
+
+    /// Slide the state up into place while fading
+    pub fn slide(mut self) -> Self {
+        self.motion = StateMotion::Slide;
+        self
+    }
+
+    /// Set the enter and exit animation explicitly
+    pub fn motion(mut self, motion: StateMotion) -> Self {
+        self.motion = motion;
+        self
+    }
+
     // === Alignment Methods ===
 
     /// Set left alignment
@@ -297,6 +369,27 @@ impl<C: ColorProvider> StateStyles<C> {
         self
     }
 
+    /// Set display mode from string
+    pub fn display_str(mut self, display: &str) -> Self {
+        self.display = match display {
+            "section" | "block" => StateDisplay::Section,
+            "inline" => StateDisplay::Inline,
+            "banner" => StateDisplay::Banner,
+            _ => StateDisplay::Section, // fallback
+        };
+        self
+    }
+
+    /// Set enter and exit animation from string
+    pub fn motion_str(mut self, motion: &str) -> Self {
+        self.motion = match motion {
+            "fade" => StateMotion::Fade,
+            "slide" | "slide-up" => StateMotion::Slide,
+            _ => StateMotion::Fade, // fallback
+        };
+        self
+    }
+
     /// Set alignment from string
     pub fn alignment_str(mut self, alignment: &str) -> Self {
         self.alignment = match alignment {
@@ -324,59 +417,104 @@ impl<C: ColorProvider> StateStyles<C> {
     // === Build Methods ===
 
     /// Build the final CSS classes string
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         self.build()
     }
 
-    /// Build the final CSS classes string (alias for classes)
-    pub fn build(self) -> String {
-        let mut all_classes = Vec::new();
-
-        // Base state classes
-        all_classes.push("state-pattern".to_string());
-
-        // Layout classes
-        let layout_classes = match self.alignment {
-            StateAlignment::Left => "flex flex-col items-start text-left",
-            StateAlignment::Center => "flex flex-col items-center text-center",
-            StateAlignment::Right => "flex flex-col items-end text-right",
+    /// Build coordinated classes for every part of the state view
+    ///
+    /// Uses the same size scale as `content_size_classes`,
+    /// `description_size_classes` and `icon_size_classes`, so a framework
+    /// can render the whole view from one call.
+    pub fn state_parts(&self) -> StateParts
+    where
+        C: Clone,
+    {
+        let justify = match self.alignment {
+            StateAlignment::Left => "justify-start",
+            StateAlignment::Center => "justify-center",
+            StateAlignment::Right => "justify-end",
+        };
+        let (icon_spacing, content, actions) = match self.display {
+            StateDisplay::Section => (
+                "mb-4",
+                "state-content flex flex-col gap-2 max-w-prose",
+                format!("mt-6 {}", justify),
+            ),
+            StateDisplay::Inline => (
+                "self-center",
+                "state-content flex flex-row flex-wrap items-baseline gap-x-1.5 min-w-0",
+                "ml-auto".to_string(),
+            ),
+            StateDisplay::Banner => (
+                "mt-0.5",
+                "state-content flex-1 flex flex-col gap-1 min-w-0",
+                "mt-2".to_string(),
+            ),
+        };
+        let has_action = self.action_requirement != StateActionRequirement::None;
+        let loading = match (self.loading_variant, self.intent) {
+            (Some(variant), _) => Some(variant),
+            (None, StateIntent::Loading) => Some(LoadingVariant::Spinner),
+            _ => None,
         };
-        all_classes.push(layout_classes.to_string());
 
-        // Fullscreen classes
-        if self.fullscreen {
-            all_classes.push("min-h-screen justify-center".to_string());
+        StateParts {
+            container: self.classes(),
+            icon: format!(
+                "state-icon shrink-0 {} {}",
+                icon_spacing,
+                self.icon_size_classes()
+            ),
+            loading: loading.map(|variant| {
+                format!(
+                    "state-loading shrink-0 {} {} {}",
+                    icon_spacing,
+                    self.get_loading_classes(variant),
+                    StateStyles {
+                        loading_variant: Some(variant),
+                        ..self.clone()
+                    }
+                    .loading_size_classes()
+                )
+            }),
+            content: content.to_string(),
+            title: format!("state-title font-semibold {}", self.content_size_classes()),
+            description: format!(
+                "state-description {} {}",
+                self.description_size_classes(),
+                self.color_provider.text_class(Color::TextSecondary)
+            ),
+            actions: has_action.then(|| format!("state-actions flex flex-wrap gap-3 {}", actions)),
+            action: has_action.then(|| self.action_button_styles().classes()),
         }
+    }
 
-        // Size-based spacing
-        let spacing_classes = match self.size {
-            StateSize::XS => "px-4 py-8",
-            StateSize::SM => "px-6 py-12",
-            StateSize::MD => "px-8 py-16",
-            StateSize::LG => "px-12 py-20",
-            StateSize::XL => "px-16 py-24",
+    /// Get live region attributes so state changes are announced
+    ///
+    /// Errors interrupt the screen reader; every other state is announced
+    /// politely, and loading states are marked busy.
+    pub fn live_region_attributes(&self) -> Vec<(&'static str, String)> {
+        let announcer = match self.intent {
+            StateIntent::Error => Announcer::assertive(),
+            StateIntent::Loading => Announcer::polite().busy(true),
+            _ => Announcer::polite(),
         };
-        all_classes.push(spacing_classes.to_string());
-
-        // Intent-based classes
-        let intent_classes = self.get_intent_classes();
-        if !intent_classes.is_empty() {
-            all_classes.push(intent_classes);
-        }
+        announcer.visible().attributes()
+    }
 
-        // Loading variant classes
-        if let Some(variant) = self.loading_variant {
-            let loading_classes = self.get_loading_classes(variant);
-            if !loading_classes.is_empty() {
-                all_classes.push(loading_classes);
-            }
-        }
+    /// Compare the classes of this state with another configuration
+    pub fn diff(&self, other: &Self) -> ClassDiff {
+        class_diff(&self.classes(), &other.classes())
+    }
 
-        // Custom classes
-        let custom_classes = self.custom_classes.join(" ");
-        if !custom_classes.is_empty() {
-            all_classes.push(custom_classes);
-        }
+    /// Build the final CSS classes string (alias for classes)
+    pub fn build(&self) -> String {
+        let all_classes: Vec<String> = self
+            .explain()
+            .into_iter()
+            .map(|(_, classes)| classes)
+            .collect();
 
         // Join and clean up
         let mut classes: Vec<String> = all_classes
@@ -389,6 +527,134 @@ impl<C: ColorProvider> StateStyles<C> {
         classes.join(" ")
     }
 
+    /// Break the classes down by the decision that produced them
+    ///
+    /// Sources are "base", "display", "alignment", "fullscreen", "size",
+    /// "intent", "loading" and "custom"; decisions that add no classes are
+    /// omitted. Inline and banner states always lay out horizontally, so they
+    /// ignore alignment and fullscreen.
+    pub fn explain(&self) -> Vec<(&'static str, String)> {
+        let mut parts = Vec::new();
+
+        // Base state classes
+        parts.push(("base", "state-pattern".to_string()));
+
+        match self.display {
+            StateDisplay::Section => {
+                // Layout classes
+                let layout_classes = match self.alignment {
+                    StateAlignment::Left => "flex flex-col items-start text-left",
+                    StateAlignment::Center => "flex flex-col items-center text-center",
+                    StateAlignment::Right => "flex flex-col items-end text-right",
+                };
+                parts.push(("alignment", layout_classes.to_string()));
+
+                // Fullscreen classes
+                if self.fullscreen {
+                    parts.push(("fullscreen", "min-h-screen justify-center".to_string()));
+                }
+            }
+            StateDisplay::Inline => parts.push((
+                "display",
+                format!(
+                    "inline-flex flex-row items-center gap-2 text-left {}",
+                    self.color_provider
+                        .radius_scale()
+                        .radius_class(Radius::ControlSmall)
+                ),
+            )),
+            StateDisplay::Banner => parts.push((
+                "display",
+                format!(
+                    "flex flex-row items-start gap-3 w-full text-left {}",
+                    self.color_provider
+                        .radius_scale()
+                        .radius_class(Radius::Control)
+                ),
+            )),
+        }
+
+        // Size-based spacing
+        let spacing_classes = match (self.display, self.size) {
+            (StateDisplay::Section, StateSize::XS) => "px-4 py-8",
+            (StateDisplay::Section, StateSize::SM) => "px-6 py-12",
+            (StateDisplay::Section, StateSize::MD) => "px-8 py-16",
+            (StateDisplay::Section, StateSize::LG) => "px-12 py-20",
+            (StateDisplay::Section, StateSize::XL) => "px-16 py-24",
+            (StateDisplay::Inline, StateSize::XS | StateSize::SM) => "px-2 py-1",
+            (StateDisplay::Inline, StateSize::MD) => "px-3 py-1.5",
+            (StateDisplay::Inline, StateSize::LG) => "px-3 py-2",
+            (StateDisplay::Inline, StateSize::XL) => "px-4 py-2",
+            (StateDisplay::Banner, StateSize::XS) => "px-3 py-2",
+            (StateDisplay::Banner, StateSize::SM) => "px-3 py-2.5",
+            (StateDisplay::Banner, StateSize::MD) => "px-4 py-3",
+            (StateDisplay::Banner, StateSize::LG) => "px-5 py-4",
+            (StateDisplay::Banner, StateSize::XL) => "px-6 py-5",
+        };
+        parts.push(("size", spacing_classes.to_string()));
+
+        // Intent-based classes
+        parts.push(("intent", self.get_intent_classes()));
+
+        // Loading variant classes
+        if let Some(variant) = self.loading_variant {
+            parts.push(("loading", self.get_loading_classes(variant)));
+        }
+
+        // Custom classes
+        parts.push(("custom", self.custom_classes.join(" ")));
+
+        parts
+            .into_iter()
+            .filter(|(_, classes)| !classes.trim().is_empty())
+            .collect()
+    }
+
+    /// Classes for a state that is shown or entering
+    ///
+    /// Swap to `exit_classes` to dismiss the state; render with the exit
+    /// classes first to animate its arrival. Durations come from the theme's
+    /// motion scale, and exits run faster than enters.
+    pub fn enter_classes(&self) -> String {
+        let offset = match self.motion {
+            StateMotion::Fade => "",
+            StateMotion::Slide => " translate-y-0",
+        };
+        format!(
+            "transition ease-out {} opacity-100{} motion-reduce:transition-none",
+            self.color_provider
+                .motion_scale()
+                .duration_class(MotionDuration::Normal),
+            offset
+        )
+    }
+
+    /// Classes for a state that is hidden or leaving
+    pub fn exit_classes(&self) -> String {
+        let offset = match self.motion {
+            StateMotion::Fade => "",
+            StateMotion::Slide => " translate-y-2",
+        };
+        format!(
+            "transition ease-in {} opacity-0{} pointer-events-none motion-reduce:transition-none",
+            self.color_provider
+                .motion_scale()
+                .duration_class(MotionDuration::Fast),
+            offset
+        )
+    }
+
+    /// Get the suggested delay in milliseconds before dismissing this state
+    ///
+    /// Success confirmations without an action dismiss themselves; every
+    /// other state stays until the user or the app removes it.
+    pub fn suggested_dismiss_delay(&self) -> Option<u32> {
+        match (self.intent, self.action_requirement) {
+            (StateIntent::Success, StateActionRequirement::None) => Some(4000),
+            _ => None,
+        }
+    }
+
     /// Get suggested icon for this state
     pub fn suggested_icon(&self) -> String {
         match self.intent {
@@ -419,44 +685,100 @@ impl<C: ColorProvider> StateStyles<C> {
         }
     }
 
+    /// Get button styles for the state's action, matched to the intent
+    ///
+    /// Errors and required warnings get a primary (retry) button; empty and
+    /// other states get a secondary one. The button size follows the state
+    /// size and display, so compact states get compact buttons. Pair it with
+    /// `suggested_action_text` for the label.
+    pub fn action_button_styles(&self) -> ButtonStyles<C>
+    where
+        C: Clone,
+    {
+        let button = ButtonStyles::new(self.color_provider.clone());
+        let button = match (self.intent, self.action_requirement) {
+            (StateIntent::Error, _) | (StateIntent::Warning, StateActionRequirement::Required) => {
+                button.primary()
+            }
+            _ => button.secondary(),
+        };
+
+        match (self.display, self.size) {
+            (StateDisplay::Inline, StateSize::XS | StateSize::SM | StateSize::MD) => {
+                button.extra_small()
+            }
+            (StateDisplay::Inline, _) | (StateDisplay::Banner, _) => button.small(),
+            (StateDisplay::Section, StateSize::XS | StateSize::SM) => button.small(),
+            (StateDisplay::Section, StateSize::MD) => button.medium(),
+            (StateDisplay::Section, StateSize::LG | StateSize::XL) => button.large(),
+        }
+    }
+
     /// Get size classes for content elements
     pub fn content_size_classes(&self) -> String {
-        match self.size {
-            StateSize::XS => "text-lg",
-            StateSize::SM => "text-xl",
-            StateSize::MD => "text-2xl",
-            StateSize::LG => "text-3xl",
-            StateSize::XL => "text-4xl",
+        match (self.display, self.size) {
+            (StateDisplay::Section, StateSize::XS) => "text-lg",
+            (StateDisplay::Section, StateSize::SM) => "text-xl",
+            (StateDisplay::Section, StateSize::MD) => "text-2xl",
+            (StateDisplay::Section, StateSize::LG) => "text-3xl",
+            (StateDisplay::Section, StateSize::XL) => "text-4xl",
+            (StateDisplay::Inline, StateSize::XS) => "text-xs",
+            (StateDisplay::Inline, StateSize::SM | StateSize::MD) => "text-sm",
+            (StateDisplay::Inline, StateSize::LG) => "text-base",
+            (StateDisplay::Inline, StateSize::XL) => "text-lg",
+            (StateDisplay::Banner, StateSize::XS | StateSize::SM) => "text-sm",
+            (StateDisplay::Banner, StateSize::MD) => "text-base",
+            (StateDisplay::Banner, StateSize::LG) => "text-lg",
+            (StateDisplay::Banner, StateSize::XL) => "text-xl",
         }
         .to_string()
     }
 
     /// Get description size classes
     pub fn description_size_classes(&self) -> String {
-        match self.size {
-            StateSize::XS => "text-sm",
-            StateSize::SM => "text-base",
-            StateSize::MD => "text-lg",
-            StateSize::LG => "text-xl",
-            StateSize::XL => "text-2xl",
+        match (self.display, self.size) {
+            (StateDisplay::Section, StateSize::XS) => "text-sm",
+            (StateDisplay::Section, StateSize::SM) => "text-base",
+            (StateDisplay::Section, StateSize::MD) => "text-lg",
+            (StateDisplay::Section, StateSize::LG) => "text-xl",
+            (StateDisplay::Section, StateSize::XL) => "text-2xl",
+            (StateDisplay::Inline | StateDisplay::Banner, StateSize::XS) => "text-xs",
+            (StateDisplay::Inline, StateSize::SM) => "text-xs",
+            (StateDisplay::Inline, StateSize::MD | StateSize::LG) => "text-sm",
+            (StateDisplay::Inline, StateSize::XL) => "text-base",
+            (StateDisplay::Banner, StateSize::SM | StateSize::MD) => "text-sm",
+            (StateDisplay::Banner, StateSize::LG | StateSize::XL) => "text-base",
         }
         .to_string()
     }
 
     /// Get icon size classes
     pub fn icon_size_classes(&self) -> String {
-        match self.size {
-            StateSize::XS => "w-8 h-8",
-            StateSize::SM => "w-12 h-12",
-            StateSize::MD => "w-16 h-16",
-            StateSize::LG => "w-20 h-20",
-            StateSize::XL => "w-24 h-24",
+        match (self.display, self.size) {
+            (StateDisplay::Section, StateSize::XS) => "w-8 h-8",
+            (StateDisplay::Section, StateSize::SM) => "w-12 h-12",
+            (StateDisplay::Section, StateSize::MD) => "w-16 h-16",
+            (StateDisplay::Section, StateSize::LG) => "w-20 h-20",
+            (StateDisplay::Section, StateSize::XL) => "w-24 h-24",
+            (StateDisplay::Inline, StateSize::XS | StateSize::SM) => "w-3.5 h-3.5",
+            (StateDisplay::Inline, StateSize::MD) => "w-4 h-4",
+            (StateDisplay::Inline, StateSize::LG) => "w-5 h-5",
+            (StateDisplay::Inline, StateSize::XL) => "w-6 h-6",
+            (StateDisplay::Banner, StateSize::XS | StateSize::SM) => "w-4 h-4",
+            (StateDisplay::Banner, StateSize::MD) => "w-5 h-5",
+            (StateDisplay::Banner, StateSize::LG | StateSize::XL) => "w-6 h-6",
         }
         .to_string()
     }
 
     /// Get loading animation size classes
+    ///
+    /// Inline and banner states size the indicator like their icon.
     pub fn loading_size_classes(&self) -> String {
+        if self.display != StateDisplay::Section {
+            return self.icon_size_classes();
+        }
+
         match self.loading_variant {
             Some(LoadingVariant::Spinner) => match self.size {
                 StateSize::XS => "w-6 h-6",
@@ -478,6 +800,14 @@ impl<C: ColorProvider> StateStyles<C> {
     }
 
     fn get_intent_classes(&self) -> String {
+        let status_color = self
+            .status
+            .as_deref()
+            .and_then(|name| self.color_provider.status_color(name));
+        if let Some(color) = status_color {
+            return format!("text-{} bg-{}/10", color, color);
+        }
+
         match self.intent {
             StateIntent::Informational => {
                 format!(
@@ -494,9 +824,9 @@ impl<C: ColorProvider> StateStyles<C> {
                     self.color_provider.bg_class(crate::core::Color::Background)
                 )
             }
-            StateIntent::Success => "text-green-600 bg-green-50".to_string(),
-            StateIntent::Warning => "text-orange-600 bg-orange-50".to_string(),
-            StateIntent::Error => "text-red-600 bg-red-50".to_string(),
+            StateIntent::Success => self.soft_classes(crate::core::Color::Success),
+            StateIntent::Warning => self.soft_classes(crate::core::Color::Warning),
+            StateIntent::Error => self.soft_classes(crate::core::Color::Error),
             StateIntent::Empty => {
                 format!(
                     "{} {}",
@@ -508,6 +838,14 @@ impl<C: ColorProvider> StateStyles<C> {
         }
     }
 
+    fn soft_classes(&self, color: crate::core::Color) -> String {
+        format!(
+            "text-{} bg-{}",
+            self.color_provider.soft_text(color),
+            self.color_provider.soft_bg(color)
+        )
+    }
+
     fn get_loading_classes(&self, variant: LoadingVariant) -> String {
         match variant {
             LoadingVariant::Spinner => {
@@ -526,6 +864,12 @@ pub fn state_styles<C: ColorProvider>(color_provider: C) -> StateStyles<C> {
     StateStyles::new(color_provider)
 }
 
+/// Convenience function to create state styles from the global theme
+#[cfg(feature = "std")]
+pub fn state_styles_default() -> StateStyles<SharedTheme> {
+    StateStyles::new(global_theme())
+}
+
 /// Convenience function to create loading state styles
 pub fn loading_state_styles<C: ColorProvider>(color_provider: C) -> StateStyles<C> {
     StateStyles::new(color_provider)
@@ -563,6 +907,159 @@ pub fn success_state_styles<C: ColorProvider>(color_provider: C) -> StateStyles<
         .no_action()
 }
 
+/// Class set for every part of a state view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateParts {
+    /// Outer container (same as `StateStyles::classes`)
+    pub container: String,
+    /// Intent icon
+    pub icon: String,
+    /// Loading indicator, shown instead of the icon (None when not loading)
+    pub loading: Option<String>,
+    /// Text column holding the title and description
+    pub content: String,
+    /// State title
+    pub title: String,
+    /// Explanatory description
+    pub description: String,
+    /// Actions row (None when the state has no action)
+    pub actions: Option<String>,
+    /// Action button (None when the state has no action)
+    pub action: Option<String>,
+}
+
+/// Class set for a full-page error (404, 500, error boundary fallback)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorPageClasses {
+    /// Full-height centered page container
+    pub container: String,
+    /// Content column holding the text and actions
+    pub content: String,
+    /// Illustration slot (None when no illustration is shown)
+    pub illustration: Option<String>,
+    /// Large error code (e.g. "404")
+    pub code: String,
+    /// Error title
+    pub title: String,
+    /// Explanatory message
+    pub message: String,
+    /// Action buttons row
+    pub actions: String,
+    /// Primary action button (e.g. "Go home")
+    pub primary_action: String,
+    /// Secondary action button (e.g. "Contact support")
+    pub secondary_action: String,
+}
+
+/// Error page styling utility builder
+///
+/// Composes the layout, typography and action buttons of a full-page error
+/// so 404/500 pages share one design.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::state::ErrorPageStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let page = ErrorPageStyles::new(VibeColors::default())
+///     .status_code(404)
+///     .with_illustration(true)
+///     .classes();
+///
+/// assert!(page.illustration.is_some());
+/// assert!(page.container.contains("min-h-screen"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorPageStyles<C: ColorProvider + Clone> {
+    status_code: Option<u16>,
+    show_illustration: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> ErrorPageStyles<C> {
+    /// Create a new error page styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            status_code: None,
+            show_illustration: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    /// Set the HTTP status code (server errors use the error color)
+    pub fn status_code(mut self, status_code: u16) -> Self {
+        self.status_code = Some(status_code);
+        self
+    }
+
+    /// Show the illustration slot
+    pub fn with_illustration(mut self, show_illustration: bool) -> Self {
+        self.show_illustration = show_illustration;
+        self
+    }
+
+    /// Add a custom CSS class to the page container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    /// Build the class set for every part of the page
+    pub fn classes(&self) -> ErrorPageClasses {
+        let mut container = vec![format!(
+            "error-page flex flex-col items-center justify-center min-h-screen px-6 py-16 text-center {}",
+            self.color_provider.bg_class(Color::Background)
+        )];
+        container.extend(self.custom_classes.iter().cloned());
+
+        let code_color = match self.status_code {
+            Some(code) if code >= 500 => Color::Error,
+            _ => Color::Primary,
+        };
+
+        ErrorPageClasses {
+            container: container.join(" "),
+            content: "error-page-content flex flex-col items-center max-w-md w-full".to_string(),
+            illustration: self.show_illustration.then(|| {
+                "error-page-illustration w-48 h-48 sm:w-64 sm:h-64 mb-8 object-contain".to_string()
+            }),
+            code: format!(
+                "error-page-code text-6xl sm:text-8xl font-extrabold tracking-tight tabular-nums leading-none {}",
+                self.color_provider.text_class(code_color)
+            ),
+            title: format!(
+                "error-page-title mt-4 {}",
+                TextStyles::new(self.color_provider.clone())
+                    .heading()
+                    .classes()
+            ),
+            message: format!(
+                "error-page-message mt-2 {}",
+                TextStyles::new(self.color_provider.clone())
+                    .body()
+                    .muted()
+                    .classes()
+            ),
+            actions: "error-page-actions flex flex-col sm:flex-row items-stretch sm:items-center justify-center gap-3 mt-8 w-full sm:w-auto"
+                .to_string(),
+            primary_action: ButtonStyles::new(self.color_provider.clone())
+                .primary()
+                .classes(),
+            secondary_action: ButtonStyles::new(self.color_provider.clone())
+                .secondary()
+                .classes(),
+        }
+    }
+}
+
+/// Convenience function to create error page styles
+pub fn error_page_styles<C: ColorProvider + Clone>(color_provider: C) -> ErrorPageStyles<C> {
+    ErrorPageStyles::new(color_provider)
+}
+
 /// One-shot convenience function to create state classes from strings
 pub fn state_classes_from_strings<C: ColorProvider>(
     color_provider: C,
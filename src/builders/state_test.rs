@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests {
+    use crate::builders::button::ButtonStyles;
     use crate::builders::state::{
-        empty_state_styles, error_state_styles, loading_state_styles, state_classes_from_strings,
-        state_styles, success_state_styles, StateStyles,
+        empty_state_styles, error_page_styles, error_state_styles, loading_state_styles,
+        state_classes_from_strings, state_styles, success_state_styles, ErrorPageStyles,
+        StateStyles,
     };
+    use crate::core::MotionScale;
+    use crate::patterns::{StateDisplay, StateMotion};
     use crate::themes::VibeColors;
 
     #[test]
@@ -41,8 +45,8 @@ mod tests {
 
         // Test warning intent
         let classes = StateStyles::new(colors.clone()).warning().classes();
-        assert!(classes.contains("text-orange-600"));
-        assert!(classes.contains("bg-orange-50"));
+        assert!(classes.contains("text-amber-600"));
+        assert!(classes.contains("bg-amber-50"));
 
         // Test error intent
         let classes = StateStyles::new(colors.clone()).error().classes();
@@ -54,6 +58,24 @@ mod tests {
         assert!(classes.contains("text-gray-600"));
     }
 
+    #[test]
+    fn test_intent_colors_follow_the_theme() {
+        let colors = VibeColors::with_overrides(|palette| {
+            palette.success = "emerald-500".to_string();
+            palette.warning = "yellow-500".to_string();
+            palette.warning_soft_text = Some("yellow-800".to_string());
+        });
+
+        let success = StateStyles::new(colors.clone()).success().classes();
+        assert!(success.contains("text-emerald-600"));
+        assert!(success.contains("bg-emerald-50"));
+
+        let warning = StateStyles::new(colors).warning().classes();
+        assert!(warning.contains("text-yellow-800"));
+        assert!(warning.contains("bg-yellow-50"));
+        assert!(!warning.contains("orange"));
+    }
+
     #[test]
     fn test_prominence_methods() {
         let colors = VibeColors::default();
@@ -427,9 +449,285 @@ mod tests {
 
         let styles = StateStyles::new(colors).error().prominent().lg();
 
-        let classes1 = styles.clone().classes();
+        let classes1 = styles.classes();
         let classes2 = styles.build();
 
         assert_eq!(classes1, classes2);
     }
+
+    #[test]
+    fn test_error_page_parts() {
+        let page = error_page_styles(VibeColors::default()).classes();
+
+        assert!(page.container.contains("error-page"));
+        assert!(page.container.contains("min-h-screen"));
+        assert!(page.container.contains("justify-center"));
+        assert!(page.code.contains("text-6xl"));
+        assert!(page.code.contains("text-jupiter-blue-500"));
+        assert!(page.title.contains("error-page-title"));
+        assert!(page.message.contains("text-gray-600"));
+        assert!(page.actions.contains("gap-3"));
+        assert!(page.primary_action.contains("bg-jupiter-blue-500"));
+        assert_ne!(page.primary_action, page.secondary_action);
+        assert!(page.illustration.is_none());
+    }
+
+    #[test]
+    fn test_error_page_status_and_illustration() {
+        let colors = VibeColors::default();
+
+        let server_error = ErrorPageStyles::new(colors.clone())
+            .status_code(500)
+            .with_illustration(true)
+            .custom("app-error")
+            .classes();
+        assert!(server_error.code.contains("text-red-500"));
+        assert!(server_error.illustration.is_some());
+        assert!(server_error.container.contains("app-error"));
+
+        let not_found = ErrorPageStyles::new(colors).status_code(404).classes();
+        assert!(not_found.code.contains("text-jupiter-blue-500"));
+    }
+
+    #[test]
+    fn test_state_styles_explain() {
+        let explanation = StateStyles::new(VibeColors::default())
+            .loading()
+            .spinner()
+            .is_fullscreen()
+            .explain();
+
+        let find = |source: &str| {
+            explanation
+                .iter()
+                .find(|(s, _)| *s == source)
+                .map(|(_, classes)| classes.clone())
+        };
+        assert_eq!(find("base").as_deref(), Some("state-pattern"));
+        assert!(find("fullscreen").unwrap().contains("min-h-screen"));
+        assert!(find("loading").unwrap().contains("animate-spin"));
+        assert!(find("custom").is_none());
+    }
+
+    #[test]
+    fn test_live_region_attributes() {
+        let colors = VibeColors::default();
+
+        let loading = loading_state_styles(colors.clone()).live_region_attributes();
+        assert!(loading.contains(&("role", "status".to_string())));
+        assert!(loading.contains(&("aria-live", "polite".to_string())));
+        assert!(loading.contains(&("aria-busy", "true".to_string())));
+
+        let success = success_state_styles(colors.clone()).live_region_attributes();
+        assert!(success.contains(&("aria-live", "polite".to_string())));
+        assert!(!success.contains(&("aria-busy", "true".to_string())));
+
+        let error = error_state_styles(colors).live_region_attributes();
+        assert!(error.contains(&("role", "alert".to_string())));
+        assert!(error.contains(&("aria-live", "assertive".to_string())));
+    }
+
+    #[test]
+    fn test_custom_status_colors() {
+        let colors = VibeColors::default().with_status("pending", "violet-500");
+
+        let pending = StateStyles::new(colors.clone()).status("pending").classes();
+        assert!(pending.contains("text-violet-500"));
+        assert!(pending.contains("bg-violet-500/10"));
+
+        let unknown = StateStyles::new(colors.clone())
+            .error()
+            .status("archived")
+            .classes();
+        assert_eq!(unknown, StateStyles::new(colors).error().classes());
+    }
+
+    #[test]
+    fn test_state_parts() {
+        let colors = VibeColors::default();
+        let state = error_state_styles(colors.clone()).lg();
+        let parts = state.state_parts();
+
+        assert_eq!(parts.container, state.classes());
+        assert!(parts.icon.contains(&state.icon_size_classes()));
+        assert!(parts.title.contains(&state.content_size_classes()));
+        assert!(parts
+            .description
+            .contains(&state.description_size_classes()));
+        assert!(parts.description.contains("text-gray-600"));
+        assert!(parts.loading.is_none());
+        assert!(parts.actions.unwrap().contains("justify-center"));
+        assert!(parts.action.unwrap().contains("bg-jupiter-blue-500"));
+
+        let empty = empty_state_styles(colors.clone())
+            .left_aligned()
+            .state_parts();
+        assert!(empty.actions.unwrap().contains("justify-start"));
+        assert!(empty.action.is_some());
+
+        let success = success_state_styles(colors.clone()).state_parts();
+        assert!(success.actions.is_none());
+        assert!(success.action.is_none());
+    }
+
+    #[test]
+    fn test_state_parts_loading() {
+        let colors = VibeColors::default();
+        let loading = loading_state_styles(colors.clone()).sm().state_parts();
+        let indicator = loading.loading.unwrap();
+        assert!(indicator.contains("animate-spin"));
+        assert!(indicator.contains("w-8 h-8"));
+
+        // Loading intent without a variant still gets a spinner
+        let bare = state_styles(colors).loading().state_parts();
+        assert!(bare.loading.unwrap().contains("animate-spin"));
+    }
+
+    #[test]
+    fn test_inline_and_banner_display() {
+        let colors = VibeColors::default();
+
+        let section = error_state_styles(colors.clone()).classes();
+        assert!(section.contains("px-8 py-16"));
+        assert!(section.contains("flex-col"));
+
+        let inline = error_state_styles(colors.clone()).inline().classes();
+        assert!(inline.contains("inline-flex"));
+        assert!(inline.contains("flex-row"));
+        assert!(inline.contains("px-3 py-1.5"));
+        assert!(inline.contains("text-red-600"));
+        assert!(!inline.contains("py-16"));
+        assert!(!inline.contains("items-center text-center"));
+
+        let banner = error_state_styles(colors.clone()).banner().classes();
+        assert!(banner.contains("w-full"));
+        assert!(banner.contains("px-4 py-3"));
+        assert!(banner.contains("gap-3"));
+        assert!(banner.contains("text-red-600"));
+
+        assert_eq!(
+            state_styles(colors.clone())
+                .display(StateDisplay::Banner)
+                .classes(),
+            state_styles(colors).display_str("banner").classes()
+        );
+    }
+
+    #[test]
+    fn test_compact_state_parts() {
+        let colors = VibeColors::default();
+
+        let inline = loading_state_styles(colors.clone()).inline();
+        assert_eq!(inline.icon_size_classes(), "w-4 h-4");
+        assert_eq!(inline.loading_size_classes(), "w-4 h-4");
+        let parts = inline.state_parts();
+        assert!(parts.content.contains("flex-row"));
+        assert!(parts.loading.unwrap().contains("w-4 h-4"));
+        assert!(!parts.icon.contains("mb-4"));
+
+        let banner = error_state_styles(colors).banner().state_parts();
+        assert!(banner.icon.contains("w-5 h-5"));
+        assert!(banner.title.contains("text-base"));
+        assert!(banner.actions.unwrap().contains("mt-2"));
+    }
+
+    #[test]
+    fn test_action_button_styles() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            error_state_styles(colors.clone())
+                .action_button_styles()
+                .classes(),
+            ButtonStyles::new(colors.clone())
+                .primary()
+                .medium()
+                .classes()
+        );
+        assert_eq!(
+            empty_state_styles(colors.clone())
+                .action_button_styles()
+                .classes(),
+            ButtonStyles::new(colors.clone())
+                .secondary()
+                .medium()
+                .classes()
+        );
+        assert_eq!(
+            state_styles(colors.clone())
+                .warning()
+                .required_action()
+                .action_button_styles()
+                .classes(),
+            ButtonStyles::new(colors.clone())
+                .primary()
+                .medium()
+                .classes()
+        );
+
+        // Compact states get compact buttons
+        assert_eq!(
+            error_state_styles(colors.clone())
+                .inline()
+                .action_button_styles()
+                .classes(),
+            ButtonStyles::new(colors.clone())
+                .primary()
+                .extra_small()
+                .classes()
+        );
+        assert_eq!(
+            error_state_styles(colors.clone())
+                .lg()
+                .action_button_styles()
+                .classes(),
+            ButtonStyles::new(colors).primary().large().classes()
+        );
+    }
+
+    #[test]
+    fn test_enter_exit_classes() {
+        let colors = VibeColors::default();
+
+        let fade = success_state_styles(colors.clone());
+        assert_eq!(
+            fade.enter_classes(),
+            "transition ease-out duration-200 opacity-100 motion-reduce:transition-none"
+        );
+        assert!(fade.exit_classes().contains("duration-150 opacity-0"));
+        assert!(!fade.exit_classes().contains("translate"));
+
+        let slide = fade.clone().slide();
+        assert!(slide.enter_classes().contains("translate-y-0"));
+        assert!(slide.exit_classes().contains("translate-y-2"));
+        assert_eq!(
+            slide.exit_classes(),
+            fade.clone().motion_str("slide-up").exit_classes()
+        );
+        assert_eq!(
+            slide.enter_classes(),
+            fade.motion(StateMotion::Slide).enter_classes()
+        );
+
+        let relaxed = success_state_styles(colors.with_motion(MotionScale::relaxed()));
+        assert!(relaxed.enter_classes().contains("duration-300"));
+        assert!(relaxed.exit_classes().contains("duration-200"));
+    }
+
+    #[test]
+    fn test_suggested_dismiss_delay() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            success_state_styles(colors.clone()).suggested_dismiss_delay(),
+            Some(4000)
+        );
+        assert_eq!(
+            success_state_styles(colors.clone())
+                .required_action()
+                .suggested_dismiss_delay(),
+            None
+        );
+        assert_eq!(error_state_styles(colors).suggested_dismiss_delay(), None);
+    }
 }
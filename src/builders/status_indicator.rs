@@ -0,0 +1,336 @@
+//! Service health styling utilities for the Jupiter Design System
+//!
+//! Provides coordinated classes for status pages: a row per service with a
+//! status dot and label, an uptime bar of one segment per period, a
+//! last-updated caption, and an overall banner summarizing every service.
+//! Dots come from `IndicatorStyles` and the banner from `StateStyles`, so
+//! health colors match the rest of the system.
+
+use crate::builders::indicator::IndicatorStyles;
+use crate::builders::state::StateStyles;
+use crate::core::color::ColorProvider;
+use crate::core::{Color, Radius, RadiusProvider};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Health of a service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ServiceStatus {
+    /// Working normally
+    Operational,
+    /// Scheduled maintenance in progress
+    Maintenance,
+    /// No recent data
+    Unknown,
+    /// Slower or less reliable than usual
+    Degraded,
+    /// Some features or regions are down
+    PartialOutage,
+    /// The service is down
+    MajorOutage,
+}
+
+impl ServiceStatus {
+    /// Name used in modifier classes, e.g. "partial-outage"
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceStatus::Operational => "operational",
+            ServiceStatus::Maintenance => "maintenance",
+            ServiceStatus::Unknown => "unknown",
+            ServiceStatus::Degraded => "degraded",
+            ServiceStatus::PartialOutage => "partial-outage",
+            ServiceStatus::MajorOutage => "major-outage",
+        }
+    }
+
+    /// Human-readable label, e.g. "Partial outage"
+    pub fn label(&self) -> &'static str {
+        match self {
+            ServiceStatus::Operational => "Operational",
+            ServiceStatus::Maintenance => "Under maintenance",
+            ServiceStatus::Unknown => "Unknown",
+            ServiceStatus::Degraded => "Degraded performance",
+            ServiceStatus::PartialOutage => "Partial outage",
+            ServiceStatus::MajorOutage => "Major outage",
+        }
+    }
+
+    /// Semantic color for the status
+    pub fn color(&self) -> Color {
+        match self {
+            ServiceStatus::Operational => Color::Success,
+            ServiceStatus::Maintenance => Color::Info,
+            ServiceStatus::Unknown => Color::InteractiveDisabled,
+            ServiceStatus::Degraded | ServiceStatus::PartialOutage => Color::Warning,
+            ServiceStatus::MajorOutage => Color::Error,
+        }
+    }
+
+    /// The most severe status, for the overall banner
+    ///
+    /// Variants are declared from least to most severe. No statuses at all
+    /// reads as `Unknown`.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::builders::status_indicator::ServiceStatus;
+    ///
+    /// let services = [ServiceStatus::Operational, ServiceStatus::Degraded];
+    /// assert_eq!(ServiceStatus::worst(services), ServiceStatus::Degraded);
+    /// assert_eq!(ServiceStatus::worst([]), ServiceStatus::Unknown);
+    /// ```
+    pub fn worst(statuses: impl IntoIterator<Item = ServiceStatus>) -> ServiceStatus {
+        statuses
+            .into_iter()
+            .max_by_key(|status| *status as u8)
+            .unwrap_or(ServiceStatus::Unknown)
+    }
+}
+
+/// Service health styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::status_indicator::{ServiceStatus, StatusIndicatorStyles};
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let api = StatusIndicatorStyles::new(VibeColors::default()).partial_outage();
+///
+/// assert!(api.dot_classes().contains("bg-amber-500"));
+/// assert_eq!(api.label(), "Partial outage");
+/// assert!(api.uptime_segment_classes(ServiceStatus::MajorOutage).contains("bg-red-500"));
+/// assert!(api.banner_classes().contains("state-pattern"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatusIndicatorStyles<C: ColorProvider + Clone> {
+    status: ServiceStatus,
+    custom_status: Option<String>,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> StatusIndicatorStyles<C> {
+    /// Create a new service health styling utility (operational)
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            status: ServiceStatus::Operational,
+            custom_status: None,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Status Methods ===
+
+    /// Set operational status
+    pub fn operational(self) -> Self {
+        self.service_status(ServiceStatus::Operational)
+    }
+
+    /// Set maintenance status
+    pub fn maintenance(self) -> Self {
+        self.service_status(ServiceStatus::Maintenance)
+    }
+
+    /// Set unknown status
+    pub fn unknown(self) -> Self {
+        self.service_status(ServiceStatus::Unknown)
+    }
+
+    /// Set degraded status
+    pub fn degraded(self) -> Self {
+        self.service_status(ServiceStatus::Degraded)
+    }
+
+    /// Set partial outage status
+    pub fn partial_outage(self) -> Self {
+        self.service_status(ServiceStatus::PartialOutage)
+    }
+
+    /// Set major outage status
+    pub fn major_outage(self) -> Self {
+        self.service_status(ServiceStatus::MajorOutage)
+    }
+
+    /// Set status explicitly
+    pub fn service_status(mut self, status: ServiceStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set status from string, e.g. "partial-outage"
+    pub fn status_str(self, status: &str) -> Self {
+        let status = match status {
+            "operational" | "up" => ServiceStatus::Operational,
+            "maintenance" => ServiceStatus::Maintenance,
+            "degraded" => ServiceStatus::Degraded,
+            "partial-outage" | "partial" => ServiceStatus::PartialOutage,
+            "major-outage" | "outage" | "down" => ServiceStatus::MajorOutage,
+            _ => ServiceStatus::Unknown, // fallback
+        };
+        self.service_status(status)
+    }
+
+    /// Color the dot, label and banner with a theme-registered status,
+    /// e.g. "investigating"
+    ///
+    /// The service status still decides the label and banner intent.
+    /// Unknown names keep the service status colors.
+    pub fn status(mut self, name: impl Into<String>) -> Self {
+        self.custom_status = Some(name.into());
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the service row
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for a service row
+    pub fn row_classes(&self) -> String {
+        let mut classes = vec![format!(
+            "status-row status-row--{} flex flex-wrap items-center justify-between gap-x-4 gap-y-2 py-4 border-b {}",
+            self.status.as_str(),
+            self.color_provider.border_class(Color::Border)
+        )];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the service name
+    pub fn name_classes(&self) -> String {
+        format!(
+            "status-name text-sm font-medium {}",
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    /// Build classes for the status dot
+    ///
+    /// Outages pulse so they stand out in a long list.
+    pub fn dot_classes(&self) -> String {
+        self.indicator().classes()
+    }
+
+    /// Build classes for the dot's ping layer (empty unless in an outage)
+    pub fn dot_ping_classes(&self) -> String {
+        self.indicator().ping_classes()
+    }
+
+    /// Build classes for the status label beside the dot
+    pub fn status_label_classes(&self) -> String {
+        format!(
+            "status-label inline-flex items-center gap-2 text-sm font-medium text-{}",
+            self.resolved_color()
+        )
+    }
+
+    /// Get the label for the current status
+    pub fn label(&self) -> &'static str {
+        self.status.label()
+    }
+
+    /// Build classes for the uptime bar
+    pub fn uptime_bar_classes(&self) -> String {
+        "status-uptime flex w-full items-stretch gap-px h-8".to_string()
+    }
+
+    /// Build classes for one uptime segment (one day or hour)
+    ///
+    /// Pass `ServiceStatus::Unknown` for periods without data.
+    pub fn uptime_segment_classes(&self, status: ServiceStatus) -> String {
+        format!(
+            "status-uptime-segment status-uptime-segment--{} flex-1 min-w-0.5 {} {} hover:opacity-75",
+            status.as_str(),
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::ControlSmall),
+            self.color_provider.bg_class(status.color())
+        )
+    }
+
+    /// Build classes for the uptime caption under the bar, e.g.
+    /// "90 days ago · 99.98% uptime · Today"
+    pub fn uptime_caption_classes(&self) -> String {
+        format!(
+            "status-uptime-caption flex justify-between gap-2 mt-1 text-xs tabular-nums {}",
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for the last-updated caption
+    pub fn updated_classes(&self) -> String {
+        format!(
+            "status-updated text-xs tabular-nums {}",
+            self.color_provider.text_class(Color::TextTertiary)
+        )
+    }
+
+    /// Get the banner state for the overall status
+    ///
+    /// Use with the worst status across services
+    /// (`ServiceStatus::worst`); the returned builder also provides the
+    /// banner's parts and live region attributes.
+    pub fn banner_styles(&self) -> StateStyles<C> {
+        let banner = StateStyles::new(self.color_provider.clone()).banner();
+        let banner = match self.status {
+            ServiceStatus::Operational => banner.success(),
+            ServiceStatus::Degraded | ServiceStatus::PartialOutage => banner.warning(),
+            ServiceStatus::MajorOutage => banner.error(),
+            ServiceStatus::Maintenance | ServiceStatus::Unknown => banner.informational(),
+        };
+        match &self.custom_status {
+            Some(name) => banner.status(name.clone()),
+            None => banner,
+        }
+    }
+
+    /// Build classes for the overall banner
+    pub fn banner_classes(&self) -> String {
+        format!(
+            "status-banner status-banner--{} {}",
+            self.status.as_str(),
+            self.banner_styles().classes()
+        )
+    }
+
+    fn indicator(&self) -> IndicatorStyles<C> {
+        let dot = IndicatorStyles::new(self.color_provider.clone())
+            .dot()
+            .inline()
+            .color(self.status.color());
+        let dot = match &self.custom_status {
+            Some(name) => dot.status(name.clone()),
+            None => dot,
+        };
+        match self.status {
+            ServiceStatus::PartialOutage | ServiceStatus::MajorOutage => dot.ping(),
+            _ => dot,
+        }
+    }
+
+    fn resolved_color(&self) -> &str {
+        self.custom_status
+            .as_deref()
+            .and_then(|name| self.color_provider.status_color(name))
+            .unwrap_or_else(|| self.color_provider.resolve_color(self.status.color()))
+    }
+}
+
+/// Convenience function to create service health styles
+pub fn status_indicator_styles<C: ColorProvider + Clone>(
+    color_provider: C,
+) -> StatusIndicatorStyles<C> {
+    StatusIndicatorStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "status_indicator_test.rs"]
+mod status_indicator_test;
@@ -0,0 +1,120 @@
+//! Tests for service health styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::status_indicator::{
+        status_indicator_styles, ServiceStatus, StatusIndicatorStyles,
+    };
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_operational_row() {
+        let service = status_indicator_styles(VibeColors::default());
+
+        assert!(service
+            .row_classes()
+            .contains("status-row status-row--operational"));
+        assert!(service.row_classes().contains("border-gray-200"));
+        assert_eq!(service.label(), "Operational");
+
+        let dot = service.dot_classes();
+        assert!(dot.contains("indicator--dot"));
+        assert!(dot.contains("relative inline-flex"));
+        assert!(dot.contains("bg-green-500"));
+        assert!(!dot.contains("absolute"));
+        assert_eq!(service.dot_ping_classes(), "");
+        assert!(service.status_label_classes().contains("text-green-500"));
+    }
+
+    #[test]
+    fn test_outages_pulse() {
+        let colors = VibeColors::default();
+
+        let partial = StatusIndicatorStyles::new(colors.clone()).partial_outage();
+        assert!(partial.dot_classes().contains("bg-amber-500"));
+        assert!(partial.dot_ping_classes().contains("animate-ping"));
+
+        let major = StatusIndicatorStyles::new(colors.clone()).major_outage();
+        assert!(major.dot_ping_classes().contains("bg-red-500"));
+
+        let degraded = StatusIndicatorStyles::new(colors).degraded();
+        assert_eq!(degraded.dot_ping_classes(), "");
+        assert_eq!(degraded.label(), "Degraded performance");
+    }
+
+    #[test]
+    fn test_custom_status_colors() {
+        let colors = VibeColors::default().with_status("investigating", "violet-500");
+
+        let service = StatusIndicatorStyles::new(colors.clone())
+            .degraded()
+            .status("investigating");
+        assert!(service.dot_classes().contains("bg-violet-500"));
+        assert!(service.status_label_classes().contains("text-violet-500"));
+        assert!(service.banner_classes().contains("violet-500"));
+        assert_eq!(service.label(), "Degraded performance");
+
+        let unknown = StatusIndicatorStyles::new(colors)
+            .maintenance()
+            .status("missing");
+        assert!(unknown.dot_classes().contains("bg-blue-500"));
+    }
+
+    #[test]
+    fn test_uptime_segments() {
+        let service = StatusIndicatorStyles::new(VibeColors::default());
+
+        assert!(service.uptime_bar_classes().contains("flex w-full"));
+        let up = service.uptime_segment_classes(ServiceStatus::Operational);
+        assert!(up.contains("status-uptime-segment--operational flex-1"));
+        assert!(up.contains("bg-green-500"));
+        assert!(service
+            .uptime_segment_classes(ServiceStatus::Unknown)
+            .contains("bg-gray-300"));
+        assert!(service.updated_classes().contains("text-xs tabular-nums"));
+        assert!(service.uptime_caption_classes().contains("text-gray-600"));
+    }
+
+    #[test]
+    fn test_overall_banner() {
+        let colors = VibeColors::default();
+        let overall = ServiceStatus::worst([
+            ServiceStatus::Operational,
+            ServiceStatus::MajorOutage,
+            ServiceStatus::Degraded,
+        ]);
+        assert_eq!(overall, ServiceStatus::MajorOutage);
+
+        let banner = StatusIndicatorStyles::new(colors.clone()).service_status(overall);
+        assert!(banner
+            .banner_classes()
+            .starts_with("status-banner status-banner--major-outage"));
+        assert_eq!(
+            banner.banner_styles().live_region_attributes()[0],
+            ("role", "alert".to_string())
+        );
+
+        let healthy = StatusIndicatorStyles::new(colors).operational();
+        assert_eq!(
+            healthy.banner_classes(),
+            format!(
+                "status-banner status-banner--operational {}",
+                crate::builders::state::StateStyles::new(VibeColors::default())
+                    .banner()
+                    .success()
+                    .classes()
+            )
+        );
+    }
+
+    #[test]
+    fn test_status_str() {
+        let colors = VibeColors::default();
+
+        let down = StatusIndicatorStyles::new(colors.clone()).status_str("down");
+        assert!(down.row_classes().contains("status-row--major-outage"));
+
+        let fallback = StatusIndicatorStyles::new(colors).status_str("bogus");
+        assert_eq!(fallback.label(), "Unknown");
+    }
+}
@@ -0,0 +1,350 @@
+//! Table styling utilities for the Jupiter Design System
+//!
+//! Provides per-part classes for data tables: a horizontal scroll container
+//! with edge fades that show when more columns are hidden, header and body
+//! cells spaced by density, an optional sticky header row and sticky first
+//! column, and a responsive mode that turns each row into a stacked card of
+//! labeled values below a breakpoint.
+
+use crate::builders::scroll::ScrollStyles;
+use crate::core::color::ColorProvider;
+use crate::core::{
+    Breakpoint, Color, Density, MotionDuration, MotionProvider, Radius, RadiusProvider,
+};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Table styling utility builder
+///
+/// Rows collapse into cards only below the chosen breakpoint; from that
+/// breakpoint up the same markup renders as a regular table, so sticky and
+/// border classes only apply there.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::table::TableStyles;
+/// use jupiter_design_system::core::Breakpoint;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let table = TableStyles::new(VibeColors::default())
+///     .sticky_first_column()
+///     .collapse_below(Breakpoint::Tablet);
+///
+/// assert!(table.table_classes().contains("block md:table"));
+/// assert!(table.sticky_cell_classes(false).contains("md:sticky md:start-0"));
+/// assert!(table.collapsed_label_classes().contains("md:hidden"));
+/// assert!(table.edge_fade_classes(false).contains("bg-gradient-to-l"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableStyles<C: ColorProvider + Clone> {
+    density: Density,
+    striped: bool,
+    sticky_header: bool,
+    sticky_first_column: bool,
+    collapse_below: Option<Breakpoint>,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> TableStyles<C> {
+    /// Create a new table styling utility
+    ///
+    /// Density and striping default to the theme's data display tokens.
+    pub fn new(color_provider: C) -> Self {
+        let data = color_provider.data_display();
+        Self {
+            density: data.resolve_density(color_provider.density()),
+            striped: data.striped,
+            sticky_header: false,
+            sticky_first_column: false,
+            collapse_below: None,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Density Methods ===
+
+    /// Set density (defaults to the theme's data density)
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set density from string ("compact", "comfortable", "spacious")
+    pub fn density_str(mut self, density: &str) -> Self {
+        self.density = Density::from_str_or_default(density);
+        self
+    }
+
+    /// Stripe alternate body rows (defaults to the theme's preference)
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    // === Sticky Methods ===
+
+    /// Keep the header row visible while the table scrolls vertically
+    pub fn sticky_header(mut self) -> Self {
+        self.sticky_header = true;
+        self
+    }
+
+    /// Keep the first column visible while the table scrolls horizontally
+    pub fn sticky_first_column(mut self) -> Self {
+        self.sticky_first_column = true;
+        self
+    }
+
+    // === Responsive Methods ===
+
+    /// Render rows as stacked cards of labeled values below a breakpoint
+    ///
+    /// `Breakpoint::Mobile` has nothing below it and turns collapsing off.
+    pub fn collapse_below(mut self, breakpoint: Breakpoint) -> Self {
+        self.collapse_below = (breakpoint != Breakpoint::Mobile).then_some(breakpoint);
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the table
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the root wrapping the scroll container and fades
+    pub fn root_classes(&self) -> String {
+        "table-root relative".to_string()
+    }
+
+    /// Build classes for the horizontal scroll container
+    pub fn scroll_classes(&self) -> String {
+        format!(
+            "table-scroll {}",
+            ScrollStyles::new(self.color_provider.clone())
+                .horizontal()
+                .thin_scrollbar()
+                .classes()
+        )
+    }
+
+    /// Build classes for the fade at the start (`true`) or end (`false`)
+    /// edge of the scroll container
+    ///
+    /// Fades stay hidden until the adapter sets `data-visible="true"`,
+    /// which it does while columns are scrolled out of view on that side.
+    pub fn edge_fade_classes(&self, start: bool) -> String {
+        let (side, gradient) = if start {
+            ("start", "bg-gradient-to-r rtl:bg-gradient-to-l")
+        } else {
+            ("end", "bg-gradient-to-l rtl:bg-gradient-to-r")
+        };
+        let mut classes = vec![format!(
+            "table-fade table-fade--{} pointer-events-none absolute inset-y-0 {}-0 z-30 w-6 {} from-{} to-transparent opacity-0 transition-opacity {} motion-reduce:transition-none data-[visible=true]:opacity-100",
+            side,
+            side,
+            gradient,
+            self.color_provider.resolve_color(Color::Surface),
+            self.color_provider
+                .motion_scale()
+                .duration_class(MotionDuration::Fast)
+        )];
+        // Collapsed rows never overflow sideways
+        if self.collapse_below.is_some() {
+            classes.push(format!("hidden {}", self.at("block")));
+        }
+        classes.join(" ")
+    }
+
+    /// Get attributes for an edge fade
+    pub fn edge_fade_attributes(&self, visible: bool) -> Vec<(&'static str, String)> {
+        vec![
+            ("aria-hidden", "true".to_string()),
+            ("data-visible", visible.to_string()),
+        ]
+    }
+
+    /// Build classes for the `table` element
+    pub fn table_classes(&self) -> String {
+        let mut classes = vec![format!(
+            "table-base w-full border-separate border-spacing-0 text-left text-sm {}",
+            self.color_provider.text_class(Color::TextPrimary)
+        )];
+        if self.collapse_below.is_some() {
+            classes.push(format!("block {}", self.at("table")));
+        }
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the `thead` element
+    ///
+    /// Collapsed rows carry their own labels, so the header row is hidden
+    /// below the breakpoint.
+    pub fn head_classes(&self) -> String {
+        match self.collapse_below {
+            Some(_) => format!("table-head hidden {}", self.at("table-header-group")),
+            None => "table-head".to_string(),
+        }
+    }
+
+    /// Build classes for the `tbody` element
+    pub fn body_classes(&self) -> String {
+        match self.collapse_below {
+            Some(_) => format!(
+                "table-body block space-y-3 {}",
+                self.at("table-row-group space-y-0")
+            ),
+            None => "table-body".to_string(),
+        }
+    }
+
+    /// Build classes for a header cell
+    pub fn header_cell_classes(&self) -> String {
+        let mut classes = vec![format!(
+            "table-header-cell {} text-xs font-semibold uppercase tracking-wide whitespace-nowrap {} {}",
+            self.cell_padding(),
+            self.color_provider.text_class(Color::TextSecondary),
+            self.color_provider.bg_class(Color::Surface)
+        )];
+        classes.push(self.at(&format!(
+            "border-b {}",
+            self.color_provider.border_class(Color::Border)
+        )));
+        if self.sticky_header {
+            classes.push(self.at("sticky top-0 z-10"));
+        }
+        classes.join(" ")
+    }
+
+    /// Build classes for a body row
+    ///
+    /// Below the collapse breakpoint each row is a bordered card, and
+    /// stripes only apply where rows render as table rows.
+    pub fn row_classes(&self) -> String {
+        let mut classes = vec![format!(
+            "table-row-base hover:{}",
+            self.color_provider.bg_class(Color::Background)
+        )];
+        if self.striped {
+            classes.push(self.at(&format!(
+                "even:{}",
+                self.color_provider.bg_class(Color::Background)
+            )));
+        }
+        if self.collapse_below.is_some() {
+            classes.push(format!(
+                "block border {} {} {}",
+                self.color_provider.border_class(Color::Border),
+                self.color_provider
+                    .radius_scale()
+                    .radius_class(Radius::Surface),
+                self.at("table-row border-0 rounded-none")
+            ));
+        }
+        classes.join(" ")
+    }
+
+    /// Build classes for a body cell
+    ///
+    /// Below the collapse breakpoint each cell is a label/value line inside
+    /// the row card.
+    pub fn cell_classes(&self) -> String {
+        let mut classes = vec![format!("table-cell-base {}", self.cell_padding())];
+        if self.collapse_below.is_some() {
+            classes.push(format!(
+                "flex items-start justify-between gap-4 {}",
+                self.at("table-cell")
+            ));
+        }
+        classes.push(self.at(&format!(
+            "border-b {}",
+            self.color_provider.border_class(Color::Border)
+        )));
+        classes.join(" ")
+    }
+
+    /// Build classes for a cell in the first column
+    ///
+    /// Pass `true` for the header cell. Same as `header_cell_classes` or
+    /// `cell_classes` unless `sticky_first_column` is set; the header cell
+    /// stacks above both sticky body cells and a sticky header row.
+    pub fn sticky_cell_classes(&self, header: bool) -> String {
+        let base = if header {
+            self.header_cell_classes()
+        } else {
+            self.cell_classes()
+        };
+        if !self.sticky_first_column {
+            return base;
+        }
+
+        let layer = if header { "z-20" } else { "z-[1]" };
+        format!(
+            "{} {} {}",
+            base,
+            self.color_provider.bg_class(Color::Surface),
+            self.at(&format!(
+                "sticky start-0 {} border-e {}",
+                layer,
+                self.color_provider.border_class(Color::Border)
+            ))
+        )
+    }
+
+    /// Build classes for the label shown beside a value in collapsed rows
+    ///
+    /// Render it inside every body cell; it is hidden wherever the row is
+    /// a regular table row.
+    pub fn collapsed_label_classes(&self) -> String {
+        match self.collapse_below {
+            Some(_) => format!(
+                "table-cell-label font-medium {} {}",
+                self.color_provider.text_class(Color::TextSecondary),
+                self.at("hidden")
+            ),
+            None => "table-cell-label hidden".to_string(),
+        }
+    }
+
+    fn cell_padding(&self) -> &'static str {
+        const PADDING: [&str; 5] = [
+            "px-2 py-1",
+            "px-3 py-1.5",
+            "px-4 py-2.5",
+            "px-5 py-3.5",
+            "px-6 py-4",
+        ];
+        self.density.scale(&PADDING, 2)
+    }
+
+    /// Prefix every class with the collapse breakpoint, so it only applies
+    /// where the table renders as a table
+    fn at(&self, classes: &str) -> String {
+        let Some(breakpoint) = self.collapse_below else {
+            return classes.to_string();
+        };
+        let prefix = self.color_provider.breakpoint_scale().prefix(breakpoint);
+        classes
+            .split_whitespace()
+            .map(|class| format!("{}{}", prefix, class))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Convenience function to create table styles
+pub fn table_styles<C: ColorProvider + Clone>(color_provider: C) -> TableStyles<C> {
+    TableStyles::new(color_provider)
+}
+
+#[cfg(test)]
+#[path = "table_test.rs"]
+mod table_test;
@@ -0,0 +1,144 @@
+//! Tests for table styling utilities
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::table::{table_styles, TableStyles};
+    use crate::core::{Breakpoint, DataDisplayTokens, Density};
+    use crate::themes::VibeColors;
+
+    fn has(classes: &str, class: &str) -> bool {
+        classes.split_whitespace().any(|c| c == class)
+    }
+
+    #[test]
+    fn test_plain_table_parts() {
+        let table = table_styles(VibeColors::default());
+
+        assert!(table
+            .table_classes()
+            .contains("w-full border-separate border-spacing-0"));
+        assert_eq!(table.head_classes(), "table-head");
+        assert_eq!(table.body_classes(), "table-body");
+
+        let header = table.header_cell_classes();
+        assert!(header.contains("px-4 py-2.5"));
+        assert!(header.contains("text-gray-600"));
+        assert!(has(&header, "border-b"));
+        assert!(!has(&header, "sticky"));
+
+        let cell = table.cell_classes();
+        assert!(has(&cell, "border-b"));
+        assert!(!has(&cell, "flex"));
+
+        assert!(table.scroll_classes().contains("overflow-x-auto"));
+        assert_eq!(table.collapsed_label_classes(), "table-cell-label hidden");
+    }
+
+    #[test]
+    fn test_density_sets_cell_padding() {
+        let compact = VibeColors::default().with_density(Density::Compact);
+        assert!(table_styles(compact).cell_classes().contains("px-3 py-1.5"));
+
+        let spacious = TableStyles::new(VibeColors::default()).density_str("spacious");
+        assert!(spacious.header_cell_classes().contains("px-5 py-3.5"));
+    }
+
+    #[test]
+    fn test_sticky_header_and_first_column() {
+        let table = TableStyles::new(VibeColors::default())
+            .sticky_header()
+            .sticky_first_column();
+
+        assert!(table.header_cell_classes().contains("sticky top-0 z-10"));
+
+        let corner = table.sticky_cell_classes(true);
+        assert!(corner.contains("sticky start-0 z-20"));
+        assert!(corner.contains("bg-white"));
+
+        let first = table.sticky_cell_classes(false);
+        assert!(first.contains("sticky start-0 z-[1] border-e border-gray-200"));
+
+        let plain = table_styles(VibeColors::default());
+        assert_eq!(plain.sticky_cell_classes(false), plain.cell_classes());
+    }
+
+    #[test]
+    fn test_edge_fades() {
+        let table = table_styles(VibeColors::default());
+
+        let start = table.edge_fade_classes(true);
+        assert!(start.contains("start-0"));
+        assert!(start.contains("from-white"));
+        assert!(start.contains("data-[visible=true]:opacity-100"));
+        assert!(!has(&start, "hidden"));
+
+        let end = table.edge_fade_classes(false);
+        assert!(end.contains("end-0 z-30"));
+        assert!(table
+            .edge_fade_attributes(true)
+            .contains(&("data-visible", "true".to_string())));
+    }
+
+    #[test]
+    fn test_collapse_below_breakpoint() {
+        let table = TableStyles::new(VibeColors::default())
+            .sticky_first_column()
+            .collapse_below(Breakpoint::Desktop);
+
+        assert!(table.table_classes().contains("block lg:table"));
+        assert!(table
+            .head_classes()
+            .contains("hidden lg:table-header-group"));
+        assert!(table.body_classes().contains("lg:space-y-0"));
+
+        let row = table.row_classes();
+        assert!(row.contains("block border border-gray-200 rounded-lg"));
+        assert!(row.contains("lg:table-row lg:border-0 lg:rounded-none"));
+
+        let cell = table.cell_classes();
+        assert!(cell.contains("flex items-start justify-between"));
+        assert!(has(&cell, "lg:table-cell"));
+        assert!(has(&cell, "lg:border-b"));
+        assert!(!has(&cell, "border-b"));
+
+        assert!(table
+            .sticky_cell_classes(false)
+            .contains("lg:sticky lg:start-0"));
+        assert!(table
+            .collapsed_label_classes()
+            .contains("text-gray-600 lg:hidden"));
+        assert!(table.edge_fade_classes(true).contains("hidden lg:block"));
+
+        let never = table_styles(VibeColors::default()).collapse_below(Breakpoint::Mobile);
+        assert_eq!(never.head_classes(), "table-head");
+    }
+
+    #[test]
+    fn test_theme_data_display_defaults() {
+        let admin = VibeColors::default().with_data_display(DataDisplayTokens {
+            density: Some(Density::Compact),
+            striped: true,
+        });
+
+        let table = table_styles(admin.clone());
+        assert!(table.cell_classes().contains("px-3 py-1.5"));
+        assert!(has(&table.row_classes(), "even:bg-gray-50"));
+
+        let collapsed = table_styles(admin.clone()).collapse_below(Breakpoint::Tablet);
+        assert!(has(&collapsed.row_classes(), "md:even:bg-gray-50"));
+
+        let plain = table_styles(admin).striped(false);
+        assert!(!plain.row_classes().contains("even:"));
+
+        // Theme density still applies when the tokens do not set one
+        let spacious = VibeColors::default()
+            .with_density(Density::Spacious)
+            .with_data_display(DataDisplayTokens {
+                density: None,
+                striped: false,
+            });
+        assert!(table_styles(spacious)
+            .cell_classes()
+            .contains("px-5 py-3.5"));
+    }
+}
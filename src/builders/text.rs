@@ -5,15 +5,24 @@
 //! typography classes based on semantic hierarchy and design system constraints.
 
 use crate::core::color::ColorProvider;
+use crate::descriptors::{ComponentDescriptor, Describe};
 use crate::patterns::typography::{
     TypographyAlignment, TypographyColor, TypographyHierarchy, TypographyOverflow,
     TypographyPattern, TypographySize, TypographyWeight,
 };
+#[cfg(feature = "std")]
+use crate::themes::{global_theme, SharedTheme};
+use crate::utils::{class_diff, ClassDiff};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 /// Text styling builder with chainable API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TextStyles<T: ColorProvider> {
     pattern: TypographyPattern<T>,
+    tabular_nums: bool,
+    slashed_zero: bool,
     custom_classes: Vec<String>,
 }
 
@@ -22,6 +31,8 @@ impl<T: ColorProvider> TextStyles<T> {
     pub fn new(color_provider: T) -> Self {
         Self {
             pattern: TypographyPattern::new(color_provider),
+            tabular_nums: false,
+            slashed_zero: false,
             custom_classes: Vec::new(),
         }
     }
@@ -304,10 +315,50 @@ impl<T: ColorProvider> TextStyles<T> {
         self.alignment(TypographyAlignment::Justify)
     }
 
+    /// Use fixed-width digits so figures line up across rows
+    pub fn tabular_numbers(mut self) -> Self {
+        self.tabular_nums = true;
+        self
+    }
+
+    /// Draw zero with a slash to tell it apart from the letter O
+    pub fn slashed_zero(mut self) -> Self {
+        self.slashed_zero = true;
+        self
+    }
+
+    /// Figures in a table column: tabular, slashed zero, right-aligned
+    pub fn numeric(self) -> Self {
+        self.tabular_numbers().slashed_zero().right()
+    }
+
+    /// Currency value preset: semibold tabular figures
+    ///
+    /// Pair with [`TextStyles::currency_superscript_classes`] for the
+    /// symbol and decimals.
+    pub fn currency(self) -> Self {
+        self.semibold().tabular_numbers()
+    }
+
+    /// Classes for superscripted currency symbol or decimals
+    ///
+    /// Sized relative to the value so it scales with any hierarchy.
+    pub fn currency_superscript_classes(&self) -> String {
+        "align-super text-[0.6em] font-medium tabular-nums".to_string()
+    }
+
     /// Generate final CSS classes
     pub fn classes(&self) -> String {
         let mut classes = vec![self.pattern.classes()];
 
+        // Numeric figure classes
+        if self.tabular_nums {
+            classes.push("tabular-nums".to_string());
+        }
+        if self.slashed_zero {
+            classes.push("slashed-zero".to_string());
+        }
+
         // Add custom classes
         for custom_class in &self.custom_classes {
             classes.push(custom_class.clone());
@@ -325,6 +376,11 @@ impl<T: ColorProvider> TextStyles<T> {
         all_classes.join(" ")
     }
 
+    /// Compare the classes of this text with another configuration
+    pub fn diff(&self, other: &Self) -> ClassDiff {
+        class_diff(&self.classes(), &other.classes())
+    }
+
     /// Get the appropriate HTML element for this text
     pub fn element(&self) -> String {
         self.pattern.get_element()
@@ -336,11 +392,23 @@ impl<T: ColorProvider> TextStyles<T> {
     }
 }
 
+impl<T: ColorProvider> Describe for TextStyles<T> {
+    fn describe(&self) -> ComponentDescriptor {
+        ComponentDescriptor::new(self.element(), self.classes())
+    }
+}
+
 /// Create a text styles builder
 pub fn text_styles<T: ColorProvider>(color_provider: T) -> TextStyles<T> {
     TextStyles::new(color_provider)
 }
 
+/// Convenience function to create text styles from the global theme
+#[cfg(feature = "std")]
+pub fn text_styles_default() -> TextStyles<SharedTheme> {
+    TextStyles::new(global_theme())
+}
+
 /// Utility function to generate text classes from string parameters
 pub fn text_classes_from_strings<T: ColorProvider>(
     color_provider: T,
@@ -453,4 +453,28 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_numeric_figures() {
+        let classes = create_text_styles().numeric().classes();
+
+        assert!(classes.contains("tabular-nums"));
+        assert!(classes.contains("slashed-zero"));
+        assert!(classes.contains("text-right"));
+
+        let tabular = create_text_styles().tabular_numbers().classes();
+        assert!(tabular.contains("tabular-nums"));
+        assert!(!tabular.contains("slashed-zero"));
+        assert!(!create_text_styles().classes().contains("tabular-nums"));
+    }
+
+    #[test]
+    fn test_currency_preset() {
+        let value = create_text_styles().heading().currency();
+        let classes = value.classes();
+
+        assert!(classes.contains("font-semibold"));
+        assert!(classes.contains("tabular-nums"));
+        assert!(value.currency_superscript_classes().contains("align-super"));
+    }
 }
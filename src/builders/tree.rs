@@ -0,0 +1,311 @@
+//! Tree view styling utilities for the Jupiter Design System
+//!
+//! Provides classes for nested list selection: rows indented per depth,
+//! expand/collapse toggles, tri-state checkboxes, and selected-row highlight.
+//! Row heights are fixed per size so trees can be virtualized.
+
+use crate::core::color::ColorProvider;
+use crate::core::{Color, Density};
+use crate::patterns::{SelectionSize, SelectionState};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Deepest level with a dedicated indentation class; deeper rows reuse it
+/// and should apply `indent_style()` instead.
+const MAX_INDENT_CLASS_DEPTH: usize = 8;
+
+/// Tree view styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::tree::TreeStyles;
+/// use jupiter_design_system::patterns::SelectionState;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let tree = TreeStyles::new(VibeColors::default()).with_checkboxes(true);
+///
+/// let children = [SelectionState::Selected, SelectionState::Unselected];
+/// let parent_state = SelectionState::from_children(&children);
+///
+/// let row_classes = tree.row_classes(1, parent_state);
+/// let checkbox_classes = tree.checkbox_classes(parent_state);
+/// let row_height = tree.row_height_px();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TreeStyles<C: ColorProvider> {
+    size: SelectionSize,
+    show_checkboxes: bool,
+    show_guides: bool,
+    density: Density,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> TreeStyles<C> {
+    /// Create a new tree styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            size: SelectionSize::MD,
+            show_checkboxes: false,
+            show_guides: false,
+            density: color_provider.density(),
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === Size Methods ===
+
+    /// Set extra small size
+    pub fn xs(mut self) -> Self {
+        self.size = SelectionSize::XS;
+        self
+    }
+
+    /// Set small size
+    pub fn sm(mut self) -> Self {
+        self.size = SelectionSize::SM;
+        self
+    }
+
+    /// Set medium size
+    pub fn md(mut self) -> Self {
+        self.size = SelectionSize::MD;
+        self
+    }
+
+    /// Set large size
+    pub fn lg(mut self) -> Self {
+        self.size = SelectionSize::LG;
+        self
+    }
+
+    /// Set extra large size
+    pub fn xl(mut self) -> Self {
+        self.size = SelectionSize::XL;
+        self
+    }
+
+    // === Feature Methods ===
+
+    /// Show tri-state checkboxes on each row
+    pub fn with_checkboxes(mut self, show_checkboxes: bool) -> Self {
+        self.show_checkboxes = show_checkboxes;
+        self
+    }
+
+    /// Show vertical guide lines for nested groups
+    pub fn with_guides(mut self, show_guides: bool) -> Self {
+        self.show_guides = show_guides;
+        self
+    }
+
+    /// Set density (defaults to the color provider's density)
+    ///
+    /// Density shifts row heights one step, which also changes
+    /// `row_height_px()` for virtualized lists.
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set density from string ("compact", "comfortable", "spacious")
+    pub fn density_str(mut self, density: &str) -> Self {
+        self.density = Density::from_str_or_default(density);
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class to the tree container
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the tree container
+    pub fn container_classes(&self) -> String {
+        let mut classes = vec!["tree-view flex flex-col w-full select-none".to_string()];
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for a nested group of child rows
+    pub fn group_classes(&self) -> String {
+        if self.show_guides {
+            format!(
+                "tree-group flex flex-col border-l ml-3 {}",
+                self.color_provider.border_class(Color::Border)
+            )
+        } else {
+            "tree-group flex flex-col".to_string()
+        }
+    }
+
+    /// Build classes for a row at the given depth
+    pub fn row_classes(&self, depth: usize, state: SelectionState) -> String {
+        let mut classes = vec![
+            "tree-row flex items-center gap-1.5 w-full pr-2 rounded-md transition-colors duration-150"
+                .to_string(),
+            self.row_height_classes().to_string(),
+            self.text_size_classes().to_string(),
+            self.indent_classes(depth),
+        ];
+
+        match state {
+            SelectionState::Selected => classes.push(format!(
+                "tree-row--selected {} {}",
+                self.color_provider.bg_class(Color::Background),
+                self.color_provider.text_class(Color::Primary)
+            )),
+            SelectionState::PartiallySelected => classes.push(format!(
+                "tree-row--partial {}",
+                self.color_provider.text_class(Color::TextPrimary)
+            )),
+            SelectionState::Unselected => classes.push(format!(
+                "{} hover:{}",
+                self.color_provider.text_class(Color::TextPrimary),
+                self.color_provider.bg_class(Color::Background)
+            )),
+            SelectionState::Disabled => classes.push(format!(
+                "cursor-not-allowed {}",
+                self.color_provider.text_class(Color::TextTertiary)
+            )),
+        }
+
+        if !matches!(state, SelectionState::Disabled) {
+            classes.push(
+                "cursor-pointer focus:outline-none focus:ring-2 focus:ring-inset".to_string(),
+            );
+        }
+
+        classes.join(" ")
+    }
+
+    /// Build classes for the expand/collapse toggle
+    ///
+    /// Leaf rows get an invisible placeholder so labels stay aligned.
+    pub fn toggle_classes(&self, expanded: bool, has_children: bool) -> String {
+        if !has_children {
+            return "tree-toggle invisible w-4 h-4 shrink-0".to_string();
+        }
+        let rotation = if expanded { "rotate-90" } else { "rotate-0" };
+        format!(
+            "tree-toggle inline-flex items-center justify-center w-4 h-4 shrink-0 transition-transform duration-150 motion-reduce:transition-none {} {}",
+            rotation,
+            self.color_provider.text_class(Color::TextTertiary)
+        )
+    }
+
+    /// Build classes for a row checkbox (empty when checkboxes are hidden)
+    pub fn checkbox_classes(&self, state: SelectionState) -> String {
+        if !self.show_checkboxes {
+            return String::new();
+        }
+
+        let state_classes = match state {
+            SelectionState::Unselected => format!(
+                "{} {}",
+                self.color_provider.bg_class(Color::Surface),
+                self.color_provider.border_class(Color::Border)
+            ),
+            SelectionState::Selected => format!(
+                "tree-checkbox--checked {} {} {}",
+                self.color_provider.bg_class(Color::Primary),
+                self.color_provider.border_class(Color::Primary),
+                self.color_provider.text_class(Color::TextInverse)
+            ),
+            SelectionState::PartiallySelected => format!(
+                "tree-checkbox--mixed {} {} {}",
+                self.color_provider.bg_class(Color::Surface),
+                self.color_provider.border_class(Color::Primary),
+                self.color_provider.text_class(Color::Primary)
+            ),
+            SelectionState::Disabled => format!(
+                "{} {}",
+                self.color_provider.bg_class(Color::InteractiveDisabled),
+                self.color_provider.border_class(Color::InteractiveDisabled)
+            ),
+        };
+
+        format!(
+            "tree-checkbox inline-flex items-center justify-center w-4 h-4 shrink-0 rounded border {}",
+            state_classes
+        )
+    }
+
+    /// Build classes for the row label
+    pub fn label_classes(&self) -> String {
+        "tree-label flex-1 min-w-0 truncate".to_string()
+    }
+
+    /// Get the indentation class for a depth
+    pub fn indent_classes(&self, depth: usize) -> String {
+        let depth = depth.min(MAX_INDENT_CLASS_DEPTH);
+        format!("pl-{}", depth * self.indent_step())
+    }
+
+    /// Get an inline indentation style for any depth
+    ///
+    /// Use this for trees nested deeper than the indentation classes cover.
+    pub fn indent_style(&self, depth: usize) -> String {
+        format!("padding-left: {}rem;", depth * self.indent_step() / 4)
+    }
+
+    /// Get the fixed row height in pixels (for virtualized lists)
+    pub fn row_height_px(&self) -> u32 {
+        const ROW_HEIGHTS: [u32; 7] = [20, 24, 28, 32, 40, 48, 56];
+        self.density.scale(&ROW_HEIGHTS, self.size_index())
+    }
+
+    fn row_height_classes(&self) -> &'static str {
+        const ROW_HEIGHTS: [&str; 7] = ["h-5", "h-6", "h-7", "h-8", "h-10", "h-12", "h-14"];
+        self.density.scale(&ROW_HEIGHTS, self.size_index())
+    }
+
+    fn size_index(&self) -> usize {
+        match self.size {
+            SelectionSize::XS => 1,
+            SelectionSize::SM => 2,
+            SelectionSize::MD => 3,
+            SelectionSize::LG => 4,
+            SelectionSize::XL => 5,
+        }
+    }
+
+    fn text_size_classes(&self) -> &'static str {
+        match self.size {
+            SelectionSize::XS => "text-xs",
+            SelectionSize::SM | SelectionSize::MD => "text-sm",
+            SelectionSize::LG => "text-base",
+            SelectionSize::XL => "text-lg",
+        }
+    }
+
+    fn indent_step(&self) -> usize {
+        match self.size {
+            SelectionSize::XS | SelectionSize::SM => 4,
+            SelectionSize::MD | SelectionSize::LG | SelectionSize::XL => 8,
+        }
+    }
+}
+
+/// Convenience function to create tree styles
+pub fn tree_styles<C: ColorProvider>(color_provider: C) -> TreeStyles<C> {
+    TreeStyles::new(color_provider)
+}
+
+/// Convenience function to create checkbox tree styles
+pub fn checkbox_tree_styles<C: ColorProvider>(color_provider: C) -> TreeStyles<C> {
+    TreeStyles::new(color_provider)
+        .with_checkboxes(true)
+        .with_guides(true)
+}
+
+#[cfg(test)]
+#[path = "tree_test.rs"]
+mod tree_test;
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::tree::{checkbox_tree_styles, tree_styles, TreeStyles};
+    use crate::core::Density;
+    use crate::patterns::SelectionState;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_tree_row_indentation() {
+        let tree = TreeStyles::new(VibeColors::default());
+
+        assert!(tree
+            .row_classes(0, SelectionState::Unselected)
+            .contains("pl-0"));
+        assert!(tree
+            .row_classes(2, SelectionState::Unselected)
+            .contains("pl-16"));
+        // Depth is capped at the deepest indentation class
+        assert_eq!(tree.indent_classes(20), "pl-64");
+        assert_eq!(tree.indent_style(20), "padding-left: 40rem;");
+
+        let small = TreeStyles::new(VibeColors::default()).sm();
+        assert_eq!(small.indent_classes(3), "pl-12");
+        assert_eq!(small.indent_style(3), "padding-left: 3rem;");
+    }
+
+    #[test]
+    fn test_tree_fixed_row_heights() {
+        let colors = VibeColors::default();
+
+        let md = tree_styles(colors.clone());
+        assert_eq!(md.row_height_px(), 32);
+        assert!(md
+            .row_classes(0, SelectionState::Unselected)
+            .contains("h-8"));
+
+        let xs = tree_styles(colors.clone()).xs();
+        assert_eq!(xs.row_height_px(), 24);
+        assert!(xs
+            .row_classes(0, SelectionState::Unselected)
+            .contains("h-6"));
+
+        let xl = tree_styles(colors).xl();
+        assert_eq!(xl.row_height_px(), 48);
+    }
+
+    #[test]
+    fn test_tree_selected_row_highlight() {
+        let tree = TreeStyles::new(VibeColors::default());
+
+        let selected = tree.row_classes(1, SelectionState::Selected);
+        assert!(selected.contains("tree-row--selected"));
+        assert!(selected.contains("text-jupiter-blue-500"));
+
+        let disabled = tree.row_classes(1, SelectionState::Disabled);
+        assert!(disabled.contains("cursor-not-allowed"));
+        assert!(!disabled.contains("cursor-pointer"));
+    }
+
+    #[test]
+    fn test_tree_toggle() {
+        let tree = TreeStyles::new(VibeColors::default());
+
+        assert!(tree.toggle_classes(true, true).contains("rotate-90"));
+        assert!(tree.toggle_classes(false, true).contains("rotate-0"));
+        assert!(tree.toggle_classes(true, false).contains("invisible"));
+    }
+
+    #[test]
+    fn test_tree_checkbox_tri_state() {
+        let plain = TreeStyles::new(VibeColors::default());
+        assert_eq!(plain.checkbox_classes(SelectionState::Selected), "");
+
+        let tree = checkbox_tree_styles(VibeColors::default());
+        assert!(tree
+            .checkbox_classes(SelectionState::Selected)
+            .contains("tree-checkbox--checked"));
+        assert!(tree
+            .checkbox_classes(SelectionState::PartiallySelected)
+            .contains("tree-checkbox--mixed"));
+        assert!(tree.group_classes().contains("border-l"));
+    }
+
+    #[test]
+    fn test_selection_state_from_children() {
+        use SelectionState::*;
+
+        assert_eq!(
+            SelectionState::from_children(&[Selected, Selected]),
+            Selected
+        );
+        assert_eq!(
+            SelectionState::from_children(&[Unselected, Unselected]),
+            Unselected
+        );
+        assert_eq!(
+            SelectionState::from_children(&[Selected, Unselected]),
+            PartiallySelected
+        );
+        assert_eq!(
+            SelectionState::from_children(&[Selected, PartiallySelected]),
+            PartiallySelected
+        );
+        assert_eq!(
+            SelectionState::from_children(&[Selected, Disabled]),
+            Selected
+        );
+        assert_eq!(SelectionState::from_children(&[Disabled]), Disabled);
+        assert_eq!(SelectionState::from_children(&[]), Unselected);
+
+        assert_eq!(PartiallySelected.aria_checked(), "mixed");
+        assert_eq!(Selected.aria_checked(), "true");
+        assert_eq!(Unselected.aria_checked(), "false");
+    }
+
+    #[test]
+    fn test_tree_density_scales_row_height() {
+        let colors = VibeColors::default();
+
+        let compact = TreeStyles::new(colors.clone()).density(Density::Compact);
+        assert_eq!(compact.row_height_px(), 28);
+        assert!(compact
+            .row_classes(0, SelectionState::Unselected)
+            .contains("h-7"));
+
+        let spacious = TreeStyles::new(colors.with_density(Density::Spacious)).xl();
+        assert_eq!(spacious.row_height_px(), 56);
+    }
+}
@@ -0,0 +1,290 @@
+//! File upload styling utilities for the Jupiter Design System
+//!
+//! Provides a chainable API for drag-and-drop upload targets and the file list
+//! rows shown while files are queued or uploading.
+
+use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderStyle, BorderWidth, Color};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Dropzone interaction states
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DropzoneState {
+    /// Waiting for files
+    Idle,
+    /// Files are being dragged over the target
+    DragOver,
+    /// A dropped file was rejected
+    Error,
+    /// Files are uploading
+    Uploading,
+}
+
+/// Dropzone styling utility builder
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::builders::upload::DropzoneStyles;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let dropzone = DropzoneStyles::new(VibeColors::default()).drag_over();
+///
+/// let zone_classes = dropzone.classes();
+/// let icon_classes = dropzone.icon_classes();
+/// let progress_classes = dropzone.file_progress_classes();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropzoneStyles<C: ColorProvider> {
+    state: DropzoneState,
+    compact: bool,
+    custom_classes: Vec<String>,
+    color_provider: C,
+}
+
+impl<C: ColorProvider> DropzoneStyles<C> {
+    /// Create a new dropzone styling utility
+    pub fn new(color_provider: C) -> Self {
+        Self {
+            state: DropzoneState::Idle,
+            compact: false,
+            custom_classes: Vec::new(),
+            color_provider,
+        }
+    }
+
+    // === State Methods ===
+
+    /// Set idle state
+    pub fn idle(mut self) -> Self {
+        self.state = DropzoneState::Idle;
+        self
+    }
+
+    /// Set drag-over state
+    pub fn drag_over(mut self) -> Self {
+        self.state = DropzoneState::DragOver;
+        self
+    }
+
+    /// Set error state (rejected file)
+    pub fn error(mut self) -> Self {
+        self.state = DropzoneState::Error;
+        self
+    }
+
+    /// Set uploading state
+    pub fn uploading(mut self) -> Self {
+        self.state = DropzoneState::Uploading;
+        self
+    }
+
+    /// Set state explicitly
+    pub fn state(mut self, state: DropzoneState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set state from string
+    pub fn state_str(mut self, state: &str) -> Self {
+        self.state = match state {
+            "idle" => DropzoneState::Idle,
+            "drag-over" | "dragover" | "active" => DropzoneState::DragOver,
+            "error" | "rejected" => DropzoneState::Error,
+            "uploading" | "loading" => DropzoneState::Uploading,
+            _ => DropzoneState::Idle, // fallback
+        };
+        self
+    }
+
+    // === Variant Methods ===
+
+    /// Use the compact, button-like variant
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    // === Custom Methods ===
+
+    /// Add a custom CSS class
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom_classes.push(class.into());
+        self
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the drop target
+    pub fn classes(&self) -> String {
+        let mut classes = Vec::new();
+
+        if self.compact {
+            classes.push(
+                "dropzone dropzone--compact inline-flex flex-row items-center gap-2 px-4 py-2 rounded-md text-sm font-medium"
+                    .to_string(),
+            );
+        } else {
+            classes.push(
+                "dropzone flex flex-col items-center justify-center gap-3 p-8 rounded-lg text-center"
+                    .to_string(),
+            );
+        }
+        classes.push(
+            self.color_provider
+                .border_scale()
+                .border_width_class(BorderWidth::Thick),
+        );
+        classes.push(BorderStyle::Dashed.class().to_string());
+        classes.push("transition-colors duration-200".to_string());
+
+        let state_classes = match self.state {
+            DropzoneState::Idle => format!(
+                "cursor-pointer {} {} hover:{}",
+                self.color_provider.bg_class(Color::Surface),
+                self.color_provider.border_class(Color::Border),
+                self.color_provider.border_class(Color::Interactive)
+            ),
+            DropzoneState::DragOver => format!(
+                "dropzone--drag-over cursor-copy {} {}/10",
+                self.color_provider.border_class(Color::Accent),
+                self.color_provider.bg_class(Color::Accent)
+            ),
+            DropzoneState::Error => format!(
+                "dropzone--error {} {}/10",
+                self.color_provider.border_class(Color::Error),
+                self.color_provider.bg_class(Color::Error)
+            ),
+            DropzoneState::Uploading => format!(
+                "dropzone--uploading cursor-wait pointer-events-none {} {}",
+                self.color_provider.border_class(Color::Border),
+                self.color_provider.bg_class(Color::Background)
+            ),
+        };
+        classes.push(state_classes);
+
+        classes.extend(self.custom_classes.iter().cloned());
+        classes.join(" ")
+    }
+
+    /// Build classes for the dropzone icon
+    pub fn icon_classes(&self) -> String {
+        let size = if self.compact { "w-4 h-4" } else { "w-10 h-10" };
+        let color = match self.state {
+            DropzoneState::Idle => Color::TextTertiary,
+            DropzoneState::DragOver => Color::Accent,
+            DropzoneState::Error => Color::Error,
+            DropzoneState::Uploading => Color::Primary,
+        };
+        let animation = if self.state == DropzoneState::Uploading {
+            " motion-safe:animate-pulse"
+        } else {
+            ""
+        };
+        format!(
+            "dropzone-icon {} {}{}",
+            size,
+            self.color_provider.text_class(color),
+            animation
+        )
+    }
+
+    /// Build classes for the dropzone primary text
+    pub fn text_classes(&self) -> String {
+        let color = match self.state {
+            DropzoneState::Error => Color::Error,
+            _ => Color::TextPrimary,
+        };
+        format!(
+            "dropzone-text text-sm font-medium {}",
+            self.color_provider.text_class(color)
+        )
+    }
+
+    /// Build classes for the dropzone hint (accepted types, size limits)
+    pub fn hint_classes(&self) -> String {
+        if self.compact {
+            return "hidden".to_string();
+        }
+        format!(
+            "dropzone-hint text-xs {}",
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for the uploaded file list
+    pub fn file_list_classes(&self) -> String {
+        "dropzone-files flex flex-col gap-2 mt-4 w-full".to_string()
+    }
+
+    /// Build classes for a file row
+    pub fn file_row_classes(&self) -> String {
+        format!(
+            "dropzone-file grid grid-cols-[1fr_auto] items-center gap-x-3 gap-y-1 px-3 py-2 rounded-md border {} {}",
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider.border_class(Color::Border)
+        )
+    }
+
+    /// Build classes for the file name
+    pub fn file_name_classes(&self) -> String {
+        format!(
+            "dropzone-file-name text-sm font-medium truncate {}",
+            self.color_provider.text_class(Color::TextPrimary)
+        )
+    }
+
+    /// Build classes for the file size caption
+    pub fn file_size_classes(&self) -> String {
+        format!(
+            "dropzone-file-size text-xs tabular-nums {}",
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for the file progress track
+    pub fn file_progress_classes(&self) -> String {
+        format!(
+            "dropzone-file-progress col-span-2 h-1.5 w-full overflow-hidden rounded-full {}",
+            self.color_provider.bg_class(Color::Background)
+        )
+    }
+
+    /// Build classes for the file progress bar fill
+    pub fn file_progress_bar_classes(&self) -> String {
+        let color = match self.state {
+            DropzoneState::Error => Color::Error,
+            _ => Color::Primary,
+        };
+        format!(
+            "dropzone-file-progress-bar h-full rounded-full transition-all duration-300 motion-reduce:transition-none {}",
+            self.color_provider.bg_class(color)
+        )
+    }
+
+    /// Build classes for the file remove action
+    pub fn file_remove_classes(&self) -> String {
+        format!(
+            "dropzone-file-remove inline-flex items-center justify-center rounded-full p-1 cursor-pointer focus:outline-none focus:ring-2 {} hover:{}",
+            self.color_provider.text_class(Color::TextTertiary),
+            self.color_provider.text_class(Color::Error)
+        )
+    }
+}
+
+/// Convenience function to create dropzone styles
+pub fn dropzone_styles<C: ColorProvider>(color_provider: C) -> DropzoneStyles<C> {
+    DropzoneStyles::new(color_provider)
+}
+
+/// Convenience function to create compact, button-like dropzone styles
+pub fn compact_dropzone_styles<C: ColorProvider>(color_provider: C) -> DropzoneStyles<C> {
+    DropzoneStyles::new(color_provider).compact()
+}
+
+#[cfg(test)]
+#[path = "upload_test.rs"]
+mod upload_test;
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::upload::{
+        compact_dropzone_styles, dropzone_styles, DropzoneState, DropzoneStyles,
+    };
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_dropzone_idle() {
+        let classes = dropzone_styles(VibeColors::default()).classes();
+
+        assert!(classes.contains("dropzone"));
+        assert!(classes.contains("border-dashed"));
+        assert!(classes.contains("border-gray-200"));
+        assert!(classes.contains("cursor-pointer"));
+    }
+
+    #[test]
+    fn test_dropzone_drag_over_uses_accent() {
+        let dropzone = DropzoneStyles::new(VibeColors::default()).drag_over();
+
+        let classes = dropzone.classes();
+        assert!(classes.contains("dropzone--drag-over"));
+        assert!(classes.contains("border-jupiter-orange-500"));
+        assert!(classes.contains("bg-jupiter-orange-500/10"));
+        assert!(dropzone.icon_classes().contains("text-jupiter-orange-500"));
+    }
+
+    #[test]
+    fn test_dropzone_error_and_uploading() {
+        let colors = VibeColors::default();
+
+        let error = DropzoneStyles::new(colors.clone()).error();
+        assert!(error.classes().contains("border-red-500"));
+        assert!(error.text_classes().contains("text-red-500"));
+        assert!(error.file_progress_bar_classes().contains("bg-red-500"));
+
+        let uploading = DropzoneStyles::new(colors).uploading();
+        assert!(uploading.classes().contains("cursor-wait"));
+        assert!(uploading.icon_classes().contains("animate-pulse"));
+    }
+
+    #[test]
+    fn test_dropzone_state_str() {
+        let colors = VibeColors::default();
+
+        let classes = DropzoneStyles::new(colors.clone())
+            .state_str("dragover")
+            .classes();
+        assert!(classes.contains("dropzone--drag-over"));
+
+        let classes = DropzoneStyles::new(colors.clone())
+            .state(DropzoneState::Error)
+            .classes();
+        assert!(classes.contains("dropzone--error"));
+
+        let classes = DropzoneStyles::new(colors).state_str("invalid").classes();
+        assert!(classes.contains("cursor-pointer"));
+    }
+
+    #[test]
+    fn test_compact_dropzone() {
+        let dropzone = compact_dropzone_styles(VibeColors::default());
+
+        let classes = dropzone.classes();
+        assert!(classes.contains("dropzone--compact"));
+        assert!(classes.contains("inline-flex"));
+        assert!(!classes.contains("p-8"));
+        assert!(dropzone.icon_classes().contains("w-4"));
+        assert_eq!(dropzone.hint_classes(), "hidden");
+    }
+
+    #[test]
+    fn test_dropzone_file_rows() {
+        let dropzone = dropzone_styles(VibeColors::default());
+
+        assert!(dropzone.file_row_classes().contains("dropzone-file"));
+        assert!(dropzone.file_name_classes().contains("truncate"));
+        assert!(dropzone.file_size_classes().contains("text-xs"));
+        assert!(dropzone.file_progress_classes().contains("col-span-2"));
+        assert!(dropzone
+            .file_progress_bar_classes()
+            .contains("bg-jupiter-blue-500"));
+        assert!(dropzone
+            .file_remove_classes()
+            .contains("hover:text-red-500"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Border width and style system for the design system
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Semantic border width tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BorderWidth {
+    /// Thinnest line for subtle separators
+    Hairline,
+    /// Cards, inputs, dividers and other outlined elements
+    Default,
+    /// Emphasized lines such as tab indicators
+    Thick,
+}
+
+/// Semantic border style tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BorderStyle {
+    /// Continuous line
+    Solid,
+    /// Dashed line for drop zones and placeholders
+    Dashed,
+}
+
+impl BorderStyle {
+    /// Get the CSS class for this style
+    pub fn class(&self) -> &'static str {
+        match self {
+            BorderStyle::Solid => "border-solid",
+            BorderStyle::Dashed => "border-dashed",
+        }
+    }
+}
+
+/// Tailwind border width suffixes for each width token
+///
+/// An empty suffix is Tailwind's 1px `border`. Themes provide their own
+/// scale to change every outline at once, e.g. `BorderScale::bold()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{BorderProvider, BorderScale, BorderWidth};
+///
+/// let scale = BorderScale::default();
+/// assert_eq!(scale.border_width_class(BorderWidth::Default), "border");
+/// assert_eq!(scale.border_side_class("b", BorderWidth::Thick), "border-b-2");
+///
+/// assert_eq!(scale.divide_class("y", BorderWidth::Default), "divide-y");
+///
+/// let bold = BorderScale::bold();
+/// assert_eq!(bold.border_width_class(BorderWidth::Default), "border-2");
+/// assert_eq!(bold.divide_class("y", BorderWidth::Default), "divide-y-2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BorderScale {
+    /// Width suffix for subtle separators, e.g. `[0.5px]`
+    pub hairline: String,
+    /// Width suffix for outlines and dividers; empty for Tailwind's 1px `border`
+    pub default: String,
+    /// Width suffix for emphasized outlines, e.g. `2`
+    pub thick: String,
+}
+
+impl Default for BorderScale {
+    fn default() -> Self {
+        Self {
+            hairline: "[0.5px]".to_string(),
+            default: String::new(),
+            thick: "2".to_string(),
+        }
+    }
+}
+
+impl BorderScale {
+    /// Heavier scale with 2px outlines, for bold or neo-brutalist brands
+    pub fn bold() -> Self {
+        Self {
+            hairline: String::new(),
+            default: "2".to_string(),
+            thick: "4".to_string(),
+        }
+    }
+}
+
+/// Trait for providing border values
+pub trait BorderProvider {
+    /// Resolve a border width to its Tailwind suffix
+    fn resolve_border_width(&self, width: BorderWidth) -> &str;
+
+    /// Get the class for a border on every side
+    fn border_width_class(&self, width: BorderWidth) -> String {
+        self.border_side_class("", width)
+    }
+
+    /// Get the class for a border on one side (`"t"`, `"b"`, `"l"`, `"r"`,
+    /// `"x"` or `"y"`; empty for every side)
+    fn border_side_class(&self, side: &str, width: BorderWidth) -> String {
+        let mut class = String::from("border");
+        if !side.is_empty() {
+            class.push('-');
+            class.push_str(side);
+        }
+        let suffix = self.resolve_border_width(width);
+        if !suffix.is_empty() {
+            class.push_str(&format!("-{}", suffix));
+        }
+        class
+    }
+
+    /// Get the class for borders between children along an axis (`"x"` or
+    /// `"y"`), e.g. dividers between list rows
+    fn divide_class(&self, axis: &str, width: BorderWidth) -> String {
+        let suffix = self.resolve_border_width(width);
+        if suffix.is_empty() {
+            format!("divide-{}", axis)
+        } else {
+            format!("divide-{}-{}", axis, suffix)
+        }
+    }
+}
+
+impl BorderProvider for BorderScale {
+    fn resolve_border_width(&self, width: BorderWidth) -> &str {
+        match width {
+            BorderWidth::Hairline => &self.hairline,
+            BorderWidth::Default => &self.default,
+            BorderWidth::Thick => &self.thick,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Data visualization colors for the design system
+//!
+//! Charts need more colors than the semantic palette offers, in a fixed
+//! order, plus ramps for heatmaps and chrome colors for grids and axes.
+//! [`ChartPalette`] provides them as Tailwind color names so dashboards pull
+//! from the theme instead of hardcoding hex values.
+
+use crate::core::color_value::ColorValue;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// Color roles for charts
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::ChartPalette;
+///
+/// let chart = ChartPalette::default();
+///
+/// // Series colors wrap around after the last categorical color
+/// assert_eq!(chart.chart_color_class(0), "text-jupiter-blue-500");
+/// assert_eq!(chart.chart_color_class(chart.categorical.len()), chart.chart_color_class(0));
+///
+/// assert_eq!(chart.sequential_color(0.0), "jupiter-blue-100");
+/// assert_eq!(chart.diverging_color(0.0), "gray-100");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChartPalette {
+    /// Series colors in order of use
+    pub categorical: Vec<String>,
+    /// Low-to-high ramp for magnitudes (heatmaps, choropleths)
+    pub sequential: Vec<String>,
+    /// Negative-to-positive ramp with a neutral middle
+    pub diverging: Vec<String>,
+    /// Grid lines
+    pub grid: String,
+    /// Axis lines and ticks
+    pub axis: String,
+    /// Axis and data labels
+    pub label: String,
+}
+
+impl Default for ChartPalette {
+    fn default() -> Self {
+        Self {
+            categorical: names(&[
+                "jupiter-blue-500",
+                "jupiter-orange-500",
+                "jupiter-green-500",
+                "violet-500",
+                "pink-500",
+                "cyan-500",
+                "amber-500",
+                "slate-500",
+            ]),
+            sequential: names(&[
+                "jupiter-blue-100",
+                "jupiter-blue-200",
+                "jupiter-blue-300",
+                "jupiter-blue-400",
+                "jupiter-blue-500",
+                "jupiter-blue-600",
+                "jupiter-blue-700",
+            ]),
+            diverging: names(&[
+                "red-700", "red-500", "red-300", "gray-100", "blue-300", "blue-500", "blue-700",
+            ]),
+            grid: "gray-200".to_string(),
+            axis: "gray-400".to_string(),
+            label: "gray-600".to_string(),
+        }
+    }
+}
+
+impl ChartPalette {
+    /// Chart colors for the legacy WaterWellness palette
+    ///
+    /// Same roles as the default, with the brand series and ramp using the
+    /// `water-*` color names.
+    pub fn water_wellness() -> Self {
+        Self {
+            categorical: names(&[
+                "water-blue-500",
+                "water-orange-500",
+                "water-green-500",
+                "violet-500",
+                "pink-500",
+                "cyan-500",
+                "amber-500",
+                "slate-500",
+            ]),
+            sequential: names(&[
+                "water-blue-100",
+                "water-blue-200",
+                "water-blue-300",
+                "water-blue-400",
+                "water-blue-500",
+                "water-blue-600",
+                "water-blue-700",
+            ]),
+            ..Self::default()
+        }
+    }
+
+    /// Chart colors derived from a brand color
+    ///
+    /// The brand leads the series, followed by its complement and an
+    /// analogous hue (the accent and secondary of
+    /// `VibeColors::from_brand_hex`), then the default series colors. The
+    /// sequential ramp runs from a light tint to a dark shade of the brand;
+    /// the diverging ramp and chrome colors keep the defaults.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::core::{ChartPalette, ColorValue};
+    ///
+    /// let chart = ChartPalette::from_brand(ColorValue::from_hex("#7c3aed").unwrap());
+    ///
+    /// assert_eq!(chart.categorical_color(0), "[#7c3aed]");
+    /// assert_eq!(chart.sequential_color(4.0 / 6.0), "[#7c3aed]");
+    /// assert_eq!(chart.diverging_color(0.0), "gray-100");
+    /// ```
+    pub fn from_brand(brand: ColorValue) -> Self {
+        let defaults = Self::default();
+        let mut categorical = Vec::with_capacity(defaults.categorical.len());
+        categorical.push(brand.palette_value());
+        categorical.push(brand.rotate_hue(180.0).palette_value());
+        categorical.push(brand.rotate_hue(-40.0).palette_value());
+        categorical.extend(defaults.categorical.iter().skip(3).cloned());
+
+        let sequential = [0.36, 0.27, 0.18, 0.09]
+            .iter()
+            .map(|amount| brand.lighten(*amount))
+            .chain([brand, brand.darken(0.08), brand.darken(0.16)])
+            .map(|color| color.palette_value())
+            .collect();
+
+        Self {
+            categorical,
+            sequential,
+            ..defaults
+        }
+    }
+
+    /// Color of the series at `index`, wrapping around the categorical list
+    pub fn categorical_color(&self, index: usize) -> &str {
+        match self.categorical.len() {
+            0 => &self.axis,
+            len => &self.categorical[index % len],
+        }
+    }
+
+    /// Sequential color for `value` in 0.0 (lowest) to 1.0 (highest)
+    pub fn sequential_color(&self, value: f32) -> &str {
+        ramp(&self.sequential, value.clamp(0.0, 1.0)).unwrap_or(&self.axis)
+    }
+
+    /// Diverging color for `value` in -1.0 (most negative) to 1.0 (most
+    /// positive); 0.0 is the neutral middle
+    pub fn diverging_color(&self, value: f32) -> &str {
+        ramp(&self.diverging, (value.clamp(-1.0, 1.0) + 1.0) / 2.0).unwrap_or(&self.axis)
+    }
+
+    /// Text color class for the series at `index`, for charts drawing with
+    /// `currentColor`
+    pub fn chart_color_class(&self, index: usize) -> String {
+        format!("text-{}", self.categorical_color(index))
+    }
+
+    /// SVG fill class for the series at `index` (bars, areas, slices)
+    pub fn fill_class(&self, index: usize) -> String {
+        format!("fill-{}", self.categorical_color(index))
+    }
+
+    /// SVG stroke class for the series at `index` (lines)
+    pub fn stroke_class(&self, index: usize) -> String {
+        format!("stroke-{}", self.categorical_color(index))
+    }
+
+    /// Background class for the legend swatch of the series at `index`
+    pub fn swatch_class(&self, index: usize) -> String {
+        format!("bg-{}", self.categorical_color(index))
+    }
+
+    /// Classes for grid lines
+    pub fn grid_classes(&self) -> String {
+        format!("stroke-{} stroke-1", self.grid)
+    }
+
+    /// Classes for axis lines and ticks
+    pub fn axis_classes(&self) -> String {
+        format!("stroke-{} stroke-1", self.axis)
+    }
+
+    /// Classes for axis and data labels
+    pub fn label_classes(&self) -> String {
+        format!(
+            "fill-{} text-{} text-xs tabular-nums",
+            self.label, self.label
+        )
+    }
+}
+
+fn ramp(colors: &[String], position: f32) -> Option<&str> {
+    let last = colors.len().checked_sub(1)?;
+    let index = (position * last as f32 + 0.5) as usize;
+    Some(&colors[index.min(last)])
+}
+
+fn names(colors: &[&str]) -> Vec<String> {
+    colors.iter().map(|color| color.to_string()).collect()
+}
+
+#[cfg(test)]
+#[path = "chart_test.rs"]
+mod chart_test;
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::chart::ChartPalette;
+    use crate::core::color::{Color, ColorProvider};
+    use crate::core::ColorValue;
+    use crate::themes::VibeColors;
+
+    #[test]
+    fn test_categorical_colors_wrap() {
+        let chart = ChartPalette::default();
+
+        assert_eq!(chart.categorical_color(1), "jupiter-orange-500");
+        assert_eq!(
+            chart.categorical_color(chart.categorical.len() + 1),
+            chart.categorical_color(1)
+        );
+        assert_eq!(chart.fill_class(0), "fill-jupiter-blue-500");
+        assert_eq!(chart.stroke_class(2), "stroke-jupiter-green-500");
+        assert_eq!(chart.swatch_class(0), "bg-jupiter-blue-500");
+    }
+
+    #[test]
+    fn test_sequential_scale() {
+        let chart = ChartPalette::default();
+
+        assert_eq!(chart.sequential_color(0.0), "jupiter-blue-100");
+        assert_eq!(chart.sequential_color(0.5), "jupiter-blue-400");
+        assert_eq!(chart.sequential_color(1.0), "jupiter-blue-700");
+        assert_eq!(chart.sequential_color(7.0), "jupiter-blue-700");
+    }
+
+    #[test]
+    fn test_diverging_scale() {
+        let chart = ChartPalette::default();
+
+        assert_eq!(chart.diverging_color(-1.0), "red-700");
+        assert_eq!(chart.diverging_color(0.0), "gray-100");
+        assert_eq!(chart.diverging_color(1.0), "blue-700");
+        assert_eq!(chart.diverging_color(-5.0), "red-700");
+    }
+
+    #[test]
+    fn test_chrome_classes() {
+        let chart = ChartPalette::default();
+
+        assert_eq!(chart.grid_classes(), "stroke-gray-200 stroke-1");
+        assert_eq!(chart.axis_classes(), "stroke-gray-400 stroke-1");
+        assert!(chart.label_classes().contains("fill-gray-600"));
+    }
+
+    #[test]
+    fn test_empty_palette_falls_back_to_axis() {
+        let chart = ChartPalette {
+            categorical: Vec::new(),
+            sequential: Vec::new(),
+            ..ChartPalette::default()
+        };
+
+        assert_eq!(chart.categorical_color(3), "gray-400");
+        assert_eq!(chart.sequential_color(0.5), "gray-400");
+    }
+
+    #[test]
+    fn test_theme_chart_palette() {
+        let colors = VibeColors::default().with_chart_palette(ChartPalette {
+            categorical: vec!["violet-600".to_string(), "teal-500".to_string()],
+            ..ChartPalette::default()
+        });
+
+        assert_eq!(colors.chart_palette().chart_color_class(1), "text-teal-500");
+        assert_eq!(
+            VibeColors::default().chart_palette(),
+            ChartPalette::default()
+        );
+    }
+
+    #[test]
+    fn test_chart_palette_deserializes_from_owned_json() {
+        let json: String = serde_json::to_string(&ChartPalette::water_wellness()).unwrap();
+        let chart: ChartPalette = serde_json::from_str(&json).unwrap();
+        drop(json);
+
+        assert_eq!(chart, ChartPalette::water_wellness());
+    }
+
+    #[test]
+    fn test_brand_chart_palette() {
+        let colors = VibeColors::from_brand_hex("#7c3aed").unwrap();
+        let chart = colors.chart_palette();
+
+        assert_eq!(
+            chart.categorical_color(0),
+            colors.resolve_color(Color::Primary)
+        );
+        assert_eq!(
+            chart.categorical_color(1),
+            colors.resolve_color(Color::Accent)
+        );
+        assert_eq!(
+            chart.categorical_color(2),
+            colors.resolve_color(Color::Secondary)
+        );
+        assert_eq!(
+            chart.categorical.len(),
+            ChartPalette::default().categorical.len()
+        );
+        assert!(chart.sequential.iter().all(|c| c.starts_with("[#")));
+
+        // The ramp runs from light to dark
+        let luminance = |value: f32| {
+            ColorValue::from_palette_value(chart.sequential_color(value))
+                .unwrap()
+                .relative_luminance()
+        };
+        assert!(luminance(0.0) > luminance(0.5));
+        assert!(luminance(0.5) > luminance(1.0));
+        assert_eq!(chart.diverging, ChartPalette::default().diverging);
+    }
+}
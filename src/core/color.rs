@@ -3,6 +3,21 @@
 //! Provides trait-based color management with semantic color naming
 //! and theme-aware color resolution.
 
+use crate::core::border::BorderScale;
+use crate::core::chart::ChartPalette;
+use crate::core::color_value::ColorValue;
+use crate::core::density::{DataDisplayTokens, Density};
+use crate::core::elevation::ElevationScale;
+use crate::core::glass::GlassScale;
+use crate::core::motion::MotionScale;
+use crate::core::radius::RadiusScale;
+use crate::core::sizing::{BreakpointScale, SizeScale};
+use crate::core::state_layer::StateLayerScale;
+use crate::themes::ComponentDefaults;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// Semantic color tokens for consistent theming
@@ -21,6 +36,8 @@ pub enum Color {
 
     // Neutral colors
     Surface,
+    SurfaceRaised,
+    SurfaceOverlay,
     Background,
     Foreground,
     Border,
@@ -39,7 +56,7 @@ pub enum Color {
 }
 
 /// Color palette containing all color values for a theme
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ColorPalette {
     // Brand colors
     pub primary: String,
@@ -54,6 +71,10 @@ pub struct ColorPalette {
 
     // Neutral colors
     pub surface: String,
+    #[serde(default = "default_raised_surface")]
+    pub surface_raised: String,
+    #[serde(default = "default_overlay_surface")]
+    pub surface_overlay: String,
     pub background: String,
     pub foreground: String,
     pub border: String,
@@ -69,6 +90,200 @@ pub struct ColorPalette {
     pub interactive_hover: String,
     pub interactive_active: String,
     pub interactive_disabled: String,
+
+    // Soft semantic variants: tinted backgrounds and the text that sits on
+    // them, derived from the semantic colors when unset
+    #[serde(default)]
+    pub success_soft_bg: Option<String>,
+    #[serde(default)]
+    pub success_soft_text: Option<String>,
+    #[serde(default)]
+    pub warning_soft_bg: Option<String>,
+    #[serde(default)]
+    pub warning_soft_text: Option<String>,
+    #[serde(default)]
+    pub error_soft_bg: Option<String>,
+    #[serde(default)]
+    pub error_soft_text: Option<String>,
+    #[serde(default)]
+    pub info_soft_bg: Option<String>,
+    #[serde(default)]
+    pub info_soft_text: Option<String>,
+
+    // Domain-specific statuses
+    #[serde(default)]
+    pub custom_statuses: Vec<CustomStatus>,
+
+    // Domain-specific action intents
+    #[serde(default)]
+    pub custom_intents: Vec<CustomIntent>,
+}
+
+fn default_raised_surface() -> String {
+    String::from("white")
+}
+
+fn default_overlay_surface() -> String {
+    String::from("white")
+}
+
+/// Levels of the surface ladder, from the page up to floating layers
+///
+/// Depth is expressed by stepping up the ladder rather than picking
+/// backgrounds ad hoc, so dark themes only need to recolor four tokens.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::prelude::*;
+/// use jupiter_design_system::core::SurfaceLevel;
+///
+/// let colors = VibeColors::default();
+///
+/// assert_eq!(colors.surface_class(SurfaceLevel::Background), "bg-gray-50");
+/// assert_eq!(SurfaceLevel::from_level(3), SurfaceLevel::Overlay);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SurfaceLevel {
+    /// Level 0: the page background
+    Background,
+    /// Level 1: cards and panels on the page
+    Surface,
+    /// Level 2: raised content such as elevated cards
+    Raised,
+    /// Level 3: menus, popovers and modals
+    Overlay,
+}
+
+impl SurfaceLevel {
+    /// Get the level for a ladder index, clamping above 3
+    pub fn from_level(level: u8) -> Self {
+        match level {
+            0 => SurfaceLevel::Background,
+            1 => SurfaceLevel::Surface,
+            2 => SurfaceLevel::Raised,
+            _ => SurfaceLevel::Overlay,
+        }
+    }
+
+    /// Get the palette color for this level
+    pub fn color(&self) -> Color {
+        match self {
+            SurfaceLevel::Background => Color::Background,
+            SurfaceLevel::Surface => Color::Surface,
+            SurfaceLevel::Raised => Color::SurfaceRaised,
+            SurfaceLevel::Overlay => Color::SurfaceOverlay,
+        }
+    }
+}
+
+/// Theme colors for selected items
+///
+/// Selected buttons, chips, list items and cards fill with the primary
+/// color unless the theme names other tokens here; unset text picks
+/// whichever of the inverse or primary text reads on the fill. Tabs and
+/// segmented controls keep their own indicator styling.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{Color, SelectionTokens};
+///
+/// let storefront = SelectionTokens {
+///     selected_bg: Some(Color::Accent),
+///     ..SelectionTokens::default()
+/// };
+/// assert_eq!(storefront.selected_border, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SelectionTokens {
+    /// Fill of selected items; `None` uses the primary color
+    pub selected_bg: Option<Color>,
+    /// Text on selected items; `None` picks readable text for the fill
+    pub selected_text: Option<Color>,
+    /// Border of selected items; `None` matches the fill
+    pub selected_border: Option<Color>,
+}
+
+/// A named status beyond success/warning/error/info
+///
+/// Apps register statuses such as "pending", "archived" or "beta" on the
+/// palette so badges and states can style them like the built-in ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CustomStatus {
+    /// Name used to look the status up
+    pub name: String,
+    /// Color class value, e.g. `"violet-500"`
+    pub color: String,
+}
+
+/// How strongly a custom action intent is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IntentEmphasis {
+    /// Solid fill with inverse text, like primary buttons
+    Filled,
+    /// Colored border and text on a transparent background
+    Outline,
+    /// Tinted background with colored text
+    Subtle,
+    /// Colored text only, like links
+    Text,
+}
+
+/// A named action intent beyond primary/secondary/destructive
+///
+/// Apps register intents such as "publish" or "archive" on the palette and
+/// reference them with `ActionIntent::Custom`, so each theme decides how
+/// domain actions look.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CustomIntent {
+    /// Name used to look the intent up
+    pub name: String,
+    /// Color class value, e.g. `"teal-600"`
+    pub color: String,
+    /// How strongly the intent is drawn
+    pub emphasis: IntentEmphasis,
+}
+
+impl ColorPalette {
+    /// Register a custom action intent, replacing any intent with the same
+    /// name
+    pub fn add_intent(
+        &mut self,
+        name: impl Into<String>,
+        color: impl Into<String>,
+        emphasis: IntentEmphasis,
+    ) {
+        let intent = CustomIntent {
+            name: name.into(),
+            color: color.into(),
+            emphasis,
+        };
+        match self
+            .custom_intents
+            .iter_mut()
+            .find(|existing| existing.name == intent.name)
+        {
+            Some(existing) => *existing = intent,
+            None => self.custom_intents.push(intent),
+        }
+    }
+
+    /// Register a custom status, replacing any status with the same name
+    pub fn add_status(&mut self, name: impl Into<String>, color: impl Into<String>) {
+        let status = CustomStatus {
+            name: name.into(),
+            color: color.into(),
+        };
+        match self
+            .custom_statuses
+            .iter_mut()
+            .find(|existing| existing.name == status.name)
+        {
+            Some(existing) => *existing = status,
+            None => self.custom_statuses.push(status),
+        }
+    }
 }
 
 /// Trait for providing color values from a color palette
@@ -88,6 +303,8 @@ pub trait ColorProvider {
             Color::Error => &palette.error,
             Color::Info => &palette.info,
             Color::Surface => &palette.surface,
+            Color::SurfaceRaised => &palette.surface_raised,
+            Color::SurfaceOverlay => &palette.surface_overlay,
             Color::Background => &palette.background,
             Color::Foreground => &palette.foreground,
             Color::Border => &palette.border,
@@ -102,6 +319,238 @@ pub trait ColorProvider {
         }
     }
 
+    /// Text color that reads on a background token
+    ///
+    /// Filled surfaces keep the inverse text while it has at least 4.5:1
+    /// contrast (WCAG AA for body-size text) and switch to the primary text
+    /// only when that reads better. Mid-tone fills such as `blue-500` and
+    /// `red-500` fall short of 4.5:1 with white and get dark text; dark
+    /// fills such as `blue-700` keep white.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::prelude::*;
+    ///
+    /// let colors = VibeColors::default();
+    /// assert_eq!(colors.on_color(Color::Primary), Color::TextPrimary);
+    /// assert_eq!(colors.on_color_value("blue-700"), Color::TextInverse);
+    /// assert_eq!(colors.on_color(Color::Warning), Color::TextPrimary);
+    /// assert_eq!(colors.on_color(Color::Surface), Color::TextPrimary);
+    /// ```
+    fn on_color(&self, background: Color) -> Color {
+        self.on_color_value(self.resolve_color(background))
+    }
+
+    /// Text color that reads on a raw palette value, e.g. a custom intent
+    /// or status color
+    ///
+    /// Values that cannot be resolved (see
+    /// [`ColorValue::from_palette_value`]) get the inverse text.
+    fn on_color_value(&self, background: &str) -> Color {
+        let value = |color: &str| ColorValue::from_palette_value(color);
+        let (Some(background), Some(inverse), Some(primary)) = (
+            value(background),
+            value(self.resolve_color(Color::TextInverse)),
+            value(self.resolve_color(Color::TextPrimary)),
+        ) else {
+            return Color::TextInverse;
+        };
+
+        let inverse_contrast = background.contrast_ratio(inverse);
+        if inverse_contrast >= 4.5 || inverse_contrast >= background.contrast_ratio(primary) {
+            Color::TextInverse
+        } else {
+            Color::TextPrimary
+        }
+    }
+
+    /// Default density for builders created with this provider
+    ///
+    /// Themes override this to switch every builder to a compact or
+    /// spacious layout; individual builders can still set their own.
+    fn density(&self) -> Density {
+        Density::Comfortable
+    }
+
+    /// Density and striping defaults for tables and lists
+    ///
+    /// Builders resolve data density through this, so a theme can make
+    /// data-heavy components compact without changing everything else.
+    fn data_display(&self) -> DataDisplayTokens {
+        DataDisplayTokens::default()
+    }
+
+    /// Colors for selected items in selection groups
+    fn selection_tokens(&self) -> SelectionTokens {
+        SelectionTokens::default()
+    }
+
+    /// Defaults for builders created with `for_theme`
+    fn component_defaults(&self) -> ComponentDefaults {
+        ComponentDefaults::default()
+    }
+
+    /// Elevation scale used by builders created with this provider
+    fn elevation_scale(&self) -> ElevationScale {
+        ElevationScale::default()
+    }
+
+    /// Radius scale used by builders created with this provider
+    fn radius_scale(&self) -> RadiusScale {
+        RadiusScale::default()
+    }
+
+    /// Border widths used by builders created with this provider
+    fn border_scale(&self) -> BorderScale {
+        BorderScale::default()
+    }
+
+    /// Transition durations used by builders created with this provider
+    fn motion_scale(&self) -> MotionScale {
+        MotionScale::default()
+    }
+
+    /// State layer opacities used by builders created with this provider
+    fn state_layer_scale(&self) -> StateLayerScale {
+        StateLayerScale::default()
+    }
+
+    /// Glass surface tint, blur and border used by builders created with
+    /// this provider
+    fn glass_scale(&self) -> GlassScale {
+        GlassScale::default()
+    }
+
+    /// Control heights used by builders created with this provider
+    fn size_scale(&self) -> SizeScale {
+        SizeScale::default()
+    }
+
+    /// Breakpoint widths used by builders created with this provider
+    fn breakpoint_scale(&self) -> BreakpointScale {
+        BreakpointScale::default()
+    }
+
+    /// Data visualization colors for charts using this provider
+    fn chart_palette(&self) -> ChartPalette {
+        ChartPalette::default()
+    }
+
+    /// Resolve a status name to its color value
+    ///
+    /// "success", "warning", "error" and "info" resolve to the semantic
+    /// colors; any other name is looked up in the palette's custom statuses.
+    fn status_color(&self, name: &str) -> Option<&str> {
+        match name {
+            "success" => Some(self.resolve_color(Color::Success)),
+            "warning" => Some(self.resolve_color(Color::Warning)),
+            "error" => Some(self.resolve_color(Color::Error)),
+            "info" => Some(self.resolve_color(Color::Info)),
+            _ => self
+                .palette()
+                .custom_statuses
+                .iter()
+                .find(|status| status.name == name)
+                .map(|status| status.color.as_str()),
+        }
+    }
+
+    /// Get a text color class for a status, if the status is known
+    fn status_text_class(&self, name: &str) -> Option<String> {
+        self.status_color(name)
+            .map(|color| format!("text-{}", color))
+    }
+
+    /// Get a background color class for a status, if the status is known
+    fn status_bg_class(&self, name: &str) -> Option<String> {
+        self.status_color(name).map(|color| format!("bg-{}", color))
+    }
+
+    /// Get badge classes for a status
+    ///
+    /// Unknown statuses get a neutral badge instead of failing.
+    fn status_badge_classes(&self, name: &str) -> String {
+        let (text, bg) = match self.status_color(name) {
+            Some(color) => (format!("text-{}", color), format!("bg-{}/10", color)),
+            None => (
+                self.text_class(Color::TextSecondary),
+                self.bg_class(Color::Background),
+            ),
+        };
+        format!(
+            "inline-flex items-center rounded-full px-2 py-0.5 text-xs font-medium {} {}",
+            text, bg
+        )
+    }
+
+    /// Look up a custom action intent by name
+    fn custom_intent(&self, name: &str) -> Option<&CustomIntent> {
+        self.palette()
+            .custom_intents
+            .iter()
+            .find(|intent| intent.name == name)
+    }
+
+    /// Get color classes for a custom action intent, if the intent is known
+    fn custom_intent_classes(&self, name: &str) -> Option<String> {
+        let intent = self.custom_intent(name)?;
+        let color = &intent.color;
+        Some(match intent.emphasis {
+            IntentEmphasis::Filled => format!(
+                "bg-{} {} hover:bg-{}/90",
+                color,
+                self.text_class(self.on_color_value(color)),
+                color
+            ),
+            IntentEmphasis::Outline => format!(
+                "bg-transparent text-{} border border-{} hover:bg-{}/10",
+                color, color, color
+            ),
+            IntentEmphasis::Subtle => {
+                format!("bg-{}/10 text-{} hover:bg-{}/20", color, color, color)
+            }
+            IntentEmphasis::Text => format!("bg-transparent text-{} hover:underline", color),
+        })
+    }
+
+    /// Tinted background value for a semantic color, e.g. `"green-50"`
+    ///
+    /// Uses the palette's soft token for success, warning, error and info
+    /// when set. Otherwise `family-shade` colors step down to their `50`
+    /// shade and other values get a 10% tint.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::prelude::*;
+    ///
+    /// let colors = VibeColors::default();
+    /// assert_eq!(colors.soft_bg(Color::Warning), "amber-50");
+    /// assert_eq!(colors.soft_text(Color::Warning), "amber-600");
+    ///
+    /// let custom = VibeColors::with_overrides(|palette| {
+    ///     palette.warning_soft_bg = Some("yellow-100".to_string());
+    /// });
+    /// assert_eq!(custom.soft_bg(Color::Warning), "yellow-100");
+    /// ```
+    fn soft_bg(&self, color: Color) -> String {
+        if let Some(token) = soft_token(self.palette(), color, true) {
+            return token.clone();
+        }
+        let value = self.resolve_color(color);
+        with_shade(value, "50").unwrap_or_else(|| format!("{}/10", value))
+    }
+
+    /// Text value that reads on [`soft_bg`](Self::soft_bg), e.g.
+    /// `"green-600"`
+    ///
+    /// Uses the palette's soft token when set, otherwise the `600` shade
+    /// of `family-shade` colors or the color itself.
+    fn soft_text(&self, color: Color) -> String {
+        if let Some(token) = soft_token(self.palette(), color, false) {
+            return token.clone();
+        }
+        let value = self.resolve_color(color);
+        with_shade(value, "600").unwrap_or_else(|| value.to_string())
+    }
+
     /// Get a Tailwind CSS class for text color
     fn text_class(&self, color: Color) -> String {
         format!("text-{}", self.resolve_color(color))
@@ -116,6 +565,262 @@ pub trait ColorProvider {
     fn border_class(&self, color: Color) -> String {
         format!("border-{}", self.resolve_color(color))
     }
+
+    /// Get the background class for a level of the surface ladder
+    fn surface_class(&self, level: SurfaceLevel) -> String {
+        self.bg_class(level.color())
+    }
+
+    /// Get the gradient classes for branded surfaces
+    ///
+    /// Runs from the primary color to its active shade, so themes built
+    /// with `with_overrides` or `from_brand_hex` rebrand hero and branded
+    /// surfaces without extra tokens. Pair with `TextInverse` text.
+    fn brand_gradient_classes(&self) -> String {
+        format!(
+            "bg-gradient-to-br from-{} to-{}",
+            self.resolve_color(Color::Primary),
+            self.resolve_color(Color::InteractiveActive)
+        )
+    }
+}
+
+/// Shared providers (e.g. the global theme) resolve through the inner provider
+impl<T: ColorProvider + ?Sized> ColorProvider for Arc<T> {
+    fn palette(&self) -> &ColorPalette {
+        (**self).palette()
+    }
+
+    fn resolve_color(&self, color: Color) -> &str {
+        (**self).resolve_color(color)
+    }
+
+    fn on_color(&self, background: Color) -> Color {
+        (**self).on_color(background)
+    }
+
+    fn on_color_value(&self, background: &str) -> Color {
+        (**self).on_color_value(background)
+    }
+
+    fn density(&self) -> Density {
+        (**self).density()
+    }
+
+    fn data_display(&self) -> DataDisplayTokens {
+        (**self).data_display()
+    }
+
+    fn selection_tokens(&self) -> SelectionTokens {
+        (**self).selection_tokens()
+    }
+
+    fn component_defaults(&self) -> ComponentDefaults {
+        (**self).component_defaults()
+    }
+
+    fn elevation_scale(&self) -> ElevationScale {
+        (**self).elevation_scale()
+    }
+
+    fn radius_scale(&self) -> RadiusScale {
+        (**self).radius_scale()
+    }
+
+    fn border_scale(&self) -> BorderScale {
+        (**self).border_scale()
+    }
+
+    fn motion_scale(&self) -> MotionScale {
+        (**self).motion_scale()
+    }
+
+    fn state_layer_scale(&self) -> StateLayerScale {
+        (**self).state_layer_scale()
+    }
+
+    fn glass_scale(&self) -> GlassScale {
+        (**self).glass_scale()
+    }
+
+    fn size_scale(&self) -> SizeScale {
+        (**self).size_scale()
+    }
+
+    fn breakpoint_scale(&self) -> BreakpointScale {
+        (**self).breakpoint_scale()
+    }
+
+    fn chart_palette(&self) -> ChartPalette {
+        (**self).chart_palette()
+    }
+
+    fn status_color(&self, name: &str) -> Option<&str> {
+        (**self).status_color(name)
+    }
+
+    fn custom_intent(&self, name: &str) -> Option<&CustomIntent> {
+        (**self).custom_intent(name)
+    }
+
+    fn custom_intent_classes(&self, name: &str) -> Option<String> {
+        (**self).custom_intent_classes(name)
+    }
+
+    fn soft_bg(&self, color: Color) -> String {
+        (**self).soft_bg(color)
+    }
+
+    fn soft_text(&self, color: Color) -> String {
+        (**self).soft_text(color)
+    }
+
+    fn text_class(&self, color: Color) -> String {
+        (**self).text_class(color)
+    }
+
+    fn bg_class(&self, color: Color) -> String {
+        (**self).bg_class(color)
+    }
+
+    fn border_class(&self, color: Color) -> String {
+        (**self).border_class(color)
+    }
+
+    fn brand_gradient_classes(&self) -> String {
+        (**self).brand_gradient_classes()
+    }
+}
+
+/// Borrowed providers resolve through the provider they point to, so
+/// builders can hand `&self.colors` to patterns without cloning
+impl<T: ColorProvider + ?Sized> ColorProvider for &T {
+    fn palette(&self) -> &ColorPalette {
+        (**self).palette()
+    }
+
+    fn resolve_color(&self, color: Color) -> &str {
+        (**self).resolve_color(color)
+    }
+
+    fn on_color(&self, background: Color) -> Color {
+        (**self).on_color(background)
+    }
+
+    fn on_color_value(&self, background: &str) -> Color {
+        (**self).on_color_value(background)
+    }
+
+    fn density(&self) -> Density {
+        (**self).density()
+    }
+
+    fn data_display(&self) -> DataDisplayTokens {
+        (**self).data_display()
+    }
+
+    fn selection_tokens(&self) -> SelectionTokens {
+        (**self).selection_tokens()
+    }
+
+    fn component_defaults(&self) -> ComponentDefaults {
+        (**self).component_defaults()
+    }
+
+    fn elevation_scale(&self) -> ElevationScale {
+        (**self).elevation_scale()
+    }
+
+    fn radius_scale(&self) -> RadiusScale {
+        (**self).radius_scale()
+    }
+
+    fn border_scale(&self) -> BorderScale {
+        (**self).border_scale()
+    }
+
+    fn motion_scale(&self) -> MotionScale {
+        (**self).motion_scale()
+    }
+
+    fn state_layer_scale(&self) -> StateLayerScale {
+        (**self).state_layer_scale()
+    }
+
+    fn glass_scale(&self) -> GlassScale {
+        (**self).glass_scale()
+    }
+
+    fn size_scale(&self) -> SizeScale {
+        (**self).size_scale()
+    }
+
+    fn breakpoint_scale(&self) -> BreakpointScale {
+        (**self).breakpoint_scale()
+    }
+
+    fn chart_palette(&self) -> ChartPalette {
+        (**self).chart_palette()
+    }
+
+    fn status_color(&self, name: &str) -> Option<&str> {
+        (**self).status_color(name)
+    }
+
+    fn custom_intent(&self, name: &str) -> Option<&CustomIntent> {
+        (**self).custom_intent(name)
+    }
+
+    fn custom_intent_classes(&self, name: &str) -> Option<String> {
+        (**self).custom_intent_classes(name)
+    }
+
+    fn soft_bg(&self, color: Color) -> String {
+        (**self).soft_bg(color)
+    }
+
+    fn soft_text(&self, color: Color) -> String {
+        (**self).soft_text(color)
+    }
+
+    fn text_class(&self, color: Color) -> String {
+        (**self).text_class(color)
+    }
+
+    fn bg_class(&self, color: Color) -> String {
+        (**self).bg_class(color)
+    }
+
+    fn border_class(&self, color: Color) -> String {
+        (**self).border_class(color)
+    }
+
+    fn brand_gradient_classes(&self) -> String {
+        (**self).brand_gradient_classes()
+    }
+}
+
+/// Palette override for a soft variant, if the color has one
+fn soft_token(palette: &ColorPalette, color: Color, background: bool) -> Option<&String> {
+    let (bg, text) = match color {
+        Color::Success => (&palette.success_soft_bg, &palette.success_soft_text),
+        Color::Warning => (&palette.warning_soft_bg, &palette.warning_soft_text),
+        Color::Error => (&palette.error_soft_bg, &palette.error_soft_text),
+        Color::Info => (&palette.info_soft_bg, &palette.info_soft_text),
+        _ => return None,
+    };
+    if background {
+        bg.as_ref()
+    } else {
+        text.as_ref()
+    }
+}
+
+/// Swap the shade of a `family-shade` color (`green-500` to `green-50`)
+fn with_shade(value: &str, shade: &str) -> Option<String> {
+    let (family, current) = value.rsplit_once('-')?;
+    let is_shade = !family.is_empty() && current.chars().all(|ch| ch.is_ascii_digit());
+    is_shade.then(|| format!("{}-{}", family, shade))
 }
 
 impl Default for ColorPalette {
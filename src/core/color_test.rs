@@ -2,7 +2,11 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::core::color::{Color, ColorPalette, ColorProvider};
+    use crate::core::color::{Color, ColorPalette, ColorProvider, IntentEmphasis, SurfaceLevel};
+    use crate::core::{
+        BorderProvider, BorderScale, BorderWidth, ColorValue, MotionScale, Radius, RadiusProvider,
+        RadiusScale, Size, SizeProvider, SizeScale,
+    };
     use crate::themes::VibeColors;
 
     #[test]
@@ -188,4 +192,284 @@ mod tests {
 
         assert_eq!(palette, deserialized);
     }
+
+    #[test]
+    fn test_radius_scale_deserializes_from_owned_json() {
+        let json: String = serde_json::to_string(&RadiusScale::sharp()).unwrap();
+        let scale: RadiusScale = serde_json::from_str(&json).unwrap();
+        drop(json);
+
+        assert_eq!(scale, RadiusScale::sharp());
+        assert_eq!(
+            VibeColors::default()
+                .with_radius(scale)
+                .radius_scale()
+                .radius_class(Radius::Control),
+            "rounded-sm"
+        );
+    }
+
+    #[test]
+    fn test_border_scale_deserializes_from_owned_json() {
+        let json: String = serde_json::to_string(&BorderScale::bold()).unwrap();
+        let scale: BorderScale = serde_json::from_str(&json).unwrap();
+        drop(json);
+
+        assert_eq!(scale, BorderScale::bold());
+        assert_eq!(
+            VibeColors::default()
+                .with_borders(scale)
+                .border_scale()
+                .border_width_class(BorderWidth::Default),
+            "border-2"
+        );
+    }
+
+    #[test]
+    fn test_size_scale_deserializes_from_owned_json() {
+        let json: String = serde_json::to_string(&SizeScale::touch()).unwrap();
+        let scale: SizeScale = serde_json::from_str(&json).unwrap();
+        drop(json);
+
+        assert_eq!(scale, SizeScale::touch());
+        assert_eq!(
+            VibeColors::default()
+                .with_sizes(scale)
+                .size_scale()
+                .control_height_class(Size::Medium),
+            "h-12"
+        );
+    }
+
+    #[test]
+    fn test_motion_scale_flags_default_when_missing() {
+        let mut json = serde_json::to_value(MotionScale::default()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("arbitrary_scales");
+        fields.remove("arbitrary_durations");
+        let scale: MotionScale = serde_json::from_value(json).unwrap();
+
+        assert_eq!(scale, MotionScale::default());
+    }
+
+    #[test]
+    fn test_status_color_lookup() {
+        let colors = VibeColors::default()
+            .with_status("pending", "violet-500")
+            .with_status("archived", "slate-400");
+
+        assert_eq!(colors.status_color("warning"), Some("amber-500"));
+        assert_eq!(colors.status_color("archived"), Some("slate-400"));
+        assert_eq!(colors.status_color("beta"), None);
+        assert_eq!(
+            colors.status_text_class("pending"),
+            Some("text-violet-500".to_string())
+        );
+        assert_eq!(
+            colors.status_bg_class("pending"),
+            Some("bg-violet-500".to_string())
+        );
+    }
+
+    #[test]
+    fn test_add_status_replaces_existing() {
+        let colors = VibeColors::default()
+            .with_status("beta", "pink-500")
+            .with_status("beta", "cyan-500");
+
+        assert_eq!(colors.palette().custom_statuses.len(), 1);
+        assert_eq!(colors.status_color("beta"), Some("cyan-500"));
+    }
+
+    #[test]
+    fn test_status_badge_classes() {
+        let colors = VibeColors::default().with_status("pending", "violet-500");
+
+        let pending = colors.status_badge_classes("pending");
+        assert!(pending.contains("text-violet-500"));
+        assert!(pending.contains("bg-violet-500/10"));
+        assert!(pending.contains("rounded-full"));
+
+        let unknown = colors.status_badge_classes("beta");
+        assert!(unknown.contains("text-gray-600"));
+        assert!(unknown.contains("bg-gray-50"));
+    }
+
+    #[test]
+    fn test_custom_statuses_deserialize_as_empty_when_missing() {
+        let mut value = serde_json::to_value(ColorPalette::default()).unwrap();
+        value.as_object_mut().unwrap().remove("custom_statuses");
+
+        let palette: ColorPalette = serde_json::from_value(value).unwrap();
+        assert!(palette.custom_statuses.is_empty());
+    }
+
+    #[test]
+    fn test_surface_ladder() {
+        let colors = VibeColors::default();
+
+        assert_eq!(colors.surface_class(SurfaceLevel::Background), "bg-gray-50");
+        assert_eq!(colors.surface_class(SurfaceLevel::Surface), "bg-white");
+        assert_eq!(colors.resolve_color(Color::SurfaceRaised), "white");
+        assert_eq!(colors.resolve_color(Color::SurfaceOverlay), "white");
+        assert_eq!(SurfaceLevel::from_level(0), SurfaceLevel::Background);
+        assert_eq!(SurfaceLevel::from_level(9), SurfaceLevel::Overlay);
+    }
+
+    #[test]
+    fn test_surface_levels_deserialize_with_defaults() {
+        let mut value = serde_json::to_value(ColorPalette::default()).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("surface_raised");
+        object.remove("surface_overlay");
+
+        let palette: ColorPalette = serde_json::from_value(value).unwrap();
+        assert_eq!(palette.surface_raised, "white");
+        assert_eq!(palette.surface_overlay, "white");
+    }
+
+    #[test]
+    fn test_custom_intent_classes_by_emphasis() {
+        let colors = VibeColors::default()
+            .with_intent("publish", "teal-600", IntentEmphasis::Filled)
+            .with_intent("archive", "slate-500", IntentEmphasis::Outline)
+            .with_intent("draft", "amber-500", IntentEmphasis::Subtle)
+            .with_intent("learn", "sky-600", IntentEmphasis::Text);
+
+        let publish = colors.custom_intent_classes("publish").unwrap();
+        assert!(publish.contains("bg-teal-600"));
+        assert!(publish.contains("text-gray-900"));
+
+        let archive = colors.custom_intent_classes("archive").unwrap();
+        assert!(archive.contains("border-slate-500"));
+        assert!(archive.contains("bg-transparent"));
+
+        let draft = colors.custom_intent_classes("draft").unwrap();
+        assert!(draft.contains("bg-amber-500/10"));
+
+        let learn = colors.custom_intent_classes("learn").unwrap();
+        assert!(learn.contains("hover:underline"));
+
+        assert_eq!(colors.custom_intent_classes("unknown"), None);
+    }
+
+    #[test]
+    fn test_add_intent_replaces_existing() {
+        let colors = VibeColors::default()
+            .with_intent("publish", "teal-600", IntentEmphasis::Filled)
+            .with_intent("publish", "indigo-600", IntentEmphasis::Subtle);
+
+        assert_eq!(colors.palette().custom_intents.len(), 1);
+        let intent = colors.custom_intent("publish").unwrap();
+        assert_eq!(intent.color, "indigo-600");
+        assert_eq!(intent.emphasis, IntentEmphasis::Subtle);
+    }
+
+    #[test]
+    fn test_soft_variants_derive_from_semantic_colors() {
+        let colors = VibeColors::default();
+
+        assert_eq!(colors.soft_bg(Color::Success), "green-50");
+        assert_eq!(colors.soft_text(Color::Success), "green-600");
+        assert_eq!(colors.soft_bg(Color::Error), "red-50");
+        assert_eq!(colors.soft_text(Color::Info), "blue-600");
+        assert_eq!(colors.soft_bg(Color::Primary), "jupiter-blue-50");
+
+        // Values without a shade get a tint of themselves
+        let hex = VibeColors::with_overrides(|palette| {
+            palette.error = "[#b91c1c]".to_string();
+        });
+        assert_eq!(hex.soft_bg(Color::Error), "[#b91c1c]/10");
+        assert_eq!(hex.soft_text(Color::Error), "[#b91c1c]");
+    }
+
+    #[test]
+    fn test_soft_variant_tokens_override_derivation() {
+        let colors = VibeColors::with_overrides(|palette| {
+            palette.success = "emerald-500".to_string();
+            palette.success_soft_text = Some("emerald-800".to_string());
+            palette.error_soft_bg = Some("rose-100".to_string());
+        });
+
+        assert_eq!(colors.soft_bg(Color::Success), "emerald-50");
+        assert_eq!(colors.soft_text(Color::Success), "emerald-800");
+        assert_eq!(colors.soft_bg(Color::Error), "rose-100");
+        assert_eq!(colors.soft_text(Color::Error), "red-600");
+
+        // Borrowed and shared providers resolve the same tokens
+        let borrowed: &VibeColors = &colors;
+        assert_eq!(
+            ColorProvider::soft_text(&borrowed, Color::Success),
+            "emerald-800"
+        );
+    }
+
+    #[test]
+    fn test_soft_variant_tokens_deserialize_as_unset() {
+        let mut json = serde_json::to_value(VibeColors::default().palette()).unwrap();
+        json.as_object_mut().unwrap().remove("warning_soft_bg");
+        let palette: ColorPalette = serde_json::from_value(json).unwrap();
+
+        assert_eq!(palette.warning_soft_bg, None);
+    }
+
+    #[test]
+    fn test_on_color_requires_body_text_contrast() {
+        // White on jupiter-blue-500 clears the 3:1 large-text minimum but
+        // not the 4.5:1 needed for labels at body size
+        let blue = ColorValue::from_palette_value("jupiter-blue-500").unwrap();
+        let white_contrast = blue.contrast_ratio(ColorValue::WHITE);
+        assert!(white_contrast > 3.0 && white_contrast < 4.5);
+
+        let colors = VibeColors::default();
+        assert_eq!(
+            colors.on_color_value("jupiter-blue-500"),
+            Color::TextPrimary
+        );
+    }
+
+    #[test]
+    fn test_on_color_picks_readable_text() {
+        let colors = VibeColors::default();
+
+        assert_eq!(colors.on_color(Color::Primary), Color::TextPrimary);
+        assert_eq!(colors.on_color(Color::Error), Color::TextPrimary);
+        assert_eq!(colors.on_color_value("blue-700"), Color::TextInverse);
+        assert_eq!(colors.on_color_value("red-700"), Color::TextInverse);
+        assert_eq!(colors.on_color(Color::Warning), Color::TextPrimary);
+        assert_eq!(colors.on_color(Color::Success), Color::TextPrimary);
+        assert_eq!(colors.on_color(Color::Background), Color::TextPrimary);
+
+        // Unresolvable colors keep the inverse text
+        assert_eq!(colors.on_color_value("brand-500"), Color::TextInverse);
+        assert_eq!(colors.on_color_value("[#facc15]"), Color::TextPrimary);
+
+        // Providers behind Arc and references resolve the same way
+        let shared = alloc::sync::Arc::new(colors.clone());
+        assert_eq!(shared.on_color(Color::Warning), Color::TextPrimary);
+        let borrowed: &VibeColors = &colors;
+        assert_eq!(
+            ColorProvider::on_color(&borrowed, Color::Primary),
+            Color::TextPrimary
+        );
+    }
+
+    #[test]
+    fn test_on_color_follows_brand_themes() {
+        let light = VibeColors::from_brand_hex("#facc15").unwrap();
+        assert_eq!(light.on_color(Color::Primary), Color::TextPrimary);
+        assert_eq!(light.text_class(Color::TextInverse), "text-white");
+
+        let colors = VibeColors::default()
+            .with_intent("caution", "amber-400", IntentEmphasis::Filled)
+            .with_intent("publish", "teal-700", IntentEmphasis::Filled);
+        assert!(colors
+            .custom_intent_classes("caution")
+            .unwrap()
+            .contains("text-gray-900"));
+        assert!(colors
+            .custom_intent_classes("publish")
+            .unwrap()
+            .contains("text-white"));
+    }
 }
@@ -0,0 +1,294 @@
+//! Concrete color values and color math
+//!
+//! Palettes normally hold Tailwind color names (`jupiter-blue-500`). When a
+//! theme is derived from a raw brand color instead, [`ColorValue`] does the
+//! math (shades, hue rotation, contrast) and renders the results as
+//! arbitrary-value classes such as `bg-[#7c3aed]`.
+//!
+//! Palette entries resolve back to values through
+//! [`ColorValue::from_palette_value`], which knows Tailwind's default
+//! colors, so contrast can be checked for any theme.
+//!
+//! Only arithmetic is used, so this works in `no_std` builds.
+
+use alloc::format;
+use alloc::string::String;
+
+/// An sRGB color
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::ColorValue;
+///
+/// let violet = ColorValue::from_hex("#7C3AED").unwrap();
+/// assert_eq!(violet.to_hex(), "#7c3aed");
+/// assert_eq!(violet.palette_value(), "[#7c3aed]");
+/// assert!(violet.contrast_ratio(ColorValue::WHITE) > 4.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ColorValue {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl ColorValue {
+    pub const WHITE: ColorValue = ColorValue::rgb(255, 255, 255);
+    /// Tailwind gray-900, used for dark text on light brand colors
+    pub const NEAR_BLACK: ColorValue = ColorValue::rgb(17, 24, 39);
+
+    /// Create a color from its channels
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parse `#rrggbb` or `#rgb` (the `#` is optional)
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim().trim_start_matches('#');
+        // from_str_radix accepts a leading sign, so check the digits first
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+        match hex.len() {
+            6 => Some(Self::rgb(
+                channel(hex.get(0..2)?)?,
+                channel(hex.get(2..4)?)?,
+                channel(hex.get(4..6)?)?,
+            )),
+            3 => {
+                let short = |i: usize| channel(hex.get(i..i + 1)?).map(|v| v * 17);
+                Some(Self::rgb(short(0)?, short(1)?, short(2)?))
+            }
+            _ => None,
+        }
+    }
+
+    /// Resolve a palette entry to its value
+    ///
+    /// Understands arbitrary values (`[#7c3aed]`), `white`, `black` and
+    /// Tailwind's default color scales (`amber-500`). The `jupiter-*`,
+    /// `water-*` and `jupiter-navy` brand scales are configured as copies
+    /// of Tailwind's blue, green, orange and slate scales. Returns `None`
+    /// for anything else, such as colors defined only in a project's own
+    /// Tailwind config.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::core::ColorValue;
+    ///
+    /// assert_eq!(ColorValue::from_palette_value("amber-500"), ColorValue::from_hex("#f59e0b"));
+    /// assert_eq!(ColorValue::from_palette_value("jupiter-blue-500"), ColorValue::from_hex("#3b82f6"));
+    /// assert_eq!(ColorValue::from_palette_value("[#7c3aed]"), ColorValue::from_hex("#7c3aed"));
+    /// assert_eq!(ColorValue::from_palette_value("brand-500"), None);
+    /// ```
+    pub fn from_palette_value(value: &str) -> Option<Self> {
+        if let Some(hex) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            return Self::from_hex(hex);
+        }
+        match value {
+            "white" => return Some(Self::WHITE),
+            "black" => return Some(Self::rgb(0, 0, 0)),
+            _ => {}
+        }
+
+        let (hue, shade) = value.rsplit_once('-')?;
+        let hue = match hue {
+            "jupiter-blue" | "water-blue" => "blue",
+            "jupiter-green" | "water-green" => "green",
+            "jupiter-orange" | "water-orange" => "orange",
+            "jupiter-navy" => "slate",
+            hue => hue,
+        };
+        let index = TAILWIND_SHADES.iter().position(|s| *s == shade)?;
+        let (_, scale) = TAILWIND_COLORS.iter().find(|(name, _)| *name == hue)?;
+        let rgb = scale[index];
+        Some(Self::rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8))
+    }
+
+    /// Format as lowercase `#rrggbb`
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Value for a [`ColorPalette`](crate::core::ColorPalette) entry, so
+    /// `bg_class` produces `bg-[#rrggbb]`
+    pub fn palette_value(&self) -> String {
+        format!("[{}]", self.to_hex())
+    }
+
+    /// Convert to hue (degrees), saturation and lightness (0.0-1.0)
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+
+        if max == min {
+            return (0.0, 0.0, lightness);
+        }
+
+        let delta = max - min;
+        let saturation = if lightness > 0.5 {
+            delta / (2.0 - max - min)
+        } else {
+            delta / (max + min)
+        };
+        let hue = if max == r {
+            (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        (hue * 60.0, saturation, lightness)
+    }
+
+    /// Create a color from hue (degrees), saturation and lightness (0.0-1.0)
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let hue = wrap_degrees(hue) / 360.0;
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        if saturation == 0.0 {
+            let v = to_channel(lightness);
+            return Self::rgb(v, v, v);
+        }
+
+        let q = if lightness < 0.5 {
+            lightness * (1.0 + saturation)
+        } else {
+            lightness + saturation - lightness * saturation
+        };
+        let p = 2.0 * lightness - q;
+
+        Self::rgb(
+            to_channel(hue_to_rgb(p, q, hue + 1.0 / 3.0)),
+            to_channel(hue_to_rgb(p, q, hue)),
+            to_channel(hue_to_rgb(p, q, hue - 1.0 / 3.0)),
+        )
+    }
+
+    /// Lighten by `amount` of lightness (0.0-1.0)
+    pub fn lighten(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount)
+    }
+
+    /// Darken by `amount` of lightness (0.0-1.0)
+    pub fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Rotate the hue by `degrees`
+    pub fn rotate_hue(&self, degrees: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h + degrees, s, l)
+    }
+
+    /// WCAG relative luminance (0.0 black - 1.0 white)
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126 * linear_channel(self.r)
+            + 0.7152 * linear_channel(self.g)
+            + 0.0722 * linear_channel(self.b)
+    }
+
+    /// WCAG contrast ratio against another color (1.0-21.0)
+    pub fn contrast_ratio(&self, other: ColorValue) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        let (light, dark) = if a > b { (a, b) } else { (b, a) };
+        (light + 0.05) / (dark + 0.05)
+    }
+
+    /// White or near-black, whichever reads better on this color
+    pub fn readable_text(&self) -> ColorValue {
+        if self.contrast_ratio(Self::WHITE) >= self.contrast_ratio(Self::NEAR_BLACK) {
+            Self::WHITE
+        } else {
+            Self::NEAR_BLACK
+        }
+    }
+}
+
+const TAILWIND_SHADES: [&str; 11] = [
+    "50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950",
+];
+
+/// Tailwind's default color scales, one entry per shade in `TAILWIND_SHADES`
+#[rustfmt::skip]
+const TAILWIND_COLORS: [(&str, [u32; 11]); 22] = [
+    ("slate", [0xf8fafc, 0xf1f5f9, 0xe2e8f0, 0xcbd5e1, 0x94a3b8, 0x64748b, 0x475569, 0x334155, 0x1e293b, 0x0f172a, 0x020617]),
+    ("gray", [0xf9fafb, 0xf3f4f6, 0xe5e7eb, 0xd1d5db, 0x9ca3af, 0x6b7280, 0x4b5563, 0x374151, 0x1f2937, 0x111827, 0x030712]),
+    ("zinc", [0xfafafa, 0xf4f4f5, 0xe4e4e7, 0xd4d4d8, 0xa1a1aa, 0x71717a, 0x52525b, 0x3f3f46, 0x27272a, 0x18181b, 0x09090b]),
+    ("neutral", [0xfafafa, 0xf5f5f5, 0xe5e5e5, 0xd4d4d4, 0xa3a3a3, 0x737373, 0x525252, 0x404040, 0x262626, 0x171717, 0x0a0a0a]),
+    ("stone", [0xfafaf9, 0xf5f5f4, 0xe7e5e4, 0xd6d3d1, 0xa8a29e, 0x78716c, 0x57534e, 0x44403c, 0x292524, 0x1c1917, 0x0c0a09]),
+    ("red", [0xfef2f2, 0xfee2e2, 0xfecaca, 0xfca5a5, 0xf87171, 0xef4444, 0xdc2626, 0xb91c1c, 0x991b1b, 0x7f1d1d, 0x450a0a]),
+    ("orange", [0xfff7ed, 0xffedd5, 0xfed7aa, 0xfdba74, 0xfb923c, 0xf97316, 0xea580c, 0xc2410c, 0x9a3412, 0x7c2d12, 0x431407]),
+    ("amber", [0xfffbeb, 0xfef3c7, 0xfde68a, 0xfcd34d, 0xfbbf24, 0xf59e0b, 0xd97706, 0xb45309, 0x92400e, 0x78350f, 0x451a03]),
+    ("yellow", [0xfefce8, 0xfef9c3, 0xfef08a, 0xfde047, 0xfacc15, 0xeab308, 0xca8a04, 0xa16207, 0x854d0e, 0x713f12, 0x422006]),
+    ("lime", [0xf7fee7, 0xecfccb, 0xd9f99d, 0xbef264, 0xa3e635, 0x84cc16, 0x65a30d, 0x4d7c0f, 0x3f6212, 0x365314, 0x1a2e05]),
+    ("green", [0xf0fdf4, 0xdcfce7, 0xbbf7d0, 0x86efac, 0x4ade80, 0x22c55e, 0x16a34a, 0x15803d, 0x166534, 0x14532d, 0x052e16]),
+    ("emerald", [0xecfdf5, 0xd1fae5, 0xa7f3d0, 0x6ee7b7, 0x34d399, 0x10b981, 0x059669, 0x047857, 0x065f46, 0x064e3b, 0x022c22]),
+    ("teal", [0xf0fdfa, 0xccfbf1, 0x99f6e4, 0x5eead4, 0x2dd4bf, 0x14b8a6, 0x0d9488, 0x0f766e, 0x115e59, 0x134e4a, 0x042f2e]),
+    ("cyan", [0xecfeff, 0xcffafe, 0xa5f3fc, 0x67e8f9, 0x22d3ee, 0x06b6d4, 0x0891b2, 0x0e7490, 0x155e75, 0x164e63, 0x083344]),
+    ("sky", [0xf0f9ff, 0xe0f2fe, 0xbae6fd, 0x7dd3fc, 0x38bdf8, 0x0ea5e9, 0x0284c7, 0x0369a1, 0x075985, 0x0c4a6e, 0x082f49]),
+    ("blue", [0xeff6ff, 0xdbeafe, 0xbfdbfe, 0x93c5fd, 0x60a5fa, 0x3b82f6, 0x2563eb, 0x1d4ed8, 0x1e40af, 0x1e3a8a, 0x172554]),
+    ("indigo", [0xeef2ff, 0xe0e7ff, 0xc7d2fe, 0xa5b4fc, 0x818cf8, 0x6366f1, 0x4f46e5, 0x4338ca, 0x3730a3, 0x312e81, 0x1e1b4b]),
+    ("violet", [0xf5f3ff, 0xede9fe, 0xddd6fe, 0xc4b5fd, 0xa78bfa, 0x8b5cf6, 0x7c3aed, 0x6d28d9, 0x5b21b6, 0x4c1d95, 0x2e1065]),
+    ("purple", [0xfaf5ff, 0xf3e8ff, 0xe9d5ff, 0xd8b4fe, 0xc084fc, 0xa855f7, 0x9333ea, 0x7e22ce, 0x6b21a8, 0x581c87, 0x3b0764]),
+    ("fuchsia", [0xfdf4ff, 0xfae8ff, 0xf5d0fe, 0xf0abfc, 0xe879f9, 0xd946ef, 0xc026d3, 0xa21caf, 0x86198f, 0x701a75, 0x4a044e]),
+    ("pink", [0xfdf2f8, 0xfce7f3, 0xfbcfe8, 0xf9a8d4, 0xf472b6, 0xec4899, 0xdb2777, 0xbe185d, 0x9d174d, 0x831843, 0x500724]),
+    ("rose", [0xfff1f2, 0xffe4e6, 0xfecdd3, 0xfda4af, 0xfb7185, 0xf43f5e, 0xe11d48, 0xbe123c, 0x9f1239, 0x881337, 0x4c0519]),
+];
+
+fn wrap_degrees(degrees: f32) -> f32 {
+    let wrapped = degrees % 360.0;
+    if wrapped < 0.0 {
+        wrapped + 360.0
+    } else {
+        wrapped
+    }
+}
+
+fn hue_to_rgb(p: f32, q: f32, t: f32) -> f32 {
+    let t = if t < 0.0 {
+        t + 1.0
+    } else if t > 1.0 {
+        t - 1.0
+    } else {
+        t
+    };
+    if t < 1.0 / 6.0 {
+        p + (q - p) * 6.0 * t
+    } else if t < 0.5 {
+        q
+    } else if t < 2.0 / 3.0 {
+        p + (q - p) * (2.0 / 3.0 - t) * 6.0
+    } else {
+        p
+    }
+}
+
+fn to_channel(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// sRGB to linear light with the piecewise sRGB transfer function
+///
+/// `libm` provides `powf` without `std`.
+fn linear_channel(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        libm::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+#[cfg(test)]
+#[path = "color_value_test.rs"]
+mod color_value_test;
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::color_value::ColorValue;
+
+    #[test]
+    fn test_hex_parsing() {
+        assert_eq!(
+            ColorValue::from_hex("#7C3AED"),
+            Some(ColorValue::rgb(124, 58, 237))
+        );
+        assert_eq!(ColorValue::from_hex("fff"), Some(ColorValue::WHITE));
+        assert_eq!(ColorValue::from_hex("#12345"), None);
+        assert_eq!(ColorValue::from_hex("#gggggg"), None);
+        assert_eq!(ColorValue::from_hex("+1+2+3"), None);
+        assert_eq!(ColorValue::from_hex("#+f+f+f"), None);
+        assert_eq!(ColorValue::rgb(124, 58, 237).to_hex(), "#7c3aed");
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        for hex in ["#7c3aed", "#0ea5e9", "#f59e0b", "#111827", "#ffffff"] {
+            let color = ColorValue::from_hex(hex).unwrap();
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(ColorValue::from_hsl(h, s, l), color, "{}", hex);
+        }
+    }
+
+    #[test]
+    fn test_shades() {
+        let brand = ColorValue::from_hex("#7c3aed").unwrap();
+        let (_, _, lightness) = brand.to_hsl();
+
+        assert!(brand.darken(0.1).to_hsl().2 < lightness);
+        assert!(brand.lighten(0.1).to_hsl().2 > lightness);
+        assert_eq!(brand.lighten(1.0), ColorValue::WHITE);
+        assert_eq!(brand.rotate_hue(360.0), brand);
+    }
+
+    #[test]
+    fn test_contrast() {
+        let black = ColorValue::rgb(0, 0, 0);
+
+        let ratio = ColorValue::WHITE.contrast_ratio(black);
+        assert!((ratio - 21.0).abs() < 0.001);
+        assert!((black.contrast_ratio(black) - 1.0).abs() < 0.01);
+
+        // Published WCAG ratios for white text
+        let white_on = |hex: &str| {
+            ColorValue::from_hex(hex)
+                .unwrap()
+                .contrast_ratio(ColorValue::WHITE)
+        };
+        assert!((white_on("#3b82f6") - 3.68).abs() < 0.01);
+        assert!((white_on("#767676") - 4.54).abs() < 0.01);
+
+        assert_eq!(
+            ColorValue::from_hex("#1d4ed8").unwrap().readable_text(),
+            ColorValue::WHITE
+        );
+        assert_eq!(
+            ColorValue::from_hex("#facc15").unwrap().readable_text(),
+            ColorValue::NEAR_BLACK
+        );
+    }
+
+    #[test]
+    fn test_luminance_follows_srgb_curve() {
+        let gray = |channel: u8| ColorValue::rgb(channel, channel, channel).relative_luminance();
+
+        // The linear segment near black and the power segment above it
+        assert!((gray(10) - 10.0 / 255.0 / 12.92).abs() < 1e-6);
+        assert!((gray(1) - 1.0 / 255.0 / 12.92).abs() < 1e-6);
+        assert!((gray(128) - 0.2159).abs() < 1e-4);
+        assert!((gray(255) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_brand_theme_generation() {
+        use crate::core::color::ColorProvider;
+        use crate::core::Color;
+        use crate::themes::VibeColors;
+
+        let colors = VibeColors::from_brand_hex("#7C3AED").unwrap();
+        let value = |color| {
+            let raw = colors.resolve_color(color);
+            ColorValue::from_hex(raw.trim_start_matches('[').trim_end_matches(']')).unwrap()
+        };
+
+        assert_eq!(colors.resolve_color(Color::Primary), "[#7c3aed]");
+        assert_eq!(colors.resolve_color(Color::Interactive), "[#7c3aed]");
+        assert!(
+            value(Color::InteractiveHover).relative_luminance()
+                < value(Color::Primary).relative_luminance()
+        );
+        assert!(
+            value(Color::InteractiveActive).relative_luminance()
+                < value(Color::InteractiveHover).relative_luminance()
+        );
+        assert_eq!(colors.on_color(Color::Primary), Color::TextInverse);
+
+        // Semantic colors keep the defaults
+        assert_eq!(colors.resolve_color(Color::Error), "red-500");
+
+        // Light brands get dark text from on_color; the inverse text used on
+        // dark surfaces stays light
+        let light = VibeColors::from_brand_hex("#fde047").unwrap();
+        assert_eq!(light.on_color(Color::Primary), Color::TextPrimary);
+        assert_eq!(light.resolve_color(Color::TextInverse), "white");
+
+        assert!(VibeColors::from_brand_hex("not a color").is_none());
+    }
+
+    #[test]
+    fn test_palette_values() {
+        assert_eq!(
+            ColorValue::from_palette_value("gray-900"),
+            Some(ColorValue::NEAR_BLACK)
+        );
+        assert_eq!(
+            ColorValue::from_palette_value("white"),
+            Some(ColorValue::WHITE)
+        );
+        assert_eq!(
+            ColorValue::from_palette_value("rose-950"),
+            ColorValue::from_hex("#4c0519")
+        );
+        assert_eq!(
+            ColorValue::from_palette_value("jupiter-orange-500"),
+            ColorValue::from_palette_value("orange-500")
+        );
+        assert_eq!(
+            ColorValue::from_palette_value("jupiter-navy-900"),
+            ColorValue::from_palette_value("slate-900")
+        );
+        assert_eq!(
+            ColorValue::from_palette_value("water-green-600"),
+            ColorValue::from_palette_value("green-600")
+        );
+        assert_eq!(ColorValue::from_palette_value("blue-550"), None);
+        assert_eq!(ColorValue::from_palette_value("[#zzzzzz]"), None);
+        assert_eq!(ColorValue::from_palette_value("transparent"), None);
+    }
+}
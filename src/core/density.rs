@@ -0,0 +1,79 @@
+//! Density system for the design system
+
+use serde::{Deserialize, Serialize};
+
+/// Density tokens controlling how tightly components are packed
+///
+/// Density shifts paddings and gaps one step along each builder's own
+/// spacing scale, so a whole screen can be switched to compact without
+/// changing component sizes at every call site.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Density {
+    /// Tighter paddings and gaps for data-heavy screens
+    Compact,
+    /// Default spacing
+    #[default]
+    Comfortable,
+    /// Roomier paddings and gaps for marketing or touch-first screens
+    Spacious,
+}
+
+impl Density {
+    /// Parse density from string, falling back to comfortable
+    pub fn from_str_or_default(density: &str) -> Self {
+        match density {
+            "compact" | "dense" => Density::Compact,
+            "comfortable" | "default" => Density::Comfortable,
+            "spacious" | "relaxed" => Density::Spacious,
+            _ => Density::Comfortable, // fallback
+        }
+    }
+
+    /// Number of steps this density moves along a spacing scale
+    pub fn step(&self) -> isize {
+        match self {
+            Density::Compact => -1,
+            Density::Comfortable => 0,
+            Density::Spacious => 1,
+        }
+    }
+
+    /// Pick the entry `index` of an ordered spacing scale, shifted by this
+    /// density and clamped to the ends of the scale
+    pub fn scale<T: Copy>(&self, scale: &[T], index: usize) -> T {
+        let last = scale.len().saturating_sub(1) as isize;
+        let shifted = (index as isize + self.step()).clamp(0, last);
+        scale[shifted as usize]
+    }
+}
+
+/// Theme defaults for data-heavy components (tables and lists)
+///
+/// An admin theme can render every table and list compact and striped
+/// without per-call configuration; builders can still override both.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{DataDisplayTokens, Density};
+///
+/// let admin = DataDisplayTokens { density: Some(Density::Compact), striped: true };
+/// assert_eq!(admin.resolve_density(Density::Comfortable), Density::Compact);
+///
+/// let inherit = DataDisplayTokens::default();
+/// assert_eq!(inherit.resolve_density(Density::Spacious), Density::Spacious);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DataDisplayTokens {
+    /// Density for tables and lists; `None` follows the theme's density
+    pub density: Option<Density>,
+    /// Stripe alternate rows
+    pub striped: bool,
+}
+
+impl DataDisplayTokens {
+    /// Get the data density, falling back to the theme's density
+    pub fn resolve_density(&self, theme_density: Density) -> Density {
+        self.density.unwrap_or(theme_density)
+    }
+}
@@ -0,0 +1,126 @@
+//! Elevation system for the design system
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Semantic elevation tokens shared by every raised surface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Elevation {
+    /// Sits on the page (no shadow)
+    Flat,
+    /// Default cards and tiles
+    Subtle,
+    /// Emphasized cards
+    Raised,
+    /// Menus, popovers, prominent selections
+    Floating,
+    /// Toasts and other transient overlays
+    Overlay,
+    /// Modals and dialogs
+    Modal,
+}
+
+impl Elevation {
+    /// The next elevation up, used for hover lift (saturates at `Modal`)
+    pub fn raised(&self) -> Elevation {
+        match self {
+            Elevation::Flat => Elevation::Subtle,
+            Elevation::Subtle => Elevation::Raised,
+            Elevation::Raised => Elevation::Floating,
+            Elevation::Floating => Elevation::Overlay,
+            Elevation::Overlay | Elevation::Modal => Elevation::Modal,
+        }
+    }
+}
+
+/// Shadow classes for each elevation token
+///
+/// Themes provide their own scale to change how raised surfaces look
+/// everywhere, e.g. `ElevationScale::minimal()` for a flatter look.
+///
+/// Scales deserialize from theme files loaded at runtime:
+///
+/// ```rust
+/// use jupiter_design_system::core::ElevationScale;
+///
+/// let json: String = [
+///     r#"{"flat": "shadow-none", "subtle": "shadow-none", "raised": "shadow-sm","#,
+///     r#""floating": "shadow", "overlay": "shadow-md", "modal": "shadow-lg"}"#,
+/// ]
+/// .concat();
+/// let scale: ElevationScale = serde_json::from_str(&json).unwrap();
+/// drop(json);
+/// assert_eq!(scale, ElevationScale::minimal());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ElevationScale {
+    /// Shadow class for surfaces on the page, usually `shadow-none`
+    pub flat: String,
+    /// Shadow class for default cards and tiles
+    pub subtle: String,
+    /// Shadow class for emphasized cards
+    pub raised: String,
+    /// Shadow class for menus, popovers and prominent selections
+    pub floating: String,
+    /// Shadow class for toasts and other transient overlays
+    pub overlay: String,
+    /// Shadow class for modals and dialogs
+    pub modal: String,
+}
+
+impl Default for ElevationScale {
+    fn default() -> Self {
+        Self {
+            flat: "shadow-none".to_string(),
+            subtle: "shadow-sm".to_string(),
+            raised: "shadow-md".to_string(),
+            floating: "shadow-lg".to_string(),
+            overlay: "shadow-xl".to_string(),
+            modal: "shadow-2xl".to_string(),
+        }
+    }
+}
+
+impl ElevationScale {
+    /// Flatter scale for dense, enterprise-style interfaces
+    pub fn minimal() -> Self {
+        Self {
+            flat: "shadow-none".to_string(),
+            subtle: "shadow-none".to_string(),
+            raised: "shadow-sm".to_string(),
+            floating: "shadow".to_string(),
+            overlay: "shadow-md".to_string(),
+            modal: "shadow-lg".to_string(),
+        }
+    }
+}
+
+/// Trait for providing elevation values
+pub trait ElevationProvider {
+    /// Resolve elevation to its shadow class
+    fn resolve_elevation(&self, elevation: Elevation) -> &str;
+
+    /// Get shadow class
+    fn shadow_class(&self, elevation: Elevation) -> String {
+        self.resolve_elevation(elevation).to_string()
+    }
+
+    /// Get hover shadow class lifting the surface one elevation step
+    fn hover_shadow_class(&self, elevation: Elevation) -> String {
+        format!("hover:{}", self.resolve_elevation(elevation.raised()))
+    }
+}
+
+impl ElevationProvider for ElevationScale {
+    fn resolve_elevation(&self, elevation: Elevation) -> &str {
+        match elevation {
+            Elevation::Flat => &self.flat,
+            Elevation::Subtle => &self.subtle,
+            Elevation::Raised => &self.raised,
+            Elevation::Floating => &self.floating,
+            Elevation::Overlay => &self.overlay,
+            Elevation::Modal => &self.modal,
+        }
+    }
+}
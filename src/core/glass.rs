@@ -0,0 +1,90 @@
+//! Glass surface system for the design system
+//!
+//! Glass surfaces tint whatever is behind them and blur it with
+//! `backdrop-filter`. The tint color, tint strength, blur and border are
+//! theme tokens so a brand can tune glass for its own imagery, and every
+//! glass surface carries an opaque fallback for browsers without
+//! `backdrop-filter` support.
+
+use crate::core::color::Color;
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Glass tones for the two kinds of background glass sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GlassTone {
+    /// Faint tint with inverse text, for dark or image backgrounds
+    Dark,
+    /// Strong tint with primary text, for light pages
+    Light,
+}
+
+/// Tint, blur and border values for glass surfaces
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{GlassScale, GlassTone};
+///
+/// let scale = GlassScale::default();
+/// assert_eq!(scale.blur_class(), "backdrop-blur-md");
+/// assert_eq!(scale.tint_opacity(GlassTone::Dark), 10);
+///
+/// let frosted = GlassScale { blur: 20, ..GlassScale::default() };
+/// assert_eq!(frosted.blur_class(), "backdrop-blur-[20px]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GlassScale {
+    /// Color token the glass is tinted with
+    pub tint: Color,
+    /// Tint opacity in percent for dark glass
+    pub tint_opacity: u8,
+    /// Tint opacity in percent for light glass
+    pub light_tint_opacity: u8,
+    /// Border opacity in percent
+    pub border_opacity: u8,
+    /// Backdrop blur radius in pixels
+    pub blur: u8,
+    /// Background opacity in percent when `backdrop-filter` is unsupported
+    pub fallback_opacity: u8,
+}
+
+impl Default for GlassScale {
+    fn default() -> Self {
+        Self {
+            tint: Color::Surface,
+            tint_opacity: 10,
+            light_tint_opacity: 70,
+            border_opacity: 20,
+            blur: 12,
+            fallback_opacity: 90,
+        }
+    }
+}
+
+impl GlassScale {
+    /// Get the tint opacity for a tone
+    pub fn tint_opacity(&self, tone: GlassTone) -> u8 {
+        match tone {
+            GlassTone::Dark => self.tint_opacity,
+            GlassTone::Light => self.light_tint_opacity,
+        }
+    }
+
+    /// Get the backdrop blur class, using Tailwind's named steps when the
+    /// radius matches one
+    pub fn blur_class(&self) -> String {
+        match self.blur {
+            0 => "backdrop-blur-none".to_string(),
+            4 => "backdrop-blur-sm".to_string(),
+            8 => "backdrop-blur".to_string(),
+            12 => "backdrop-blur-md".to_string(),
+            16 => "backdrop-blur-lg".to_string(),
+            24 => "backdrop-blur-xl".to_string(),
+            40 => "backdrop-blur-2xl".to_string(),
+            64 => "backdrop-blur-3xl".to_string(),
+            px => format!("backdrop-blur-[{}px]", px),
+        }
+    }
+}
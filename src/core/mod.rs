@@ -3,13 +3,34 @@
 //! This module provides the foundational building blocks for the design system,
 //! including colors, spacing, typography, and sizing systems.
 
+pub mod border;
+pub mod chart;
 pub mod color;
+pub mod color_value;
+pub mod density;
+pub mod elevation;
+pub mod glass;
+pub mod motion;
+pub mod radius;
 pub mod sizing;
 pub mod spacing;
+pub mod state_layer;
 pub mod typography;
 
 // Re-export main types
-pub use color::{Color, ColorPalette, ColorProvider};
-pub use sizing::{Breakpoint, Size, SizeProvider};
+pub use border::{BorderProvider, BorderScale, BorderStyle, BorderWidth};
+pub use chart::ChartPalette;
+pub use color::{
+    Color, ColorPalette, ColorProvider, CustomIntent, CustomStatus, IntentEmphasis,
+    SelectionTokens, SurfaceLevel,
+};
+pub use color_value::ColorValue;
+pub use density::{DataDisplayTokens, Density};
+pub use elevation::{Elevation, ElevationProvider, ElevationScale};
+pub use glass::{GlassScale, GlassTone};
+pub use motion::{MotionDuration, MotionProvider, MotionScale, ScaleEffect};
+pub use radius::{Radius, RadiusProvider, RadiusScale};
+pub use sizing::{Breakpoint, BreakpointScale, Size, SizeProvider, SizeScale};
 pub use spacing::{Spacing, SpacingProvider};
+pub use state_layer::{StateLayer, StateLayerProvider, StateLayerScale};
 pub use typography::{FontFamily, FontWeight, Typography, TypographyProvider};
@@ -0,0 +1,302 @@
+//! Motion system for the design system
+//!
+//! Durations are tokens rather than hardcoded `duration-*` classes so a
+//! theme can speed up or slow down every transition at once. Hover and
+//! press scale amounts are tokens too. Both resolve to classes that exist
+//! in Tailwind's default theme unless a theme opts into arbitrary values.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Semantic motion duration tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MotionDuration {
+    /// Small feedback such as hover and press
+    Fast,
+    /// Most enter and exit transitions
+    Normal,
+    /// Large surfaces and emphasized transitions
+    Slow,
+}
+
+/// Semantic scale effects for hover and press feedback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ScaleEffect {
+    /// Barely-there lift for large surfaces such as cards
+    HoverSubtle,
+    /// Standard hover lift
+    Hover,
+    /// Emphasized hover lift for small targets such as icon buttons
+    HoverStrong,
+    /// Standard press feedback
+    Press,
+    /// Emphasized press feedback for small targets
+    PressStrong,
+}
+
+impl ScaleEffect {
+    /// Variant prefix the effect applies under
+    pub fn variant(&self) -> &'static str {
+        match self {
+            ScaleEffect::HoverSubtle | ScaleEffect::Hover | ScaleEffect::HoverStrong => "hover:",
+            ScaleEffect::Press | ScaleEffect::PressStrong => "active:",
+        }
+    }
+}
+
+/// Steps of Tailwind's default `transitionDuration` theme, in milliseconds
+pub const TAILWIND_DURATIONS: [u16; 9] = [0, 75, 100, 150, 200, 300, 500, 700, 1000];
+
+/// Steps of Tailwind's default `scale` theme, in percent
+pub const TAILWIND_SCALES: [u16; 10] = [0, 50, 75, 90, 95, 100, 105, 110, 125, 150];
+
+/// Check that a `scale-*` class (with any variants) exists in Tailwind's
+/// default theme or is a well-formed arbitrary value
+///
+/// Classes that are not scale utilities pass.
+///
+/// ```rust
+/// use jupiter_design_system::core::motion::is_valid_scale_class;
+///
+/// assert!(is_valid_scale_class("hover:scale-105"));
+/// assert!(is_valid_scale_class("hover:scale-[1.01]"));
+/// assert!(!is_valid_scale_class("hover:scale-101"));
+/// assert!(is_valid_scale_class("shadow-md"));
+/// ```
+pub fn is_valid_scale_class(class: &str) -> bool {
+    let utility = class.rsplit(':').next().unwrap_or(class);
+    let utility = utility.trim_start_matches('-');
+    let value = match utility
+        .strip_prefix("scale-x-")
+        .or_else(|| utility.strip_prefix("scale-y-"))
+        .or_else(|| utility.strip_prefix("scale-"))
+    {
+        Some(value) => value,
+        None => return true,
+    };
+
+    if let Some(arbitrary) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        return arbitrary.parse::<f32>().is_ok();
+    }
+    value
+        .parse::<u16>()
+        .map(|percent| TAILWIND_SCALES.contains(&percent))
+        .unwrap_or(false)
+}
+
+/// Duration in milliseconds for each motion token
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{MotionDuration, MotionProvider, MotionScale};
+///
+/// let scale = MotionScale::default();
+/// assert_eq!(scale.duration_class(MotionDuration::Normal), "duration-200");
+///
+/// let relaxed = MotionScale::relaxed();
+/// assert_eq!(relaxed.duration_class(MotionDuration::Normal), "duration-300");
+/// ```
+///
+/// Scale amounts are percentages. Amounts missing from Tailwind's default
+/// theme snap to the nearest step (and a hover that snaps to 100% emits
+/// nothing) unless `arbitrary_scales` is set:
+///
+/// ```rust
+/// use jupiter_design_system::core::{MotionProvider, MotionScale, ScaleEffect};
+///
+/// let scale = MotionScale::default();
+/// assert_eq!(scale.scale_effect_class(ScaleEffect::Hover), "hover:scale-105");
+/// assert_eq!(scale.scale_effect_class(ScaleEffect::HoverSubtle), "");
+///
+/// let precise = MotionScale::default().with_arbitrary_scales();
+/// assert_eq!(precise.scale_effect_class(ScaleEffect::HoverSubtle), "hover:scale-[1.01]");
+/// ```
+///
+/// Durations snap the same way unless `arbitrary_durations` is set:
+///
+/// ```rust
+/// use jupiter_design_system::core::{MotionDuration, MotionProvider, MotionScale};
+///
+/// let scale = MotionScale { normal: 250, ..MotionScale::default() };
+/// assert_eq!(scale.duration_class(MotionDuration::Normal), "duration-200");
+///
+/// let precise = scale.with_arbitrary_durations();
+/// assert_eq!(precise.duration_class(MotionDuration::Normal), "duration-[250ms]");
+/// assert_eq!(precise.duration_class(MotionDuration::Slow), "duration-300");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MotionScale {
+    /// Milliseconds for hover and press feedback
+    pub fast: u16,
+    /// Milliseconds for most enter and exit transitions
+    pub normal: u16,
+    /// Milliseconds for large surfaces and emphasized transitions
+    pub slow: u16,
+    /// Percent scale for the subtle hover lift on large surfaces
+    pub hover_subtle: u16,
+    /// Percent scale for the standard hover lift
+    pub hover: u16,
+    /// Percent scale for the emphasized hover lift on small targets
+    pub hover_strong: u16,
+    /// Percent scale while pressed
+    pub press: u16,
+    /// Percent scale while pressed, for small targets
+    pub press_strong: u16,
+    /// Emit arbitrary values such as `scale-[1.01]` instead of snapping
+    #[serde(default)]
+    pub arbitrary_scales: bool,
+    /// Emit arbitrary values such as `duration-[250ms]` instead of snapping
+    #[serde(default)]
+    pub arbitrary_durations: bool,
+}
+
+impl Default for MotionScale {
+    fn default() -> Self {
+        Self {
+            fast: 150,
+            normal: 200,
+            slow: 300,
+            hover_subtle: 101,
+            hover: 105,
+            hover_strong: 110,
+            press: 95,
+            press_strong: 90,
+            arbitrary_scales: false,
+            arbitrary_durations: false,
+        }
+    }
+}
+
+impl MotionScale {
+    /// Slower scale for calm, spacious brands
+    pub fn relaxed() -> Self {
+        Self {
+            fast: 200,
+            normal: 300,
+            slow: 500,
+            ..Self::default()
+        }
+    }
+
+    /// Emit arbitrary scale values for amounts Tailwind does not ship
+    ///
+    /// Requires a Tailwind build that generates arbitrary values (JIT).
+    pub fn with_arbitrary_scales(mut self) -> Self {
+        self.arbitrary_scales = true;
+        self
+    }
+
+    /// Emit arbitrary duration values for times Tailwind does not ship
+    ///
+    /// Requires a Tailwind build that generates arbitrary values (JIT).
+    pub fn with_arbitrary_durations(mut self) -> Self {
+        self.arbitrary_durations = true;
+        self
+    }
+}
+
+/// Trait for providing motion values
+pub trait MotionProvider {
+    /// Resolve a duration token to milliseconds
+    fn resolve_duration(&self, duration: MotionDuration) -> u16;
+
+    /// Whether durations outside Tailwind's default theme are emitted as
+    /// arbitrary values
+    fn arbitrary_durations(&self) -> bool {
+        false
+    }
+
+    /// Get the Tailwind duration class for a token
+    ///
+    /// Durations missing from Tailwind's default theme snap to the nearest
+    /// step unless arbitrary durations are enabled.
+    fn duration_class(&self, duration: MotionDuration) -> String {
+        let ms = self.resolve_duration(duration);
+        if TAILWIND_DURATIONS.contains(&ms) || !self.arbitrary_durations() {
+            format!("duration-{}", nearest_step(&TAILWIND_DURATIONS, ms))
+        } else {
+            format!("duration-[{}ms]", ms)
+        }
+    }
+
+    /// Resolve a scale effect to a percentage
+    fn resolve_scale(&self, effect: ScaleEffect) -> u16;
+
+    /// Whether amounts outside Tailwind's default theme are emitted as
+    /// arbitrary values
+    fn arbitrary_scales(&self) -> bool {
+        false
+    }
+
+    /// Get the `scale-*` class for an effect, without its variant
+    ///
+    /// `None` when the effect resolves to no visible change.
+    fn scale_class(&self, effect: ScaleEffect) -> Option<String> {
+        let percent = self.resolve_scale(effect);
+        if TAILWIND_SCALES.contains(&percent) || !self.arbitrary_scales() {
+            let snapped = nearest_tailwind_scale(percent);
+            (snapped != 100).then(|| format!("scale-{}", snapped))
+        } else {
+            Some(format!("scale-[{}]", scale_factor(percent)))
+        }
+    }
+
+    /// Get the class for an effect under its variant (`hover:` or
+    /// `active:`), or an empty string when there is nothing to apply
+    fn scale_effect_class(&self, effect: ScaleEffect) -> String {
+        self.scale_class(effect)
+            .map(|class| format!("{}{}", effect.variant(), class))
+            .unwrap_or_default()
+    }
+}
+
+impl MotionProvider for MotionScale {
+    fn resolve_duration(&self, duration: MotionDuration) -> u16 {
+        match duration {
+            MotionDuration::Fast => self.fast,
+            MotionDuration::Normal => self.normal,
+            MotionDuration::Slow => self.slow,
+        }
+    }
+
+    fn resolve_scale(&self, effect: ScaleEffect) -> u16 {
+        match effect {
+            ScaleEffect::HoverSubtle => self.hover_subtle,
+            ScaleEffect::Hover => self.hover,
+            ScaleEffect::HoverStrong => self.hover_strong,
+            ScaleEffect::Press => self.press,
+            ScaleEffect::PressStrong => self.press_strong,
+        }
+    }
+
+    fn arbitrary_scales(&self) -> bool {
+        self.arbitrary_scales
+    }
+
+    fn arbitrary_durations(&self) -> bool {
+        self.arbitrary_durations
+    }
+}
+
+fn nearest_tailwind_scale(percent: u16) -> u16 {
+    nearest_step(&TAILWIND_SCALES, percent)
+}
+
+fn nearest_step(steps: &[u16], value: u16) -> u16 {
+    steps
+        .iter()
+        .copied()
+        .min_by_key(|step| step.abs_diff(value))
+        .unwrap_or(value)
+}
+
+/// Percentage as a decimal factor without trailing zeros (`120` is `1.2`)
+fn scale_factor(percent: u16) -> String {
+    let factor = format!("{}.{:02}", percent / 100, percent % 100);
+    factor
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
@@ -0,0 +1,96 @@
+//! Border radius system for the design system
+
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Semantic border radius tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Radius {
+    /// Square corners
+    None,
+    /// Small controls (compact buttons, tags)
+    ControlSmall,
+    /// Buttons, inputs and other controls
+    Control,
+    /// Cards, panels and other surfaces
+    Surface,
+    /// Fully rounded chips, badges and avatars
+    Pill,
+}
+
+/// Radius classes for each radius token
+///
+/// Themes provide their own scale to make the whole system sharper or
+/// rounder, e.g. `RadiusScale::sharp()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RadiusScale {
+    /// Class for square corners, usually `rounded-none`
+    pub none: String,
+    /// Class for small controls such as compact buttons and tags
+    pub control_small: String,
+    /// Class for buttons, inputs and other controls
+    pub control: String,
+    /// Class for cards, panels and other surfaces
+    pub surface: String,
+    /// Class for chips, badges and avatars, usually `rounded-full`
+    pub pill: String,
+}
+
+impl Default for RadiusScale {
+    fn default() -> Self {
+        Self {
+            none: "rounded-none".to_string(),
+            control_small: "rounded".to_string(),
+            control: "rounded-md".to_string(),
+            surface: "rounded-lg".to_string(),
+            pill: "rounded-full".to_string(),
+        }
+    }
+}
+
+impl RadiusScale {
+    /// Sharper scale with minimal rounding
+    pub fn sharp() -> Self {
+        Self {
+            none: "rounded-none".to_string(),
+            control_small: "rounded-sm".to_string(),
+            control: "rounded-sm".to_string(),
+            surface: "rounded".to_string(),
+            pill: "rounded-full".to_string(),
+        }
+    }
+
+    /// Softer scale with generous rounding
+    pub fn rounded() -> Self {
+        Self {
+            none: "rounded-none".to_string(),
+            control_small: "rounded-md".to_string(),
+            control: "rounded-lg".to_string(),
+            surface: "rounded-2xl".to_string(),
+            pill: "rounded-full".to_string(),
+        }
+    }
+}
+
+/// Trait for providing radius values
+pub trait RadiusProvider {
+    /// Resolve radius to its CSS class
+    fn resolve_radius(&self, radius: Radius) -> &str;
+
+    /// Get radius class
+    fn radius_class(&self, radius: Radius) -> String {
+        self.resolve_radius(radius).to_string()
+    }
+}
+
+impl RadiusProvider for RadiusScale {
+    fn resolve_radius(&self, radius: Radius) -> &str {
+        match radius {
+            Radius::None => &self.none,
+            Radius::ControlSmall => &self.control_small,
+            Radius::Control => &self.control,
+            Radius::Surface => &self.surface,
+            Radius::Pill => &self.pill,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 //! Sizing system for the design system
 
+use alloc::format;
+use alloc::string::{String, ToString};
 use serde::{Deserialize, Serialize};
 
 /// Size tokens for consistent component sizing
@@ -21,6 +23,171 @@ pub enum Breakpoint {
     Large,
 }
 
+impl Breakpoint {
+    /// Tailwind variant prefix where this breakpoint starts (none for mobile)
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Breakpoint::Mobile => "",
+            Breakpoint::Tablet => "md:",
+            Breakpoint::Desktop => "lg:",
+            Breakpoint::Large => "xl:",
+        }
+    }
+
+    /// Default viewport width in pixels where this breakpoint starts
+    pub fn min_width(&self) -> u16 {
+        match self {
+            Breakpoint::Mobile => 0,
+            Breakpoint::Tablet => 768,
+            Breakpoint::Desktop => 1024,
+            Breakpoint::Large => 1280,
+        }
+    }
+
+    /// Container query variant prefix where this breakpoint starts
+    ///
+    /// Container breakpoints are scaled to component widths rather than the
+    /// viewport, so a card in a sidebar can switch layout on its own width.
+    /// The element's container needs the `@container` class.
+    pub fn container_prefix(&self) -> &'static str {
+        match self {
+            Breakpoint::Mobile => "",
+            Breakpoint::Tablet => "@sm:",
+            Breakpoint::Desktop => "@lg:",
+            Breakpoint::Large => "@2xl:",
+        }
+    }
+
+    /// The next wider breakpoint, if any
+    pub fn next(&self) -> Option<Breakpoint> {
+        match self {
+            Breakpoint::Mobile => Some(Breakpoint::Tablet),
+            Breakpoint::Tablet => Some(Breakpoint::Desktop),
+            Breakpoint::Desktop => Some(Breakpoint::Large),
+            Breakpoint::Large => None,
+        }
+    }
+}
+
+/// Viewport widths (in pixels) where each breakpoint starts
+///
+/// Themes override these through `ColorProvider::breakpoint_scale`. Widths
+/// matching the Tailwind defaults use the named variants (`md:`); other
+/// widths fall back to arbitrary `min-[...]` variants.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{Breakpoint, BreakpointScale};
+///
+/// let scale = BreakpointScale::default();
+/// assert_eq!(scale.prefix(Breakpoint::Tablet), "md:");
+///
+/// let wide = BreakpointScale { tablet: 900, ..BreakpointScale::default() };
+/// assert_eq!(wide.prefix(Breakpoint::Tablet), "min-[900px]:");
+/// assert_eq!(wide.media_query(Breakpoint::Tablet), "(min-width: 900px)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BreakpointScale {
+    /// Width in pixels where `Breakpoint::Tablet` starts
+    pub tablet: u16,
+    /// Width in pixels where `Breakpoint::Desktop` starts
+    pub desktop: u16,
+    /// Width in pixels where `Breakpoint::Large` starts
+    pub large: u16,
+}
+
+impl Default for BreakpointScale {
+    fn default() -> Self {
+        Self {
+            tablet: Breakpoint::Tablet.min_width(),
+            desktop: Breakpoint::Desktop.min_width(),
+            large: Breakpoint::Large.min_width(),
+        }
+    }
+}
+
+impl BreakpointScale {
+    /// Viewport width in pixels where a breakpoint starts
+    pub fn min_width(&self, breakpoint: Breakpoint) -> u16 {
+        match breakpoint {
+            Breakpoint::Mobile => 0,
+            Breakpoint::Tablet => self.tablet,
+            Breakpoint::Desktop => self.desktop,
+            Breakpoint::Large => self.large,
+        }
+    }
+
+    /// Variant prefix where a breakpoint starts (none for mobile)
+    pub fn prefix(&self, breakpoint: Breakpoint) -> String {
+        let width = self.min_width(breakpoint);
+        if width == breakpoint.min_width() {
+            breakpoint.prefix().to_string()
+        } else {
+            format!("min-[{}px]:", width)
+        }
+    }
+
+    /// CSS media query matching a breakpoint and wider
+    pub fn media_query(&self, breakpoint: Breakpoint) -> String {
+        format!("(min-width: {}px)", self.min_width(breakpoint))
+    }
+}
+
+/// Control heights (Tailwind spacing values) for each size token
+///
+/// Buttons, inputs and selection items of the same [`Size`] share one
+/// height so they line up when placed side by side.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{Size, SizeProvider, SizeScale};
+///
+/// let scale = SizeScale::default();
+/// assert_eq!(scale.control_height_class(Size::Small), "h-8");
+/// assert_eq!(scale.control_height_class(Size::Medium), "h-10");
+/// assert_eq!(scale.control_height_class(Size::Large), "h-12");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SizeScale {
+    /// Height (Tailwind spacing value) of extra small controls
+    pub xsmall: String,
+    /// Height of small controls
+    pub small: String,
+    /// Height of medium controls
+    pub medium: String,
+    /// Height of large controls
+    pub large: String,
+    /// Height of extra large controls
+    pub xlarge: String,
+}
+
+impl Default for SizeScale {
+    fn default() -> Self {
+        Self {
+            xsmall: "7".to_string(),
+            small: "8".to_string(),
+            medium: "10".to_string(),
+            large: "12".to_string(),
+            xlarge: "14".to_string(),
+        }
+    }
+}
+
+impl SizeScale {
+    /// Larger controls for touch-first screens
+    pub fn touch() -> Self {
+        Self {
+            xsmall: "8".to_string(),
+            small: "10".to_string(),
+            medium: "12".to_string(),
+            large: "14".to_string(),
+            xlarge: "16".to_string(),
+        }
+    }
+}
+
 /// Trait for providing size values
 pub trait SizeProvider {
     /// Resolve size to CSS class value
@@ -35,4 +202,21 @@ pub trait SizeProvider {
     fn height_class(&self, size: Size) -> String {
         format!("h-{}", self.resolve_size(size))
     }
+
+    /// Get the shared control height class
+    fn control_height_class(&self, size: Size) -> String {
+        self.height_class(size)
+    }
+}
+
+impl SizeProvider for SizeScale {
+    fn resolve_size(&self, size: Size) -> &str {
+        match size {
+            Size::XSmall => &self.xsmall,
+            Size::Small => &self.small,
+            Size::Medium => &self.medium,
+            Size::Large => &self.large,
+            Size::XLarge => &self.xlarge,
+        }
+    }
 }
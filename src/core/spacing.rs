@@ -1,5 +1,7 @@
 //! Spacing system for the design system
 
+use alloc::format;
+use alloc::string::String;
 use serde::{Deserialize, Serialize};
 
 /// Spacing tokens for consistent spacing
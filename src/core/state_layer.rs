@@ -0,0 +1,118 @@
+//! State layer system for the design system
+//!
+//! A state layer is a translucent overlay in the element's content color
+//! drawn over its surface on hover, focus, press or drag. Unlike scale
+//! transforms or color swaps it works the same on every variant and never
+//! shifts layout.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+
+/// Interaction states that draw a state layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StateLayer {
+    /// Pointer over the element
+    Hover,
+    /// Keyboard focus
+    Focus,
+    /// Being pressed
+    Pressed,
+    /// Being dragged
+    Dragged,
+}
+
+/// Overlay opacity (in percent) for each state layer
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::core::{StateLayer, StateLayerProvider, StateLayerScale};
+///
+/// let scale = StateLayerScale::default();
+/// assert_eq!(
+///     scale.state_layer_class(StateLayer::Hover),
+///     "hover:before:opacity-[0.08]"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StateLayerScale {
+    /// Overlay opacity while hovered
+    pub hover: u8,
+    /// Overlay opacity while focused
+    pub focus: u8,
+    /// Overlay opacity while pressed
+    pub pressed: u8,
+    /// Overlay opacity while dragged
+    pub dragged: u8,
+}
+
+impl Default for StateLayerScale {
+    fn default() -> Self {
+        Self {
+            hover: 8,
+            focus: 12,
+            pressed: 12,
+            dragged: 16,
+        }
+    }
+}
+
+impl StateLayerScale {
+    /// Stronger overlays for low-contrast or high-glare surfaces
+    pub fn strong() -> Self {
+        Self {
+            hover: 12,
+            focus: 16,
+            pressed: 20,
+            dragged: 24,
+        }
+    }
+}
+
+/// Trait for providing state layer values
+pub trait StateLayerProvider {
+    /// Resolve a state layer to its overlay opacity in percent
+    fn resolve_state_layer(&self, layer: StateLayer) -> u8;
+
+    /// Get the overlay element classes (hidden until a state applies)
+    fn state_layer_base_classes(&self) -> String {
+        "relative overflow-hidden before:content-[''] before:absolute before:inset-0 before:rounded-[inherit] before:bg-current before:opacity-0 before:pointer-events-none before:transition-opacity before:duration-150 motion-reduce:before:transition-none"
+            .to_string()
+    }
+
+    /// Get the class applying the overlay for one state
+    fn state_layer_class(&self, layer: StateLayer) -> String {
+        let variant = match layer {
+            StateLayer::Hover => "hover:",
+            StateLayer::Focus => "focus-visible:",
+            StateLayer::Pressed => "active:",
+            StateLayer::Dragged => "data-[dragging=true]:",
+        };
+        format!(
+            "{}before:opacity-[{}]",
+            variant,
+            opacity_value(self.resolve_state_layer(layer))
+        )
+    }
+}
+
+impl StateLayerProvider for StateLayerScale {
+    fn resolve_state_layer(&self, layer: StateLayer) -> u8 {
+        match layer {
+            StateLayer::Hover => self.hover,
+            StateLayer::Focus => self.focus,
+            StateLayer::Pressed => self.pressed,
+            StateLayer::Dragged => self.dragged,
+        }
+    }
+}
+
+fn opacity_value(percent: u8) -> String {
+    let percent = percent.min(100);
+    if percent == 100 {
+        "1".to_string()
+    } else {
+        format!("0.{:02}", percent)
+    }
+}
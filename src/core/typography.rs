@@ -1,5 +1,7 @@
 //! Typography system for the design system
 
+use alloc::format;
+use alloc::string::{String, ToString};
 use serde::{Deserialize, Serialize};
 
 /// Typography tokens for consistent text styling
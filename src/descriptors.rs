@@ -0,0 +1,86 @@
+//! Component descriptors for framework adapters
+//!
+//! Builders produce class strings, patterns produce accessibility attributes
+//! and typography suggests an element, but an adapter rendering a component
+//! needs all three together. A [`ComponentDescriptor`] bundles the element
+//! to render, its classes and its attributes, so adapters have a single
+//! integration point per component.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::descriptors::Describe;
+//! use jupiter_design_system::prelude::*;
+//!
+//! let button = button_styles(VibeColors::default()).primary().loading();
+//! let descriptor = button.describe();
+//!
+//! assert_eq!(descriptor.element, "button");
+//! assert_eq!(descriptor.attribute("aria-busy"), Some("true"));
+//! assert_eq!(descriptor.classes, button.classes());
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Element, classes and attributes for rendering one component
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentDescriptor {
+    /// HTML element to render
+    pub element: String,
+    /// CSS classes for the element
+    pub classes: String,
+    /// HTML and ARIA attributes for the element
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl ComponentDescriptor {
+    /// Create a descriptor without attributes
+    pub fn new(element: impl Into<String>, classes: impl Into<String>) -> Self {
+        Self {
+            element: element.into(),
+            classes: classes.into(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Add an attribute, replacing any previous value
+    pub fn with_attribute(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        let value = value.into();
+        match self.attributes.iter_mut().find(|(n, _)| *n == name) {
+            Some(existing) => existing.1 = value,
+            None => self.attributes.push((name, value)),
+        }
+        self
+    }
+
+    /// Get an attribute value by name
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Builders that can describe the component they style
+pub trait Describe {
+    /// Get the element, classes and attributes for this component
+    fn describe(&self) -> ComponentDescriptor;
+}
+
+/// Describe a badge from its classes
+///
+/// Badges that only show a count or a dot should pass a `label` so screen
+/// readers announce what it counts.
+pub fn badge_descriptor(classes: impl Into<String>, label: Option<&str>) -> ComponentDescriptor {
+    let descriptor = ComponentDescriptor::new("span", classes);
+    match label {
+        Some(label) => descriptor.with_attribute("aria-label", label),
+        None => descriptor,
+    }
+}
+
+#[cfg(test)]
+#[path = "descriptors_test.rs"]
+mod descriptors_test;
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "buttons")]
+    use crate::builders::button::button_styles;
+    #[cfg(feature = "cards")]
+    use crate::builders::card::card_styles;
+    #[cfg(feature = "buttons")]
+    use crate::builders::interactive::interactive_input;
+    #[cfg(feature = "typography")]
+    use crate::builders::text::text_styles;
+    use crate::descriptors::{badge_descriptor, ComponentDescriptor};
+    #[cfg(any(feature = "buttons", feature = "cards", feature = "typography"))]
+    use crate::{descriptors::Describe, themes::VibeColors};
+
+    #[test]
+    fn test_with_attribute_replaces_existing() {
+        let descriptor = ComponentDescriptor::new("div", "p-4")
+            .with_attribute("role", "button")
+            .with_attribute("role", "option");
+
+        assert_eq!(descriptor.attributes, vec![("role", "option".to_string())]);
+        assert_eq!(descriptor.attribute("role"), Some("option"));
+        assert_eq!(descriptor.attribute("aria-label"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_button_descriptor() {
+        let button = button_styles(VibeColors::default()).secondary();
+        let descriptor = button.describe();
+
+        assert_eq!(descriptor.element, "button");
+        assert_eq!(descriptor.classes, button.classes());
+        assert_eq!(descriptor.attribute("type"), Some("button"));
+        assert_eq!(descriptor.attribute("aria-disabled"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_button_descriptor_states() {
+        let colors = VibeColors::default();
+
+        let disabled = button_styles(colors.clone()).disabled().describe();
+        assert_eq!(disabled.attribute("aria-disabled"), Some("true"));
+        assert_eq!(disabled.attribute("aria-busy"), None);
+
+        let loading = button_styles(colors).loading().describe();
+        assert_eq!(loading.attribute("aria-busy"), Some("true"));
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_link_button_descriptor() {
+        let descriptor = button_styles(VibeColors::default()).link().describe();
+
+        assert_eq!(descriptor.element, "a");
+        assert_eq!(descriptor.attribute("type"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "cards")]
+    fn test_card_descriptor_interactions() {
+        let colors = VibeColors::default();
+
+        let card = card_styles(colors.clone());
+        let descriptor = card.describe();
+        assert_eq!(descriptor.element, "article");
+        assert_eq!(descriptor.classes, card.classes());
+        assert!(descriptor.attributes.is_empty());
+
+        let clickable = card_styles(colors.clone())
+            .clickable_interaction()
+            .describe();
+        assert_eq!(clickable.element, "button");
+        assert_eq!(clickable.attribute("type"), Some("button"));
+        assert_eq!(clickable.attribute("role"), None);
+
+        let selectable = card_styles(colors)
+            .selectable_interaction()
+            .selected(true)
+            .describe();
+        assert_eq!(selectable.element, "div");
+        assert_eq!(selectable.attribute("role"), Some("option"));
+        assert_eq!(selectable.attribute("aria-selected"), Some("true"));
+    }
+
+    #[test]
+    #[cfg(feature = "typography")]
+    fn test_text_descriptor() {
+        let text = text_styles(VibeColors::default()).title();
+        let descriptor = text.describe();
+
+        assert_eq!(descriptor.element, text.element());
+        assert_eq!(descriptor.classes, text.classes());
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_input_descriptor() {
+        let input = interactive_input(VibeColors::default()).standard_style();
+        let descriptor = input.describe();
+
+        assert_eq!(descriptor.element, "input");
+        assert_eq!(descriptor.classes, input.build());
+        assert!(descriptor.classes.contains("w-full"));
+        assert_eq!(descriptor.attribute("type"), Some("text"));
+    }
+
+    #[test]
+    fn test_badge_descriptor() {
+        let badge = badge_descriptor("rounded-full", Some("3 unread"));
+        assert_eq!(badge.element, "span");
+        assert_eq!(badge.attribute("aria-label"), Some("3 unread"));
+        assert!(badge_descriptor("rounded-full", None).attributes.is_empty());
+    }
+}
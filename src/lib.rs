@@ -19,48 +19,99 @@
 //! // Use with any component library (Dioxus example):
 //! // rsx! { button { class: "{button_classes}", "Click me" } }
 //! ```
+//!
+//! ## `no_std`
+//!
+//! The crate only needs `alloc`. Disable the default `std` feature to build
+//! for `#![no_std]` targets; std-only helpers such as
+//! [`utils::fingerprint`] are then left out.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod macros;
 
+pub mod a11y;
 pub mod builders;
 pub mod core;
+pub mod descriptors;
 pub mod patterns;
+pub mod presets;
 pub mod themes;
 pub mod utils;
 
 // Re-export commonly used items
+pub use crate::a11y::ControlledIds;
 pub use crate::builders::*;
 pub use crate::core::*;
+pub use crate::descriptors::{ComponentDescriptor, Describe};
+pub use crate::patterns::{
+    action_semantics, focus_management, interactive_element, ActionContext, ActionHierarchy,
+    ActionIntent, ActionSemantics, CompositeRole, FocusBehavior, FocusManagement, InteractionEvent,
+    InteractionIntensity, InteractionStateMachine, InteractiveElement, InteractiveState,
+    KeyboardPattern, KeyboardShortcut, RenderTarget, ScreenReaderPattern, TabindexStrategy,
+};
+#[cfg(feature = "typography")]
+pub use crate::patterns::{
+    body_typography, caption_typography, code_typography, heading_typography, quote_typography,
+    title_typography, typography_pattern, HeadingLevelContext, TypographyAlignment,
+    TypographyColor, TypographyHierarchy, TypographyOverflow, TypographyPattern, TypographySize,
+    TypographyWeight,
+};
+#[cfg(feature = "buttons")]
 pub use crate::patterns::{
-    action_semantics, body_typography, button_link, button_pattern, caption_typography,
-    card_pattern, code_typography, destructive_button, focus_management, heading_typography,
-    hero_button, interactive_element, navigation_button, primary_button, secondary_button,
-    title_typography, typography_pattern, ActionContext, ActionHierarchy, ActionIntent,
-    ActionSemantics, ButtonPattern, ButtonSemanticInfo, CardElevation, CardInteraction,
-    CardPattern, CardSpacing, CardSurface, FocusBehavior, FocusManagement, InteractionIntensity,
-    InteractiveElement, InteractiveState, KeyboardPattern, ScreenReaderPattern,
-    TypographyAlignment, TypographyColor, TypographyHierarchy, TypographyOverflow,
-    TypographyPattern, TypographySize, TypographyWeight,
+    button_link, button_pattern, destructive_button, hero_button, navigation_button,
+    primary_button, secondary_button, ButtonPattern, ButtonSemanticInfo,
 };
+#[cfg(feature = "cards")]
+pub use crate::patterns::{
+    card_pattern, CardElevation, CardInteraction, CardPattern, CardSpacing, CardSurface,
+};
+pub use crate::presets::{Preset, PresetDefinition, PresetError, PresetRegistry};
 pub use crate::themes::*;
 pub use crate::utils::*;
 
 /// Common imports for everyday usage
+///
+/// Only items from enabled component features are included.
 pub mod prelude {
+    #[cfg(feature = "layout")]
+    pub use crate::builders::layout_styles;
+    #[cfg(feature = "states")]
+    pub use crate::builders::state_styles;
+    #[cfg(feature = "buttons")]
     pub use crate::builders::{
-        button_classes_from_strings, button_styles, card_styles, layout_styles,
-        selection_classes_from_strings, selection_styles, state_styles, text_classes_from_strings,
-        text_styles, ButtonBuilder, ButtonState, ButtonStyles, ButtonVariant, CardStyles,
-        InputBuilder, TextStyles,
+        button_classes_from_strings, button_styles, ButtonBuilder, ButtonState, ButtonStyles,
+        ButtonVariant, InputBuilder,
     };
+    #[cfg(feature = "cards")]
+    pub use crate::builders::{card_styles, CardStyles};
+    #[cfg(feature = "selection")]
+    pub use crate::builders::{selection_classes_from_strings, selection_styles};
+    #[cfg(feature = "typography")]
+    pub use crate::builders::{text_classes_from_strings, text_styles, TextStyles};
     pub use crate::core::color::ColorProvider;
-    pub use crate::core::{Breakpoint, Color, Size, Spacing, Typography};
+    pub use crate::core::{Breakpoint, Color, Density, Size, Spacing, Typography};
+    pub use crate::descriptors::Describe;
+    pub use crate::patterns::{
+        action_semantics, focus_management, ActionIntent, ActionSemantics, FocusManagement,
+        InteractiveElement, InteractiveState,
+    };
+    #[cfg(feature = "typography")]
+    pub use crate::patterns::{
+        body_typography, caption_typography, code_typography, heading_typography, title_typography,
+        typography_pattern, HeadingLevelContext, TypographyAlignment, TypographyColor,
+        TypographyHierarchy, TypographyOverflow, TypographyPattern, TypographySize,
+        TypographyWeight,
+    };
+    #[cfg(feature = "cards")]
+    pub use crate::patterns::{
+        card_pattern, CardElevation, CardInteraction, CardPattern, CardSpacing, CardSurface,
+    };
+    #[cfg(feature = "buttons")]
     pub use crate::patterns::{
-        action_semantics, body_typography, caption_typography, card_pattern, code_typography,
-        destructive_button, focus_management, heading_typography, hero_button, primary_button,
-        secondary_button, title_typography, typography_pattern, ActionIntent, ActionSemantics,
-        ButtonPattern, CardElevation, CardInteraction, CardPattern, CardSpacing, CardSurface,
-        FocusManagement, InteractiveElement, InteractiveState, TypographyAlignment,
-        TypographyColor, TypographyHierarchy, TypographyOverflow, TypographyPattern,
-        TypographySize, TypographyWeight,
+        destructive_button, hero_button, primary_button, secondary_button, ButtonPattern,
     };
     pub use crate::themes::VibeColors;
     pub use crate::themes::{Theme, VibeTheme};
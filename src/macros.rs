@@ -0,0 +1,91 @@
+//! Shorthand for builder chains in view code
+
+/// Build classes from a component name and a list of its setters
+///
+/// Long builder chains are noisy in view code. `jclasses!` expands a list
+/// of setter names into the chain and returns its classes; each name is a
+/// method call, so a misspelled variant is a compile error just as it
+/// would be in the chain.
+///
+/// Components: `button`, `card`, `text`, `state` and `layout`. Setters
+/// taking arguments are written as calls, e.g. `size(Size::Large)`.
+/// Without a provider in parentheses after the component the global theme
+/// is used, which requires the `std` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "buttons", feature = "cards"))]
+/// # {
+/// use jupiter_design_system::builders::{ButtonStyles, CardStyles};
+/// use jupiter_design_system::core::Size;
+/// use jupiter_design_system::jclasses;
+/// use jupiter_design_system::themes::{global_theme, VibeColors};
+///
+/// assert_eq!(
+///     jclasses!(button: primary, large, full_width; custom: "shadow-xl"),
+///     ButtonStyles::new(global_theme())
+///         .primary()
+///         .large()
+///         .full_width()
+///         .custom("shadow-xl")
+///         .classes()
+/// );
+///
+/// let colors = VibeColors::default();
+/// assert_eq!(
+///     jclasses!(card(&colors): clickable_interaction, spacing_str("lg")),
+///     CardStyles::new(&colors)
+///         .clickable_interaction()
+///         .spacing_str("lg")
+///         .classes()
+/// );
+/// assert_eq!(
+///     jclasses!(button(&colors): size(Size::Small)),
+///     ButtonStyles::new(&colors).small().classes()
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! jclasses {
+    (@new button, $colors:expr) => {
+        $crate::builders::button::ButtonStyles::new($colors)
+    };
+    (@new card, $colors:expr) => {
+        $crate::builders::card::CardStyles::new($colors)
+    };
+    (@new text, $colors:expr) => {
+        $crate::builders::text::TextStyles::new($colors)
+    };
+    (@new state, $colors:expr) => {
+        $crate::builders::state::StateStyles::new($colors)
+    };
+    (@new layout, $colors:expr) => {
+        $crate::builders::layout::LayoutStyles::new($colors)
+    };
+    (
+        $component:ident ( $colors:expr )
+        $( : $( $setter:ident $( ( $( $arg:expr ),* $(,)? ) )? ),* $(,)? )?
+        $( ; custom : $( $class:expr ),+ $(,)? )?
+    ) => {
+        $crate::jclasses!(@new $component, $colors)
+            $( $( .$setter( $( $( $arg ),* )? ) )* )?
+            $( $( .custom($class) )+ )?
+            .classes()
+    };
+    (
+        $component:ident
+        $( : $( $setter:ident $( ( $( $arg:expr ),* $(,)? ) )? ),* $(,)? )?
+        $( ; custom : $( $class:expr ),+ $(,)? )?
+    ) => {
+        $crate::jclasses!(
+            $component ($crate::themes::global_theme())
+            $( : $( $setter $( ( $( $arg ),* ) )? ),* )?
+            $( ; custom : $( $class ),+ )?
+        )
+    };
+}
+
+#[cfg(test)]
+#[path = "macros_test.rs"]
+mod macros_test;
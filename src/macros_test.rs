@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "buttons")]
+    use crate::builders::button::ButtonStyles;
+    #[cfg(feature = "cards")]
+    use crate::builders::card::CardStyles;
+    #[cfg(feature = "layout")]
+    use crate::builders::layout::LayoutStyles;
+    #[cfg(feature = "typography")]
+    use crate::builders::text::TextStyles;
+    #[cfg(any(feature = "buttons", feature = "cards", feature = "typography"))]
+    use crate::themes::VibeColors;
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_button_setters_and_custom_classes() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            crate::jclasses!(button(&colors): secondary, small, with_icon; custom: "shadow-xl", "ring-1"),
+            ButtonStyles::new(&colors)
+                .secondary()
+                .small()
+                .with_icon()
+                .custom("shadow-xl")
+                .custom("ring-1")
+                .classes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_component_without_setters() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            crate::jclasses!(button(&colors)),
+            ButtonStyles::new(&colors).classes()
+        );
+        assert_eq!(
+            crate::jclasses!(button(&colors); custom: "w-40"),
+            ButtonStyles::new(&colors).custom("w-40").classes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cards")]
+    fn test_setters_with_arguments() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            crate::jclasses!(card(&colors): elevation_str("floating"), selected(true),),
+            CardStyles::new(&colors)
+                .floating_elevation()
+                .is_selected()
+                .classes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "typography")]
+    fn test_text_component() {
+        let colors = VibeColors::default();
+
+        assert_eq!(
+            crate::jclasses!(text(&colors): title, bold),
+            TextStyles::new(&colors).title().bold().classes()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "layout"))]
+    fn test_global_theme_by_default() {
+        use crate::themes::global_theme;
+
+        assert_eq!(
+            crate::jclasses!(layout: divider_top, spacing_lg),
+            LayoutStyles::new(global_theme())
+                .divider_top()
+                .spacing_lg()
+                .classes()
+        );
+    }
+}
@@ -4,11 +4,14 @@
 //! of user actions, independent of visual implementation.
 
 use crate::core::color::ColorProvider;
-use crate::core::Color;
+use crate::core::{Color, Radius, RadiusProvider};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// Semantic action types that represent the intent and importance of actions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum ActionIntent {
     /// Primary action - the main thing user should do
     Primary,
@@ -22,6 +25,37 @@ pub enum ActionIntent {
     Navigation,
     /// Informational action - shows details, help, etc.
     Informational,
+    /// Domain action registered on the theme, e.g. `Custom("publish")`
+    ///
+    /// Colors come from `ColorProvider::custom_intent_classes`; intents the
+    /// theme does not know fall back to secondary styling. Serializes by
+    /// name but cannot be deserialized, since the name is a `&'static str`.
+    Custom(&'static str),
+}
+
+/// Built-in intents, so deserializing does not require `'static` input
+#[derive(Deserialize)]
+#[serde(rename = "ActionIntent")]
+enum BuiltinActionIntent {
+    Primary,
+    Secondary,
+    Constructive,
+    Destructive,
+    Navigation,
+    Informational,
+}
+
+impl<'de> Deserialize<'de> for ActionIntent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match BuiltinActionIntent::deserialize(deserializer)? {
+            BuiltinActionIntent::Primary => ActionIntent::Primary,
+            BuiltinActionIntent::Secondary => ActionIntent::Secondary,
+            BuiltinActionIntent::Constructive => ActionIntent::Constructive,
+            BuiltinActionIntent::Destructive => ActionIntent::Destructive,
+            BuiltinActionIntent::Navigation => ActionIntent::Navigation,
+            BuiltinActionIntent::Informational => ActionIntent::Informational,
+        })
+    }
 }
 
 /// Action hierarchy - how prominent should this action be?
@@ -56,6 +90,172 @@ pub enum ActionContext {
     Floating,
 }
 
+/// Element an action should render as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RenderTarget {
+    /// `<a>`: the action takes the user somewhere and needs an `href`
+    Anchor,
+    /// `<button>`: the action does something in place
+    Button,
+}
+
+impl RenderTarget {
+    /// Get the HTML element name
+    pub fn element(&self) -> &'static str {
+        match self {
+            RenderTarget::Anchor => "a",
+            RenderTarget::Button => "button",
+        }
+    }
+
+    /// Get suggested attributes for the element
+    ///
+    /// Buttons get `type="button"` so they never submit a surrounding form
+    /// by accident. External anchors open in a new tab without handing the
+    /// new page a reference to this one. Disabled anchors cannot use the
+    /// `disabled` attribute, so they drop out of the tab order instead;
+    /// omit their `href` too.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::RenderTarget;
+    ///
+    /// assert_eq!(
+    ///     RenderTarget::Button.attributes(false, false),
+    ///     vec![("type", "button".to_string())]
+    /// );
+    ///
+    /// let external = RenderTarget::Anchor.attributes(true, false);
+    /// assert!(external.contains(&("target", "_blank".to_string())));
+    /// assert!(external.contains(&("rel", "noopener noreferrer".to_string())));
+    /// ```
+    pub fn attributes(&self, external: bool, disabled: bool) -> Vec<(&'static str, String)> {
+        let mut attrs = Vec::new();
+        match self {
+            RenderTarget::Button => {
+                attrs.push(("type", "button".to_string()));
+                if disabled {
+                    attrs.push(("disabled", "true".to_string()));
+                }
+            }
+            RenderTarget::Anchor => {
+                if external {
+                    attrs.push(("target", "_blank".to_string()));
+                    attrs.push(("rel", "noopener noreferrer".to_string()));
+                }
+                if disabled {
+                    attrs.push(("aria-disabled", "true".to_string()));
+                    attrs.push(("tabindex", "-1".to_string()));
+                }
+            }
+        }
+        attrs
+    }
+}
+
+/// A keyboard shortcut written as `+`-separated keys, e.g. `"mod+s"`
+///
+/// `mod` is Command on macOS and Control elsewhere. Modifiers are `mod`,
+/// `ctrl`, `alt` (or `option`), `shift` and `meta` (or `cmd`); the last
+/// part is the key.
+///
+/// ```rust
+/// use jupiter_design_system::patterns::KeyboardShortcut;
+///
+/// let save = KeyboardShortcut::new("mod+shift+s");
+/// assert_eq!(save.aria_keyshortcuts(), "Control+Shift+S Meta+Shift+S");
+/// assert_eq!(save.label(false), "Ctrl+Shift+S");
+/// assert_eq!(save.label(true), "⌘⇧S");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyboardShortcut {
+    keys: String,
+}
+
+impl KeyboardShortcut {
+    /// Create a shortcut from its `+`-separated keys
+    pub fn new(keys: impl Into<String>) -> Self {
+        Self { keys: keys.into() }
+    }
+
+    /// Get the keys as written
+    pub fn keys(&self) -> &str {
+        &self.keys
+    }
+
+    /// Get the `aria-keyshortcuts` value
+    ///
+    /// Shortcuts using `mod` list the Control and Meta variants, since the
+    /// attribute cannot express "whichever is native".
+    pub fn aria_keyshortcuts(&self) -> String {
+        let uses_mod = self.parts().any(|part| part == "mod");
+        let variants: &[&str] = if uses_mod {
+            &["Control", "Meta"]
+        } else {
+            &["Control"]
+        };
+        let mut shortcuts: Vec<String> = variants
+            .iter()
+            .map(|mod_key| {
+                self.parts()
+                    .map(|part| match part.as_str() {
+                        "mod" => mod_key.to_string(),
+                        _ => aria_key(&part),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("+")
+            })
+            .collect();
+        shortcuts.dedup();
+        shortcuts.join(" ")
+    }
+
+    /// Get the visible label, with macOS symbols when `mac` is set
+    pub fn label(&self, mac: bool) -> String {
+        let parts: Vec<String> = self
+            .parts()
+            .map(|part| match (part.as_str(), mac) {
+                ("mod" | "meta" | "cmd", true) => "⌘".to_string(),
+                ("ctrl" | "control", true) => "⌃".to_string(),
+                ("alt" | "option", true) => "⌥".to_string(),
+                ("shift", true) => "⇧".to_string(),
+                ("mod" | "ctrl" | "control", false) => "Ctrl".to_string(),
+                ("meta" | "cmd", false) => "Meta".to_string(),
+                ("alt" | "option", false) => "Alt".to_string(),
+                ("shift", false) => "Shift".to_string(),
+                _ => aria_key(&part),
+            })
+            .collect();
+        parts.join(if mac { "" } else { "+" })
+    }
+
+    fn parts(&self) -> impl Iterator<Item = String> + '_ {
+        self.keys
+            .split('+')
+            .map(|part| part.trim().to_lowercase())
+            .filter(|part| !part.is_empty())
+    }
+}
+
+/// Key name as `aria-keyshortcuts` spells it
+fn aria_key(part: &str) -> String {
+    match part {
+        "ctrl" | "control" => "Control".to_string(),
+        "alt" | "option" => "Alt".to_string(),
+        "shift" => "Shift".to_string(),
+        "meta" | "cmd" => "Meta".to_string(),
+        "esc" => "Escape".to_string(),
+        "del" => "Delete".to_string(),
+        "space" => "Space".to_string(),
+        _ => {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
 /// Action semantics builder for creating consistent action meaning across components
 #[derive(Debug, Clone)]
 pub struct ActionSemantics<C: ColorProvider> {
@@ -63,6 +263,8 @@ pub struct ActionSemantics<C: ColorProvider> {
     hierarchy: ActionHierarchy,
     context: ActionContext,
     is_urgent: bool,
+    is_external: bool,
+    shortcut: Option<KeyboardShortcut>,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -75,6 +277,8 @@ impl<C: ColorProvider> ActionSemantics<C> {
             hierarchy: ActionHierarchy::Secondary,
             context: ActionContext::Standalone,
             is_urgent: false,
+            is_external: false,
+            shortcut: None,
             custom_classes: Vec::new(),
             color_provider,
         }
@@ -104,6 +308,18 @@ impl<C: ColorProvider> ActionSemantics<C> {
         self
     }
 
+    /// Mark as leading to another site
+    pub fn external(mut self) -> Self {
+        self.is_external = true;
+        self
+    }
+
+    /// Attach a keyboard shortcut, e.g. `"mod+s"`
+    pub fn with_shortcut(mut self, keys: impl Into<String>) -> Self {
+        self.shortcut = Some(KeyboardShortcut::new(keys));
+        self
+    }
+
     /// Add custom classes
     pub fn custom(mut self, class: impl Into<String>) -> Self {
         self.custom_classes.push(class.into());
@@ -139,6 +355,25 @@ impl<C: ColorProvider> ActionSemantics<C> {
         self
     }
 
+    /// Custom domain action registered on the theme (shorthand)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::core::IntentEmphasis;
+    /// use jupiter_design_system::patterns::action_semantics;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let colors = VibeColors::default().with_intent("archive", "slate-500", IntentEmphasis::Outline);
+    ///
+    /// let classes = action_semantics(colors).custom_intent("archive").classes();
+    /// assert!(classes.contains("border-slate-500"));
+    /// ```
+    pub fn custom_intent(mut self, name: &'static str) -> Self {
+        self.intent = ActionIntent::Custom(name);
+        self
+    }
+
     /// Navigation action (shorthand)
     pub fn navigation(mut self) -> Self {
         self.intent = ActionIntent::Navigation;
@@ -146,8 +381,119 @@ impl<C: ColorProvider> ActionSemantics<C> {
         self
     }
 
+    /// Get the element this action should render as
+    ///
+    /// Navigation intents, navigation contexts and external links move the
+    /// user somewhere else and render as anchors; everything else is a
+    /// button.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::{action_semantics, RenderTarget};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let save = action_semantics(VibeColors::default()).primary();
+    /// assert_eq!(save.render_target(), RenderTarget::Button);
+    /// assert_eq!(save.render_attributes(), vec![("type", "button".to_string())]);
+    ///
+    /// let docs = action_semantics(VibeColors::default()).navigation().external();
+    /// assert_eq!(docs.render_target().element(), "a");
+    /// assert!(docs.render_attributes().contains(&("target", "_blank".to_string())));
+    /// ```
+    pub fn render_target(&self) -> RenderTarget {
+        if self.intent == ActionIntent::Navigation
+            || self.context == ActionContext::Navigation
+            || self.is_external
+        {
+            RenderTarget::Anchor
+        } else {
+            RenderTarget::Button
+        }
+    }
+
+    /// Whether the action leads to another site
+    pub fn is_external(&self) -> bool {
+        self.is_external
+    }
+
+    /// Get suggested attributes for the rendered element
+    ///
+    /// Includes `aria-keyshortcuts` when a shortcut is attached.
+    pub fn render_attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = self.render_target().attributes(self.is_external, false);
+        attrs.extend(self.shortcut_attributes());
+        attrs
+    }
+
+    /// Get the attached keyboard shortcut
+    pub fn shortcut(&self) -> Option<&KeyboardShortcut> {
+        self.shortcut.as_ref()
+    }
+
+    /// Get the `aria-keyshortcuts` attribute, if a shortcut is attached
+    pub fn shortcut_attributes(&self) -> Option<(&'static str, String)> {
+        self.shortcut
+            .as_ref()
+            .map(|shortcut| ("aria-keyshortcuts", shortcut.aria_keyshortcuts()))
+    }
+
+    /// Get classes for the `kbd` badge showing the shortcut
+    ///
+    /// The badge pairs with the action's colors: on filled intents it is a
+    /// translucent inverse chip, elsewhere a bordered neutral chip. Use the
+    /// same classes in menus and tooltips so shortcuts look alike
+    /// everywhere.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::action_semantics;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let save = action_semantics(VibeColors::default()).primary().with_shortcut("mod+s");
+    /// assert!(save.shortcut_classes().unwrap().contains("bg-white/20"));
+    /// assert!(save
+    ///     .render_attributes()
+    ///     .contains(&("aria-keyshortcuts", "Control+S Meta+S".to_string())));
+    ///
+    /// assert_eq!(action_semantics(VibeColors::default()).shortcut_classes(), None);
+    /// ```
+    pub fn shortcut_classes(&self) -> Option<String> {
+        self.shortcut.as_ref()?;
+
+        let filled = match self.intent {
+            ActionIntent::Primary | ActionIntent::Constructive | ActionIntent::Destructive => true,
+            ActionIntent::Custom(name) => matches!(
+                self.color_provider
+                    .custom_intent(name)
+                    .map(|intent| intent.emphasis),
+                Some(crate::core::IntentEmphasis::Filled)
+            ),
+            _ => false,
+        };
+        let colors = if filled {
+            format!(
+                "{}/20 {} border-transparent",
+                self.color_provider.bg_class(Color::TextInverse),
+                self.color_provider.text_class(Color::TextInverse)
+            )
+        } else {
+            format!(
+                "{} {} {}",
+                self.color_provider.bg_class(Color::Background),
+                self.color_provider.text_class(Color::TextSecondary),
+                self.color_provider.border_class(Color::Border)
+            )
+        };
+
+        Some(format!(
+            "action-shortcut kbd inline-flex items-center ml-2 px-1.5 py-0.5 border font-mono text-xs leading-none {} {}",
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::ControlSmall),
+            colors
+        ))
+    }
+
     /// Build semantic color and visual weight classes
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut classes = Vec::new();
 
         // Color based on intent
@@ -170,13 +516,17 @@ impl<C: ColorProvider> ActionSemantics<C> {
         }
 
         // Add custom classes
-        classes.extend(self.custom_classes);
+        classes.extend(self.custom_classes.iter().cloned());
 
         classes.join(" ")
     }
 
     fn get_intent_colors(&self) -> String {
         match self.intent {
+            ActionIntent::Custom(name) => self
+                .color_provider
+                .custom_intent_classes(name)
+                .unwrap_or_else(|| self.secondary_colors()),
             ActionIntent::Primary => {
                 let hover_bg = format!(
                     "hover:{}",
@@ -189,13 +539,7 @@ impl<C: ColorProvider> ActionSemantics<C> {
                     hover_bg
                 )
             }
-            ActionIntent::Secondary => format!(
-                "{} {} {} {}",
-                self.color_provider.bg_class(Color::Surface),
-                self.color_provider.text_class(Color::TextPrimary),
-                self.color_provider.border_class(Color::Border),
-                "border"
-            ),
+            ActionIntent::Secondary => self.secondary_colors(),
             ActionIntent::Constructive => format!(
                 "{} {} {}",
                 self.color_provider.bg_class(Color::Success),
@@ -226,6 +570,16 @@ impl<C: ColorProvider> ActionSemantics<C> {
         }
     }
 
+    fn secondary_colors(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            self.color_provider.bg_class(Color::Surface),
+            self.color_provider.text_class(Color::TextPrimary),
+            self.color_provider.border_class(Color::Border),
+            "border"
+        )
+    }
+
     fn get_hierarchy_weight(&self) -> String {
         match self.hierarchy {
             ActionHierarchy::Hero => "text-xl font-bold px-8 py-4 rounded-lg shadow-lg".to_string(),
@@ -0,0 +1,128 @@
+//! Live region announcement patterns
+//!
+//! Screen readers only announce content that changes inside a live region.
+//! The announcer describes that region so loading, success and error
+//! messages are announced the same way everywhere.
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// How urgently a live region interrupts the screen reader
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LiveRegionPoliteness {
+    /// Announced when the user is idle (status updates, confirmations)
+    Polite,
+    /// Announced immediately, interrupting speech (errors, urgent alerts)
+    Assertive,
+}
+
+impl LiveRegionPoliteness {
+    /// Value of the `aria-live` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LiveRegionPoliteness::Polite => "polite",
+            LiveRegionPoliteness::Assertive => "assertive",
+        }
+    }
+
+    /// Implicit role carrying this politeness
+    pub fn role(&self) -> &'static str {
+        match self {
+            LiveRegionPoliteness::Polite => "status",
+            LiveRegionPoliteness::Assertive => "alert",
+        }
+    }
+}
+
+/// Live region builder for screen reader announcements
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::patterns::Announcer;
+///
+/// // Visually hidden region announcing errors immediately
+/// let announcer = Announcer::assertive();
+/// assert_eq!(announcer.classes(), "sr-only");
+/// assert!(announcer
+///     .attributes()
+///     .contains(&("aria-live", "assertive".to_string())));
+///
+/// // Visible status message that is also announced
+/// let status = Announcer::polite().visible().busy(true);
+/// assert_eq!(status.classes(), "");
+/// assert!(status.attributes().contains(&("aria-busy", "true".to_string())));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Announcer {
+    politeness: LiveRegionPoliteness,
+    visually_hidden: bool,
+    busy: bool,
+}
+
+impl Announcer {
+    /// Create a visually hidden announcer
+    pub fn new(politeness: LiveRegionPoliteness) -> Self {
+        Self {
+            politeness,
+            visually_hidden: true,
+            busy: false,
+        }
+    }
+
+    /// Visually hidden polite announcer
+    pub fn polite() -> Self {
+        Self::new(LiveRegionPoliteness::Polite)
+    }
+
+    /// Visually hidden assertive announcer
+    pub fn assertive() -> Self {
+        Self::new(LiveRegionPoliteness::Assertive)
+    }
+
+    /// Keep the region visible (the message is also shown on screen)
+    pub fn visible(mut self) -> Self {
+        self.visually_hidden = false;
+        self
+    }
+
+    /// Mark the region as busy while its content is still loading
+    pub fn busy(mut self, busy: bool) -> Self {
+        self.busy = busy;
+        self
+    }
+
+    /// Get the region's politeness
+    pub fn politeness(&self) -> LiveRegionPoliteness {
+        self.politeness
+    }
+
+    /// Build classes for the region
+    pub fn classes(&self) -> String {
+        if self.visually_hidden {
+            "sr-only".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Get live region attributes
+    pub fn attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![
+            ("role", self.politeness.role().to_string()),
+            ("aria-live", self.politeness.as_str().to_string()),
+            ("aria-atomic", "true".to_string()),
+        ];
+        if self.busy {
+            attrs.push(("aria-busy", "true".to_string()));
+        }
+        attrs
+    }
+}
+
+/// Convenience function to create a live region announcer
+pub fn announcer(politeness: LiveRegionPoliteness) -> Announcer {
+    Announcer::new(politeness)
+}
@@ -6,8 +6,10 @@
 use crate::core::color::ColorProvider;
 use crate::patterns::{
     ActionContext, ActionHierarchy, ActionIntent, ActionSemantics, FocusBehavior, FocusManagement,
-    InteractiveElement,
+    InteractiveElement, KeyboardPattern, RenderTarget,
 };
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Complete button pattern combining all abstract concepts
 ///
@@ -111,6 +113,12 @@ impl<C: ColorProvider + Clone> ButtonPattern<C> {
         self
     }
 
+    /// Custom domain action button, styled by the theme's intent mapping
+    pub fn custom_action(mut self, name: &'static str) -> Self {
+        self.action_semantics = self.action_semantics.custom_intent(name);
+        self
+    }
+
     /// Navigation action button
     pub fn navigation_action(mut self) -> Self {
         self.action_semantics = self.action_semantics.navigation();
@@ -271,6 +279,27 @@ impl<C: ColorProvider + Clone> ButtonPattern<C> {
         self
     }
 
+    /// Attach a keyboard shortcut, e.g. `"mod+s"`
+    ///
+    /// Adds `aria-keyshortcuts` to the accessibility attributes; render the
+    /// label in a `kbd` with [`shortcut_classes`](Self::shortcut_classes).
+    pub fn with_shortcut(mut self, keys: impl Into<String>) -> Self {
+        self.action_semantics = self.action_semantics.with_shortcut(keys);
+        self
+    }
+
+    /// Get classes for the shortcut `kbd` badge, if a shortcut is attached
+    pub fn shortcut_classes(&self) -> Option<String> {
+        self.action_semantics.shortcut_classes()
+    }
+
+    /// Mark as a link to another site, rendered as an anchor that opens
+    /// in a new tab
+    pub fn external(mut self) -> Self {
+        self.action_semantics = self.action_semantics.external();
+        self
+    }
+
     /// Mark as urgent
     pub fn urgent(mut self) -> Self {
         self.action_semantics = self.action_semantics.urgent();
@@ -280,7 +309,7 @@ impl<C: ColorProvider + Clone> ButtonPattern<C> {
     // === Build Methods ===
 
     /// Build complete button classes
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Get classes from each pattern
@@ -305,7 +334,7 @@ impl<C: ColorProvider + Clone> ButtonPattern<C> {
         }
 
         // Custom classes
-        all_classes.extend(self.custom_classes);
+        all_classes.extend(self.custom_classes.iter().cloned());
 
         // Remove duplicates and join
         let mut classes: Vec<String> = all_classes
@@ -331,10 +360,66 @@ impl<C: ColorProvider + Clone> ButtonPattern<C> {
         if self.selected {
             attrs.push(("aria-pressed", "true".to_string()));
         }
+        attrs.extend(self.action_semantics.shortcut_attributes());
 
         attrs
     }
 
+    /// Get the suggested HTML element for this button
+    ///
+    /// Navigation actions and link-focused buttons move the user somewhere
+    /// else and should render as `a`; everything else is a `button`.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::{navigation_button, primary_button};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// assert_eq!(primary_button(VibeColors::default()).suggested_element(), "button");
+    /// assert_eq!(navigation_button(VibeColors::default()).suggested_element(), "a");
+    /// ```
+    pub fn suggested_element(&self) -> &'static str {
+        self.render_target().element()
+    }
+
+    /// Get whether this button renders as an anchor or a button
+    ///
+    /// Anchors come from navigation actions, external links and link focus
+    /// behavior.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::{button_link, primary_button, RenderTarget};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// assert_eq!(primary_button(VibeColors::default()).render_target(), RenderTarget::Button);
+    /// assert_eq!(button_link(VibeColors::default()).render_target(), RenderTarget::Anchor);
+    ///
+    /// // Disabled anchors leave the tab order instead of using `disabled`
+    /// let attrs = button_link(VibeColors::default()).disabled(true).render_attributes();
+    /// assert!(attrs.contains(&("tabindex", "-1".to_string())));
+    /// assert!(!attrs.iter().any(|(name, _)| *name == "disabled"));
+    /// ```
+    pub fn render_target(&self) -> RenderTarget {
+        let is_link = matches!(
+            self.focus_management.keyboard_pattern(),
+            Some(KeyboardPattern::Link)
+        );
+        if is_link {
+            RenderTarget::Anchor
+        } else {
+            self.action_semantics.render_target()
+        }
+    }
+
+    /// Get suggested attributes for the rendered element
+    ///
+    /// Includes `type="button"` for buttons, `target` and `rel` for
+    /// external anchors, and the disabled treatment that fits the element.
+    /// Pair with [`accessibility_attributes`](Self::accessibility_attributes).
+    pub fn render_attributes(&self) -> Vec<(&'static str, String)> {
+        self.render_target()
+            .attributes(self.action_semantics.is_external(), self.disabled)
+    }
+
     /// Get semantic information about this button
     pub fn semantic_info(&self) -> ButtonSemanticInfo {
         ButtonSemanticInfo {
@@ -5,7 +5,15 @@
 //! appropriate elevation, interactivity, and visual hierarchy.
 
 use crate::core::color::ColorProvider;
+use crate::core::{
+    BorderProvider, BorderWidth, Color, Density, Elevation, ElevationProvider, GlassTone, Radius,
+    RadiusProvider, SurfaceLevel,
+};
 use crate::patterns::{FocusManagement, InteractiveElement};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// Card elevation levels representing visual hierarchy
@@ -23,17 +31,33 @@ pub enum CardElevation {
     Modal,
 }
 
+impl From<CardElevation> for Elevation {
+    fn from(elevation: CardElevation) -> Self {
+        match elevation {
+            CardElevation::Flat => Elevation::Flat,
+            CardElevation::Subtle => Elevation::Subtle,
+            CardElevation::Raised => Elevation::Raised,
+            CardElevation::Floating => Elevation::Floating,
+            CardElevation::Modal => Elevation::Modal,
+        }
+    }
+}
+
 /// Card surface variants representing different visual treatments
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CardSurface {
     /// Standard white/light surface
     Standard,
-    /// Elevated surface with subtle background
+    /// Raised surface one step up the surface ladder
     Elevated,
+    /// Floating surface for menus, popovers and modals
+    Overlay,
     /// Branded surface using theme colors
     Branded,
-    /// Glass morphism effect
+    /// Glass morphism effect for dark or image backgrounds
     Glass,
+    /// Glass morphism effect for light pages
+    GlassLight,
     /// Dark surface for dark themes
     Dark,
     /// Transparent surface
@@ -55,6 +79,146 @@ pub enum CardSpacing {
     Spacious,
 }
 
+impl CardSpacing {
+    /// Padding class for this spacing, shifted by `density`
+    ///
+    /// Shared by cards and the sections inside them (see
+    /// `LayoutStyles::card_spacing`) so both agree on the inset.
+    pub fn padding_class(&self, density: Density) -> &'static str {
+        const PADDING: [&str; 6] = ["p-2", "p-3", "p-5", "p-6", "p-8", "p-10"];
+        match self {
+            CardSpacing::None => "p-0",
+            CardSpacing::Compact => density.scale(&PADDING, 1),
+            CardSpacing::Standard => density.scale(&PADDING, 2),
+            CardSpacing::Comfortable => density.scale(&PADDING, 3),
+            CardSpacing::Spacious => density.scale(&PADDING, 4),
+        }
+    }
+}
+
+/// Classes for a card nested inside another card
+///
+/// A nested card drops its border and shadow and is tinted one step down the
+/// surface ladder (`SurfaceLevel::Background`), so it reads as a region of
+/// its parent rather than a second card stacked on top. Its radius is one
+/// step smaller than the parent's surface radius.
+pub(crate) fn nested_card_classes<C: ColorProvider>(colors: &C) -> String {
+    format!(
+        "{} border-0 shadow-none {} {} transition-all duration-300",
+        colors.radius_scale().radius_class(Radius::Control),
+        colors.surface_class(SurfaceLevel::Background),
+        colors.text_class(Color::TextPrimary)
+    )
+}
+
+/// Classes for a glass surface of the given tone
+///
+/// Tint, blur and border come from the theme's `GlassScale`. The tint and
+/// blur only apply where `backdrop-filter` is supported; elsewhere the
+/// surface falls back to a nearly opaque background so text stays readable.
+pub(crate) fn glass_surface_classes<C: ColorProvider>(colors: &C, tone: GlassTone) -> String {
+    let glass = colors.glass_scale();
+    let (fallback, border, text) = match tone {
+        GlassTone::Dark => (
+            colors.bg_class(Color::Foreground),
+            format!(
+                "{}/{}",
+                colors.border_class(glass.tint),
+                glass.border_opacity
+            ),
+            colors.text_class(Color::TextInverse),
+        ),
+        GlassTone::Light => (
+            colors.bg_class(glass.tint),
+            colors.border_class(Color::Border),
+            colors.text_class(Color::TextPrimary),
+        ),
+    };
+
+    format!(
+        "{}/{} {} {} supports-[backdrop-filter]:{}/{} supports-[backdrop-filter]:{}",
+        fallback,
+        glass.fallback_opacity,
+        border,
+        text,
+        colors.bg_class(glass.tint),
+        glass.tint_opacity(tone),
+        glass.blur_class()
+    )
+}
+
+/// Classes for a branded surface
+///
+/// The theme's brand gradient with text that reads on the primary color
+/// and a faint border in the same color.
+pub(crate) fn branded_surface_classes<C: ColorProvider>(colors: &C) -> String {
+    let text = colors.on_color(Color::Primary);
+    format!(
+        "{} {}/10 {}",
+        colors.brand_gradient_classes(),
+        colors.border_class(text),
+        colors.text_class(text)
+    )
+}
+
+/// Card sections that show placeholders while the card is loading
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardPart {
+    /// Title and subtitle area (`card_header_styles`)
+    Header,
+    /// Body copy (`card_content_styles`)
+    Content,
+    /// Actions row (`card_footer_styles`)
+    Footer,
+}
+
+/// Classes for a card whose content is still loading
+///
+/// The surface is muted to the background tint and the card pulses; all
+/// pointer interaction is switched off until the content arrives.
+pub(crate) fn loading_card_classes<C: ColorProvider>(colors: &C) -> String {
+    format!(
+        "{} {} motion-safe:animate-pulse pointer-events-none select-none",
+        colors.surface_class(SurfaceLevel::Background),
+        colors.border_class(Color::Border)
+    )
+}
+
+/// Placeholder blocks for one card section, one class string per block
+///
+/// Shapes follow the section they stand in for: a title and subtitle bar in
+/// the header, lines of copy in the content and button-sized blocks in the
+/// footer, so the layout does not jump once real content replaces them.
+pub(crate) fn card_placeholder_classes<C: ColorProvider>(
+    colors: &C,
+    part: CardPart,
+) -> Vec<String> {
+    let block = |shape: &str, radius: Radius| {
+        format!(
+            "block {} {} {}",
+            shape,
+            colors.radius_scale().radius_class(radius),
+            colors.bg_class(Color::Border)
+        )
+    };
+
+    match part {
+        CardPart::Header => vec![
+            block("h-5 w-1/2", Radius::ControlSmall),
+            block("h-3 w-1/3 mt-2", Radius::ControlSmall),
+        ],
+        CardPart::Content => vec![
+            block("h-4 w-full", Radius::ControlSmall),
+            block("h-4 w-full", Radius::ControlSmall),
+            block("h-4 w-2/3", Radius::ControlSmall),
+        ],
+        CardPart::Footer => vec![
+            block("h-9 w-20", Radius::Control),
+            block("h-9 w-24", Radius::Control),
+        ],
+    }
+}
+
 /// Card interaction patterns
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CardInteraction {
@@ -70,6 +234,21 @@ pub enum CardInteraction {
     Draggable,
 }
 
+impl CardInteraction {
+    /// Suggested HTML element for a card with this interaction
+    ///
+    /// Static cards are self-contained content (`article`), clickable cards
+    /// are a single action (`button`), and selectable or draggable cards are
+    /// generic containers whose role is set through attributes.
+    pub fn suggested_element(&self) -> &'static str {
+        match self {
+            CardInteraction::Static | CardInteraction::Hoverable => "article",
+            CardInteraction::Clickable => "button",
+            CardInteraction::Selectable | CardInteraction::Draggable => "div",
+        }
+    }
+}
+
 /// Complete card pattern combining all abstract concepts
 ///
 /// This represents the full abstract concept of a "card" - a container that
@@ -123,6 +302,8 @@ pub struct CardPattern<C: ColorProvider + Clone> {
 
     // State
     selected: bool,
+    nested: bool,
+    stretched_link: bool,
 
     // Abstract patterns
     interactive_element: InteractiveElement<C>,
@@ -142,6 +323,8 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
             spacing: CardSpacing::Standard,
             interaction: CardInteraction::Static,
             selected: false,
+            nested: false,
+            stretched_link: false,
 
             interactive_element: InteractiveElement::new(color_provider.clone()),
             focus_management: FocusManagement::new(color_provider.clone()),
@@ -209,6 +392,12 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
         self
     }
 
+    /// Glass morphism surface for light pages
+    pub fn glass_light_surface(mut self) -> Self {
+        self.surface = CardSurface::GlassLight;
+        self
+    }
+
     /// Dark surface
     pub fn dark_surface(mut self) -> Self {
         self.surface = CardSurface::Dark;
@@ -221,6 +410,12 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
         self
     }
 
+    /// Overlay surface
+    pub fn overlay_surface(mut self) -> Self {
+        self.surface = CardSurface::Overlay;
+        self
+    }
+
     // === Spacing Methods ===
 
     /// No internal padding
@@ -283,6 +478,19 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
         self
     }
 
+    /// Clickable card that navigates through a stretched link
+    ///
+    /// The card stays an `article` and a real `a` inside it (usually the
+    /// title) is stretched over the whole card, so the link text names the
+    /// destination and the card needs no role or click handler. See
+    /// `stretched_link_markup`.
+    pub fn link_interaction(mut self) -> Self {
+        self = self.clickable_interaction();
+        self.stretched_link = true;
+        self.focus_management = self.focus_management.link();
+        self
+    }
+
     /// Selectable card (can be selected/deselected)
     pub fn selectable_interaction(mut self) -> Self {
         self.interaction = CardInteraction::Selectable;
@@ -307,6 +515,15 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
 
     // === State Methods ===
 
+    /// Style the card for use inside another card
+    ///
+    /// Drops the border and shadow in favor of a background tint; see
+    /// `SurfaceLevel` for how surfaces stack.
+    pub fn nested(mut self) -> Self {
+        self.nested = true;
+        self
+    }
+
     /// Set selected state
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -342,26 +559,33 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
     // === Build Methods ===
 
     /// Build complete card classes
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut all_classes = Vec::new();
 
-        // Base classes
-        all_classes.push("rounded-lg border transition-all duration-300".to_string());
+        let elevation_scale = self.color_provider.elevation_scale();
+        if self.nested {
+            // Nested cards replace border, shadow and surface with a tint
+            all_classes.push(nested_card_classes(&self.color_provider));
+        } else {
+            // Base classes
+            all_classes.push(format!(
+                "{} {} transition-all duration-300",
+                self.color_provider
+                    .radius_scale()
+                    .radius_class(Radius::Surface),
+                self.color_provider
+                    .border_scale()
+                    .border_width_class(BorderWidth::Default)
+            ));
 
-        // Elevation classes
-        let elevation_classes = match self.elevation {
-            CardElevation::Flat => "shadow-none",
-            CardElevation::Subtle => "shadow-sm",
-            CardElevation::Raised => "shadow-md",
-            CardElevation::Floating => "shadow-lg",
-            CardElevation::Modal => "shadow-2xl",
-        };
-        all_classes.push(elevation_classes.to_string());
+            // Elevation classes
+            all_classes.push(elevation_scale.shadow_class(self.elevation.into()));
 
-        // Surface classes
-        let surface_classes = self.get_surface_classes();
-        if !surface_classes.is_empty() {
-            all_classes.push(surface_classes);
+            // Surface classes
+            let surface_classes = self.get_surface_classes();
+            if !surface_classes.is_empty() {
+                all_classes.push(surface_classes);
+            }
         }
 
         // Spacing classes
@@ -380,10 +604,19 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
             all_classes.push(interactive_classes);
         }
 
-        // Focus classes
-        let focus_classes = self.focus_management.classes();
-        if !focus_classes.is_empty() {
-            all_classes.push(focus_classes);
+        // Focus classes (a stretched link takes focus, so the card shows it)
+        if self.stretched_link {
+            all_classes.push(format!(
+                "relative focus-within:ring-2 focus-within:ring-offset-2 focus-within:ring-{}",
+                self.color_provider
+                    .resolve_color(crate::core::Color::Primary)
+                    .replace("-500", "-300")
+            ));
+        } else {
+            let focus_classes = self.focus_management.classes();
+            if !focus_classes.is_empty() {
+                all_classes.push(focus_classes);
+            }
         }
 
         // Selection state
@@ -399,20 +632,21 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
         }
 
         // Hover elevation effects for interactive cards
-        if matches!(
-            self.interaction,
-            CardInteraction::Hoverable | CardInteraction::Clickable
-        ) {
-            match self.elevation {
-                CardElevation::Subtle => all_classes.push("hover:shadow-md".to_string()),
-                CardElevation::Raised => all_classes.push("hover:shadow-lg".to_string()),
-                CardElevation::Floating => all_classes.push("hover:shadow-xl".to_string()),
-                _ => {}
-            }
+        if !self.nested
+            && matches!(
+                self.interaction,
+                CardInteraction::Hoverable | CardInteraction::Clickable
+            )
+            && matches!(
+                self.elevation,
+                CardElevation::Subtle | CardElevation::Raised | CardElevation::Floating
+            )
+        {
+            all_classes.push(elevation_scale.hover_shadow_class(self.elevation.into()));
         }
 
         // Custom classes
-        all_classes.extend(self.custom_classes);
+        all_classes.extend(self.custom_classes.iter().cloned());
 
         // Remove duplicates and join
         let mut classes: Vec<String> = all_classes
@@ -429,31 +663,46 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
             CardSurface::Standard => format!(
                 "{} {} {}",
                 self.color_provider.bg_class(crate::core::Color::Surface),
-                self.color_provider.text_class(crate::core::Color::TextPrimary),
+                self.color_provider
+                    .text_class(crate::core::Color::TextPrimary),
                 self.color_provider.border_class(crate::core::Color::Border)
             ),
             CardSurface::Elevated => format!(
                 "{} {} {}",
-                self.color_provider.bg_class(crate::core::Color::Background),
-                self.color_provider.text_class(crate::core::Color::TextPrimary),
+                self.color_provider
+                    .bg_class(crate::core::Color::SurfaceRaised),
+                self.color_provider
+                    .text_class(crate::core::Color::TextPrimary),
+                self.color_provider.border_class(crate::core::Color::Border)
+            ),
+            CardSurface::Overlay => format!(
+                "{} {} {}",
+                self.color_provider
+                    .bg_class(crate::core::Color::SurfaceOverlay),
+                self.color_provider
+                    .text_class(crate::core::Color::TextPrimary),
                 self.color_provider.border_class(crate::core::Color::Border)
             ),
-            CardSurface::Branded => "bg-gradient-to-br from-jupiter-navy-900/80 to-jupiter-blue-900/80 border-white/10 text-white".to_string(),
-            CardSurface::Glass => {
-                "bg-white/10 backdrop-blur-md border-white/20 text-white".to_string()
-            },
-            CardSurface::Dark => {
-                "bg-gray-900 border-gray-700 text-white".to_string()
-            },
-            CardSurface::Transparent => {
-                "bg-transparent border-transparent".to_string()
-            },
+            CardSurface::Branded => branded_surface_classes(&self.color_provider),
+            CardSurface::Glass => glass_surface_classes(&self.color_provider, GlassTone::Dark),
+            CardSurface::GlassLight => {
+                glass_surface_classes(&self.color_provider, GlassTone::Light)
+            }
+            CardSurface::Dark => "bg-gray-900 border-gray-700 text-white".to_string(),
+            CardSurface::Transparent => "bg-transparent border-transparent".to_string(),
         }
     }
 
     /// Get accessibility attributes
+    ///
+    /// Cards with a stretched link get none of their own: the link inside
+    /// is focusable and announced natively.
     pub fn accessibility_attributes(&self) -> Vec<(&'static str, String)> {
-        let mut attrs = self.focus_management.data_attributes();
+        let mut attrs = if self.stretched_link {
+            Vec::new()
+        } else {
+            self.focus_management.data_attributes()
+        };
 
         // Add card-specific attributes
         if self.selected {
@@ -462,7 +711,7 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
 
         // Add role based on interaction type
         match self.interaction {
-            CardInteraction::Clickable => {
+            CardInteraction::Clickable if !self.stretched_link => {
                 attrs.push(("role", "button".to_string()));
             }
             CardInteraction::Selectable => {
@@ -474,6 +723,52 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
         attrs
     }
 
+    /// Get the suggested HTML element for this card
+    pub fn suggested_element(&self) -> &'static str {
+        if self.stretched_link {
+            return "article";
+        }
+        self.interaction.suggested_element()
+    }
+
+    /// Get classes for the link stretched over a link card
+    ///
+    /// The link's `::after` covers the card, so a click anywhere on the card
+    /// follows the link. Empty unless `link_interaction` is set.
+    pub fn stretched_link_classes(&self) -> String {
+        if !self.stretched_link {
+            return String::new();
+        }
+        "card-link after:absolute after:inset-0 after:z-0 after:rounded-[inherit] after:content-[''] focus:outline-none focus-visible:outline-none".to_string()
+    }
+
+    /// Get the markup a link card should render
+    ///
+    /// Returns `None` unless `link_interaction` is set.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::CardPattern;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let card = CardPattern::new(VibeColors::default()).link_interaction();
+    /// let markup = card.stretched_link_markup().unwrap();
+    ///
+    /// assert_eq!(markup.container_element, "article");
+    /// assert_eq!(markup.link_element, "a");
+    /// assert!(markup.container_classes.contains("relative"));
+    /// assert!(markup.link_classes.contains("after:inset-0"));
+    /// assert!(card.accessibility_attributes().is_empty());
+    /// ```
+    pub fn stretched_link_markup(&self) -> Option<StretchedLinkMarkup> {
+        self.stretched_link.then(|| StretchedLinkMarkup {
+            container_element: self.suggested_element(),
+            container_classes: self.classes(),
+            link_element: "a",
+            link_classes: self.stretched_link_classes(),
+            interactive_content_classes: "relative z-10".to_string(),
+        })
+    }
+
     /// Get semantic information about this card
     pub fn semantic_info(&self) -> CardSemanticInfo {
         CardSemanticInfo {
@@ -487,6 +782,22 @@ impl<C: ColorProvider + Clone> CardPattern<C> {
     }
 }
 
+/// Markup for a card that navigates through a stretched link
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StretchedLinkMarkup {
+    /// Element for the card itself (not the link)
+    pub container_element: &'static str,
+    /// Card classes, including the `relative` positioning context
+    pub container_classes: String,
+    /// Element for the link, usually wrapping the card title
+    pub link_element: &'static str,
+    /// Classes stretching the link over the card
+    pub link_classes: String,
+    /// Classes for other buttons and links inside the card so they stay
+    /// clickable above the stretched link
+    pub interactive_content_classes: String,
+}
+
 /// Semantic information about a card pattern
 #[derive(Debug, Clone)]
 pub struct CardSemanticInfo {
@@ -0,0 +1,261 @@
+//! Form field patterns
+//!
+//! A form field is a label, a control, optional help text and an optional
+//! error message. Their styling and ARIA wiring must change together when
+//! the field becomes invalid, so the field pattern owns all of them and a
+//! single `invalid` switch coordinates every part.
+
+use crate::a11y;
+use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth, Color, Radius, RadiusProvider, Size, SizeProvider};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Complete form field pattern
+///
+/// Part ids follow one convention derived from the field id: the label is
+/// `{id}-label`, help text `{id}-help` and the error message `{id}-error`.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::patterns::FieldPattern;
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let field = FieldPattern::new(VibeColors::default(), "email")
+///     .with_help_text()
+///     .invalid(true);
+///
+/// assert!(field.input_classes().contains("border-red-500"));
+/// assert!(field.label_classes().contains("text-red-500"));
+///
+/// let attrs = field.input_attributes();
+/// assert!(attrs.contains(&("aria-invalid", "true".to_string())));
+/// assert!(attrs.contains(&("aria-describedby", "email-help email-error".to_string())));
+/// assert_eq!(field.help_attributes(), vec![("id", "email-help".to_string())]);
+///
+/// let keyed = FieldPattern::keyed(VibeColors::default(), "billing-email");
+/// assert_eq!(keyed.input_id(), "field-billing-email");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FieldPattern<C: ColorProvider + Clone> {
+    id: String,
+    invalid: bool,
+    error_message: bool,
+    help_text: bool,
+    required: bool,
+    disabled: bool,
+    color_provider: C,
+}
+
+impl<C: ColorProvider + Clone> FieldPattern<C> {
+    /// Create a new field pattern for the control with the given id
+    pub fn new(color_provider: C, id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            invalid: false,
+            error_message: false,
+            help_text: false,
+            required: false,
+            disabled: false,
+            color_provider,
+        }
+    }
+
+    /// Create a new field pattern with a control id derived from a key
+    ///
+    /// The id comes from `a11y::id("field", key)`, so it is the same on
+    /// every render.
+    pub fn keyed(color_provider: C, key: impl core::fmt::Display) -> Self {
+        Self::new(color_provider, a11y::id("field", key))
+    }
+
+    // === State Methods ===
+
+    /// Mark the field invalid, with or without a rendered error message
+    pub fn invalid(mut self, message_present: bool) -> Self {
+        self.invalid = true;
+        self.error_message = message_present;
+        self
+    }
+
+    /// Mark the field valid again
+    pub fn valid(mut self) -> Self {
+        self.invalid = false;
+        self.error_message = false;
+        self
+    }
+
+    /// Mark the field required
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Mark the field disabled
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Render help text below the control
+    pub fn with_help_text(mut self) -> Self {
+        self.help_text = true;
+        self
+    }
+
+    // === Id Methods ===
+
+    /// Get the control id
+    pub fn input_id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the label id
+    pub fn label_id(&self) -> String {
+        format!("{}-label", self.id)
+    }
+
+    /// Get the help text id
+    pub fn help_id(&self) -> String {
+        format!("{}-help", self.id)
+    }
+
+    /// Get the error message id
+    pub fn error_id(&self) -> String {
+        format!("{}-error", self.id)
+    }
+
+    // === Build Methods ===
+
+    /// Build classes for the field wrapper
+    pub fn container_classes(&self) -> String {
+        "field flex flex-col gap-1.5".to_string()
+    }
+
+    /// Build classes for the label
+    pub fn label_classes(&self) -> String {
+        let color = if self.invalid {
+            Color::Error
+        } else {
+            Color::TextPrimary
+        };
+        let mut classes = vec![
+            "text-sm font-medium".to_string(),
+            self.color_provider.text_class(color),
+        ];
+        if self.required {
+            classes.push("after:content-['*'] after:ml-0.5".to_string());
+            classes.push(format!(
+                "after:{}",
+                self.color_provider.text_class(Color::Error)
+            ));
+        }
+        if self.disabled {
+            classes.push("opacity-50".to_string());
+        }
+        classes.join(" ")
+    }
+
+    /// Build classes for the control
+    pub fn input_classes(&self) -> String {
+        let color = if self.invalid {
+            Color::Error
+        } else {
+            Color::Border
+        };
+        let ring = if self.invalid {
+            Color::Error
+        } else {
+            Color::Primary
+        };
+        let mut classes = vec![
+            format!(
+                "w-full {} px-4 {} {} transition-colors focus:outline-none focus:ring-2",
+                self.color_provider
+                    .size_scale()
+                    .control_height_class(Size::Medium),
+                self.color_provider
+                    .border_scale()
+                    .border_width_class(BorderWidth::Default),
+                self.color_provider
+                    .radius_scale()
+                    .radius_class(Radius::Control)
+            ),
+            self.color_provider.border_class(color),
+            format!("focus:ring-{}", self.color_provider.resolve_color(ring)),
+            self.color_provider.bg_class(Color::Surface),
+        ];
+        if self.disabled {
+            classes.push("opacity-50 cursor-not-allowed".to_string());
+        }
+        classes.join(" ")
+    }
+
+    /// Build classes for the help text
+    pub fn help_classes(&self) -> String {
+        format!(
+            "text-sm {}",
+            self.color_provider.text_class(Color::TextSecondary)
+        )
+    }
+
+    /// Build classes for the error message
+    pub fn error_classes(&self) -> String {
+        format!(
+            "text-sm font-medium {}",
+            self.color_provider.text_class(Color::Error)
+        )
+    }
+
+    /// Get attributes for the control
+    pub fn input_attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![("id", self.id.clone())];
+
+        let mut described_by = Vec::new();
+        if self.help_text {
+            described_by.push(self.help_id());
+        }
+        if self.invalid && self.error_message {
+            described_by.push(self.error_id());
+        }
+        if !described_by.is_empty() {
+            attrs.push(("aria-describedby", described_by.join(" ")));
+        }
+
+        if self.invalid {
+            attrs.push(("aria-invalid", "true".to_string()));
+        }
+        if self.required {
+            attrs.push(("aria-required", "true".to_string()));
+        }
+        if self.disabled {
+            attrs.push(("disabled", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Get attributes for the label
+    pub fn label_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![("id", self.label_id()), ("for", self.id.clone())]
+    }
+
+    /// Get attributes for the help text
+    pub fn help_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![("id", self.help_id())]
+    }
+
+    /// Get attributes for the error message
+    pub fn error_attributes(&self) -> Vec<(&'static str, String)> {
+        vec![("id", self.error_id()), ("role", "alert".to_string())]
+    }
+}
+
+/// Convenience function to create a form field pattern
+pub fn field_pattern<C: ColorProvider + Clone>(
+    color_provider: C,
+    id: impl Into<String>,
+) -> FieldPattern<C> {
+    FieldPattern::new(color_provider, id)
+}
@@ -2,6 +2,9 @@
 
 use crate::core::color::ColorProvider;
 use crate::core::Color;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use serde::{Deserialize, Serialize};
 
 /// Focus behavior types
@@ -53,7 +56,96 @@ pub enum ScreenReaderPattern {
     Expandable,
 }
 
+/// Composite widgets whose container coordinates focus across its items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompositeRole {
+    /// Row of tabs controlling tab panels
+    Tablist,
+    /// Menu of actions
+    Menu,
+    /// List of selectable options
+    Listbox,
+    /// Two-dimensional grid of cells
+    Grid,
+}
+
+impl CompositeRole {
+    /// ARIA role of the container
+    pub fn container_role(&self) -> &'static str {
+        match self {
+            CompositeRole::Tablist => "tablist",
+            CompositeRole::Menu => "menu",
+            CompositeRole::Listbox => "listbox",
+            CompositeRole::Grid => "grid",
+        }
+    }
+
+    /// ARIA role of each item
+    pub fn item_role(&self) -> &'static str {
+        match self {
+            CompositeRole::Tablist => "tab",
+            CompositeRole::Menu => "menuitem",
+            CompositeRole::Listbox => "option",
+            CompositeRole::Grid => "gridcell",
+        }
+    }
+
+    /// Default `aria-orientation`, matching the arrow keys that move focus
+    ///
+    /// Grids move in both directions and have no orientation.
+    pub fn orientation(&self) -> Option<&'static str> {
+        match self {
+            CompositeRole::Tablist => Some("horizontal"),
+            CompositeRole::Menu | CompositeRole::Listbox => Some("vertical"),
+            CompositeRole::Grid => None,
+        }
+    }
+}
+
+/// How keyboard focus moves between the items of a composite widget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TabindexStrategy {
+    /// The active item has `tabindex="0"` and the rest `-1`; arrow keys
+    /// move DOM focus between items
+    Roving,
+    /// The container keeps DOM focus and points at the active item with
+    /// `aria-activedescendant`
+    ActiveDescendant,
+}
+
+impl TabindexStrategy {
+    /// Value of the `data-focus-strategy` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TabindexStrategy::Roving => "roving",
+            TabindexStrategy::ActiveDescendant => "active-descendant",
+        }
+    }
+}
+
 /// Focus management builder for consistent accessibility
+///
+/// Composite widgets (tab lists, menus, listboxes, grids) take a single
+/// Tab stop and move focus between items with the arrow keys. After
+/// `composite`, the builder describes both halves: attributes for the
+/// container and per-item tabindex and focus-visible classes.
+///
+/// ```rust
+/// use jupiter_design_system::patterns::{CompositeRole, FocusManagement, TabindexStrategy};
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let tabs = FocusManagement::new(VibeColors::default()).composite(CompositeRole::Tablist);
+/// assert!(tabs.container_attributes().contains(&("role", "tablist".to_string())));
+/// assert!(tabs.item_attributes(true).contains(&("tabindex", "0".to_string())));
+/// assert!(tabs.item_attributes(false).contains(&("tabindex", "-1".to_string())));
+/// assert!(tabs.item_classes().contains("focus-visible:ring-jupiter-blue-300"));
+///
+/// let options = FocusManagement::new(VibeColors::default())
+///     .composite(CompositeRole::Listbox)
+///     .with_tabindex_strategy(TabindexStrategy::ActiveDescendant);
+/// assert!(options.container_attributes().contains(&("tabindex", "0".to_string())));
+/// assert!(options.item_attributes(true).contains(&("data-active", "true".to_string())));
+/// ```
 #[derive(Debug, Clone)]
 pub struct FocusManagement<C: ColorProvider> {
     focus_behavior: FocusBehavior,
@@ -61,6 +153,9 @@ pub struct FocusManagement<C: ColorProvider> {
     screen_reader_pattern: Option<ScreenReaderPattern>,
     is_focusable: bool,
     tab_index: Option<i32>,
+    composite: Option<CompositeRole>,
+    tabindex_strategy: TabindexStrategy,
+    orientation: Option<&'static str>,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -74,6 +169,9 @@ impl<C: ColorProvider> FocusManagement<C> {
             screen_reader_pattern: None,
             is_focusable: true,
             tab_index: None,
+            composite: None,
+            tabindex_strategy: TabindexStrategy::Roving,
+            orientation: None,
             custom_classes: Vec::new(),
             color_provider,
         }
@@ -125,8 +223,38 @@ impl<C: ColorProvider> FocusManagement<C> {
         self
     }
 
+    /// Composite widget accessibility
+    ///
+    /// Tab lists and menus get their keyboard pattern; listboxes and grids
+    /// rely on the arrow-key handling the container attributes describe.
+    pub fn composite(mut self, role: CompositeRole) -> Self {
+        self.composite = Some(role);
+        self.keyboard_pattern = match role {
+            CompositeRole::Tablist => Some(KeyboardPattern::Tab),
+            CompositeRole::Menu => Some(KeyboardPattern::MenuItem),
+            CompositeRole::Listbox | CompositeRole::Grid => None,
+        };
+        self.screen_reader_pattern = None;
+        self.focus_behavior = FocusBehavior::Standard;
+        self
+    }
+
+    /// Set how focus moves between composite items (roving by default)
+    pub fn with_tabindex_strategy(mut self, strategy: TabindexStrategy) -> Self {
+        self.tabindex_strategy = strategy;
+        self
+    }
+
+    /// Override the composite orientation, e.g. for vertical tab lists
+    ///
+    /// Grids ignore this; they move in both directions.
+    pub fn with_orientation(mut self, vertical: bool) -> Self {
+        self.orientation = Some(if vertical { "vertical" } else { "horizontal" });
+        self
+    }
+
     /// Build focus and accessibility classes
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut classes = Vec::new();
 
         // Base focus classes
@@ -165,11 +293,120 @@ impl<C: ColorProvider> FocusManagement<C> {
         }
 
         // Add custom classes
-        classes.extend(self.custom_classes);
+        classes.extend(self.custom_classes.iter().cloned());
 
         classes.join(" ")
     }
 
+    /// Get the keyboard interaction pattern, if one is set
+    pub fn keyboard_pattern(&self) -> Option<KeyboardPattern> {
+        self.keyboard_pattern
+    }
+
+    /// Get the composite role, if one is set
+    pub fn composite_role(&self) -> Option<CompositeRole> {
+        self.composite
+    }
+
+    /// Get the tabindex strategy used for composite items
+    pub fn tabindex_strategy(&self) -> TabindexStrategy {
+        self.tabindex_strategy
+    }
+
+    /// Get attributes for a composite container
+    ///
+    /// Empty unless `composite` is set. With the active-descendant
+    /// strategy the container is the Tab stop, and the adapter keeps
+    /// `aria-activedescendant` pointing at the active item's id.
+    pub fn container_attributes(&self) -> Vec<(&'static str, String)> {
+        let Some(role) = self.composite else {
+            return Vec::new();
+        };
+
+        let mut attrs = vec![("role", role.container_role().to_string())];
+        let orientation = match role {
+            CompositeRole::Grid => None,
+            _ => self.orientation.or(role.orientation()),
+        };
+        if let Some(orientation) = orientation {
+            attrs.push(("aria-orientation", orientation.to_string()));
+        }
+        if self.tabindex_strategy == TabindexStrategy::ActiveDescendant {
+            attrs.push(("tabindex", "0".to_string()));
+        }
+        attrs.push((
+            "data-focus-strategy",
+            self.tabindex_strategy.as_str().to_string(),
+        ));
+        attrs
+    }
+
+    /// Get classes for a composite container
+    ///
+    /// With the active-descendant strategy the container names a Tailwind
+    /// group so items can show the focus ring while it has focus.
+    pub fn container_classes(&self) -> String {
+        match (self.composite, self.tabindex_strategy) {
+            (Some(_), TabindexStrategy::ActiveDescendant) => {
+                "group/composite focus:outline-none".to_string()
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Get attributes for a composite item
+    ///
+    /// `active` marks the item that currently owns focus: the single Tab
+    /// stop under the roving strategy, or the `aria-activedescendant`
+    /// target under the active-descendant strategy.
+    pub fn item_attributes(&self, active: bool) -> Vec<(&'static str, String)> {
+        let Some(role) = self.composite else {
+            return Vec::new();
+        };
+
+        let mut attrs = vec![("role", role.item_role().to_string())];
+        match self.tabindex_strategy {
+            TabindexStrategy::Roving => {
+                let tabindex = if active { "0" } else { "-1" };
+                attrs.push(("tabindex", tabindex.to_string()));
+            }
+            TabindexStrategy::ActiveDescendant => {
+                attrs.push(("data-active", active.to_string()));
+            }
+        }
+        attrs
+    }
+
+    /// Get focus-visible classes for a composite item
+    ///
+    /// Grid cells sit edge to edge, so their ring is inset rather than
+    /// offset.
+    pub fn item_classes(&self) -> String {
+        let Some(role) = self.composite else {
+            return String::new();
+        };
+
+        let variant = match self.tabindex_strategy {
+            TabindexStrategy::Roving => "focus-visible:",
+            TabindexStrategy::ActiveDescendant => {
+                "group-focus-visible/composite:data-[active=true]:"
+            }
+        };
+        let placement = if role == CompositeRole::Grid {
+            "ring-inset"
+        } else {
+            "ring-offset-2"
+        };
+        format!(
+            "focus-visible:outline-none {v}ring-2 {v}{} {v}ring-{}",
+            placement,
+            self.color_provider
+                .resolve_color(Color::Primary)
+                .replace("-500", "-300"),
+            v = variant
+        )
+    }
+
     /// Get data attributes for proper semantic markup
     pub fn data_attributes(&self) -> Vec<(&'static str, String)> {
         let mut attrs = Vec::new();
@@ -209,6 +446,142 @@ impl<C: ColorProvider> FocusManagement<C> {
     }
 }
 
+/// Element that receives focus when a focus scope opens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InitialFocus {
+    /// First tabbable element inside the scope
+    FirstTabbable,
+    /// The scope container itself (long or read-first content)
+    Container,
+    /// The primary action of the scope
+    PrimaryAction,
+    /// The search input (command palettes, pickers)
+    SearchInput,
+    /// The close/dismiss button (destructive confirmations)
+    DismissButton,
+}
+
+impl InitialFocus {
+    /// Value of the `data-initial-focus` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InitialFocus::FirstTabbable => "first-tabbable",
+            InitialFocus::Container => "container",
+            InitialFocus::PrimaryAction => "primary-action",
+            InitialFocus::SearchInput => "search-input",
+            InitialFocus::DismissButton => "dismiss-button",
+        }
+    }
+}
+
+/// Focus trap metadata for overlays (modals, drawers, command palettes)
+///
+/// Describes where focus goes when the overlay opens, whether Tab is kept
+/// inside it, and where focus returns when it closes. Adapters read the
+/// container attributes and place sentinel elements at both ends of the
+/// scope; a sentinel receiving focus moves it to the opposite end.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::patterns::{FocusScope, InitialFocus};
+///
+/// let scope = FocusScope::command_palette();
+/// assert_eq!(scope.initial_focus, InitialFocus::SearchInput);
+///
+/// let attrs = scope.container_attributes();
+/// assert!(attrs.contains(&("aria-modal", "true".to_string())));
+/// assert!(attrs.contains(&("data-initial-focus", "search-input".to_string())));
+///
+/// let (start, end) = (scope.sentinel_attributes(true), scope.sentinel_attributes(false));
+/// assert!(start.contains(&("data-focus-sentinel", "start".to_string())));
+/// assert!(end.contains(&("data-focus-sentinel", "end".to_string())));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FocusScope {
+    /// Element focused when the scope opens
+    pub initial_focus: InitialFocus,
+    /// Keep Tab and Shift+Tab inside the scope
+    pub trap: bool,
+    /// Return focus to the triggering element when the scope closes
+    pub restore_focus: bool,
+    /// Close the scope on Escape
+    pub close_on_escape: bool,
+}
+
+impl FocusScope {
+    /// Modal dialog: trapped, focuses the first tabbable element
+    pub fn modal() -> Self {
+        Self {
+            initial_focus: InitialFocus::FirstTabbable,
+            trap: true,
+            restore_focus: true,
+            close_on_escape: true,
+        }
+    }
+
+    /// Drawer or sheet: trapped, focuses the container so it reads from the top
+    pub fn drawer() -> Self {
+        Self {
+            initial_focus: InitialFocus::Container,
+            ..Self::modal()
+        }
+    }
+
+    /// Command palette: trapped, focuses the search input
+    pub fn command_palette() -> Self {
+        Self {
+            initial_focus: InitialFocus::SearchInput,
+            ..Self::modal()
+        }
+    }
+
+    /// Set the initial focus target
+    pub fn initial_focus(mut self, initial_focus: InitialFocus) -> Self {
+        self.initial_focus = initial_focus;
+        self
+    }
+
+    /// Get attributes for the scope container
+    pub fn container_attributes(&self) -> Vec<(&'static str, String)> {
+        let mut attrs = vec![
+            ("role", "dialog".to_string()),
+            ("tabindex", "-1".to_string()),
+            (
+                "data-initial-focus",
+                self.initial_focus.as_str().to_string(),
+            ),
+        ];
+        if self.trap {
+            attrs.push(("aria-modal", "true".to_string()));
+            attrs.push(("data-focus-trap", "true".to_string()));
+        }
+        if self.restore_focus {
+            attrs.push(("data-restore-focus", "true".to_string()));
+        }
+        if self.close_on_escape {
+            attrs.push(("data-close-on-escape", "true".to_string()));
+        }
+        attrs
+    }
+
+    /// Get classes for the focus sentinels at either end of the scope
+    pub fn sentinel_classes(&self) -> String {
+        "focus-sentinel sr-only".to_string()
+    }
+
+    /// Get attributes for the start (`true`) or end (`false`) sentinel
+    pub fn sentinel_attributes(&self, start: bool) -> Vec<(&'static str, String)> {
+        let edge = if start { "start" } else { "end" };
+        let tabindex = if self.trap { "0" } else { "-1" };
+        vec![
+            ("tabindex", tabindex.to_string()),
+            ("aria-hidden", "true".to_string()),
+            ("data-focus-sentinel", edge.to_string()),
+        ]
+    }
+}
+
 /// Convenience function to create focus management
 pub fn focus_management<C: ColorProvider>(color_provider: C) -> FocusManagement<C> {
     FocusManagement::new(color_provider)
@@ -4,7 +4,14 @@
 //! to any clickable element (buttons, links, cards, menu items, etc.)
 
 use crate::core::color::ColorProvider;
-use crate::core::Color;
+use crate::core::{
+    Color, Elevation, ElevationProvider, MotionProvider, ScaleEffect, StateLayer,
+    StateLayerProvider,
+};
+use crate::utils::{class_diff, ClassDiff};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// Interactive element states
@@ -25,7 +32,7 @@ pub enum InteractiveState {
 }
 
 /// Intensity of interactive effects
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InteractionIntensity {
     /// Subtle effects - for cards, menu items
     Gentle,
@@ -33,6 +40,8 @@ pub enum InteractionIntensity {
     Standard,
     /// Strong effects - for primary actions
     Prominent,
+    /// Translucent overlay on hover/focus/press instead of scale transforms
+    StateLayer,
 }
 
 /// Interactive element builder for creating consistent interactive behaviors
@@ -97,6 +106,35 @@ impl<C: ColorProvider> InteractiveElement<C> {
         self
     }
 
+    /// Set interaction intensity
+    pub fn intensity(mut self, intensity: InteractionIntensity) -> Self {
+        self.interaction_intensity = intensity;
+        self
+    }
+
+    /// Use state-layer overlays instead of scale and shadow effects
+    ///
+    /// Overlay opacities come from the color provider's state layer scale.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::InteractiveElement;
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let classes = InteractiveElement::new(VibeColors::default())
+    ///     .hoverable()
+    ///     .pressable()
+    ///     .state_layer_interaction()
+    ///     .classes();
+    ///
+    /// assert!(classes.contains("hover:before:opacity-[0.08]"));
+    /// assert!(classes.contains("active:before:opacity-[0.12]"));
+    /// assert!(!classes.contains("hover:scale"));
+    /// ```
+    pub fn state_layer_interaction(mut self) -> Self {
+        self.interaction_intensity = InteractionIntensity::StateLayer;
+        self
+    }
+
     /// Set the current state
     pub fn state(mut self, state: InteractiveState) -> Self {
         self.state = state;
@@ -140,7 +178,7 @@ impl<C: ColorProvider> InteractiveElement<C> {
     }
 
     /// Build the interactive classes
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut classes = Vec::new();
 
         // Base interactive classes
@@ -158,24 +196,64 @@ impl<C: ColorProvider> InteractiveElement<C> {
             _ => {}
         }
 
-        // Hover effects
+        let motion = self.color_provider.motion_scale();
+
+        // State layer overlay
+        let state_layers = self.color_provider.state_layer_scale();
+        let uses_state_layer = self.interaction_intensity == InteractionIntensity::StateLayer
+            && self.state != InteractiveState::Disabled;
+        if uses_state_layer && (self.is_hoverable || self.is_focusable || self.is_pressable) {
+            classes.push(state_layers.state_layer_base_classes());
+        }
+
+        // Hover effects, lifting the element one elevation step
         if self.is_hoverable && self.state != InteractiveState::Disabled {
+            let elevation = self.color_provider.elevation_scale();
             let hover_classes = match self.interaction_intensity {
-                InteractionIntensity::Gentle => "hover:scale-101 hover:shadow-sm",
-                InteractionIntensity::Standard => "hover:scale-105 hover:shadow-md",
-                InteractionIntensity::Prominent => "hover:scale-110 hover:shadow-lg",
+                InteractionIntensity::Gentle => format!(
+                    "{} {}",
+                    motion.scale_effect_class(ScaleEffect::HoverSubtle),
+                    elevation.hover_shadow_class(Elevation::Flat)
+                )
+                .trim_start()
+                .to_string(),
+                InteractionIntensity::Standard => format!(
+                    "{} {}",
+                    motion.scale_effect_class(ScaleEffect::Hover),
+                    elevation.hover_shadow_class(Elevation::Subtle)
+                ),
+                InteractionIntensity::Prominent => format!(
+                    "{} {}",
+                    motion.scale_effect_class(ScaleEffect::HoverStrong),
+                    elevation.hover_shadow_class(Elevation::Raised)
+                ),
+                InteractionIntensity::StateLayer => {
+                    state_layers.state_layer_class(StateLayer::Hover)
+                }
             };
-            classes.push(hover_classes.to_string());
+            classes.push(hover_classes);
         }
 
         // Press/Active effects
         if self.is_pressable && self.state != InteractiveState::Disabled {
             let active_classes = match self.interaction_intensity {
-                InteractionIntensity::Gentle => "active:scale-100",
-                InteractionIntensity::Standard => "active:scale-95",
-                InteractionIntensity::Prominent => "active:scale-95",
+                InteractionIntensity::Gentle => "active:scale-100".to_string(),
+                InteractionIntensity::Standard => motion.scale_effect_class(ScaleEffect::Press),
+                InteractionIntensity::Prominent => {
+                    motion.scale_effect_class(ScaleEffect::PressStrong)
+                }
+                InteractionIntensity::StateLayer => format!(
+                    "{} {}",
+                    state_layers.state_layer_class(StateLayer::Pressed),
+                    state_layers.state_layer_class(StateLayer::Dragged)
+                ),
             };
-            classes.push(active_classes.to_string());
+            classes.push(active_classes);
+        }
+
+        // Focus overlay for state layers
+        if uses_state_layer && self.is_focusable {
+            classes.push(state_layers.state_layer_class(StateLayer::Focus));
         }
 
         // Focus effects (accessibility)
@@ -218,12 +296,167 @@ impl<C: ColorProvider> InteractiveElement<C> {
         }
 
         // Add custom classes
-        classes.extend(self.custom_classes);
+        classes.extend(self.custom_classes.iter().cloned());
 
         classes.join(" ")
     }
 }
 
+/// Input events driving an interactive element's state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InteractionEvent {
+    /// Pointer moved over the element
+    PointerEnter,
+    /// Pointer moved off the element
+    PointerLeave,
+    /// Pointer (or Enter/Space key) pressed on the element
+    PointerDown,
+    /// Pointer (or Enter/Space key) released
+    PointerUp,
+    /// Element received focus
+    FocusIn,
+    /// Element lost focus
+    FocusOut,
+    /// Element became disabled
+    Disable,
+    /// Element became enabled again
+    Enable,
+    /// Element started processing an action
+    LoadStart,
+    /// Element finished processing an action
+    LoadEnd,
+}
+
+/// A state change produced by an [`InteractionEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StateTransition {
+    pub from: InteractiveState,
+    pub to: InteractiveState,
+}
+
+impl StateTransition {
+    /// Whether the event changed the visible state
+    pub fn is_change(&self) -> bool {
+        self.from != self.to
+    }
+
+    /// Classes to add and remove on `element` for this transition
+    pub fn class_delta<C: ColorProvider + Clone>(
+        &self,
+        element: &InteractiveElement<C>,
+    ) -> ClassDiff {
+        class_diff(
+            &element.clone().state(self.from).classes(),
+            &element.clone().state(self.to).classes(),
+        )
+    }
+}
+
+/// Resolves pointer, focus and lifecycle events into an [`InteractiveState`]
+///
+/// The machine tracks hover, focus and press independently and resolves
+/// them by priority: disabled, loading, active, focused, hover, default.
+/// Hover and focus changes received while disabled are still tracked, so
+/// the element comes back in the right state when it is enabled again;
+/// presses are ignored, since a disabled or loading element cannot be
+/// pressed.
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::patterns::{
+///     interactive_element, InteractionEvent, InteractionStateMachine, InteractiveState,
+/// };
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let mut machine = InteractionStateMachine::new();
+///
+/// assert_eq!(machine.on_event(InteractionEvent::PointerEnter), InteractiveState::Hover);
+/// assert_eq!(machine.on_event(InteractionEvent::PointerDown), InteractiveState::Active);
+/// assert_eq!(machine.on_event(InteractionEvent::PointerUp), InteractiveState::Hover);
+/// assert_eq!(machine.on_event(InteractionEvent::Disable), InteractiveState::Disabled);
+/// assert_eq!(machine.on_event(InteractionEvent::PointerLeave), InteractiveState::Disabled);
+/// assert_eq!(machine.on_event(InteractionEvent::PointerDown), InteractiveState::Disabled);
+/// assert_eq!(machine.on_event(InteractionEvent::Enable), InteractiveState::Default);
+///
+/// // Presses during loading don't show up once it ends
+/// machine.on_event(InteractionEvent::LoadStart);
+/// assert_eq!(machine.on_event(InteractionEvent::PointerDown), InteractiveState::Loading);
+/// assert_eq!(machine.on_event(InteractionEvent::LoadEnd), InteractiveState::Default);
+///
+/// // Class changes to apply for a transition
+/// let element = interactive_element(VibeColors::default()).focusable();
+/// let transition = machine.transition(InteractionEvent::FocusIn);
+/// assert_eq!(transition.to, InteractiveState::Focused);
+/// assert!(transition.class_delta(&element).added.contains(&"ring-2".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InteractionStateMachine {
+    hovered: bool,
+    focused: bool,
+    pressed: bool,
+    disabled: bool,
+    loading: bool,
+}
+
+impl InteractionStateMachine {
+    /// Create a machine in the default state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the current state
+    pub fn state(&self) -> InteractiveState {
+        if self.disabled {
+            InteractiveState::Disabled
+        } else if self.loading {
+            InteractiveState::Loading
+        } else if self.pressed {
+            InteractiveState::Active
+        } else if self.focused {
+            InteractiveState::Focused
+        } else if self.hovered {
+            InteractiveState::Hover
+        } else {
+            InteractiveState::Default
+        }
+    }
+
+    /// Apply an event and get the resulting state
+    pub fn on_event(&mut self, event: InteractionEvent) -> InteractiveState {
+        match event {
+            InteractionEvent::PointerEnter => self.hovered = true,
+            InteractionEvent::PointerLeave => {
+                // Leaving while pressed cancels the press
+                self.hovered = false;
+                self.pressed = false;
+            }
+            InteractionEvent::PointerDown => self.pressed = !self.disabled && !self.loading,
+            InteractionEvent::PointerUp => self.pressed = false,
+            InteractionEvent::FocusIn => self.focused = true,
+            InteractionEvent::FocusOut => self.focused = false,
+            InteractionEvent::Disable => {
+                self.disabled = true;
+                self.pressed = false;
+            }
+            InteractionEvent::Enable => self.disabled = false,
+            InteractionEvent::LoadStart => {
+                self.loading = true;
+                self.pressed = false;
+            }
+            InteractionEvent::LoadEnd => self.loading = false,
+        }
+        self.state()
+    }
+
+    /// Apply an event and get the state transition it caused
+    pub fn transition(&mut self, event: InteractionEvent) -> StateTransition {
+        let from = self.state();
+        let to = self.on_event(event);
+        StateTransition { from, to }
+    }
+}
+
 /// Convenience function to create interactive element
 pub fn interactive_element<C: ColorProvider>(color_provider: C) -> InteractiveElement<C> {
     InteractiveElement::new(color_provider)
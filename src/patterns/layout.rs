@@ -4,6 +4,10 @@
 //! like card sub-components, dividers, and container elements.
 
 use crate::core::color::ColorProvider;
+use crate::core::{BorderProvider, BorderWidth};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// Layout spacing types for consistent spacing
@@ -25,6 +29,21 @@ pub enum LayoutSpacing {
     XL2,
 }
 
+impl LayoutSpacing {
+    /// Tailwind spacing scale step for the token, e.g. `"4"` for `MD`
+    pub fn step(&self) -> &'static str {
+        match self {
+            LayoutSpacing::None => "0",
+            LayoutSpacing::XS => "1",
+            LayoutSpacing::SM => "2",
+            LayoutSpacing::MD => "4",
+            LayoutSpacing::LG => "6",
+            LayoutSpacing::XL => "8",
+            LayoutSpacing::XL2 => "12",
+        }
+    }
+}
+
 /// Layout divider types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum LayoutDivider {
@@ -121,32 +140,26 @@ impl<C: ColorProvider> CardSectionLayout<C> {
     }
 
     /// Build the CSS classes
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Divider classes
-        let divider_classes = match self.divider {
-            LayoutDivider::None => "",
-            LayoutDivider::Top => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-t {}", border_class)
-            }
-            LayoutDivider::Bottom => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-b {}", border_class)
-            }
-            LayoutDivider::Left => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-l {}", border_class)
-            }
-            LayoutDivider::Right => {
-                let border_class = self.color_provider.border_class(crate::core::Color::Border);
-                &format!("border-r {}", border_class)
-            }
+        let divider_side = match self.divider {
+            LayoutDivider::None => None,
+            LayoutDivider::Top => Some("t"),
+            LayoutDivider::Bottom => Some("b"),
+            LayoutDivider::Left => Some("l"),
+            LayoutDivider::Right => Some("r"),
         };
 
-        if !divider_classes.is_empty() {
-            all_classes.push(divider_classes.to_string());
+        if let Some(side) = divider_side {
+            all_classes.push(format!(
+                "{} {}",
+                self.color_provider
+                    .border_scale()
+                    .border_side_class(side, BorderWidth::Default),
+                self.color_provider.border_class(crate::core::Color::Border)
+            ));
         }
 
         // Spacing classes
@@ -187,7 +200,7 @@ impl<C: ColorProvider> CardSectionLayout<C> {
         }
 
         // Custom classes
-        all_classes.extend(self.custom_classes);
+        all_classes.extend(self.custom_classes.iter().cloned());
 
         // Join and clean up
         let mut classes: Vec<String> = all_classes
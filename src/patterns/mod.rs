@@ -4,24 +4,42 @@
 //! that can be applied across different component types.
 
 pub mod actions;
+pub mod announcer;
+#[cfg(feature = "buttons")]
 pub mod button;
+#[cfg(feature = "cards")]
 pub mod card;
+pub mod field;
 pub mod focus;
 pub mod interactions;
+#[cfg(feature = "layout")]
 pub mod layout;
+#[cfg(feature = "product")]
 pub mod product;
+#[cfg(feature = "selection")]
 pub mod selection;
+#[cfg(feature = "states")]
 pub mod states;
+#[cfg(feature = "typography")]
 pub mod typography;
 
 // Re-export commonly used patterns
 pub use actions::*;
+pub use announcer::*;
+#[cfg(feature = "buttons")]
 pub use button::*;
+#[cfg(feature = "cards")]
 pub use card::*;
+pub use field::*;
 pub use focus::*;
 pub use interactions::*;
+#[cfg(feature = "layout")]
 pub use layout::*;
+#[cfg(feature = "product")]
 pub use product::*;
+#[cfg(feature = "selection")]
 pub use selection::*;
+#[cfg(feature = "states")]
 pub use states::*;
+#[cfg(feature = "typography")]
 pub use typography::*;
@@ -1,11 +1,37 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
 /// Product Patterns - E-commerce semantic design abstractions
 ///
 /// This module provides abstract patterns for product display, interaction,
 /// and commerce behaviors, enabling consistent and semantic product components.
 use crate::core::color::{Color, ColorProvider};
+use crate::core::{MotionProvider, Radius, RadiusProvider, ScaleEffect};
+use crate::patterns::focus::FocusManagement;
+use crate::patterns::typography::TypographyHierarchy;
+
+/// Title levels from smallest to largest; display picks a step and
+/// prominence moves along it
+const TITLE_LADDER: [TypographyHierarchy; 7] = [
+    TypographyHierarchy::BodySmall,
+    TypographyHierarchy::Body,
+    TypographyHierarchy::BodyLarge,
+    TypographyHierarchy::H4,
+    TypographyHierarchy::Subheading,
+    TypographyHierarchy::Heading,
+    TypographyHierarchy::Title,
+];
+
+/// Description levels from smallest to largest
+const DESCRIPTION_LADDER: [TypographyHierarchy; 3] = [
+    TypographyHierarchy::BodySmall,
+    TypographyHierarchy::Body,
+    TypographyHierarchy::BodyLarge,
+];
 
 /// Abstract product display patterns
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductDisplayPattern {
     /// Standard product listing item
     ListItem,
@@ -20,7 +46,7 @@ pub enum ProductDisplayPattern {
 }
 
 /// Product interaction states
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductInteractionState {
     /// Default interactive state
     Default,
@@ -35,7 +61,7 @@ pub enum ProductInteractionState {
 }
 
 /// Product availability states
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductAvailabilityState {
     /// Product is available for purchase
     Available,
@@ -49,8 +75,21 @@ pub enum ProductAvailabilityState {
     Limited,
 }
 
+impl ProductAvailabilityState {
+    /// Get suggested label text for an availability badge
+    pub fn suggested_label(&self) -> &'static str {
+        match self {
+            ProductAvailabilityState::Available => "In Stock",
+            ProductAvailabilityState::OutOfStock => "Out of Stock",
+            ProductAvailabilityState::Backorder => "Backorder",
+            ProductAvailabilityState::Discontinued => "Discontinued",
+            ProductAvailabilityState::Limited => "Only a Few Left",
+        }
+    }
+}
+
 /// Product prominence levels
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductProminence {
     /// Subtle product display
     Subtle,
@@ -63,7 +102,7 @@ pub enum ProductProminence {
 }
 
 /// Product image display patterns
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductImagePattern {
     /// Standard product image with aspect ratio
     Standard,
@@ -78,7 +117,7 @@ pub enum ProductImagePattern {
 }
 
 /// Product badge types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductBadgeType {
     /// Sale/discount badge
     Sale,
@@ -96,8 +135,23 @@ pub enum ProductBadgeType {
     Custom(String),
 }
 
+impl ProductBadgeType {
+    /// Get suggested label text; custom badges pass their text through
+    pub fn suggested_label(&self) -> &str {
+        match self {
+            ProductBadgeType::Sale => "Sale",
+            ProductBadgeType::New => "New",
+            ProductBadgeType::Featured => "Featured",
+            ProductBadgeType::BestSeller => "Best Seller",
+            ProductBadgeType::Limited => "Limited Time",
+            ProductBadgeType::OutOfStock => "Sold Out",
+            ProductBadgeType::Custom(text) => text,
+        }
+    }
+}
+
 /// Product action types
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductActionType {
     /// Add to cart action
     AddToCart,
@@ -113,8 +167,28 @@ pub enum ProductActionType {
     ViewDetails,
 }
 
+impl ProductActionType {
+    /// Get suggested label text, used as the `aria-label` of icon-only
+    /// action buttons
+    pub fn suggested_label(&self) -> &'static str {
+        match self {
+            ProductActionType::AddToCart => "Add to Cart",
+            ProductActionType::QuickView => "Quick View",
+            ProductActionType::Compare => "Compare",
+            ProductActionType::Wishlist => "Add to Wishlist",
+            ProductActionType::Share => "Share",
+            ProductActionType::ViewDetails => "View Details",
+        }
+    }
+
+    /// Whether the action renders as an icon without visible text
+    pub fn is_icon_only(&self) -> bool {
+        matches!(self, ProductActionType::Wishlist | ProductActionType::Share)
+    }
+}
+
 /// Product information sections
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductInfoSection {
     /// Basic product information (title, price)
     Basic,
@@ -127,7 +201,7 @@ pub enum ProductInfoSection {
 }
 
 /// Product price display patterns
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductPricePattern {
     /// Standard price display
     Standard,
@@ -141,8 +215,104 @@ pub enum ProductPricePattern {
     OnSale,
 }
 
+impl ProductPricePattern {
+    /// Get the price parts and accessible label templates for this pattern
+    pub fn semantic_info(&self) -> PriceSemanticInfo {
+        let (compare_at, discount_percent, range, aria_label_template) = match self {
+            ProductPricePattern::Standard => (false, false, false, "Price: {price}"),
+            ProductPricePattern::WithCompare => {
+                (true, false, false, "Price: {price}, was {compare_at}")
+            }
+            ProductPricePattern::Range => (false, false, true, "Price: from {min} to {max}"),
+            ProductPricePattern::WithDiscount => (
+                true,
+                true,
+                false,
+                "Price: {price}, was {compare_at}, {discount} off",
+            ),
+            ProductPricePattern::OnSale => {
+                (true, false, false, "Sale price: {price}, was {compare_at}")
+            }
+        };
+
+        PriceSemanticInfo {
+            compare_at,
+            discount_percent,
+            range,
+            on_sale: *self == ProductPricePattern::OnSale,
+            aria_label_template,
+        }
+    }
+}
+
+/// Which parts a price display has, plus an `aria-label` template
+///
+/// The classes treat the price as one visual group; screen readers need it
+/// read as one sentence ("Price: $20, was $25") rather than two bare
+/// numbers. Component libraries format the amounts for the user's locale
+/// and currency, then fill the template's `{price}`, `{compare_at}`,
+/// `{discount}`, `{min}` and `{max}` placeholders.
+///
+/// ```rust
+/// use jupiter_design_system::patterns::ProductPricePattern;
+///
+/// let info = ProductPricePattern::WithDiscount.semantic_info();
+/// assert!(info.compare_at && info.discount_percent);
+///
+/// let label = info.aria_label(&[
+///     ("price", "16,00 €"),
+///     ("compare_at", "20,00 €"),
+///     ("discount", "20 %"),
+/// ]);
+/// assert_eq!(label, "Price: 16,00 €, was 20,00 €, 20 % off");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PriceSemanticInfo {
+    /// A compare-at (original) price is shown; render it in `<s>` and
+    /// hide it from screen readers, since the label already reads it
+    pub compare_at: bool,
+    /// A discount percentage is shown
+    pub discount_percent: bool,
+    /// The price is a range with a minimum and maximum
+    pub range: bool,
+    /// The price is a sale price
+    pub on_sale: bool,
+    /// Template for the price group's `aria-label`
+    pub aria_label_template: &'static str,
+}
+
+impl PriceSemanticInfo {
+    /// Placeholders the template expects, in reading order
+    pub fn placeholders(&self) -> Vec<&'static str> {
+        let mut placeholders = Vec::new();
+        if self.range {
+            placeholders.extend(["min", "max"]);
+        } else {
+            placeholders.push("price");
+        }
+        if self.compare_at {
+            placeholders.push("compare_at");
+        }
+        if self.discount_percent {
+            placeholders.push("discount");
+        }
+        placeholders
+    }
+
+    /// Fill the `aria-label` template with locale-formatted values
+    ///
+    /// Placeholders without a value are left in place so missing data is
+    /// easy to spot.
+    pub fn aria_label(&self, values: &[(&str, &str)]) -> String {
+        values.iter().fold(
+            self.aria_label_template.to_string(),
+            |label, (name, value)| label.replace(&format!("{{{}}}", name), value),
+        )
+    }
+}
+
 /// Product variant display patterns
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ProductVariantPattern {
     /// Dropdown selector
     Dropdown,
@@ -157,7 +327,7 @@ pub enum ProductVariantPattern {
 }
 
 /// Product card patterns for different contexts
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProductCardPattern {
     /// Display pattern
     pub display: ProductDisplayPattern,
@@ -179,6 +349,21 @@ pub struct ProductCardPattern {
     pub badges: Vec<ProductBadgeType>,
     /// Variant display pattern
     pub variant_pattern: Option<ProductVariantPattern>,
+    /// Whether the product is in the user's wishlist
+    pub favorited: bool,
+    /// Whether limited-availability badges pulse to draw attention
+    pub pulse_limited: bool,
+}
+
+/// Class groups for a wishlist/favorite toggle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WishlistParts {
+    /// Toggle button wrapping the heart icon
+    pub button: String,
+    /// Heart icon (outline when unselected, filled when selected)
+    pub icon: String,
+    /// Count badge paired with the toggle
+    pub count: String,
 }
 
 impl ProductCardPattern {
@@ -195,6 +380,8 @@ impl ProductCardPattern {
             actions: vec![ProductActionType::AddToCart],
             badges: vec![],
             variant_pattern: None,
+            favorited: false,
+            pulse_limited: false,
         }
     }
 
@@ -258,6 +445,18 @@ impl ProductCardPattern {
         self
     }
 
+    /// Set wishlist/favorite state
+    pub fn favorited(mut self, favorited: bool) -> Self {
+        self.favorited = favorited;
+        self
+    }
+
+    /// Set whether limited-availability badges pulse
+    pub fn pulse_limited(mut self, pulse: bool) -> Self {
+        self.pulse_limited = pulse;
+        self
+    }
+
     /// Get CSS classes for the pattern
     pub fn classes<C: ColorProvider>(&self, colors: C) -> String {
         let mut classes = Vec::new();
@@ -282,6 +481,7 @@ impl ProductCardPattern {
             ProductInteractionState::Loading => classes.push("product-card--loading".to_string()),
             ProductInteractionState::Disabled => classes.push("product-card--disabled".to_string()),
         }
+        classes.push(self.interaction_state_classes(&colors));
 
         // Availability state classes
         match self.availability {
@@ -311,7 +511,10 @@ impl ProductCardPattern {
             classes.push(colors.text_class(Color::InteractiveDisabled));
         } else {
             match self.prominence {
-                ProductProminence::Hero => classes.push(colors.bg_class(Color::Primary)),
+                ProductProminence::Hero => {
+                    classes.push(colors.brand_gradient_classes());
+                    classes.push(colors.text_class(colors.on_color(Color::Primary)));
+                }
                 ProductProminence::Prominent => classes.push(colors.bg_class(Color::Secondary)),
                 _ => classes.push(colors.bg_class(Color::Surface)),
             }
@@ -320,6 +523,230 @@ impl ProductCardPattern {
         classes.join(" ")
     }
 
+    /// Get the visual classes for the interaction state
+    ///
+    /// Keyboard focus shows the standard focus ring, and the focused state
+    /// keeps that ring visible without `:focus`. Selected products get the
+    /// ring used by selected cards, loading products pulse and ignore
+    /// pointer input, and disabled products fade.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::{ProductCardPattern, ProductInteractionState};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let loading = ProductCardPattern::new().interaction_state(ProductInteractionState::Loading);
+    /// let classes = loading.interaction_state_classes(VibeColors::default());
+    /// assert!(classes.contains("motion-safe:animate-pulse"));
+    /// assert!(classes.contains("pointer-events-none"));
+    /// ```
+    pub fn interaction_state_classes<C: ColorProvider>(&self, colors: C) -> String {
+        let focus_ring = FocusManagement::new(&colors).classes();
+        let selected_ring = format!(
+            "ring-2 ring-offset-2 ring-{}",
+            colors.resolve_color(Color::Primary).replace("-500", "-300")
+        );
+
+        match self.interaction_state {
+            ProductInteractionState::Default => focus_ring,
+            ProductInteractionState::Focused => focus_ring
+                .split_whitespace()
+                .map(|class| class.strip_prefix("focus:").unwrap_or(class))
+                .collect::<Vec<_>>()
+                .join(" "),
+            ProductInteractionState::Selected => format!("{} {}", focus_ring, selected_ring),
+            ProductInteractionState::Loading => {
+                "motion-safe:animate-pulse pointer-events-none select-none cursor-wait".to_string()
+            }
+            ProductInteractionState::Disabled => {
+                "opacity-50 cursor-not-allowed pointer-events-none".to_string()
+            }
+        }
+    }
+
+    /// Get availability badge classes coordinated with the stock status
+    pub fn availability_badge_classes<C: ColorProvider>(&self, colors: C) -> String {
+        let mut classes = vec![format!(
+            "availability-badge inline-flex items-center px-2 py-0.5 {} text-xs font-medium",
+            colors.radius_scale().radius_class(Radius::Pill)
+        )];
+
+        let (background, text) = match self.availability {
+            ProductAvailabilityState::Available => {
+                (Color::Success, colors.on_color(Color::Success))
+            }
+            ProductAvailabilityState::Limited => (Color::Warning, colors.on_color(Color::Warning)),
+            ProductAvailabilityState::Backorder => (Color::Info, colors.on_color(Color::Info)),
+            ProductAvailabilityState::OutOfStock => (Color::Error, colors.on_color(Color::Error)),
+            ProductAvailabilityState::Discontinued => {
+                (Color::InteractiveDisabled, Color::TextSecondary)
+            }
+        };
+        classes.push(colors.bg_class(background));
+        classes.push(colors.text_class(text));
+
+        if self.pulse_limited && self.availability == ProductAvailabilityState::Limited {
+            classes.push("motion-safe:animate-pulse".to_string());
+        }
+
+        classes.join(" ")
+    }
+
+    /// Get classes for one merchandising badge
+    ///
+    /// Colors come from the palette: sale uses the error color, new the
+    /// success color, featured the accent, best seller the primary and
+    /// limited the warning color. Out-of-stock and custom badges are
+    /// neutral.
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::{ProductBadgeType, ProductCardPattern};
+    /// use jupiter_design_system::themes::VibeColors;
+    ///
+    /// let pattern = ProductCardPattern::new();
+    /// let sale = pattern.badge_classes(VibeColors::default(), &ProductBadgeType::Sale);
+    /// assert!(sale.contains("bg-red-500"));
+    /// ```
+    pub fn badge_classes<C: ColorProvider>(&self, colors: C, badge: &ProductBadgeType) -> String {
+        let mut classes = vec![format!(
+            "product-badge inline-flex items-center px-2 py-0.5 {} text-xs font-semibold",
+            colors.radius_scale().radius_class(Radius::Pill)
+        )];
+
+        let (background, text) = match badge {
+            ProductBadgeType::Sale => (Color::Error, colors.on_color(Color::Error)),
+            ProductBadgeType::New => (Color::Success, colors.on_color(Color::Success)),
+            ProductBadgeType::Featured => (Color::Accent, colors.on_color(Color::Accent)),
+            ProductBadgeType::BestSeller => (Color::Primary, colors.on_color(Color::Primary)),
+            ProductBadgeType::Limited => (Color::Warning, colors.on_color(Color::Warning)),
+            ProductBadgeType::OutOfStock => (Color::InteractiveDisabled, Color::TextSecondary),
+            ProductBadgeType::Custom(_) => (Color::Surface, Color::TextPrimary),
+        };
+        classes.push(colors.bg_class(background));
+        classes.push(colors.text_class(text));
+        if let ProductBadgeType::Custom(_) = badge {
+            classes.push(format!("border {}", colors.border_class(Color::Border)));
+        }
+
+        classes.join(" ")
+    }
+
+    /// Get suggested availability badge label
+    pub fn suggested_availability_label(&self) -> &'static str {
+        self.availability.suggested_label()
+    }
+
+    /// Get wishlist toggle classes, if the card offers a wishlist action
+    ///
+    /// Unselected hearts render as an outline, selected hearts are filled with
+    /// the accent color. The hover pop is disabled for users who prefer
+    /// reduced motion.
+    pub fn wishlist_parts<C: ColorProvider>(&self, colors: C) -> Option<WishlistParts> {
+        if !self.actions.contains(&ProductActionType::Wishlist) {
+            return None;
+        }
+
+        let disabled = matches!(
+            self.interaction_state,
+            ProductInteractionState::Disabled | ProductInteractionState::Loading
+        );
+
+        let mut button = vec![
+            "wishlist-toggle inline-flex items-center justify-center rounded-full p-2".to_string(),
+            "transition-transform duration-200 ease-out".to_string(),
+            "focus:outline-none focus:ring-2 focus:ring-offset-2".to_string(),
+            "motion-reduce:transition-none motion-reduce:transform-none".to_string(),
+            colors.bg_class(Color::Surface),
+        ];
+        if disabled {
+            button.push("cursor-not-allowed opacity-50".to_string());
+        } else {
+            let motion = colors.motion_scale();
+            button.push(format!(
+                "cursor-pointer {} {}",
+                motion.scale_effect_class(ScaleEffect::HoverStrong),
+                motion.scale_effect_class(ScaleEffect::Press)
+            ));
+        }
+
+        let icon = if self.favorited {
+            format!(
+                "wishlist-icon w-5 h-5 fill-current stroke-current {}",
+                colors.text_class(Color::Accent)
+            )
+        } else {
+            format!(
+                "wishlist-icon w-5 h-5 fill-none stroke-current {} hover:{}",
+                colors.text_class(Color::TextSecondary),
+                colors.text_class(Color::Accent)
+            )
+        };
+
+        let count_color = if self.favorited {
+            colors.text_class(Color::Accent)
+        } else {
+            colors.text_class(Color::TextSecondary)
+        };
+        let count = format!("wishlist-count ml-1 text-xs font-medium tabular-nums {count_color}");
+
+        Some(WishlistParts {
+            button: button.join(" "),
+            icon,
+            count,
+        })
+    }
+
+    /// Get the typography level for the product title
+    ///
+    /// The display pattern sets the base level and prominence steps it
+    /// down (subtle) or up (prominent, hero).
+    ///
+    /// ```rust
+    /// use jupiter_design_system::patterns::{
+    ///     ProductCardPattern, ProductDisplayPattern, ProductProminence, TypographyHierarchy,
+    /// };
+    ///
+    /// let tile = ProductCardPattern::new().display(ProductDisplayPattern::Tile);
+    /// assert_eq!(tile.title_hierarchy(), TypographyHierarchy::Body);
+    ///
+    /// let hero = tile.prominence(ProductProminence::Hero);
+    /// assert_eq!(hero.title_hierarchy(), TypographyHierarchy::H4);
+    /// ```
+    pub fn title_hierarchy(&self) -> TypographyHierarchy {
+        let base = match self.display {
+            ProductDisplayPattern::Preview | ProductDisplayPattern::Tile => 1,
+            ProductDisplayPattern::ListItem => 2,
+            ProductDisplayPattern::Featured => 3,
+            ProductDisplayPattern::Showcase => 4,
+        };
+        TITLE_LADDER[self.prominence_step(base, TITLE_LADDER.len())].clone()
+    }
+
+    /// Get the typography level for the product description
+    pub fn description_hierarchy(&self) -> TypographyHierarchy {
+        let base = match self.display {
+            ProductDisplayPattern::Preview | ProductDisplayPattern::Tile => 0,
+            ProductDisplayPattern::ListItem | ProductDisplayPattern::Featured => 1,
+            ProductDisplayPattern::Showcase => 2,
+        };
+        DESCRIPTION_LADDER[self.prominence_step(base, DESCRIPTION_LADDER.len())].clone()
+    }
+
+    /// Get the typography level for product meta text (brand, SKU, category)
+    pub fn meta_hierarchy(&self) -> TypographyHierarchy {
+        TypographyHierarchy::Caption
+    }
+
+    /// Move a ladder index by prominence, clamped to the ladder
+    fn prominence_step(&self, base: usize, len: usize) -> usize {
+        let step = match self.prominence {
+            ProductProminence::Subtle => base.saturating_sub(1),
+            ProductProminence::Standard => base,
+            ProductProminence::Prominent => base + 1,
+            ProductProminence::Hero => base + 2,
+        };
+        step.min(len - 1)
+    }
+
     /// Get suggested image aspect ratio
     pub fn suggested_image_aspect_ratio(&self) -> &'static str {
         match self.image_pattern {
@@ -331,10 +758,23 @@ impl ProductCardPattern {
         }
     }
 
+    /// Whether the card lays out as a horizontal row (thumbnail, content,
+    /// trailing price and actions) rather than a vertical stack
+    pub fn is_horizontal(&self) -> bool {
+        self.display == ProductDisplayPattern::ListItem
+    }
+
     /// Get suggested image sizes
+    ///
+    /// List items use a fixed-width thumbnail that grows with prominence.
     pub fn suggested_image_sizes(&self) -> &'static str {
         match self.display {
-            ProductDisplayPattern::ListItem => "h-48 w-48",
+            ProductDisplayPattern::ListItem => match self.prominence {
+                ProductProminence::Subtle => "h-16 w-16",
+                ProductProminence::Standard => "h-24 w-24",
+                ProductProminence::Prominent => "h-32 w-32",
+                ProductProminence::Hero => "h-40 w-40",
+            },
             ProductDisplayPattern::Featured => "h-64 w-64",
             ProductDisplayPattern::Tile => "h-40 w-40",
             ProductDisplayPattern::Showcase => "h-80 w-80",
@@ -353,6 +793,15 @@ impl ProductCardPattern {
         }
     }
 
+    /// Get suggested layout for the card's direct children
+    pub fn suggested_layout(&self) -> &'static str {
+        if self.is_horizontal() {
+            "flex flex-row items-start gap-4"
+        } else {
+            self.suggested_spacing()
+        }
+    }
+
     /// Get suggested spacing between elements
     pub fn suggested_spacing(&self) -> &'static str {
         match self.display {
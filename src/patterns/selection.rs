@@ -4,6 +4,14 @@
 //! interfaces including filters, toggles, single selection, and multi-selection.
 
 use crate::core::color::ColorProvider;
+use crate::core::{
+    BorderProvider, BorderWidth, Color, Elevation, ElevationProvider, MotionProvider, Radius,
+    RadiusProvider, ScaleEffect,
+};
+use crate::patterns::InteractionIntensity;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// Selection behavior defining how items can be selected
@@ -32,6 +40,42 @@ pub enum SelectionState {
     Disabled,
 }
 
+impl SelectionState {
+    /// Derive a parent state from its children (tri-state coordination)
+    ///
+    /// Disabled children are ignored. A parent with every remaining child
+    /// selected is selected, with none selected is unselected, and anything in
+    /// between is partially selected.
+    pub fn from_children(children: &[SelectionState]) -> SelectionState {
+        let mut selected = 0;
+        let mut unselected = 0;
+        for child in children {
+            match child {
+                SelectionState::Selected => selected += 1,
+                SelectionState::Unselected => unselected += 1,
+                SelectionState::PartiallySelected => return SelectionState::PartiallySelected,
+                SelectionState::Disabled => {}
+            }
+        }
+
+        match (selected, unselected) {
+            (0, 0) if !children.is_empty() => SelectionState::Disabled,
+            (0, _) => SelectionState::Unselected,
+            (_, 0) => SelectionState::Selected,
+            _ => SelectionState::PartiallySelected,
+        }
+    }
+
+    /// Get the matching `aria-checked` value
+    pub fn aria_checked(&self) -> &'static str {
+        match self {
+            SelectionState::Selected => "true",
+            SelectionState::PartiallySelected => "mixed",
+            SelectionState::Unselected | SelectionState::Disabled => "false",
+        }
+    }
+}
+
 /// Selection display style affecting visual presentation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SelectionDisplay {
@@ -45,6 +89,8 @@ pub enum SelectionDisplay {
     Card,
     /// Tab-like selection
     Tab,
+    /// Equal-width segment of a segmented control
+    Segment,
 }
 
 /// Selection layout for organizing multiple selection items
@@ -77,6 +123,207 @@ pub enum SelectionSize {
     XL,
 }
 
+/// Active-indicator style for tab display
+///
+/// Vertical tabs (vertical layout) move the indicator to the start edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TabStyle {
+    /// Border under the active tab (default)
+    Underline,
+    /// Underline that grows in from the start when a tab becomes active
+    AnimatedUnderline,
+    /// Filled pill behind the active tab
+    Pill,
+    /// Boxed tab that joins the panel below it
+    Enclosed,
+}
+
+impl TabStyle {
+    /// Classes for the tab list container
+    pub fn list_classes<C: ColorProvider>(&self, colors: &C, vertical: bool) -> String {
+        let side = if vertical { "l" } else { "b" };
+        match self {
+            TabStyle::Underline | TabStyle::AnimatedUnderline | TabStyle::Enclosed => format!(
+                "{} {}",
+                colors
+                    .border_scale()
+                    .border_side_class(side, BorderWidth::Default),
+                colors.border_class(Color::Border)
+            ),
+            TabStyle::Pill => format!(
+                "p-1 gap-1 {} {}",
+                colors.bg_class(Color::Background),
+                colors.radius_scale().radius_class(Radius::Control)
+            ),
+        }
+    }
+
+    /// Structural classes for every tab, independent of its state
+    pub fn item_classes<C: ColorProvider>(&self, colors: &C, vertical: bool) -> String {
+        let borders = colors.border_scale();
+        match (self, vertical) {
+            (TabStyle::Underline, false) => {
+                format!("-mb-px {}", borders.border_side_class("b", BorderWidth::Thick))
+            }
+            (TabStyle::Underline, true) => {
+                format!("-ml-px {}", borders.border_side_class("l", BorderWidth::Thick))
+            }
+            (TabStyle::AnimatedUnderline, false) => format!(
+                "relative after:absolute after:inset-x-0 after:-bottom-px after:h-0.5 {} after:origin-left after:transition-transform after:duration-200 motion-reduce:after:transition-none",
+                after_bg(colors)
+            ),
+            (TabStyle::AnimatedUnderline, true) => format!(
+                "relative after:absolute after:inset-y-0 after:-left-px after:w-0.5 {} after:origin-top after:transition-transform after:duration-200 motion-reduce:after:transition-none",
+                after_bg(colors)
+            ),
+            (TabStyle::Pill, _) => colors.radius_scale().radius_class(Radius::Pill),
+            (TabStyle::Enclosed, false) => format!(
+                "-mb-px {} rounded-b-none {}",
+                borders.border_width_class(BorderWidth::Default),
+                colors.radius_scale().radius_class(Radius::Control)
+            ),
+            (TabStyle::Enclosed, true) => format!(
+                "-mr-px {} rounded-r-none {}",
+                borders.border_width_class(BorderWidth::Default),
+                colors.radius_scale().radius_class(Radius::Control)
+            ),
+        }
+    }
+
+    /// Color classes for a tab in the given state
+    pub fn state_classes<C: ColorProvider>(
+        &self,
+        colors: &C,
+        state: SelectionState,
+        vertical: bool,
+    ) -> String {
+        let active = matches!(
+            state,
+            SelectionState::Selected | SelectionState::PartiallySelected
+        );
+        if state == SelectionState::Disabled {
+            return format!(
+                "{} border-transparent after:scale-0",
+                colors.text_class(Color::TextTertiary)
+            );
+        }
+
+        match (self, active) {
+            (TabStyle::Underline, true) => format!(
+                "{} {}",
+                colors.text_class(Color::Primary),
+                colors.border_class(Color::Primary)
+            ),
+            (TabStyle::Underline, false) => format!(
+                "border-transparent {} hover:{} hover:{}",
+                colors.text_class(Color::TextSecondary),
+                colors.text_class(Color::TextPrimary),
+                colors.border_class(Color::Border)
+            ),
+            (TabStyle::AnimatedUnderline, true) => format!(
+                "{} {}",
+                colors.text_class(Color::Primary),
+                if vertical {
+                    "after:scale-y-100"
+                } else {
+                    "after:scale-x-100"
+                }
+            ),
+            (TabStyle::AnimatedUnderline, false) => format!(
+                "{} hover:{} {}",
+                colors.text_class(Color::TextSecondary),
+                colors.text_class(Color::TextPrimary),
+                if vertical {
+                    "after:scale-y-0"
+                } else {
+                    "after:scale-x-0"
+                }
+            ),
+            (TabStyle::Pill, true) => format!(
+                "{} {}",
+                colors.bg_class(Color::Primary),
+                colors.text_class(Color::TextInverse)
+            ),
+            (TabStyle::Pill, false) => format!(
+                "{} hover:{} hover:{}",
+                colors.text_class(Color::TextSecondary),
+                colors.text_class(Color::TextPrimary),
+                colors.bg_class(Color::Surface)
+            ),
+            (TabStyle::Enclosed, true) => format!(
+                "{} {} {} {}",
+                colors.bg_class(Color::Surface),
+                colors.text_class(Color::TextPrimary),
+                colors.border_class(Color::Border),
+                if vertical {
+                    "border-r-transparent"
+                } else {
+                    "border-b-transparent"
+                }
+            ),
+            (TabStyle::Enclosed, false) => format!(
+                "border-transparent {} hover:{}",
+                colors.text_class(Color::TextSecondary),
+                colors.text_class(Color::TextPrimary)
+            ),
+        }
+    }
+}
+
+/// Classes for the inset track holding the segments of a segmented control
+pub(crate) fn segment_track_classes<C: ColorProvider>(colors: &C) -> String {
+    format!(
+        "inline-flex items-stretch p-1 gap-1 {} {}",
+        colors.bg_class(Color::Background),
+        colors.radius_scale().radius_class(Radius::Control)
+    )
+}
+
+/// Color classes for a segment in the given state
+///
+/// The selected segment is lifted onto the surface color with a subtle
+/// shadow; the others stay transparent on the track.
+pub(crate) fn segment_state_classes<C: ColorProvider>(colors: &C, state: SelectionState) -> String {
+    match state {
+        SelectionState::Selected => format!(
+            "{} {} {}",
+            colors.bg_class(Color::Surface),
+            colors.text_class(Color::TextPrimary),
+            colors.elevation_scale().shadow_class(Elevation::Subtle)
+        ),
+        SelectionState::Unselected | SelectionState::PartiallySelected => format!(
+            "bg-transparent {} hover:{}",
+            colors.text_class(Color::TextSecondary),
+            colors.text_class(Color::TextPrimary)
+        ),
+        SelectionState::Disabled => {
+            format!("bg-transparent {}", colors.text_class(Color::TextTertiary))
+        }
+    }
+}
+
+fn after_bg<C: ColorProvider>(colors: &C) -> String {
+    format!("after:{}", colors.bg_class(Color::Primary))
+}
+
+/// Leading content shown before a chip's label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChipLeading {
+    /// Small icon
+    Icon,
+    /// Round avatar image that sits close to the chip edge
+    Avatar,
+}
+
+/// Placement of the count badge on a selection item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CountPosition {
+    /// After the label, in the item's text flow (default)
+    Inline,
+    /// Superscript badge pinned to the item's top-right corner
+    Corner,
+}
+
 /// Selection interaction intensity
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SelectionInteraction {
@@ -88,6 +335,17 @@ pub enum SelectionInteraction {
     Prominent,
 }
 
+impl SelectionInteraction {
+    /// Matching intensity of `InteractiveElement` effects
+    pub fn intensity(&self) -> InteractionIntensity {
+        match self {
+            SelectionInteraction::Subtle => InteractionIntensity::Gentle,
+            SelectionInteraction::Standard => InteractionIntensity::Standard,
+            SelectionInteraction::Prominent => InteractionIntensity::Prominent,
+        }
+    }
+}
+
 /// Complete selection pattern for interactive selection interfaces
 #[derive(Debug, Clone)]
 pub struct SelectionPattern<C: ColorProvider> {
@@ -99,6 +357,7 @@ pub struct SelectionPattern<C: ColorProvider> {
     interaction: SelectionInteraction,
     show_counts: bool,
     show_clear_all: bool,
+    tab_style: TabStyle,
     custom_classes: Vec<String>,
     color_provider: C,
 }
@@ -115,6 +374,7 @@ impl<C: ColorProvider> SelectionPattern<C> {
             interaction: SelectionInteraction::Standard,
             show_counts: false,
             show_clear_all: false,
+            tab_style: TabStyle::Underline,
             custom_classes: Vec::new(),
             color_provider,
         }
@@ -204,6 +464,18 @@ impl<C: ColorProvider> SelectionPattern<C> {
         self
     }
 
+    /// Set segmented control display style
+    pub fn segment_display(mut self) -> Self {
+        self.display = SelectionDisplay::Segment;
+        self
+    }
+
+    /// Set the active-indicator style used by tab display
+    pub fn tab_style(mut self, tab_style: TabStyle) -> Self {
+        self.tab_style = tab_style;
+        self
+    }
+
     // === Layout Methods ===
 
     /// Set horizontal layout
@@ -311,34 +583,47 @@ impl<C: ColorProvider> SelectionPattern<C> {
     // === Build Methods ===
 
     /// Build container classes for the selection group
-    pub fn container_classes(self) -> String {
+    pub fn container_classes(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Base selection classes
         all_classes.push("selection-pattern".to_string());
 
-        // Layout classes
-        let layout_classes = match self.layout {
-            SelectionLayout::Horizontal => "flex flex-row gap-2 items-center",
-            SelectionLayout::Vertical => "flex flex-col gap-2",
-            SelectionLayout::Grid => "grid grid-cols-auto gap-2",
-            SelectionLayout::Dropdown => "relative",
-            SelectionLayout::Inline => "flex flex-wrap gap-2 items-center",
-        };
-        all_classes.push(layout_classes.to_string());
-
-        // Size-based spacing
-        let spacing_classes = match self.size {
-            SelectionSize::XS => "gap-1",
-            SelectionSize::SM => "gap-1.5",
-            SelectionSize::MD => "gap-2",
-            SelectionSize::LG => "gap-3",
-            SelectionSize::XL => "gap-4",
-        };
-        all_classes.push(spacing_classes.to_string());
+        if self.display == SelectionDisplay::Segment {
+            // The track sets its own layout and spacing
+            all_classes.push(segment_track_classes(&self.color_provider));
+        } else {
+            // Layout classes
+            let layout_classes = match self.layout {
+                SelectionLayout::Horizontal => "flex flex-row gap-2 items-center",
+                SelectionLayout::Vertical => "flex flex-col gap-2",
+                SelectionLayout::Grid => {
+                    "grid grid-cols-[repeat(auto-fill,minmax(10rem,1fr))] gap-2"
+                }
+                SelectionLayout::Dropdown => "relative",
+                SelectionLayout::Inline => "flex flex-wrap gap-2 items-center",
+            };
+            all_classes.push(layout_classes.to_string());
+            if self.display == SelectionDisplay::Tab {
+                all_classes.push(
+                    self.tab_style
+                        .list_classes(&self.color_provider, self.is_vertical()),
+                );
+            }
+
+            // Size-based spacing
+            let spacing_classes = match self.size {
+                SelectionSize::XS => "gap-1",
+                SelectionSize::SM => "gap-1.5",
+                SelectionSize::MD => "gap-2",
+                SelectionSize::LG => "gap-3",
+                SelectionSize::XL => "gap-4",
+            };
+            all_classes.push(spacing_classes.to_string());
+        }
 
         // Custom classes
-        all_classes.extend(self.custom_classes);
+        all_classes.extend(self.custom_classes.iter().cloned());
 
         // Join and clean up
         let mut classes: Vec<String> = all_classes
@@ -360,14 +645,46 @@ impl<C: ColorProvider> SelectionPattern<C> {
 
         // Display style classes
         let display_classes = match self.display {
-            SelectionDisplay::Button => "inline-flex items-center justify-center font-medium rounded-md transition-all duration-200",
-            SelectionDisplay::Chip => "inline-flex items-center rounded-full transition-all duration-200",
-            SelectionDisplay::ListItem => "flex items-center w-full px-3 py-2 transition-all duration-200",
-            SelectionDisplay::Card => "flex flex-col items-center p-4 rounded-lg border transition-all duration-200",
-            SelectionDisplay::Tab => "flex items-center px-4 py-2 border-b-2 transition-all duration-200",
+            SelectionDisplay::Button => {
+                "inline-flex items-center justify-center font-medium transition-all duration-200"
+            }
+            SelectionDisplay::Chip => "inline-flex items-center transition-all duration-200",
+            SelectionDisplay::ListItem => {
+                "flex items-center w-full px-3 py-2 transition-all duration-200"
+            }
+            SelectionDisplay::Card => "flex flex-col items-center p-4 transition-all duration-200",
+            SelectionDisplay::Tab => "flex items-center px-4 py-2 transition-all duration-200",
+            SelectionDisplay::Segment => {
+                "flex-1 basis-0 inline-flex items-center justify-center font-medium whitespace-nowrap transition-all duration-200"
+            }
         };
         all_classes.push(display_classes.to_string());
 
+        // Border width classes
+        let borders = self.color_provider.border_scale();
+        match self.display {
+            SelectionDisplay::Card => {
+                all_classes.push(borders.border_width_class(BorderWidth::Default))
+            }
+            SelectionDisplay::Tab => all_classes.push(
+                self.tab_style
+                    .item_classes(&self.color_provider, self.is_vertical()),
+            ),
+            _ => {}
+        }
+
+        // Radius classes
+        let radius = match self.display {
+            SelectionDisplay::Button => Some(Radius::Control),
+            SelectionDisplay::Chip => Some(Radius::Pill),
+            SelectionDisplay::Card => Some(Radius::Surface),
+            SelectionDisplay::Segment => Some(Radius::ControlSmall),
+            SelectionDisplay::ListItem | SelectionDisplay::Tab => None,
+        };
+        if let Some(radius) = radius {
+            all_classes.push(self.color_provider.radius_scale().radius_class(radius));
+        }
+
         // Size classes
         let size_classes = match (self.display, self.size) {
             (SelectionDisplay::Button, SelectionSize::XS) => "px-2 py-1 text-xs",
@@ -380,6 +697,11 @@ impl<C: ColorProvider> SelectionPattern<C> {
             (SelectionDisplay::Chip, SelectionSize::MD) => "px-3 py-1.5 text-base",
             (SelectionDisplay::Chip, SelectionSize::LG) => "px-4 py-2 text-lg",
             (SelectionDisplay::Chip, SelectionSize::XL) => "px-6 py-3 text-xl",
+            (SelectionDisplay::Segment, SelectionSize::XS) => "px-2 py-0.5 text-xs",
+            (SelectionDisplay::Segment, SelectionSize::SM) => "px-3 py-1 text-sm",
+            (SelectionDisplay::Segment, SelectionSize::MD) => "px-3 py-1.5 text-sm",
+            (SelectionDisplay::Segment, SelectionSize::LG) => "px-4 py-2 text-base",
+            (SelectionDisplay::Segment, SelectionSize::XL) => "px-6 py-2.5 text-lg",
             _ => "px-4 py-2 text-base", // fallback
         };
         all_classes.push(size_classes.to_string());
@@ -414,7 +736,12 @@ impl<C: ColorProvider> SelectionPattern<C> {
         }
 
         let mut classes = Vec::new();
-        classes.push("ml-2 px-2 py-0.5 text-xs rounded-full".to_string());
+        classes.push(format!(
+            "ml-2 px-2 py-0.5 text-xs {}",
+            self.color_provider
+                .radius_scale()
+                .radius_class(Radius::Pill)
+        ));
 
         // Color based on state
         match self.state {
@@ -459,7 +786,22 @@ impl<C: ColorProvider> SelectionPattern<C> {
         }
     }
 
+    fn is_vertical(&self) -> bool {
+        self.layout == SelectionLayout::Vertical
+    }
+
     fn get_state_classes(&self) -> String {
+        if self.display == SelectionDisplay::Tab {
+            return self.tab_style.state_classes(
+                &self.color_provider,
+                self.state,
+                self.is_vertical(),
+            );
+        }
+        if self.display == SelectionDisplay::Segment {
+            return segment_state_classes(&self.color_provider, self.state);
+        }
+
         match self.state {
             SelectionState::Unselected => format!(
                 "{} {} {}",
@@ -468,6 +810,16 @@ impl<C: ColorProvider> SelectionPattern<C> {
                     .text_class(crate::core::Color::TextPrimary),
                 self.color_provider.border_class(crate::core::Color::Border)
             ),
+            // Toggles look pressed in rather than merely highlighted
+            SelectionState::Selected if self.behavior == SelectionBehavior::Toggle => format!(
+                "{} {} {} shadow-inner",
+                self.color_provider
+                    .bg_class(crate::core::Color::InteractiveActive),
+                self.color_provider
+                    .text_class(crate::core::Color::TextInverse),
+                self.color_provider
+                    .border_class(crate::core::Color::InteractiveActive)
+            ),
             SelectionState::Selected => format!(
                 "{} {} {}",
                 self.color_provider.bg_class(crate::core::Color::Primary),
@@ -502,6 +854,10 @@ impl<C: ColorProvider> SelectionPattern<C> {
 
         let mut classes = Vec::new();
         classes.push("cursor-pointer".to_string());
+        if self.display == SelectionDisplay::Segment {
+            // Segments sit in a fixed track, so they never scale
+            return classes.join(" ");
+        }
 
         match self.interaction {
             SelectionInteraction::Subtle => {
@@ -516,7 +872,12 @@ impl<C: ColorProvider> SelectionPattern<C> {
                             .border_class(crate::core::Color::Interactive)
                     ));
                 }
-                classes.push("hover:scale-105 active:scale-95".to_string());
+                let motion = self.color_provider.motion_scale();
+                classes.push(format!(
+                    "{} {}",
+                    motion.scale_effect_class(ScaleEffect::Hover),
+                    motion.scale_effect_class(ScaleEffect::Press)
+                ));
             }
             SelectionInteraction::Prominent => {
                 if matches!(self.state, SelectionState::Unselected) {
@@ -528,8 +889,15 @@ impl<C: ColorProvider> SelectionPattern<C> {
                             .text_class(crate::core::Color::TextInverse)
                     ));
                 }
-                classes
-                    .push("hover:scale-110 active:scale-90 shadow-lg hover:shadow-xl".to_string());
+                let elevation_scale = self.color_provider.elevation_scale();
+                let motion = self.color_provider.motion_scale();
+                classes.push(format!(
+                    "{} {} {} {}",
+                    motion.scale_effect_class(ScaleEffect::HoverStrong),
+                    motion.scale_effect_class(ScaleEffect::PressStrong),
+                    elevation_scale.shadow_class(Elevation::Floating),
+                    elevation_scale.hover_shadow_class(Elevation::Floating)
+                ));
             }
         }
 
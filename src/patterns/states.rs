@@ -4,6 +4,9 @@
 //! to users, including empty states, loading states, error states, and success states.
 
 use crate::core::color::ColorProvider;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// State intent representing the semantic meaning of the state
@@ -60,6 +63,26 @@ pub enum StateAlignment {
     Right,
 }
 
+/// How much room a state view takes up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StateDisplay {
+    /// Full section block with a stacked icon and text (default)
+    Section,
+    /// Compact single line for table rows and form fields
+    Inline,
+    /// Full-width strip with the icon beside the text, for cards and sections
+    Banner,
+}
+
+/// Enter and exit animation for a state view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StateMotion {
+    /// Fade in and out in place (default)
+    Fade,
+    /// Fade while sliding up into place
+    Slide,
+}
+
 /// State action requirement
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StateActionRequirement {
@@ -303,7 +326,7 @@ impl<C: ColorProvider> StatePattern<C> {
     // === Build Methods ===
 
     /// Build the CSS classes for this state pattern
-    pub fn classes(self) -> String {
+    pub fn classes(&self) -> String {
         let mut all_classes = Vec::new();
 
         // Base state classes
@@ -347,7 +370,7 @@ impl<C: ColorProvider> StatePattern<C> {
         }
 
         // Custom classes
-        all_classes.extend(self.custom_classes);
+        all_classes.extend(self.custom_classes.iter().cloned());
 
         // Join and clean up
         let mut classes: Vec<String> = all_classes
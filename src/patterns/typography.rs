@@ -5,9 +5,11 @@
 //! weight progression, and color semantics for consistent typography.
 
 use crate::core::color::ColorProvider;
+use alloc::string::{String, ToString};
+use alloc::{format, vec};
 
 /// Typography hierarchy levels following semantic design principles
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographyHierarchy {
     /// Main page title (h1 equivalent)
     Title,
@@ -32,7 +34,7 @@ pub enum TypographyHierarchy {
 }
 
 /// Typography size system following design scale principles
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographySize {
     /// Extra small (0.75rem)
     XS,
@@ -53,7 +55,7 @@ pub enum TypographySize {
 }
 
 /// Typography weight system following font weight progression
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographyWeight {
     /// Light (300)
     Light,
@@ -70,7 +72,7 @@ pub enum TypographyWeight {
 }
 
 /// Typography color semantics for consistent meaning
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographyColor {
     /// Primary brand color
     Primary,
@@ -99,7 +101,7 @@ pub enum TypographyColor {
 }
 
 /// Text alignment options
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographyAlignment {
     /// Left aligned
     Left,
@@ -112,7 +114,7 @@ pub enum TypographyAlignment {
 }
 
 /// Text overflow behavior
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographyOverflow {
     /// No overflow handling
     Normal,
@@ -123,7 +125,7 @@ pub enum TypographyOverflow {
 }
 
 /// HTML element semantics for accessibility
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TypographyElement {
     /// Auto-select based on hierarchy
     Auto,
@@ -145,10 +147,12 @@ pub enum TypographyElement {
     Span,
     /// Generic div
     Div,
+    /// Block quotation
+    Blockquote,
 }
 
 /// Typography pattern configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypographyPattern<T: ColorProvider> {
     pub hierarchy: TypographyHierarchy,
     pub size: Option<TypographySize>,
@@ -219,32 +223,28 @@ impl<T: ColorProvider> TypographyPattern<T> {
 
     /// Generate CSS classes for this typography pattern
     pub fn classes(&self) -> String {
-        let mut classes = vec![];
-
         // Base typography classes
-        classes.push("leading-relaxed".to_string());
+        let mut classes = vec!["leading-relaxed"];
 
         // Hierarchy-based classes (size, weight, tracking)
-        let hierarchy_classes = self.get_hierarchy_classes();
-        if !hierarchy_classes.is_empty() {
-            classes.push(hierarchy_classes);
-        }
+        let (extra, default_size, default_weight) = self.hierarchy_classes();
+        classes.extend_from_slice(extra);
 
         // Size override
-        if let Some(size) = &self.size {
-            classes.push(self.get_size_classes(size));
-        }
+        classes.push(match &self.size {
+            Some(size) => self.get_size_classes(size),
+            None => default_size,
+        });
 
         // Weight override
-        if let Some(weight) = &self.weight {
-            classes.push(self.get_weight_classes(weight));
-        }
+        classes.push(match &self.weight {
+            Some(weight) => self.get_weight_classes(weight),
+            None => default_weight,
+        });
 
-        // Color classes
+        // Color classes (the only provider-dependent part)
         let color_classes = self.get_color_classes();
-        if !color_classes.is_empty() {
-            classes.push(color_classes);
-        }
+        classes.extend(color_classes.split_whitespace());
 
         // Alignment classes
         if let Some(alignment) = &self.alignment {
@@ -252,128 +252,40 @@ impl<T: ColorProvider> TypographyPattern<T> {
         }
 
         // Overflow classes
-        let overflow_classes = self.get_overflow_classes();
-        if !overflow_classes.is_empty() {
-            classes.push(overflow_classes);
-        }
-
-        // Join and deduplicate classes
-        let mut all_classes: Vec<String> = classes
-            .join(" ")
-            .split_whitespace()
-            .map(|s| s.to_string())
-            .collect();
+        classes.push(self.get_overflow_classes());
 
-        all_classes.sort();
-        all_classes.dedup();
-        all_classes.join(" ")
+        // Deduplicate, allocating only the final string
+        classes.retain(|class| !class.is_empty());
+        classes.sort_unstable();
+        classes.dedup();
+        classes.join(" ")
     }
 
-    /// Get CSS classes for hierarchy
-    fn get_hierarchy_classes(&self) -> String {
+    /// Get hierarchy classes: extra classes plus the default size and
+    /// weight used when no override is set
+    fn hierarchy_classes(&self) -> (&'static [&'static str], &'static str, &'static str) {
         match self.hierarchy {
-            TypographyHierarchy::Title => {
-                let mut classes = vec!["tracking-tight"];
-                if self.size.is_none() {
-                    classes.push("text-4xl");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-bold");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::Heading => {
-                let mut classes = vec!["tracking-tight"];
-                if self.size.is_none() {
-                    classes.push("text-3xl");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-bold");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::Subheading => {
-                let mut classes = vec!["tracking-tight"];
-                if self.size.is_none() {
-                    classes.push("text-2xl");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-bold");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::H4 => {
-                let mut classes = vec!["tracking-tight"];
-                if self.size.is_none() {
-                    classes.push("text-xl");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-bold");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::Body => {
-                let mut classes = vec![];
-                if self.size.is_none() {
-                    classes.push("text-base");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-normal");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::BodyLarge => {
-                let mut classes = vec![];
-                if self.size.is_none() {
-                    classes.push("text-lg");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-normal");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::BodySmall => {
-                let mut classes = vec![];
-                if self.size.is_none() {
-                    classes.push("text-sm");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-normal");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::Caption => {
-                let mut classes = vec![];
-                if self.size.is_none() {
-                    classes.push("text-sm");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-medium");
-                }
-                classes.join(" ")
-            }
+            TypographyHierarchy::Title => (&["tracking-tight"], "text-4xl", "font-bold"),
+            TypographyHierarchy::Heading => (&["tracking-tight"], "text-3xl", "font-bold"),
+            TypographyHierarchy::Subheading => (&["tracking-tight"], "text-2xl", "font-bold"),
+            TypographyHierarchy::H4 => (&["tracking-tight"], "text-xl", "font-bold"),
+            TypographyHierarchy::Body => (&[], "text-base", "font-normal"),
+            TypographyHierarchy::BodyLarge => (&[], "text-lg", "font-normal"),
+            TypographyHierarchy::BodySmall => (&[], "text-sm", "font-normal"),
+            TypographyHierarchy::Caption => (&[], "text-sm", "font-medium"),
             TypographyHierarchy::Overline => {
-                let mut classes = vec!["uppercase", "tracking-wider"];
-                if self.size.is_none() {
-                    classes.push("text-xs");
-                }
-                if self.weight.is_none() {
-                    classes.push("font-medium");
-                }
-                classes.join(" ")
-            }
-            TypographyHierarchy::Code => {
-                let mut classes = vec!["font-mono", "bg-gray-100", "px-1", "py-0.5", "rounded"];
-                if self.size.is_none() {
-                    classes.push("text-sm");
-                }
-                classes.join(" ")
+                (&["uppercase", "tracking-wider"], "text-xs", "font-medium")
             }
+            TypographyHierarchy::Code => (
+                &["font-mono", "bg-gray-100", "px-1", "py-0.5", "rounded"],
+                "text-sm",
+                "",
+            ),
         }
     }
 
     /// Get CSS classes for size
-    fn get_size_classes(&self, size: &TypographySize) -> String {
+    fn get_size_classes(&self, size: &TypographySize) -> &'static str {
         match size {
             TypographySize::XS => "text-xs",
             TypographySize::SM => "text-sm",
@@ -384,11 +296,10 @@ impl<T: ColorProvider> TypographyPattern<T> {
             TypographySize::XL3 => "text-3xl",
             TypographySize::XL4 => "text-4xl",
         }
-        .to_string()
     }
 
     /// Get CSS classes for weight
-    fn get_weight_classes(&self, weight: &TypographyWeight) -> String {
+    fn get_weight_classes(&self, weight: &TypographyWeight) -> &'static str {
         match weight {
             TypographyWeight::Light => "font-light",
             TypographyWeight::Normal => "font-normal",
@@ -397,7 +308,6 @@ impl<T: ColorProvider> TypographyPattern<T> {
             TypographyWeight::Bold => "font-bold",
             TypographyWeight::ExtraBold => "font-extrabold",
         }
-        .to_string()
     }
 
     /// Get CSS classes for color
@@ -440,26 +350,23 @@ impl<T: ColorProvider> TypographyPattern<T> {
     }
 
     /// Get CSS classes for alignment
-    fn get_alignment_classes(&self, alignment: &TypographyAlignment) -> String {
+    fn get_alignment_classes(&self, alignment: &TypographyAlignment) -> &'static str {
         match alignment {
             TypographyAlignment::Left => "text-left",
             TypographyAlignment::Center => "text-center",
             TypographyAlignment::Right => "text-right",
             TypographyAlignment::Justify => "text-justify",
         }
-        .to_string()
     }
 
     /// Get CSS classes for overflow
-    fn get_overflow_classes(&self) -> String {
+    fn get_overflow_classes(&self) -> &'static str {
         match self.overflow {
-            TypographyOverflow::Normal => String::new(),
-            TypographyOverflow::Truncate => "truncate".to_string(),
-            TypographyOverflow::Clamp(_) => {
-                // Line clamping requires CSS style, not class
-                // This will be handled by the component implementation
-                String::new()
-            }
+            TypographyOverflow::Normal => "",
+            TypographyOverflow::Truncate => "truncate",
+            // Line clamping requires CSS style, not class; the component
+            // implementation handles it
+            TypographyOverflow::Clamp(_) => "",
         }
     }
 
@@ -489,6 +396,7 @@ impl<T: ColorProvider> TypographyPattern<T> {
             TypographyElement::P => "p".to_string(),
             TypographyElement::Span => "span".to_string(),
             TypographyElement::Div => "div".to_string(),
+            TypographyElement::Blockquote => "blockquote".to_string(),
         }
     }
 
@@ -505,6 +413,109 @@ impl<T: ColorProvider> TypographyPattern<T> {
     }
 }
 
+/// Heading level for the current depth of the document outline
+///
+/// Start from the page root and call `nested` for each sectioning level
+/// (section, card, dialog) so titles get the next heading level instead of
+/// a hardcoded `h1`. Levels stop at `h6`. Components rendered inside an
+/// existing outline start from `at_level`.
+///
+/// ```rust
+/// use jupiter_design_system::patterns::{HeadingLevelContext, TypographyHierarchy};
+/// use jupiter_design_system::themes::VibeColors;
+///
+/// let page = HeadingLevelContext::root();
+/// let section = page.nested();
+/// let card = section.nested();
+///
+/// assert_eq!(page.typography(VibeColors::default()).get_element(), "h1");
+/// assert_eq!(card.level(), 3);
+/// assert_eq!(card.hierarchy(), TypographyHierarchy::Subheading);
+/// assert_eq!(card.typography(VibeColors::default()).get_element(), "h3");
+///
+/// // Keep the element, but style the heading differently
+/// let compact = card.typography_as(VibeColors::default(), TypographyHierarchy::H4);
+/// assert_eq!(compact.get_element(), "h3");
+/// assert_eq!(compact.hierarchy, TypographyHierarchy::H4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HeadingLevelContext {
+    level: u8,
+}
+
+impl Default for HeadingLevelContext {
+    fn default() -> Self {
+        Self::root()
+    }
+}
+
+impl HeadingLevelContext {
+    /// Context for the page title (`h1`)
+    pub fn root() -> Self {
+        Self { level: 1 }
+    }
+
+    /// Context at a given heading level (clamped to 1-6)
+    pub fn at_level(level: u8) -> Self {
+        Self {
+            level: level.clamp(1, 6),
+        }
+    }
+
+    /// Context for a section nested one level deeper
+    pub fn nested(&self) -> Self {
+        Self::at_level(self.level.saturating_add(1))
+    }
+
+    /// Heading level, 1-6
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Heading element for this level
+    pub fn element(&self) -> TypographyElement {
+        match self.level {
+            1 => TypographyElement::H1,
+            2 => TypographyElement::H2,
+            3 => TypographyElement::H3,
+            4 => TypographyElement::H4,
+            5 => TypographyElement::H5,
+            _ => TypographyElement::H6,
+        }
+    }
+
+    /// Default visual hierarchy for this level
+    ///
+    /// Levels past 4 share the smallest heading style.
+    pub fn hierarchy(&self) -> TypographyHierarchy {
+        match self.level {
+            1 => TypographyHierarchy::Title,
+            2 => TypographyHierarchy::Heading,
+            3 => TypographyHierarchy::Subheading,
+            _ => TypographyHierarchy::H4,
+        }
+    }
+
+    /// Set a pattern's element and hierarchy for this level
+    pub fn apply<T: ColorProvider>(&self, pattern: TypographyPattern<T>) -> TypographyPattern<T> {
+        pattern.hierarchy(self.hierarchy()).element(self.element())
+    }
+
+    /// Heading typography for this level
+    pub fn typography<T: ColorProvider>(&self, color_provider: T) -> TypographyPattern<T> {
+        self.apply(TypographyPattern::new(color_provider))
+    }
+
+    /// Heading typography for this level, styled as another hierarchy
+    pub fn typography_as<T: ColorProvider>(
+        &self,
+        color_provider: T,
+        hierarchy: TypographyHierarchy,
+    ) -> TypographyPattern<T> {
+        self.typography(color_provider).hierarchy(hierarchy)
+    }
+}
+
 /// Convenience function to create a typography pattern
 pub fn typography_pattern<T: ColorProvider>(color_provider: T) -> TypographyPattern<T> {
     TypographyPattern::new(color_provider)
@@ -525,6 +536,14 @@ pub fn body_typography<T: ColorProvider>(color_provider: T) -> TypographyPattern
     TypographyPattern::new(color_provider).hierarchy(TypographyHierarchy::Body)
 }
 
+/// Convenience function to create a block quotation typography pattern
+pub fn quote_typography<T: ColorProvider>(color_provider: T) -> TypographyPattern<T> {
+    TypographyPattern::new(color_provider)
+        .hierarchy(TypographyHierarchy::BodyLarge)
+        .color(TypographyColor::Muted)
+        .element(TypographyElement::Blockquote)
+}
+
 /// Convenience function to create a caption typography pattern
 pub fn caption_typography<T: ColorProvider>(color_provider: T) -> TypographyPattern<T> {
     TypographyPattern::new(color_provider).hierarchy(TypographyHierarchy::Caption)
@@ -0,0 +1,339 @@
+//! Named builder configurations
+//!
+//! Apps tend to repeat the same builder chains ("the big primary button on
+//! landing pages", "the card on settings screens") across many call sites.
+//! A [`PresetRegistry`] names those configurations once so call sites ask
+//! for `"cta-button"` instead of repeating the chain. Definitions are plain
+//! data and derive serde, so a registry can be loaded from a config file.
+//!
+//! Each option in a definition maps to the builder's `_str` setter of the
+//! same name (`"variant"` calls `variant_str`) or, for flags such as
+//! `"full_width"`, to the shorthand setter when the value is `"true"`.
+//! Unknown options are ignored, like unknown values in the `_str` setters.
+//! A definition can `extend` another of the same component; its options
+//! override the base's and its custom classes are added after the base's.
+//!
+//! With the `std` feature, [`register_preset`] and [`register_presets`]
+//! fill a process-wide registry at startup and [`preset`] builds from it
+//! with the global theme.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "buttons")]
+//! # {
+//! use jupiter_design_system::builders::ButtonStyles;
+//! use jupiter_design_system::presets::{PresetDefinition, PresetRegistry};
+//! use jupiter_design_system::themes::VibeColors;
+//!
+//! let presets = PresetRegistry::new()
+//!     .with("primary-button", PresetDefinition::new("button").option("variant", "primary"))
+//!     .with(
+//!         "cta-button",
+//!         PresetDefinition::new("button")
+//!             .extends("primary-button")
+//!             .option("size", "lg")
+//!             .option("full_width", "true"),
+//!     );
+//!
+//! let colors = VibeColors::default();
+//! let cta: ButtonStyles<_> = presets.build("cta-button", &colors).unwrap();
+//!
+//! assert_eq!(
+//!     cta.classes(),
+//!     ButtonStyles::new(&colors).primary().large().full_width().classes()
+//! );
+//! # }
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Builders that can be created from a preset definition
+pub trait Preset<C>: Sized {
+    /// Component name definitions for this builder declare, e.g. "button"
+    const COMPONENT: &'static str;
+
+    /// Create a builder from a resolved definition
+    ///
+    /// Builders start from the theme's component defaults, as with
+    /// `for_theme`.
+    fn from_preset(color_provider: C, preset: &PresetDefinition) -> Self;
+}
+
+/// One named builder configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PresetDefinition {
+    /// Component the preset configures, e.g. "button" or "card"
+    pub component: String,
+    /// Name of a preset this one builds on
+    #[serde(default)]
+    pub extends: Option<String>,
+    /// Setter values by option name, e.g. "size" → "lg"
+    #[serde(default)]
+    pub options: BTreeMap<String, String>,
+    /// Extra classes added after the builder's own
+    #[serde(default)]
+    pub custom: Vec<String>,
+}
+
+impl PresetDefinition {
+    /// Create an empty definition for a component
+    pub fn new(component: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Build on another preset
+    pub fn extends(mut self, base: impl Into<String>) -> Self {
+        self.extends = Some(base.into());
+        self
+    }
+
+    /// Set an option, replacing any previous value
+    pub fn option(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.options.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add a custom CSS class
+    pub fn custom(mut self, class: impl Into<String>) -> Self {
+        self.custom.push(class.into());
+        self
+    }
+
+    /// Get an option value
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Whether a flag option is set to "true"
+    pub fn flag(&self, name: &str) -> bool {
+        self.get(name) == Some("true")
+    }
+}
+
+/// Why a preset could not be built
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PresetError {
+    /// No preset is registered under the name
+    NotFound(String),
+    /// The preset (or one it extends) configures a different component
+    ComponentMismatch {
+        /// Preset declaring the other component
+        name: String,
+        /// Component of the requested builder
+        expected: String,
+        /// Component the preset declares
+        found: String,
+    },
+    /// The preset extends itself, directly or through other presets
+    Cycle(String),
+}
+
+impl fmt::Display for PresetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresetError::NotFound(name) => write!(f, "no preset named \"{}\"", name),
+            PresetError::ComponentMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "preset \"{}\" configures a {}, not a {}",
+                name, found, expected
+            ),
+            PresetError::Cycle(name) => write!(f, "preset \"{}\" extends itself", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PresetError {}
+
+/// Preset definitions by name
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PresetRegistry {
+    presets: BTreeMap<String, PresetDefinition>,
+}
+
+impl PresetRegistry {
+    /// Create an empty registry
+    pub const fn new() -> Self {
+        Self {
+            presets: BTreeMap::new(),
+        }
+    }
+
+    /// Add a preset, replacing any previous one with the same name
+    pub fn with(mut self, name: impl Into<String>, preset: PresetDefinition) -> Self {
+        self.insert(name, preset);
+        self
+    }
+
+    /// Add a preset in place, replacing any previous one with the same name
+    pub fn insert(&mut self, name: impl Into<String>, preset: PresetDefinition) {
+        self.presets.insert(name.into(), preset);
+    }
+
+    /// Add every preset from another registry, replacing ones with the same name
+    pub fn merge(&mut self, other: PresetRegistry) {
+        self.presets.extend(other.presets);
+    }
+
+    /// Get a preset as registered, without resolving what it extends
+    pub fn get(&self, name: &str) -> Option<&PresetDefinition> {
+        self.presets.get(name)
+    }
+
+    /// Registered preset names, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.presets.keys().map(String::as_str)
+    }
+
+    /// Number of registered presets
+    pub fn len(&self) -> usize {
+        self.presets.len()
+    }
+
+    /// Whether no presets are registered
+    pub fn is_empty(&self) -> bool {
+        self.presets.is_empty()
+    }
+
+    /// Get a preset with everything it extends folded in
+    ///
+    /// The result has no `extends`. A base that is not registered is a
+    /// `NotFound` error naming the base.
+    pub fn resolve(&self, name: &str) -> Result<PresetDefinition, PresetError> {
+        let mut chain: Vec<(&str, &PresetDefinition)> = Vec::new();
+        let mut next = Some(name);
+        while let Some(current) = next {
+            if chain.iter().any(|(seen, _)| *seen == current) {
+                return Err(PresetError::Cycle(String::from(name)));
+            }
+            let preset = self
+                .get(current)
+                .ok_or_else(|| PresetError::NotFound(String::from(current)))?;
+            if let Some((_, child)) = chain.last() {
+                if preset.component != child.component {
+                    return Err(PresetError::ComponentMismatch {
+                        name: String::from(current),
+                        expected: child.component.clone(),
+                        found: preset.component.clone(),
+                    });
+                }
+            }
+            chain.push((current, preset));
+            next = preset.extends.as_deref();
+        }
+
+        let mut resolved = PresetDefinition::new(chain[0].1.component.clone());
+        for (_, preset) in chain.iter().rev() {
+            resolved
+                .options
+                .extend(preset.options.iter().map(|(k, v)| (k.clone(), v.clone())));
+            resolved.custom.extend(preset.custom.iter().cloned());
+        }
+        Ok(resolved)
+    }
+
+    /// Build a builder from a preset
+    pub fn build<B: Preset<C>, C>(&self, name: &str, color_provider: C) -> Result<B, PresetError> {
+        build_resolved(name, self.resolve(name)?, color_provider)
+    }
+}
+
+fn build_resolved<B: Preset<C>, C>(
+    name: &str,
+    preset: PresetDefinition,
+    color_provider: C,
+) -> Result<B, PresetError> {
+    if preset.component != B::COMPONENT {
+        return Err(PresetError::ComponentMismatch {
+            name: String::from(name),
+            expected: String::from(B::COMPONENT),
+            found: preset.component,
+        });
+    }
+    Ok(B::from_preset(color_provider, &preset))
+}
+
+#[cfg(feature = "std")]
+static PRESETS: std::sync::RwLock<PresetRegistry> = std::sync::RwLock::new(PresetRegistry::new());
+
+/// Register a preset in the process-wide registry
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn register_preset(name: impl Into<String>, preset: PresetDefinition) {
+    match PRESETS.write() {
+        Ok(mut presets) => presets.insert(name, preset),
+        Err(poisoned) => poisoned.into_inner().insert(name, preset),
+    }
+}
+
+/// Register every preset from a registry, e.g. one loaded from a config file
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn register_presets(registry: PresetRegistry) {
+    match PRESETS.write() {
+        Ok(mut presets) => presets.merge(registry),
+        Err(poisoned) => poisoned.into_inner().merge(registry),
+    }
+}
+
+/// Get a copy of the process-wide registry
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn presets() -> PresetRegistry {
+    match PRESETS.read() {
+        Ok(presets) => presets.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Build a builder from a registered preset with the global theme
+///
+/// Requires the `std` feature.
+///
+/// ```rust
+/// # #[cfg(feature = "cards")]
+/// # {
+/// use jupiter_design_system::builders::CardStyles;
+/// use jupiter_design_system::presets::{preset, register_preset, PresetDefinition};
+/// use jupiter_design_system::themes::global_theme;
+///
+/// register_preset(
+///     "settings-card",
+///     PresetDefinition::new("card").option("spacing", "comfortable"),
+/// );
+///
+/// let card = preset::<CardStyles<_>>("settings-card").unwrap();
+/// assert_eq!(
+///     card.classes(),
+///     CardStyles::new(global_theme()).comfortable_spacing().classes()
+/// );
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn preset<B: Preset<crate::themes::SharedTheme>>(name: &str) -> Result<B, PresetError> {
+    let resolved = match PRESETS.read() {
+        Ok(presets) => presets.resolve(name),
+        Err(poisoned) => poisoned.into_inner().resolve(name),
+    }?;
+    build_resolved(name, resolved, crate::themes::global_theme())
+}
+
+#[cfg(test)]
+#[path = "presets_test.rs"]
+mod presets_test;
@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "buttons")]
+    use crate::builders::button::ButtonStyles;
+    #[cfg(feature = "cards")]
+    use crate::builders::card::CardStyles;
+    use crate::presets::{PresetDefinition, PresetError, PresetRegistry};
+    #[cfg(any(feature = "buttons", feature = "cards"))]
+    use crate::themes::VibeColors;
+
+    fn registry() -> PresetRegistry {
+        PresetRegistry::new()
+            .with(
+                "primary-button",
+                PresetDefinition::new("button")
+                    .option("variant", "primary")
+                    .custom("tracking-wide"),
+            )
+            .with(
+                "cta-button",
+                PresetDefinition::new("button")
+                    .extends("primary-button")
+                    .option("size", "lg")
+                    .option("full_width", "true")
+                    .custom("shadow-xl"),
+            )
+            .with(
+                "settings-card",
+                PresetDefinition::new("card")
+                    .option("spacing", "comfortable")
+                    .option("elevation", "flat"),
+            )
+    }
+
+    #[test]
+    fn test_resolve_folds_in_bases() {
+        let cta = registry().resolve("cta-button").unwrap();
+
+        assert_eq!(cta.component, "button");
+        assert_eq!(cta.extends, None);
+        assert_eq!(cta.get("variant"), Some("primary"));
+        assert_eq!(cta.get("size"), Some("lg"));
+        assert!(cta.flag("full_width"));
+        assert_eq!(cta.custom, vec!["tracking-wide", "shadow-xl"]);
+    }
+
+    #[test]
+    fn test_resolve_child_options_override_base() {
+        let presets = registry().with(
+            "danger-cta",
+            PresetDefinition::new("button")
+                .extends("cta-button")
+                .option("variant", "danger"),
+        );
+
+        let resolved = presets.resolve("danger-cta").unwrap();
+        assert_eq!(resolved.get("variant"), Some("danger"));
+        assert_eq!(resolved.get("size"), Some("lg"));
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let presets = registry()
+            .with(
+                "orphan",
+                PresetDefinition::new("button").extends("missing-base"),
+            )
+            .with(
+                "card-button",
+                PresetDefinition::new("button").extends("settings-card"),
+            )
+            .with("loop-a", PresetDefinition::new("card").extends("loop-b"))
+            .with("loop-b", PresetDefinition::new("card").extends("loop-a"));
+
+        assert_eq!(
+            presets.resolve("nope"),
+            Err(PresetError::NotFound("nope".to_string()))
+        );
+        assert_eq!(
+            presets.resolve("orphan"),
+            Err(PresetError::NotFound("missing-base".to_string()))
+        );
+        assert_eq!(
+            presets.resolve("card-button"),
+            Err(PresetError::ComponentMismatch {
+                name: "settings-card".to_string(),
+                expected: "button".to_string(),
+                found: "card".to_string(),
+            })
+        );
+        assert_eq!(
+            presets.resolve("loop-a"),
+            Err(PresetError::Cycle("loop-a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_registry_loads_from_json() {
+        let json = r#"{
+            "cta-button": {
+                "component": "button",
+                "options": { "variant": "success", "size": "lg" },
+                "custom": ["shadow-xl"]
+            },
+            "quiet-card": { "component": "card" }
+        }"#;
+
+        let presets: PresetRegistry = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            presets.names().collect::<Vec<_>>(),
+            ["cta-button", "quiet-card"]
+        );
+        assert_eq!(
+            presets.get("cta-button"),
+            Some(
+                &PresetDefinition::new("button")
+                    .option("variant", "success")
+                    .option("size", "lg")
+                    .custom("shadow-xl")
+            )
+        );
+        assert_eq!(
+            presets.get("quiet-card"),
+            Some(&PresetDefinition::new("card"))
+        );
+
+        let round_trip: PresetRegistry =
+            serde_json::from_str(&serde_json::to_string(&presets).unwrap()).unwrap();
+        assert_eq!(round_trip, presets);
+    }
+
+    #[test]
+    fn test_merge_replaces_same_name() {
+        let mut presets = registry();
+        presets.merge(PresetRegistry::new().with(
+            "settings-card",
+            PresetDefinition::new("card").option("spacing", "compact"),
+        ));
+
+        assert_eq!(presets.len(), 3);
+        assert_eq!(
+            presets.get("settings-card").unwrap().get("spacing"),
+            Some("compact")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_build_button_preset() {
+        let colors = VibeColors::default();
+        let cta: ButtonStyles<_> = registry().build("cta-button", &colors).unwrap();
+
+        assert_eq!(
+            cta.classes(),
+            ButtonStyles::new(&colors)
+                .primary()
+                .large()
+                .full_width()
+                .custom("tracking-wide")
+                .custom("shadow-xl")
+                .classes()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_build_rejects_other_component() {
+        let colors = VibeColors::default();
+        let result = registry().build::<ButtonStyles<_>, _>("settings-card", &colors);
+
+        assert_eq!(
+            result,
+            Err(PresetError::ComponentMismatch {
+                name: "settings-card".to_string(),
+                expected: "button".to_string(),
+                found: "card".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "cards")]
+    fn test_build_card_preset() {
+        let colors = VibeColors::default();
+        let card: CardStyles<_> = registry().build("settings-card", &colors).unwrap();
+
+        assert_eq!(
+            card.classes(),
+            CardStyles::new(&colors)
+                .comfortable_spacing()
+                .flat_elevation()
+                .classes()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "buttons"))]
+    fn test_global_registry() {
+        use crate::presets::{preset, presets, register_preset, register_presets};
+        use crate::themes::global_theme;
+
+        register_presets(registry().with(
+            "test-global-base",
+            PresetDefinition::new("button").option("variant", "secondary"),
+        ));
+        register_preset(
+            "test-global-small",
+            PresetDefinition::new("button")
+                .extends("test-global-base")
+                .option("size", "sm"),
+        );
+
+        assert!(presets().get("test-global-small").is_some());
+        assert_eq!(
+            preset::<ButtonStyles<_>>("test-global-small")
+                .unwrap()
+                .classes(),
+            ButtonStyles::new(global_theme())
+                .secondary()
+                .small()
+                .classes()
+        );
+        assert_eq!(
+            preset::<ButtonStyles<_>>("test-global-missing").unwrap_err(),
+            PresetError::NotFound("test-global-missing".to_string())
+        );
+    }
+}
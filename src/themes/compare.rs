@@ -0,0 +1,219 @@
+//! Theme comparison for reviewing token changes
+//!
+//! Renders a fixed set of builder presets with two themes and reports every
+//! preset whose classes differ, so a token change can be reviewed by its
+//! blast radius before it ships.
+
+use crate::builders::{dropzone_styles, scroll_styles};
+use crate::core::color::ColorProvider;
+use crate::utils::{class_diff, ClassDiff};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A preset whose classes differ between two themes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresetChange {
+    /// Preset name, e.g. "button primary md"
+    pub preset: &'static str,
+    /// Classes added and removed going from the first theme to the second
+    pub diff: ClassDiff,
+}
+
+/// Report of the presets affected by switching themes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThemeComparison {
+    /// Presets whose classes differ, in preset order
+    pub changes: Vec<PresetChange>,
+    /// Number of presets with identical classes
+    pub unchanged: usize,
+}
+
+impl ThemeComparison {
+    /// Whether every preset renders the same in both themes
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Names of the presets that changed
+    pub fn changed_presets(&self) -> Vec<&'static str> {
+        self.changes.iter().map(|change| change.preset).collect()
+    }
+
+    /// Get the change for one preset, if it changed
+    pub fn change(&self, preset: &str) -> Option<&PresetChange> {
+        self.changes.iter().find(|change| change.preset == preset)
+    }
+}
+
+impl fmt::Display for ThemeComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} presets changed, {} unchanged",
+            self.changes.len(),
+            self.unchanged
+        )?;
+        for change in &self.changes {
+            writeln!(f, "{}: {}", change.preset, change.diff)?;
+        }
+        Ok(())
+    }
+}
+
+/// Render every reviewed preset with one theme
+///
+/// Presets cover the common configuration of each enabled builder.
+pub fn theme_presets<C: ColorProvider + Clone>(colors: &C) -> Vec<(&'static str, String)> {
+    #[allow(unused_mut)]
+    let mut presets = vec![
+        ("dropzone idle", dropzone_styles(colors.clone()).classes()),
+        (
+            "scroll branded",
+            scroll_styles(colors.clone()).branded_scrollbar().classes(),
+        ),
+    ];
+
+    #[cfg(feature = "buttons")]
+    {
+        use crate::builders::{button_styles, interactive_input};
+
+        presets.push((
+            "button primary md",
+            button_styles(colors.clone()).primary().medium().classes(),
+        ));
+        presets.push((
+            "button secondary md",
+            button_styles(colors.clone()).secondary().medium().classes(),
+        ));
+        presets.push((
+            "button error md",
+            button_styles(colors.clone()).error().medium().classes(),
+        ));
+        presets.push((
+            "button ghost sm",
+            button_styles(colors.clone()).ghost().small().classes(),
+        ));
+        presets.push((
+            "input standard",
+            interactive_input(colors.clone()).standard_style().build(),
+        ));
+    }
+
+    #[cfg(feature = "cards")]
+    {
+        use crate::builders::card_styles;
+
+        presets.push((
+            "card subtle standard",
+            card_styles(colors.clone()).standard_surface().classes(),
+        ));
+        presets.push((
+            "card raised standard",
+            card_styles(colors.clone())
+                .raised_elevation()
+                .standard_surface()
+                .classes(),
+        ));
+        presets.push((
+            "card floating overlay",
+            card_styles(colors.clone())
+                .floating_elevation()
+                .overlay_surface()
+                .classes(),
+        ));
+    }
+
+    #[cfg(feature = "typography")]
+    {
+        use crate::builders::{callout_styles, text_styles};
+
+        presets.push(("text title", text_styles(colors.clone()).title().classes()));
+        presets.push(("text body", text_styles(colors.clone()).body().classes()));
+        presets.push((
+            "callout warning",
+            callout_styles(colors.clone()).warning().classes(),
+        ));
+    }
+
+    #[cfg(feature = "layout")]
+    {
+        use crate::builders::card_header_styles;
+
+        presets.push((
+            "layout card header",
+            card_header_styles(colors.clone()).classes(),
+        ));
+    }
+
+    #[cfg(feature = "selection")]
+    {
+        use crate::builders::{chip_selection_styles, tab_selection_styles};
+
+        presets.push((
+            "selection chip selected",
+            chip_selection_styles(colors.clone())
+                .selected()
+                .item_classes(),
+        ));
+        presets.push((
+            "selection tab selected",
+            tab_selection_styles(colors.clone())
+                .selected()
+                .item_classes(),
+        ));
+    }
+
+    #[cfg(feature = "states")]
+    {
+        use crate::builders::{error_state_styles, loading_state_styles};
+
+        presets.push((
+            "state loading",
+            loading_state_styles(colors.clone()).classes(),
+        ));
+        presets.push(("state error", error_state_styles(colors.clone()).classes()));
+    }
+
+    presets
+}
+
+/// Compare every preset between two themes
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::themes::{compare, VibeColors};
+///
+/// let report = compare(&VibeColors::default(), &VibeColors::water_wellness());
+///
+/// assert!(!report.is_empty());
+/// assert!(report.unchanged > 0);
+/// assert!(compare(&VibeColors::default(), &VibeColors::default()).is_empty());
+/// ```
+pub fn compare<A, B>(theme_a: &A, theme_b: &B) -> ThemeComparison
+where
+    A: ColorProvider + Clone,
+    B: ColorProvider + Clone,
+{
+    let mut comparison = ThemeComparison::default();
+
+    for ((preset, a), (_, b)) in theme_presets(theme_a)
+        .into_iter()
+        .zip(theme_presets(theme_b))
+    {
+        let diff = class_diff(&a, &b);
+        if diff.is_empty() {
+            comparison.unchanged += 1;
+        } else {
+            comparison.changes.push(PresetChange { preset, diff });
+        }
+    }
+
+    comparison
+}
+
+#[cfg(test)]
+#[path = "compare_test.rs"]
+mod compare_test;
@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "buttons", feature = "cards"))]
+    use crate::core::BorderScale;
+    use crate::themes::{compare, theme_presets, VibeColors};
+
+    #[test]
+    fn test_identical_themes_have_no_changes() {
+        let report = compare(&VibeColors::default(), &VibeColors::default());
+
+        assert!(report.is_empty());
+        assert_eq!(
+            report.unchanged,
+            theme_presets(&VibeColors::default()).len()
+        );
+    }
+
+    #[test]
+    fn test_preset_names_are_unique() {
+        let presets = theme_presets(&VibeColors::default());
+        let mut names: Vec<_> = presets.iter().map(|(name, _)| *name).collect();
+        names.sort();
+        names.dedup();
+
+        assert_eq!(names.len(), presets.len());
+    }
+
+    #[test]
+    fn test_scroll_reports_primary_change() {
+        let report = compare(&VibeColors::default(), &VibeColors::water_wellness());
+        let change = report.change("scroll branded").unwrap();
+
+        assert!(change
+            .diff
+            .removed
+            .iter()
+            .any(|class| class.contains("jupiter-blue-500")));
+        assert!(change
+            .diff
+            .added
+            .iter()
+            .any(|class| class.contains("water-blue-500")));
+    }
+
+    #[test]
+    #[cfg(all(feature = "buttons", feature = "cards"))]
+    fn test_border_change_blast_radius() {
+        let report = compare(
+            &VibeColors::default(),
+            &VibeColors::default().with_borders(BorderScale::bold()),
+        );
+        let changed = report.changed_presets();
+
+        assert!(changed.contains(&"card raised standard"));
+        assert!(changed.contains(&"input standard"));
+        assert!(!changed.contains(&"button primary md"));
+    }
+
+    #[test]
+    fn test_report_display() {
+        let report = compare(&VibeColors::default(), &VibeColors::water_wellness());
+        let text = report.to_string();
+
+        assert!(text.starts_with(&format!("{} presets changed", report.changes.len())));
+        assert!(text.contains("scroll branded: -"));
+    }
+}
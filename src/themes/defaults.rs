@@ -0,0 +1,77 @@
+//! Theme-level component defaults
+//!
+//! A brand may want every button large or every card comfortably spaced.
+//! Rather than repeating the setters at each call site, the theme declares
+//! [`ComponentDefaults`] and builders created with `for_theme` start from
+//! them. Setters called afterwards still win, and `new` ignores the
+//! defaults entirely.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "buttons")]
+//! # {
+//! use jupiter_design_system::builders::ButtonStyles;
+//! use jupiter_design_system::core::Size;
+//! use jupiter_design_system::themes::{ButtonDefaults, ComponentDefaults, VibeColors};
+//!
+//! let brand = VibeColors::default().with_component_defaults(ComponentDefaults {
+//!     button: ButtonDefaults {
+//!         size: Some(Size::Large),
+//!         ..ButtonDefaults::default()
+//!     },
+//!     ..ComponentDefaults::default()
+//! });
+//!
+//! let themed = ButtonStyles::for_theme(&brand);
+//! assert_eq!(themed.classes(), ButtonStyles::new(&brand).large().classes());
+//! # }
+//! ```
+
+#[cfg(feature = "buttons")]
+use crate::builders::button::ButtonVariant;
+#[cfg(feature = "buttons")]
+use crate::core::Size;
+#[cfg(feature = "cards")]
+use crate::patterns::{CardElevation, CardInteraction, CardSpacing, CardSurface};
+use serde::{Deserialize, Serialize};
+
+/// Defaults for builders created with `for_theme`
+///
+/// Each component's defaults are present when its feature is enabled;
+/// unset values keep the builder's own default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ComponentDefaults {
+    /// Defaults for `ButtonStyles`
+    #[cfg(feature = "buttons")]
+    #[serde(default)]
+    pub button: ButtonDefaults,
+    /// Defaults for `CardStyles`
+    #[cfg(feature = "cards")]
+    #[serde(default)]
+    pub card: CardDefaults,
+}
+
+/// Button defaults
+#[cfg(feature = "buttons")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ButtonDefaults {
+    /// Variant of new buttons; builders default to primary
+    pub variant: Option<ButtonVariant>,
+    /// Size of new buttons; builders default to medium
+    pub size: Option<Size>,
+}
+
+/// Card defaults
+#[cfg(feature = "cards")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CardDefaults {
+    /// Elevation of new cards; builders default to subtle
+    pub elevation: Option<CardElevation>,
+    /// Surface of new cards; builders default to standard
+    pub surface: Option<CardSurface>,
+    /// Spacing of new cards; builders default to standard
+    pub spacing: Option<CardSpacing>,
+    /// Interaction of new cards; builders default to static
+    pub interaction: Option<CardInteraction>,
+}
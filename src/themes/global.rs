@@ -0,0 +1,135 @@
+//! Process-wide theme for apps that use a single theme
+//!
+//! Threading a [`ColorProvider`](crate::core::color::ColorProvider) through
+//! every call is the most flexible option, but most apps configure one theme
+//! at startup. [`set_global_theme`] stores it once and the `*_styles_default()`
+//! constructors (e.g. `button_styles_default()`) pick it up.
+//!
+//! The theme is held in an `Arc`, so reading it is a reference count bump
+//! rather than a palette clone. Tests can swap in a different theme with
+//! [`GlobalTheme::scoped`]; the override only applies to the current thread,
+//! so tests running in parallel do not see each other's themes.
+//!
+//! Requires the `std` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::themes::{global_theme, set_global_theme, GlobalTheme, VibeColors};
+//! use jupiter_design_system::core::Density;
+//!
+//! set_global_theme(VibeColors::default());
+//!
+//! {
+//!     let _guard = GlobalTheme::scoped(VibeColors::default().with_density(Density::Compact));
+//!     assert_eq!(global_theme().density(), Density::Compact);
+//! }
+//!
+//! assert_eq!(global_theme().density(), Density::Comfortable);
+//! # use jupiter_design_system::core::color::ColorProvider;
+//! ```
+
+use crate::themes::VibeColors;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Theme handle shared by every builder created from the global theme
+pub type SharedTheme = Arc<VibeColors>;
+
+static GLOBAL_THEME: RwLock<Option<SharedTheme>> = RwLock::new(None);
+
+/// Default Jupiter theme, built on first use when no theme is set
+static DEFAULT_THEME: OnceLock<SharedTheme> = OnceLock::new();
+
+thread_local! {
+    /// Scoped overrides on this thread, innermost last, keyed by guard id
+    static SCOPED_THEMES: RefCell<Vec<(u64, SharedTheme)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_SCOPE_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Access to the process-wide theme
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalTheme;
+
+impl GlobalTheme {
+    /// Replace the process-wide theme
+    pub fn set(colors: VibeColors) {
+        let theme = Arc::new(colors);
+        match GLOBAL_THEME.write() {
+            Ok(mut global) => *global = Some(theme),
+            Err(poisoned) => *poisoned.into_inner() = Some(theme),
+        }
+    }
+
+    /// Get the current theme
+    ///
+    /// Returns the innermost scoped override on this thread, then the
+    /// process-wide theme, then the default Jupiter theme.
+    pub fn get() -> SharedTheme {
+        if let Some(theme) =
+            SCOPED_THEMES.with(|scoped| scoped.borrow().last().map(|(_, theme)| Arc::clone(theme)))
+        {
+            return theme;
+        }
+
+        let global = match GLOBAL_THEME.read() {
+            Ok(global) => global.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        global.unwrap_or_else(default_theme)
+    }
+
+    /// Override the theme on the current thread until the guard is dropped
+    ///
+    /// Guards can be dropped in any order; dropping one removes only its
+    /// own override.
+    pub fn scoped(colors: VibeColors) -> ScopedTheme {
+        let id = NEXT_SCOPE_ID.with(|next| {
+            let id = next.get();
+            next.set(id.wrapping_add(1));
+            id
+        });
+        SCOPED_THEMES.with(|scoped| scoped.borrow_mut().push((id, Arc::new(colors))));
+        ScopedTheme {
+            id,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+fn default_theme() -> SharedTheme {
+    Arc::clone(DEFAULT_THEME.get_or_init(|| Arc::new(VibeColors::default())))
+}
+
+/// Guard removing its theme override when dropped
+///
+/// Not `Send`: the override belongs to the thread that created it.
+#[derive(Debug)]
+#[must_use = "the theme override ends when the guard is dropped"]
+pub struct ScopedTheme {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ScopedTheme {
+    fn drop(&mut self) {
+        SCOPED_THEMES.with(|scoped| {
+            scoped.borrow_mut().retain(|(id, _)| *id != self.id);
+        });
+    }
+}
+
+/// Replace the process-wide theme
+pub fn set_global_theme(colors: VibeColors) {
+    GlobalTheme::set(colors);
+}
+
+/// Get the current theme (see [`GlobalTheme::get`])
+pub fn global_theme() -> SharedTheme {
+    GlobalTheme::get()
+}
+
+#[cfg(test)]
+#[path = "global_test.rs"]
+mod global_test;
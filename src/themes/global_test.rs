@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::color::ColorProvider;
+    use crate::core::{Color, Density};
+    use crate::themes::global::{default_theme, global_theme, set_global_theme, GlobalTheme};
+    use crate::themes::VibeColors;
+    use std::sync::Arc;
+
+    fn branded() -> VibeColors {
+        VibeColors::with_overrides(|palette| palette.primary = "purple-600".to_string())
+    }
+
+    #[test]
+    fn test_scoped_override_restores_previous_theme() {
+        let before = global_theme();
+
+        {
+            let _guard = GlobalTheme::scoped(branded());
+            assert_eq!(global_theme().resolve_color(Color::Primary), "purple-600");
+
+            {
+                let _inner =
+                    GlobalTheme::scoped(VibeColors::default().with_density(Density::Compact));
+                assert_eq!(global_theme().density(), Density::Compact);
+            }
+
+            assert_eq!(global_theme().resolve_color(Color::Primary), "purple-600");
+        }
+
+        assert_eq!(*global_theme(), *before);
+    }
+
+    #[test]
+    fn test_scoped_guards_dropped_out_of_order() {
+        let before = global_theme();
+
+        let outer = GlobalTheme::scoped(branded());
+        let inner = GlobalTheme::scoped(VibeColors::default().with_density(Density::Compact));
+
+        // Dropping the outer guard first keeps the inner override active
+        drop(outer);
+        assert_eq!(global_theme().density(), Density::Compact);
+        assert_ne!(global_theme().resolve_color(Color::Primary), "purple-600");
+
+        drop(inner);
+        assert_eq!(*global_theme(), *before);
+    }
+
+    #[test]
+    fn test_scoped_override_is_thread_local() {
+        let _guard = GlobalTheme::scoped(branded());
+
+        let other_thread =
+            std::thread::spawn(|| global_theme().resolve_color(Color::Primary).to_string())
+                .join()
+                .unwrap();
+
+        assert_ne!(other_thread, "purple-600");
+        assert_eq!(global_theme().resolve_color(Color::Primary), "purple-600");
+    }
+
+    #[test]
+    fn test_set_global_theme_is_shared() {
+        // Other tests only use scoped overrides, so setting the default here
+        // cannot change their results
+        set_global_theme(VibeColors::default());
+
+        let theme = std::thread::spawn(global_theme).join().unwrap();
+        assert_eq!(*theme, VibeColors::default());
+    }
+
+    #[test]
+    fn test_shared_theme_is_not_cloned() {
+        let _guard = GlobalTheme::scoped(branded());
+
+        assert!(Arc::ptr_eq(&global_theme(), &global_theme()));
+    }
+
+    #[test]
+    fn test_default_theme_is_built_once() {
+        assert!(Arc::ptr_eq(&default_theme(), &default_theme()));
+        assert_eq!(*default_theme(), VibeColors::default());
+    }
+
+    #[test]
+    #[cfg(all(feature = "buttons", feature = "typography"))]
+    fn test_default_constructors_use_global_theme() {
+        use crate::builders::{
+            button_styles, button_styles_default, text_styles, text_styles_default,
+        };
+
+        let _guard = GlobalTheme::scoped(branded());
+
+        assert_eq!(
+            button_styles_default().primary().classes(),
+            button_styles(branded()).primary().classes()
+        );
+        assert_eq!(
+            text_styles_default().title().classes(),
+            text_styles(branded()).title().classes()
+        );
+    }
+}
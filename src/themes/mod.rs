@@ -1,6 +1,37 @@
 //! Theme system for the design system
 
-use crate::core::color::{ColorPalette, ColorProvider};
+use crate::core::border::BorderScale;
+use crate::core::chart::ChartPalette;
+use crate::core::color::{ColorPalette, ColorProvider, IntentEmphasis, SelectionTokens};
+use crate::core::color_value::ColorValue;
+use crate::core::density::{DataDisplayTokens, Density};
+use crate::core::elevation::ElevationScale;
+use crate::core::glass::GlassScale;
+use crate::core::motion::MotionScale;
+use crate::core::radius::RadiusScale;
+use crate::core::sizing::{BreakpointScale, SizeScale};
+use crate::core::state_layer::StateLayerScale;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+pub mod compare;
+pub mod defaults;
+#[cfg(feature = "std")]
+pub mod global;
+
+#[cfg(test)]
+#[path = "providers_test.rs"]
+mod providers_test;
+
+pub use compare::{compare, theme_presets, PresetChange, ThemeComparison};
+#[cfg(feature = "buttons")]
+pub use defaults::ButtonDefaults;
+#[cfg(feature = "cards")]
+pub use defaults::CardDefaults;
+pub use defaults::ComponentDefaults;
+#[cfg(feature = "std")]
+pub use global::{global_theme, set_global_theme, GlobalTheme, ScopedTheme, SharedTheme};
 
 /// Trait for theme providers
 pub trait Theme {
@@ -9,9 +40,22 @@ pub trait Theme {
 }
 
 /// Default Jupiter Design System color palette with vibrant psychedelic colors
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VibeColors {
     palette: ColorPalette,
+    density: Density,
+    data_display: DataDisplayTokens,
+    selection: SelectionTokens,
+    components: ComponentDefaults,
+    elevation: ElevationScale,
+    radius: RadiusScale,
+    borders: BorderScale,
+    motion: MotionScale,
+    state_layers: StateLayerScale,
+    glass: GlassScale,
+    sizes: SizeScale,
+    breakpoints: BreakpointScale,
+    charts: ChartPalette,
 }
 
 impl Default for VibeColors {
@@ -31,6 +75,8 @@ impl Default for VibeColors {
 
                 // Neutral colors
                 surface: "white".to_string(),
+                surface_raised: "white".to_string(),
+                surface_overlay: "white".to_string(),
                 background: "gray-50".to_string(),
                 foreground: "gray-900".to_string(),
                 border: "gray-200".to_string(),
@@ -46,7 +92,34 @@ impl Default for VibeColors {
                 interactive_hover: "jupiter-blue-600".to_string(),
                 interactive_active: "jupiter-blue-700".to_string(),
                 interactive_disabled: "gray-300".to_string(),
+
+                // Soft semantic variants, derived
+                success_soft_bg: None,
+                success_soft_text: None,
+                warning_soft_bg: None,
+                warning_soft_text: None,
+                error_soft_bg: None,
+                error_soft_text: None,
+                info_soft_bg: None,
+                info_soft_text: None,
+
+                // Domain-specific statuses
+                custom_statuses: Vec::new(),
+                custom_intents: Vec::new(),
             },
+            density: Density::Comfortable,
+            data_display: DataDisplayTokens::default(),
+            selection: SelectionTokens::default(),
+            components: ComponentDefaults::default(),
+            elevation: ElevationScale::default(),
+            radius: RadiusScale::default(),
+            borders: BorderScale::default(),
+            motion: MotionScale::default(),
+            state_layers: StateLayerScale::default(),
+            glass: GlassScale::default(),
+            sizes: SizeScale::default(),
+            breakpoints: BreakpointScale::default(),
+            charts: ChartPalette::default(),
         }
     }
 }
@@ -55,6 +128,58 @@ impl ColorProvider for VibeColors {
     fn palette(&self) -> &ColorPalette {
         &self.palette
     }
+
+    fn density(&self) -> Density {
+        self.density
+    }
+
+    fn data_display(&self) -> DataDisplayTokens {
+        self.data_display
+    }
+
+    fn selection_tokens(&self) -> SelectionTokens {
+        self.selection
+    }
+
+    fn component_defaults(&self) -> ComponentDefaults {
+        self.components
+    }
+
+    fn elevation_scale(&self) -> ElevationScale {
+        self.elevation.clone()
+    }
+
+    fn radius_scale(&self) -> RadiusScale {
+        self.radius.clone()
+    }
+
+    fn border_scale(&self) -> BorderScale {
+        self.borders.clone()
+    }
+
+    fn motion_scale(&self) -> MotionScale {
+        self.motion
+    }
+
+    fn state_layer_scale(&self) -> StateLayerScale {
+        self.state_layers
+    }
+
+    fn glass_scale(&self) -> GlassScale {
+        self.glass
+    }
+
+    fn size_scale(&self) -> SizeScale {
+        self.sizes.clone()
+    }
+
+    fn breakpoint_scale(&self) -> BreakpointScale {
+        self.breakpoints
+    }
+
+    fn chart_palette(&self) -> ChartPalette {
+        self.charts.clone()
+    }
 }
 
 impl VibeColors {
@@ -67,10 +192,214 @@ impl VibeColors {
     pub fn with_overrides(overrides: impl Fn(&mut ColorPalette)) -> Self {
         let mut palette = ColorPalette::default();
         overrides(&mut palette);
-        Self { palette }
+        Self {
+            palette,
+            ..Self::default()
+        }
+    }
+
+    /// Legacy WaterWellness palette using the `water-*` color names
+    ///
+    /// For apps migrating from WaterWellness whose Tailwind config and
+    /// markup still use classes like `bg-water-blue-500`. Only the brand and
+    /// interactive colors (and the brand chart colors) differ from the Jupiter
+    /// defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::prelude::*;
+    ///
+    /// let colors = VibeColors::water_wellness();
+    ///
+    /// assert_eq!(colors.bg_class(Color::Primary), "bg-water-blue-500");
+    /// assert_eq!(colors.text_class(Color::Error), "text-red-500");
+    /// ```
+    pub fn water_wellness() -> Self {
+        Self::with_overrides(|palette| {
+            palette.primary = "water-blue-500".to_string();
+            palette.secondary = "water-green-500".to_string();
+            palette.accent = "water-orange-500".to_string();
+            palette.interactive = "water-blue-500".to_string();
+            palette.interactive_hover = "water-blue-600".to_string();
+            palette.interactive_active = "water-blue-700".to_string();
+        })
+        .with_chart_palette(ChartPalette::water_wellness())
+    }
+
+    /// Generate a full palette from a single brand color
+    ///
+    /// The brand color becomes primary and the interactive color, with
+    /// darker hover/active shades, an analogous secondary and a
+    /// complementary accent, and chart series and ramps follow the brand
+    /// (see `ChartPalette::from_brand`). Semantic, neutral and text colors
+    /// keep the Jupiter defaults; text on the brand color comes from `on_color`, which
+    /// picks dark text for light brands. Returns `None` if `hex` is not a
+    /// `#rrggbb` or `#rgb` color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::prelude::*;
+    ///
+    /// let colors = VibeColors::from_brand_hex("#7C3AED").unwrap();
+    ///
+    /// assert_eq!(colors.bg_class(Color::Primary), "bg-[#7c3aed]");
+    /// assert_eq!(colors.on_color(Color::Primary), Color::TextInverse);
+    ///
+    /// let light = VibeColors::from_brand_hex("#fde047").unwrap();
+    /// assert_eq!(light.on_color(Color::Primary), Color::TextPrimary);
+    /// ```
+    pub fn from_brand_hex(hex: &str) -> Option<Self> {
+        let brand = ColorValue::from_hex(hex)?;
+        let hover = brand.darken(0.08);
+        let active = brand.darken(0.16);
+
+        Some(
+            Self::with_overrides(|palette| {
+                palette.primary = brand.palette_value();
+                palette.secondary = brand.rotate_hue(-40.0).palette_value();
+                palette.accent = brand.rotate_hue(180.0).palette_value();
+                palette.interactive = brand.palette_value();
+                palette.interactive_hover = hover.palette_value();
+                palette.interactive_active = active.palette_value();
+            })
+            .with_chart_palette(ChartPalette::from_brand(brand)),
+        )
+    }
+
+    /// Register a custom status for badges and states
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::prelude::*;
+    ///
+    /// let colors = VibeColors::default().with_status("pending", "violet-500");
+    ///
+    /// assert_eq!(colors.status_color("pending"), Some("violet-500"));
+    /// assert_eq!(colors.status_color("success"), Some("green-500"));
+    /// assert_eq!(colors.status_color("archived"), None);
+    /// ```
+    pub fn with_status(mut self, name: impl Into<String>, color: impl Into<String>) -> Self {
+        self.palette.add_status(name, color);
+        self
+    }
+
+    /// Register a custom action intent for `ActionIntent::Custom`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use jupiter_design_system::core::IntentEmphasis;
+    /// use jupiter_design_system::prelude::*;
+    ///
+    /// let colors = VibeColors::default().with_intent("publish", "teal-600", IntentEmphasis::Filled);
+    ///
+    /// let classes = colors.custom_intent_classes("publish").unwrap();
+    /// assert!(classes.contains("bg-teal-600"));
+    /// assert_eq!(colors.custom_intent_classes("archive"), None);
+    /// ```
+    pub fn with_intent(
+        mut self,
+        name: impl Into<String>,
+        color: impl Into<String>,
+        emphasis: IntentEmphasis,
+    ) -> Self {
+        self.palette.add_intent(name, color, emphasis);
+        self
+    }
+
+    /// Set the default density for every builder using these colors
+    pub fn with_density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Set the density and striping defaults for tables and lists
+    pub fn with_data_display(mut self, data_display: DataDisplayTokens) -> Self {
+        self.data_display = data_display;
+        self
+    }
+
+    /// Set the colors of selected items in selection groups
+    pub fn with_selection(mut self, selection: SelectionTokens) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Set the defaults for builders created with `for_theme`
+    pub fn with_component_defaults(mut self, components: ComponentDefaults) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Set the elevation scale for every builder using these colors
+    pub fn with_elevation(mut self, elevation: ElevationScale) -> Self {
+        self.elevation = elevation;
+        self
+    }
+
+    /// Set the radius scale for every builder using these colors
+    pub fn with_radius(mut self, radius: RadiusScale) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Set the border widths for every builder using these colors
+    pub fn with_borders(mut self, borders: BorderScale) -> Self {
+        self.borders = borders;
+        self
+    }
+
+    /// Set the transition durations for every builder using these colors
+    pub fn with_motion(mut self, motion: MotionScale) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Set the state layer opacities for every builder using these colors
+    pub fn with_state_layers(mut self, state_layers: StateLayerScale) -> Self {
+        self.state_layers = state_layers;
+        self
+    }
+
+    /// Set the glass surface tint, blur and border for every builder using
+    /// these colors
+    pub fn with_glass(mut self, glass: GlassScale) -> Self {
+        self.glass = glass;
+        self
+    }
+
+    /// Set the control heights for every builder using these colors
+    pub fn with_sizes(mut self, sizes: SizeScale) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Set the breakpoint widths for every builder using these colors
+    pub fn with_breakpoints(mut self, breakpoints: BreakpointScale) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    /// Set the data visualization colors for charts using these colors
+    pub fn with_chart_palette(mut self, charts: ChartPalette) -> Self {
+        self.charts = charts;
+        self
     }
 }
 
+/// Former name of [`VibeColors`]
+///
+/// The alias keeps the Jupiter palette. Apps whose Tailwind config still
+/// defines the `water-*` colors should use [`VibeColors::water_wellness`].
+#[deprecated(
+    since = "0.1.0",
+    note = "renamed to `VibeColors`; use `VibeColors::water_wellness()` for the legacy palette"
+)]
+pub type WaterWellnessColors = VibeColors;
+
 /// Jupiter Design System theme
 #[derive(Debug, Clone, Default)]
 pub struct VibeTheme {
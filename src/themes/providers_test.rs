@@ -0,0 +1,359 @@
+//! Compatibility suite: every builder works with every shipped provider
+
+#[cfg(test)]
+mod tests {
+    use crate::builders::{dropzone_styles, position_styles, scroll_styles};
+    use crate::core::color::{Color, ColorProvider};
+    use crate::themes::VibeColors;
+
+    /// Every provider the crate ships or documents
+    fn shipped_providers() -> Vec<(&'static str, VibeColors)> {
+        vec![
+            ("jupiter", VibeColors::default()),
+            ("water-wellness", VibeColors::water_wellness()),
+            ("brand", VibeColors::from_brand_hex("#7c3aed").unwrap()),
+        ]
+    }
+
+    fn class_for(colors: &impl ColorProvider, prefix: &str, color: Color) -> String {
+        format!("{}-{}", prefix, colors.resolve_color(color))
+    }
+
+    fn assert_builders_work<C: ColorProvider + Clone>(name: &str, colors: C) {
+        let primary_bg = class_for(&colors, "bg", Color::Primary);
+
+        assert!(
+            !dropzone_styles(colors.clone()).classes().is_empty(),
+            "{name}: dropzone"
+        );
+        assert!(
+            scroll_styles(colors.clone())
+                .branded_scrollbar()
+                .classes()
+                .contains(&primary_bg),
+            "{name}: scroll"
+        );
+        assert!(
+            !position_styles().top_end().classes().is_empty(),
+            "{name}: position"
+        );
+
+        #[cfg(feature = "buttons")]
+        {
+            use crate::builders::{button_styles, interactive_input};
+
+            assert!(
+                button_styles(colors.clone())
+                    .primary()
+                    .classes()
+                    .contains(&primary_bg),
+                "{name}: button"
+            );
+            assert!(
+                interactive_input(colors.clone())
+                    .standard_style()
+                    .build()
+                    .contains(&class_for(&colors, "border", Color::Border)),
+                "{name}: input"
+            );
+        }
+
+        #[cfg(feature = "cards")]
+        {
+            use crate::builders::card_styles;
+
+            assert!(
+                card_styles(colors.clone()).classes().contains(&class_for(
+                    &colors,
+                    "bg",
+                    Color::Surface
+                )),
+                "{name}: card"
+            );
+        }
+
+        #[cfg(feature = "typography")]
+        {
+            use crate::builders::{
+                callout_styles, detail_list_styles, labeled_value_styles, message_styles,
+                text_styles,
+            };
+
+            assert!(
+                text_styles(colors.clone())
+                    .title()
+                    .classes()
+                    .contains(&class_for(&colors, "text", Color::TextPrimary)),
+                "{name}: text"
+            );
+            assert!(
+                !callout_styles(colors.clone()).classes().is_empty(),
+                "{name}: callout"
+            );
+            assert!(
+                !detail_list_styles(colors.clone()).classes().is_empty(),
+                "{name}: detail list"
+            );
+            assert!(
+                !labeled_value_styles(colors.clone()).classes().is_empty(),
+                "{name}: labeled value"
+            );
+            assert!(
+                !message_styles(colors.clone()).bubble_classes().is_empty(),
+                "{name}: message"
+            );
+        }
+
+        #[cfg(feature = "layout")]
+        {
+            use crate::builders::layout_styles;
+
+            assert!(
+                layout_styles(colors.clone())
+                    .divider_bottom()
+                    .classes()
+                    .contains(&class_for(&colors, "border", Color::Border)),
+                "{name}: layout"
+            );
+        }
+
+        #[cfg(feature = "selection")]
+        {
+            use crate::builders::{facet_styles, selection_styles, tree_styles};
+
+            assert!(
+                !selection_styles(colors.clone())
+                    .selected()
+                    .item_classes()
+                    .is_empty(),
+                "{name}: selection"
+            );
+            assert!(
+                !facet_styles(colors.clone()).container_classes().is_empty(),
+                "{name}: facet"
+            );
+            assert!(
+                !tree_styles(colors.clone()).container_classes().is_empty(),
+                "{name}: tree"
+            );
+        }
+
+        #[cfg(all(feature = "buttons", feature = "selection"))]
+        {
+            use crate::builders::search_bar_styles;
+
+            assert!(
+                !search_bar_styles(colors.clone()).field_classes().is_empty(),
+                "{name}: search"
+            );
+        }
+
+        #[cfg(feature = "states")]
+        {
+            use crate::builders::state_styles;
+
+            assert!(
+                state_styles(colors.clone())
+                    .loading()
+                    .classes()
+                    .contains(&class_for(&colors, "text", Color::Primary)),
+                "{name}: state"
+            );
+        }
+
+        #[cfg(feature = "product")]
+        {
+            use crate::builders::product_styles;
+
+            assert!(
+                !product_styles(colors.clone()).classes().is_empty(),
+                "{name}: product"
+            );
+        }
+
+        #[cfg(all(feature = "buttons", feature = "cards", feature = "typography"))]
+        {
+            use crate::builders::coachmark_styles;
+
+            assert!(
+                !coachmark_styles(colors.clone()).body_classes().is_empty(),
+                "{name}: coachmark"
+            );
+        }
+    }
+
+    #[test]
+    fn test_builders_work_with_every_shipped_provider() {
+        for (name, colors) in shipped_providers() {
+            assert_builders_work(name, colors);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_builders_work_with_shared_providers() {
+        use std::sync::Arc;
+
+        for (name, colors) in shipped_providers() {
+            assert_builders_work(name, Arc::new(colors));
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_builders_work_with_deprecated_alias() {
+        use crate::themes::WaterWellnessColors;
+
+        assert_builders_work("alias", WaterWellnessColors::default());
+    }
+
+    #[test]
+    fn test_water_wellness_palette() {
+        let colors = VibeColors::water_wellness();
+
+        assert_eq!(colors.resolve_color(Color::Primary), "water-blue-500");
+        assert_eq!(
+            colors.resolve_color(Color::InteractiveHover),
+            "water-blue-600"
+        );
+        // Semantic and neutral colors are shared with Jupiter
+        assert_eq!(
+            colors.resolve_color(Color::Error),
+            VibeColors::default().resolve_color(Color::Error)
+        );
+        assert_eq!(
+            colors.palette().surface,
+            VibeColors::default().palette().surface
+        );
+
+        // Chart series follow the legacy names too
+        let chart = colors.chart_palette();
+        assert_eq!(chart.fill_class(0), "fill-water-blue-500");
+        assert!(chart.sequential.iter().all(|c| !c.starts_with("jupiter-")));
+    }
+
+    /// Default output of the color-bearing builders, by name
+    fn builder_outputs<C: ColorProvider + Clone>(colors: C) -> Vec<(&'static str, String)> {
+        let mut outputs = vec![(
+            "scroll",
+            scroll_styles(colors.clone()).branded_scrollbar().classes(),
+        )];
+
+        #[cfg(feature = "buttons")]
+        {
+            use crate::builders::button_styles;
+
+            for variant in ["primary", "secondary", "ghost", "error", "link"] {
+                outputs.push((
+                    "button",
+                    button_styles(colors.clone()).variant_str(variant).classes(),
+                ));
+            }
+        }
+
+        #[cfg(feature = "cards")]
+        {
+            use crate::builders::card_styles;
+
+            for surface in ["standard", "elevated", "branded", "glass", "glass-light"] {
+                outputs.push((
+                    "card",
+                    card_styles(colors.clone())
+                        .surface_str(surface)
+                        .selectable_interaction()
+                        .is_selected()
+                        .classes(),
+                ));
+            }
+        }
+
+        #[cfg(feature = "typography")]
+        {
+            use crate::builders::text_styles;
+
+            outputs.push(("text", text_styles(colors.clone()).title().classes()));
+        }
+
+        #[cfg(feature = "layout")]
+        {
+            use crate::builders::layout_styles;
+
+            outputs.push((
+                "layout",
+                layout_styles(colors.clone()).divider_bottom().classes(),
+            ));
+        }
+
+        #[cfg(feature = "selection")]
+        {
+            use crate::builders::selection_styles;
+
+            outputs.push((
+                "selection",
+                selection_styles(colors.clone()).selected().item_classes(),
+            ));
+        }
+
+        #[cfg(feature = "states")]
+        {
+            use crate::builders::state_styles;
+
+            for intent in ["loading", "empty", "error", "success", "warning"] {
+                outputs.push((
+                    "state",
+                    state_styles(colors.clone()).intent_str(intent).classes(),
+                ));
+            }
+        }
+
+        #[cfg(feature = "product")]
+        {
+            use crate::builders::product_styles;
+
+            outputs.push(("product", product_styles(colors.clone()).hero().classes()));
+        }
+
+        outputs
+    }
+
+    #[test]
+    fn test_no_jupiter_classes_leak_into_other_themes() {
+        for (name, colors) in shipped_providers() {
+            if name == "jupiter" {
+                continue;
+            }
+            for (builder, classes) in builder_outputs(colors) {
+                assert!(
+                    !classes.contains("jupiter-"),
+                    "{name}: {builder} emits jupiter classes: {classes}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_builder_colors_come_from_the_provider() {
+        use crate::utils::ThemeLint;
+
+        for (name, colors) in shipped_providers() {
+            let lint = ThemeLint::new(&colors).allow_shades();
+            for (builder, classes) in builder_outputs(colors.clone()) {
+                let findings = lint.check(&classes);
+                assert!(findings.is_empty(), "{name}: {builder}: {findings:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_builders_rebrand_with_the_provider() {
+        let jupiter = builder_outputs(VibeColors::default());
+        let brand = builder_outputs(VibeColors::from_brand_hex("#7c3aed").unwrap());
+
+        // Branded outputs differ between themes; nothing keeps the old brand
+        for ((builder, before), (_, after)) in jupiter.iter().zip(&brand) {
+            if before.contains("jupiter-blue-500") {
+                assert_ne!(before, after, "{builder} ignores the provider");
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Class diffing for comparing builder output
+//!
+//! Reports which utility classes change between two class strings, e.g. when
+//! switching a variant or upgrading a theme.
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Classes added and removed between two class strings
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassDiff {
+    /// Classes present only in the new string, in order of appearance
+    pub added: Vec<String>,
+    /// Classes present only in the old string, in order of appearance
+    pub removed: Vec<String>,
+}
+
+impl ClassDiff {
+    /// Whether both class strings contain the same classes
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl fmt::Display for ClassDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let changes: Vec<String> = self
+            .removed
+            .iter()
+            .map(|class| format!("-{}", class))
+            .chain(self.added.iter().map(|class| format!("+{}", class)))
+            .collect();
+        write!(f, "{}", changes.join(" "))
+    }
+}
+
+/// Compare two class strings, ignoring order and duplicates
+///
+/// # Examples
+///
+/// ```rust
+/// use jupiter_design_system::utils::class_diff;
+///
+/// let diff = class_diff("px-4 py-2 rounded-md", "px-3 py-2 rounded-md");
+///
+/// assert_eq!(diff.added, vec!["px-3"]);
+/// assert_eq!(diff.removed, vec!["px-4"]);
+/// assert_eq!(diff.to_string(), "-px-4 +px-3");
+/// ```
+pub fn class_diff(a: &str, b: &str) -> ClassDiff {
+    let old: BTreeSet<&str> = a.split_whitespace().collect();
+    let new: BTreeSet<&str> = b.split_whitespace().collect();
+
+    ClassDiff {
+        added: unique_missing(b, &old),
+        removed: unique_missing(a, &new),
+    }
+}
+
+fn unique_missing(classes: &str, other: &BTreeSet<&str>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    classes
+        .split_whitespace()
+        .filter(|class| !other.contains(class) && seen.insert(*class))
+        .map(|class| class.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "class_diff_test.rs"]
+mod class_diff_test;
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::class_diff::{class_diff, ClassDiff};
+
+    #[test]
+    fn test_class_diff_identical() {
+        let diff = class_diff("a b c", "c b a");
+
+        assert!(diff.is_empty());
+        assert_eq!(diff, ClassDiff::default());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_class_diff_added_and_removed() {
+        let diff = class_diff("px-4 py-2 text-sm", "px-4 py-3 text-sm shadow-md");
+
+        assert_eq!(diff.added, vec!["py-3", "shadow-md"]);
+        assert_eq!(diff.removed, vec!["py-2"]);
+        assert_eq!(diff.to_string(), "-py-2 +py-3 +shadow-md");
+    }
+
+    #[test]
+    fn test_class_diff_ignores_duplicates_and_whitespace() {
+        let diff = class_diff("  a   a b ", "b c c");
+
+        assert_eq!(diff.added, vec!["c"]);
+        assert_eq!(diff.removed, vec!["a"]);
+    }
+}
@@ -0,0 +1,400 @@
+//! CSS output budget checking for builder presets
+//!
+//! Tailwind emits one rule per distinct class, so every preset an app uses
+//! adds to the stylesheet. Responsive, dark and state variants multiply
+//! quickly: a single `bg-*` utility under three breakpoints, dark mode and
+//! hover is already twelve rules. [`CssBudget`] takes the presets an app
+//! renders, computes the union of their classes, estimates the generated
+//! CSS weight per utility category and warns when a configured budget is
+//! exceeded.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::utils::css_budget::{BudgetWarning, CssBudget};
+//!
+//! let report = CssBudget::new()
+//!     .max_variants(2)
+//!     .preset("cta", "px-4 md:px-6 bg-blue-500 hover:bg-blue-500/90")
+//!     .preset("hero", "px-4 md:px-4 lg:px-4")
+//!     .report();
+//!
+//! assert_eq!(report.classes.len(), 6);
+//! assert!(report.estimated_bytes > 0);
+//! assert!(matches!(
+//!     report.warnings[0],
+//!     BudgetWarning::VariantExplosion { variants: 3, .. }
+//! ));
+//! ```
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Utility categories used to break down CSS weight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UtilityCategory {
+    /// Display, position, flex and grid placement
+    Layout,
+    /// Padding, margin and gaps
+    Spacing,
+    /// Widths and heights
+    Sizing,
+    /// Font size, weight, line height and text treatment
+    Typography,
+    /// Text, background, gradient and SVG colors
+    Color,
+    /// Border widths and colors, radius, rings and outlines
+    Border,
+    /// Shadows, opacity, blur and filters
+    Effects,
+    /// Transitions, animations and transforms
+    Transition,
+    /// Cursor, pointer, selection and scroll behavior
+    Interactivity,
+    /// Anything unrecognized, including the builders' own marker classes
+    Other,
+}
+
+impl UtilityCategory {
+    /// Categorize a class, ignoring its variants
+    pub fn of(class: &str) -> Self {
+        let utility = base_utility(class);
+        let utility = utility.trim_start_matches('!').trim_start_matches('-');
+        let prefix = utility.split('-').next().unwrap_or(utility);
+
+        match prefix {
+            "p" | "px" | "py" | "pt" | "pr" | "pb" | "pl" | "ps" | "pe" | "m" | "mx" | "my"
+            | "mt" | "mr" | "mb" | "ml" | "ms" | "me" | "gap" | "space" => UtilityCategory::Spacing,
+            "w" | "h" | "min" | "max" | "size" => UtilityCategory::Sizing,
+            "text" if is_text_typography(utility) => UtilityCategory::Typography,
+            "font" | "leading" | "tracking" | "uppercase" | "lowercase" | "capitalize"
+            | "italic" | "truncate" | "line" | "whitespace" | "break" | "tabular" | "underline"
+            | "decoration" | "antialiased" => UtilityCategory::Typography,
+            "text" | "bg" | "from" | "via" | "to" | "fill" | "stroke" | "accent" | "caret" => {
+                UtilityCategory::Color
+            }
+            "border" | "rounded" | "ring" | "outline" | "divide" => UtilityCategory::Border,
+            "shadow" | "opacity" | "blur" | "backdrop" | "mix" | "filter" | "drop" => {
+                UtilityCategory::Effects
+            }
+            "transition" | "duration" | "ease" | "delay" | "animate" | "transform" | "scale"
+            | "rotate" | "translate" | "origin" => UtilityCategory::Transition,
+            "cursor" | "pointer" | "select" | "resize" | "scroll" | "snap" | "touch"
+            | "appearance" | "overscroll" | "will" => UtilityCategory::Interactivity,
+            "flex" | "grid" | "inline" | "block" | "hidden" | "contents" | "absolute"
+            | "relative" | "fixed" | "sticky" | "static" | "inset" | "top" | "right" | "bottom"
+            | "left" | "start" | "end" | "z" | "items" | "justify" | "self" | "order" | "col"
+            | "row" | "overflow" | "place" | "content" | "float" | "clear" | "object"
+            | "aspect" | "container" | "sr" | "not" | "visible" | "invisible" | "shrink"
+            | "grow" | "basis" | "isolate" | "table" => UtilityCategory::Layout,
+            _ => UtilityCategory::Other,
+        }
+    }
+
+    /// Name used in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            UtilityCategory::Layout => "layout",
+            UtilityCategory::Spacing => "spacing",
+            UtilityCategory::Sizing => "sizing",
+            UtilityCategory::Typography => "typography",
+            UtilityCategory::Color => "color",
+            UtilityCategory::Border => "border",
+            UtilityCategory::Effects => "effects",
+            UtilityCategory::Transition => "transition",
+            UtilityCategory::Interactivity => "interactivity",
+            UtilityCategory::Other => "other",
+        }
+    }
+}
+
+/// Classes and estimated CSS weight for one utility category
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryUsage {
+    pub category: UtilityCategory,
+    pub classes: usize,
+    pub estimated_bytes: usize,
+}
+
+/// A budget the presets exceed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BudgetWarning {
+    /// More distinct classes than allowed
+    TooManyClasses { count: usize, max: usize },
+    /// Estimated CSS larger than allowed
+    TooManyBytes { bytes: usize, max: usize },
+    /// One utility emitted under more variant combinations than allowed
+    VariantExplosion {
+        utility: String,
+        variants: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for BudgetWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetWarning::TooManyClasses { count, max } => {
+                write!(f, "{} distinct classes exceed the budget of {}", count, max)
+            }
+            BudgetWarning::TooManyBytes { bytes, max } => write!(
+                f,
+                "estimated {} bytes of CSS exceed the budget of {}",
+                bytes, max
+            ),
+            BudgetWarning::VariantExplosion {
+                utility,
+                variants,
+                max,
+            } => write!(
+                f,
+                "\"{}\" is emitted under {} variant combinations (budget {})",
+                utility, variants, max
+            ),
+        }
+    }
+}
+
+/// Result of checking presets against a budget
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BudgetReport {
+    /// Distinct classes across all presets, sorted
+    pub classes: Vec<String>,
+    /// Estimated size of the generated CSS in bytes
+    pub estimated_bytes: usize,
+    /// Breakdown by utility category, omitting unused categories
+    pub categories: Vec<CategoryUsage>,
+    /// Exceeded budgets
+    pub warnings: Vec<BudgetWarning>,
+}
+
+impl BudgetReport {
+    /// Whether every budget is met
+    pub fn within_budget(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Get the usage of one category
+    pub fn category(&self, category: UtilityCategory) -> Option<&CategoryUsage> {
+        self.categories
+            .iter()
+            .find(|usage| usage.category == category)
+    }
+}
+
+/// Budget checker for the presets an app renders
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssBudget {
+    max_classes: usize,
+    max_bytes: usize,
+    max_variants: usize,
+    presets: Vec<(String, String)>,
+}
+
+impl Default for CssBudget {
+    fn default() -> Self {
+        Self {
+            max_classes: 600,
+            max_bytes: 50_000,
+            max_variants: 8,
+            presets: Vec::new(),
+        }
+    }
+}
+
+impl CssBudget {
+    /// Create a budget with the default limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of distinct classes
+    pub fn max_classes(mut self, max: usize) -> Self {
+        self.max_classes = max;
+        self
+    }
+
+    /// Set the maximum estimated CSS size in bytes
+    pub fn max_bytes(mut self, max: usize) -> Self {
+        self.max_bytes = max;
+        self
+    }
+
+    /// Set the maximum variant combinations per utility
+    pub fn max_variants(mut self, max: usize) -> Self {
+        self.max_variants = max;
+        self
+    }
+
+    /// Add the classes of one preset
+    pub fn preset(mut self, name: impl Into<String>, classes: impl Into<String>) -> Self {
+        self.presets.push((name.into(), classes.into()));
+        self
+    }
+
+    /// Add several presets, e.g. from `themes::theme_presets`
+    pub fn presets<N, S>(mut self, presets: impl IntoIterator<Item = (N, S)>) -> Self
+    where
+        N: Into<String>,
+        S: Into<String>,
+    {
+        for (name, classes) in presets {
+            self.presets.push((name.into(), classes.into()));
+        }
+        self
+    }
+
+    /// Compute the class union, its estimated weight and any warnings
+    pub fn report(&self) -> BudgetReport {
+        let classes: BTreeSet<&str> = self
+            .presets
+            .iter()
+            .flat_map(|(_, classes)| classes.split_whitespace())
+            .collect();
+
+        let mut categories: BTreeMap<UtilityCategory, CategoryUsage> = BTreeMap::new();
+        let mut variants: BTreeMap<&str, usize> = BTreeMap::new();
+        let mut estimated_bytes = 0;
+
+        for class in &classes {
+            let bytes = estimate_rule_bytes(class);
+            estimated_bytes += bytes;
+
+            let category = UtilityCategory::of(class);
+            let usage = categories.entry(category).or_insert(CategoryUsage {
+                category,
+                classes: 0,
+                estimated_bytes: 0,
+            });
+            usage.classes += 1;
+            usage.estimated_bytes += bytes;
+
+            *variants.entry(base_utility(class)).or_insert(0) += 1;
+        }
+
+        let mut warnings = Vec::new();
+        if classes.len() > self.max_classes {
+            warnings.push(BudgetWarning::TooManyClasses {
+                count: classes.len(),
+                max: self.max_classes,
+            });
+        }
+        if estimated_bytes > self.max_bytes {
+            warnings.push(BudgetWarning::TooManyBytes {
+                bytes: estimated_bytes,
+                max: self.max_bytes,
+            });
+        }
+        for (utility, count) in variants {
+            if count > self.max_variants {
+                warnings.push(BudgetWarning::VariantExplosion {
+                    utility: utility.to_string(),
+                    variants: count,
+                    max: self.max_variants,
+                });
+            }
+        }
+
+        BudgetReport {
+            classes: classes.into_iter().map(|class| class.to_string()).collect(),
+            estimated_bytes,
+            categories: categories.into_values().collect(),
+            warnings,
+        }
+    }
+}
+
+/// Strip variant prefixes (`md:`, `hover:`, `dark:`...) from a class
+///
+/// Colons inside arbitrary values (`[...]`) are not variant separators.
+pub(crate) fn base_utility(class: &str) -> &str {
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (index, ch) in class.char_indices() {
+        match ch {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ':' if depth == 0 => start = index + 1,
+            _ => {}
+        }
+    }
+    &class[start..]
+}
+
+fn variant_prefixes(class: &str) -> impl Iterator<Item = &str> {
+    let base = base_utility(class);
+    class[..class.len() - base.len()]
+        .split(':')
+        .filter(|variant| !variant.is_empty())
+}
+
+fn is_text_typography(utility: &str) -> bool {
+    let value = &utility["text-".len().min(utility.len())..];
+    matches!(
+        value,
+        "xs" | "sm"
+            | "base"
+            | "lg"
+            | "xl"
+            | "left"
+            | "center"
+            | "right"
+            | "justify"
+            | "start"
+            | "end"
+            | "balance"
+            | "pretty"
+            | "wrap"
+            | "nowrap"
+            | "ellipsis"
+            | "clip"
+    ) || (value.ends_with("xl") && value[..value.len() - 2].chars().all(|c| c.is_ascii_digit()))
+        || value.starts_with("[length:")
+}
+
+/// Rough size of the rule Tailwind generates for one class
+///
+/// The escaped selector plus an average declaration block, with extra
+/// weight for pseudo-class variants and for media or container queries
+/// wrapping responsive and dark variants.
+fn estimate_rule_bytes(class: &str) -> usize {
+    const DECLARATION_BYTES: usize = 28;
+    const PSEUDO_BYTES: usize = 10;
+    const QUERY_BYTES: usize = 36;
+
+    let escapes = class
+        .chars()
+        .filter(|ch| {
+            matches!(
+                ch,
+                ':' | '/' | '[' | ']' | '.' | '#' | '%' | '(' | ')' | ','
+            )
+        })
+        .count();
+    let selector = 1 + class.len() + escapes;
+
+    let variant_bytes: usize = variant_prefixes(class)
+        .map(|variant| {
+            let is_query = variant == "dark"
+                || variant == "print"
+                || variant.starts_with("motion-")
+                || variant.starts_with('@')
+                || variant.starts_with("min-")
+                || variant.starts_with("max-")
+                || matches!(variant, "sm" | "md" | "lg" | "xl" | "2xl");
+            if is_query {
+                QUERY_BYTES
+            } else {
+                PSEUDO_BYTES
+            }
+        })
+        .sum();
+
+    selector + DECLARATION_BYTES + variant_bytes
+}
+
+#[cfg(test)]
+#[path = "css_budget_test.rs"]
+mod css_budget_test;
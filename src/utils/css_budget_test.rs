@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::css_budget::{BudgetWarning, CssBudget, UtilityCategory};
+
+    #[test]
+    fn test_union_deduplicates_presets() {
+        let report = CssBudget::new()
+            .preset("a", "px-4 py-2 rounded-md")
+            .preset("b", "px-4 rounded-md font-medium")
+            .report();
+
+        assert_eq!(
+            report.classes,
+            vec!["font-medium", "px-4", "py-2", "rounded-md"]
+        );
+        assert!(report.within_budget());
+    }
+
+    #[test]
+    fn test_categories() {
+        assert_eq!(UtilityCategory::of("md:px-4"), UtilityCategory::Spacing);
+        assert_eq!(UtilityCategory::of("text-sm"), UtilityCategory::Typography);
+        assert_eq!(UtilityCategory::of("text-2xl"), UtilityCategory::Typography);
+        assert_eq!(
+            UtilityCategory::of("hover:text-gray-900"),
+            UtilityCategory::Color
+        );
+        assert_eq!(UtilityCategory::of("border-b-2"), UtilityCategory::Border);
+        assert_eq!(
+            UtilityCategory::of("-translate-y-1"),
+            UtilityCategory::Transition
+        );
+        assert_eq!(
+            UtilityCategory::of("[&::-webkit-scrollbar]:hidden"),
+            UtilityCategory::Layout
+        );
+        assert_eq!(UtilityCategory::of("card-pattern"), UtilityCategory::Other);
+    }
+
+    #[test]
+    fn test_category_breakdown() {
+        let report = CssBudget::new()
+            .preset("button", "px-4 py-2 bg-blue-500 text-white text-sm")
+            .report();
+
+        let spacing = report.category(UtilityCategory::Spacing).unwrap();
+        assert_eq!(spacing.classes, 2);
+        assert_eq!(report.category(UtilityCategory::Color).unwrap().classes, 2);
+        assert!(report.category(UtilityCategory::Effects).is_none());
+        assert_eq!(
+            report
+                .categories
+                .iter()
+                .map(|usage| usage.estimated_bytes)
+                .sum::<usize>(),
+            report.estimated_bytes
+        );
+    }
+
+    #[test]
+    fn test_variants_add_weight() {
+        let plain = CssBudget::new().preset("a", "bg-blue-500").report();
+        let hover = CssBudget::new().preset("a", "hover:bg-blue-500").report();
+        let responsive = CssBudget::new().preset("a", "md:bg-blue-500").report();
+
+        assert!(hover.estimated_bytes > plain.estimated_bytes);
+        assert!(responsive.estimated_bytes > hover.estimated_bytes);
+    }
+
+    #[test]
+    fn test_class_and_byte_budgets() {
+        let report = CssBudget::new()
+            .max_classes(2)
+            .max_bytes(10)
+            .preset("a", "px-4 py-2 rounded-md")
+            .report();
+
+        assert_eq!(
+            report.warnings[0],
+            BudgetWarning::TooManyClasses { count: 3, max: 2 }
+        );
+        assert!(matches!(
+            report.warnings[1],
+            BudgetWarning::TooManyBytes { max: 10, .. }
+        ));
+        assert!(!report.within_budget());
+    }
+
+    #[test]
+    fn test_variant_explosion() {
+        let classes = ["", "sm:", "md:", "lg:", "dark:", "dark:md:", "hover:"]
+            .iter()
+            .map(|variant| format!("{}bg-blue-500", variant))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let report = CssBudget::new()
+            .max_variants(4)
+            .preset("hero", classes)
+            .report();
+
+        assert_eq!(
+            report.warnings,
+            vec![BudgetWarning::VariantExplosion {
+                utility: "bg-blue-500".to_string(),
+                variants: 7,
+                max: 4,
+            }]
+        );
+        assert_eq!(
+            report.warnings[0].to_string(),
+            "\"bg-blue-500\" is emitted under 7 variant combinations (budget 4)"
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_values_are_not_variants() {
+        let report = CssBudget::new()
+            .max_variants(1)
+            .preset("a", "bg-[url(a:b)] hover:bg-[url(a:b)]")
+            .report();
+
+        assert!(matches!(
+            &report.warnings[0],
+            BudgetWarning::VariantExplosion { utility, variants: 2, .. } if utility == "bg-[url(a:b)]"
+        ));
+    }
+
+    #[test]
+    fn test_theme_presets_fit_default_budget() {
+        use crate::themes::{theme_presets, VibeColors};
+
+        let report = CssBudget::new()
+            .presets(theme_presets(&VibeColors::default()))
+            .report();
+
+        assert!(report.within_budget(), "{:?}", report.warnings);
+        assert!(!report.classes.is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+//! Configuration fingerprints for memoization
+//!
+//! Builders implement `PartialEq`, `Eq` and `Hash` over their configuration
+//! and color provider, so they can be compared directly (Yew properties) or
+//! reduced to a [`fingerprint`] and used as a dependency key (Dioxus
+//! `use_memo`) to skip rebuilding unchanged class strings.
+//!
+//! Fingerprints are stable within one process only; do not persist them.
+//! Requires the `std` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::prelude::*;
+//! use jupiter_design_system::utils::fingerprint;
+//!
+//! let colors = VibeColors::default();
+//! let a = button_styles(colors.clone()).primary().large();
+//! let b = button_styles(colors.clone()).large().primary();
+//! let c = button_styles(colors).secondary().large();
+//!
+//! assert_eq!(a, b);
+//! assert_eq!(fingerprint(&a), fingerprint(&b));
+//! assert_ne!(fingerprint(&a), fingerprint(&c));
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Hash a builder's configuration into a memoization key
+pub fn fingerprint<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(all(test, feature = "buttons", feature = "cards", feature = "typography"))]
+#[path = "fingerprint_test.rs"]
+mod fingerprint_test;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use crate::builders::button::button_styles;
+    use crate::builders::card::card_styles;
+    use crate::builders::text::text_styles;
+    use crate::core::Density;
+    use crate::themes::VibeColors;
+    use crate::utils::fingerprint::fingerprint;
+
+    #[test]
+    fn test_equal_configurations_match() {
+        let colors = VibeColors::default();
+
+        let a = card_styles(colors.clone())
+            .elevated_surface()
+            .clickable_interaction();
+        let b = card_styles(colors)
+            .clickable_interaction()
+            .elevated_surface();
+
+        assert_eq!(a, b);
+        assert_eq!(fingerprint(&a), fingerprint(&b));
+    }
+
+    #[test]
+    fn test_configuration_changes_fingerprint() {
+        let colors = VibeColors::default();
+        let base = button_styles(colors.clone()).primary();
+
+        assert_ne!(fingerprint(&base), fingerprint(&base.clone().loading()));
+        assert_ne!(
+            fingerprint(&base),
+            fingerprint(&base.clone().custom("mt-2"))
+        );
+    }
+
+    #[test]
+    fn test_color_provider_is_part_of_fingerprint() {
+        let comfortable = VibeColors::default();
+        let compact = VibeColors::default().with_density(Density::Compact);
+
+        assert_ne!(
+            text_styles(comfortable.clone()).body(),
+            text_styles(compact.clone()).body()
+        );
+        assert_ne!(
+            fingerprint(&button_styles(comfortable)),
+            fingerprint(&button_styles(compact))
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Migration helpers for renamed design system vocabulary
+//!
+//! Older releases and downstream apps used a different set of names for
+//! themes, variants and sizes (most notably the "water" naming from the
+//! WaterWellness days). The builders' `_str` setters silently fall back on
+//! names they do not know, so a renamed value would quietly change styling.
+//! [`Migrator`] maps those legacy strings onto the current vocabulary and
+//! reports every rewrite through an optional warning callback.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::utils::migrations::{Migrator, Vocabulary};
+//! use std::cell::RefCell;
+//!
+//! let warnings = RefCell::new(Vec::new());
+//! let migrator = Migrator::new().on_warning(|migration| {
+//!     warnings.borrow_mut().push(migration.to_string());
+//! });
+//!
+//! assert_eq!(migrator.migrate(Vocabulary::Theme, "water"), "jupiter");
+//! assert_eq!(migrator.migrate(Vocabulary::Theme, "jupiter"), "jupiter");
+//! assert_eq!(warnings.borrow().len(), 1);
+//! ```
+
+use alloc::boxed::Box;
+use core::fmt;
+
+/// String vocabularies that have legacy names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Vocabulary {
+    /// Theme names, e.g. `VibeTheme::theme_description`
+    Theme,
+    /// Button variant names, e.g. `ButtonStyles::variant_str`
+    ButtonVariant,
+    /// Component size names, e.g. `ButtonStyles::size_str`
+    Size,
+}
+
+impl Vocabulary {
+    /// Name used in warnings
+    pub fn name(&self) -> &'static str {
+        match self {
+            Vocabulary::Theme => "theme",
+            Vocabulary::ButtonVariant => "button variant",
+            Vocabulary::Size => "size",
+        }
+    }
+}
+
+/// A single legacy name and its replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Migration {
+    pub vocabulary: Vocabulary,
+    pub from: &'static str,
+    pub to: &'static str,
+    pub note: &'static str,
+}
+
+impl fmt::Display for Migration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} \"{}\" is deprecated, use \"{}\" ({})",
+            self.vocabulary.name(),
+            self.from,
+            self.to,
+            self.note
+        )
+    }
+}
+
+/// Every known legacy name
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        vocabulary: Vocabulary::Theme,
+        from: "water",
+        to: "jupiter",
+        note: "the water theme was renamed to jupiter",
+    },
+    Migration {
+        vocabulary: Vocabulary::Theme,
+        from: "water-wellness",
+        to: "jupiter",
+        note: "the water theme was renamed to jupiter",
+    },
+    Migration {
+        vocabulary: Vocabulary::Theme,
+        from: "vibe",
+        to: "jupiter",
+        note: "VibeTheme is registered as jupiter",
+    },
+    Migration {
+        vocabulary: Vocabulary::ButtonVariant,
+        from: "water",
+        to: "primary",
+        note: "brand variants now follow the theme's primary color",
+    },
+    Migration {
+        vocabulary: Vocabulary::ButtonVariant,
+        from: "vibe",
+        to: "primary",
+        note: "brand variants now follow the theme's primary color",
+    },
+    Migration {
+        vocabulary: Vocabulary::ButtonVariant,
+        from: "destructive",
+        to: "error",
+        note: "destructive buttons use the error variant",
+    },
+    Migration {
+        vocabulary: Vocabulary::ButtonVariant,
+        from: "text",
+        to: "link",
+        note: "text buttons use the link variant",
+    },
+    Migration {
+        vocabulary: Vocabulary::Size,
+        from: "tiny",
+        to: "xs",
+        note: "sizes follow the xs-xl scale",
+    },
+    Migration {
+        vocabulary: Vocabulary::Size,
+        from: "huge",
+        to: "xl",
+        note: "sizes follow the xs-xl scale",
+    },
+];
+
+/// Find the migration for a legacy name, if there is one
+pub fn find_migration(vocabulary: Vocabulary, value: &str) -> Option<&'static Migration> {
+    MIGRATIONS
+        .iter()
+        .find(|migration| migration.vocabulary == vocabulary && migration.from == value)
+}
+
+/// Callback receiving each applied migration
+pub type WarningCallback<'a> = Box<dyn Fn(&Migration) + 'a>;
+
+/// Rewrites legacy names to the current vocabulary
+///
+/// Current names pass through unchanged; legacy names are rewritten and
+/// reported to the warning callback, if one is set.
+#[derive(Default)]
+pub struct Migrator<'a> {
+    on_warning: Option<WarningCallback<'a>>,
+}
+
+impl<'a> Migrator<'a> {
+    /// Create a migrator without a warning callback
+    pub fn new() -> Self {
+        Self { on_warning: None }
+    }
+
+    /// Call `callback` every time a legacy name is rewritten
+    pub fn on_warning(mut self, callback: impl Fn(&Migration) + 'a) -> Self {
+        self.on_warning = Some(Box::new(callback));
+        self
+    }
+
+    /// Map a value onto the current vocabulary
+    pub fn migrate<'v>(&self, vocabulary: Vocabulary, value: &'v str) -> &'v str {
+        match find_migration(vocabulary, value) {
+            Some(migration) => {
+                if let Some(callback) = &self.on_warning {
+                    callback(migration);
+                }
+                migration.to
+            }
+            None => value,
+        }
+    }
+
+    /// Map a theme name onto the current vocabulary
+    pub fn theme<'v>(&self, theme: &'v str) -> &'v str {
+        self.migrate(Vocabulary::Theme, theme)
+    }
+
+    /// Map a button variant onto the current vocabulary
+    pub fn button_variant<'v>(&self, variant: &'v str) -> &'v str {
+        self.migrate(Vocabulary::ButtonVariant, variant)
+    }
+
+    /// Map a size onto the current vocabulary
+    pub fn size<'v>(&self, size: &'v str) -> &'v str {
+        self.migrate(Vocabulary::Size, size)
+    }
+}
+
+impl fmt::Debug for Migrator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrator")
+            .field("on_warning", &self.on_warning.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[path = "migrations_test.rs"]
+mod migrations_test;
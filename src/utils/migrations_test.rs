@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "buttons")]
+    use crate::builders::button::button_styles;
+    use crate::themes::VibeColors;
+    use crate::utils::migrations::{find_migration, Migrator, Vocabulary, MIGRATIONS};
+    use std::cell::RefCell;
+
+    #[test]
+    fn test_current_names_pass_through() {
+        let migrator = Migrator::new();
+
+        assert_eq!(migrator.theme("jupiter"), "jupiter");
+        assert_eq!(migrator.button_variant("primary"), "primary");
+        assert_eq!(migrator.size("md"), "md");
+        assert_eq!(migrator.button_variant("unknown"), "unknown");
+    }
+
+    #[test]
+    fn test_water_aliases() {
+        let migrator = Migrator::new();
+
+        assert_eq!(migrator.theme("water"), "jupiter");
+        assert_eq!(migrator.theme("water-wellness"), "jupiter");
+        assert_eq!(migrator.button_variant("water"), "primary");
+    }
+
+    #[test]
+    fn test_vocabularies_are_separate() {
+        // "water" is a theme and a variant alias, "tiny" only a size
+        assert!(find_migration(Vocabulary::Size, "water").is_none());
+        assert!(find_migration(Vocabulary::Theme, "tiny").is_none());
+        assert_eq!(Migrator::new().size("tiny"), "xs");
+    }
+
+    #[test]
+    fn test_warning_callback() {
+        let warnings = RefCell::new(Vec::new());
+        let migrator = Migrator::new().on_warning(|migration| {
+            warnings.borrow_mut().push(migration.from);
+        });
+
+        migrator.theme("water");
+        migrator.theme("jupiter");
+        migrator.button_variant("destructive");
+
+        assert_eq!(*warnings.borrow(), vec!["water", "destructive"]);
+    }
+
+    #[test]
+    fn test_warning_message() {
+        let migration = find_migration(Vocabulary::Theme, "water").unwrap();
+
+        assert_eq!(
+            migration.to_string(),
+            "theme \"water\" is deprecated, use \"jupiter\" (the water theme was renamed to jupiter)"
+        );
+    }
+
+    #[test]
+    fn test_migrations_target_current_names() {
+        let migrator = Migrator::new();
+
+        for migration in MIGRATIONS {
+            // Targets must not themselves be legacy names
+            assert_eq!(
+                migrator.migrate(migration.vocabulary, migration.to),
+                migration.to
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "buttons")]
+    fn test_migrated_variant_styles_match_current_name() {
+        let colors = VibeColors::default();
+        let migrator = Migrator::new();
+
+        assert_eq!(
+            button_styles(colors.clone())
+                .variant_str(migrator.button_variant("destructive"))
+                .classes(),
+            button_styles(colors).variant_str("error").classes()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_color_alias() {
+        use crate::themes::WaterWellnessColors;
+
+        let colors: WaterWellnessColors = VibeColors::default();
+        assert_eq!(colors, VibeColors::default());
+    }
+}
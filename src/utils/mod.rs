@@ -1,5 +1,23 @@
 //! Utility functions for the design system
 
+pub mod class_diff;
+pub mod css_budget;
+#[cfg(feature = "std")]
+pub mod fingerprint;
+pub mod migrations;
+pub mod theme_audit;
+pub mod theme_lint;
+pub mod visibility;
+
+pub use class_diff::{class_diff, ClassDiff};
+pub use css_budget::{BudgetReport, BudgetWarning, CssBudget, UtilityCategory};
+#[cfg(feature = "std")]
+pub use fingerprint::fingerprint;
+pub use migrations::{Migration, Migrator, Vocabulary};
+pub use theme_audit::{AuditFinding, ThemeAudit};
+pub use theme_lint::{HardcodedColor, ThemeLint};
+pub use visibility::{hidden_above, hidden_below, only_on, Visibility};
+
 /// Design system utility struct
 pub struct DesignSystem;
 
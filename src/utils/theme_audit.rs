@@ -0,0 +1,517 @@
+//! Palette token coverage audit
+//!
+//! [`ThemeLint`] checks one class string at a time. [`ThemeAudit`] runs
+//! every enabled builder through its common variants and states and
+//! reports two things about a theme: palette tokens no builder consumes,
+//! and builder code paths that emit colors the theme does not provide.
+//! Use it to steer builders toward full token coverage; findings are not
+//! errors on their own.
+//!
+//! Token usage is measured with a copy of the theme whose tokens all
+//! resolve to distinct marker colors, so tokens that share a value (white
+//! surfaces and inverse text, say) are still told apart.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::core::Color;
+//! use jupiter_design_system::themes::VibeColors;
+//! use jupiter_design_system::utils::ThemeAudit;
+//!
+//! let audit = ThemeAudit::run(&VibeColors::default());
+//!
+//! assert!(!audit.unused_tokens.contains(&Color::Primary));
+//! for finding in &audit.hardcoded {
+//!     println!("{}", finding);
+//! }
+//! ```
+
+use crate::core::color::{Color, ColorPalette, ColorProvider};
+use crate::core::{
+    BorderScale, BreakpointScale, ChartPalette, DataDisplayTokens, Density, ElevationScale,
+    GlassScale, MotionScale, RadiusScale, SelectionTokens, SizeScale, StateLayerScale,
+};
+use crate::themes::ComponentDefaults;
+use crate::utils::theme_lint::{color_family, color_literal, HardcodedColor, ThemeLint, COLORS};
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+
+/// Marker family prefix for the token usage probe
+const MARKER: &str = "theme-audit-";
+
+/// A hardcoded color and the builder that emits it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditFinding {
+    /// Builder the class came from, e.g. `"state"`
+    pub builder: &'static str,
+    /// The class and the color inside it
+    pub color: HardcodedColor,
+}
+
+impl fmt::Display for AuditFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.builder, self.color)
+    }
+}
+
+/// Token coverage report for one theme
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeAudit {
+    /// Palette tokens that no sampled builder output uses
+    pub unused_tokens: Vec<Color>,
+    /// Colors in builder output that the theme does not provide
+    ///
+    /// Other shades of a provided color are accepted, matching
+    /// [`ThemeLint::allow_shades`]. Each class is reported once per builder.
+    pub hardcoded: Vec<AuditFinding>,
+}
+
+impl ThemeAudit {
+    /// Audit a theme against every enabled builder
+    pub fn run<C: ColorProvider>(theme: &C) -> Self {
+        let probe_samples = builder_samples(&Probe::new(theme));
+        let used: Vec<&str> = probe_samples
+            .iter()
+            .flat_map(|(_, classes)| classes.split_whitespace())
+            .filter_map(color_literal)
+            .filter_map(color_family)
+            .filter_map(|family| family.strip_prefix(MARKER))
+            .collect();
+        let unused_tokens = COLORS
+            .iter()
+            .copied()
+            .filter(|color| !used.contains(&token_name(*color)))
+            .collect();
+
+        let lint = ThemeLint::new(theme).allow_shades();
+        let mut hardcoded: Vec<AuditFinding> = Vec::new();
+        for (builder, classes) in builder_samples(theme) {
+            for color in lint.check(&classes) {
+                let seen = hardcoded
+                    .iter()
+                    .any(|finding| finding.builder == builder && finding.color == color);
+                if !seen {
+                    hardcoded.push(AuditFinding { builder, color });
+                }
+            }
+        }
+
+        Self {
+            unused_tokens,
+            hardcoded,
+        }
+    }
+
+    /// Whether every token is used and no builder hardcodes a color
+    pub fn is_clean(&self) -> bool {
+        self.unused_tokens.is_empty() && self.hardcoded.is_empty()
+    }
+
+    /// Hardcoded colors emitted by one builder
+    pub fn hardcoded_in<'a>(&'a self, builder: &'a str) -> impl Iterator<Item = &'a AuditFinding> {
+        self.hardcoded
+            .iter()
+            .filter(move |finding| finding.builder == builder)
+    }
+}
+
+impl fmt::Display for ThemeAudit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clean() {
+            return write!(
+                f,
+                "every palette token is used and no builder hardcodes colors"
+            );
+        }
+        if !self.unused_tokens.is_empty() {
+            let names: Vec<&str> = self.unused_tokens.iter().map(|c| token_name(*c)).collect();
+            writeln!(f, "unused palette tokens: {}", names.join(", "))?;
+        }
+        for finding in &self.hardcoded {
+            writeln!(f, "{}", finding)?;
+        }
+        Ok(())
+    }
+}
+
+/// Palette field name of a token, e.g. `"text-secondary"`
+pub fn token_name(color: Color) -> &'static str {
+    match color {
+        Color::Primary => "primary",
+        Color::Secondary => "secondary",
+        Color::Accent => "accent",
+        Color::Success => "success",
+        Color::Warning => "warning",
+        Color::Error => "error",
+        Color::Info => "info",
+        Color::Surface => "surface",
+        Color::SurfaceRaised => "surface-raised",
+        Color::SurfaceOverlay => "surface-overlay",
+        Color::Background => "background",
+        Color::Foreground => "foreground",
+        Color::Border => "border",
+        Color::TextPrimary => "text-primary",
+        Color::TextSecondary => "text-secondary",
+        Color::TextTertiary => "text-tertiary",
+        Color::TextInverse => "text-inverse",
+        Color::Interactive => "interactive",
+        Color::InteractiveHover => "interactive-hover",
+        Color::InteractiveActive => "interactive-active",
+        Color::InteractiveDisabled => "interactive-disabled",
+    }
+}
+
+/// The audited theme with every palette token replaced by a marker color
+/// (`theme-audit-text-primary-500`); scales and chart colors pass through
+struct Probe<'a, C: ?Sized> {
+    palette: ColorPalette,
+    theme: &'a C,
+}
+
+impl<'a, C: ColorProvider + ?Sized> Probe<'a, C> {
+    fn new(theme: &'a C) -> Self {
+        let mut palette = theme.palette().clone();
+        for color in COLORS {
+            let marker = format!("{}{}-500", MARKER, token_name(color));
+            match color {
+                Color::Primary => palette.primary = marker,
+                Color::Secondary => palette.secondary = marker,
+                Color::Accent => palette.accent = marker,
+                Color::Success => palette.success = marker,
+                Color::Warning => palette.warning = marker,
+                Color::Error => palette.error = marker,
+                Color::Info => palette.info = marker,
+                Color::Surface => palette.surface = marker,
+                Color::SurfaceRaised => palette.surface_raised = marker,
+                Color::SurfaceOverlay => palette.surface_overlay = marker,
+                Color::Background => palette.background = marker,
+                Color::Foreground => palette.foreground = marker,
+                Color::Border => palette.border = marker,
+                Color::TextPrimary => palette.text_primary = marker,
+                Color::TextSecondary => palette.text_secondary = marker,
+                Color::TextTertiary => palette.text_tertiary = marker,
+                Color::TextInverse => palette.text_inverse = marker,
+                Color::Interactive => palette.interactive = marker,
+                Color::InteractiveHover => palette.interactive_hover = marker,
+                Color::InteractiveActive => palette.interactive_active = marker,
+                Color::InteractiveDisabled => palette.interactive_disabled = marker,
+            }
+        }
+        Self { palette, theme }
+    }
+}
+
+impl<C: ColorProvider + ?Sized> ColorProvider for Probe<'_, C> {
+    fn palette(&self) -> &ColorPalette {
+        &self.palette
+    }
+
+    fn density(&self) -> Density {
+        self.theme.density()
+    }
+
+    fn data_display(&self) -> DataDisplayTokens {
+        self.theme.data_display()
+    }
+
+    fn selection_tokens(&self) -> SelectionTokens {
+        self.theme.selection_tokens()
+    }
+
+    fn component_defaults(&self) -> ComponentDefaults {
+        self.theme.component_defaults()
+    }
+
+    fn elevation_scale(&self) -> ElevationScale {
+        self.theme.elevation_scale()
+    }
+
+    fn radius_scale(&self) -> RadiusScale {
+        self.theme.radius_scale()
+    }
+
+    fn border_scale(&self) -> BorderScale {
+        self.theme.border_scale()
+    }
+
+    fn motion_scale(&self) -> MotionScale {
+        self.theme.motion_scale()
+    }
+
+    fn state_layer_scale(&self) -> StateLayerScale {
+        self.theme.state_layer_scale()
+    }
+
+    fn glass_scale(&self) -> GlassScale {
+        self.theme.glass_scale()
+    }
+
+    fn size_scale(&self) -> SizeScale {
+        self.theme.size_scale()
+    }
+
+    fn breakpoint_scale(&self) -> BreakpointScale {
+        self.theme.breakpoint_scale()
+    }
+
+    fn chart_palette(&self) -> ChartPalette {
+        self.theme.chart_palette()
+    }
+}
+
+/// Output of every enabled builder across its common variants, by name
+fn builder_samples<C: ColorProvider + Clone>(colors: C) -> Vec<(&'static str, String)> {
+    use crate::builders::dnd::{dnd_styles, DragState};
+    use crate::builders::{chart_container_styles, indicator_styles, scroll_styles};
+    use crate::builders::{dropzone_styles, table_styles};
+
+    let mut samples = vec![(
+        "scroll",
+        scroll_styles(colors.clone()).branded_scrollbar().classes(),
+    )];
+
+    let chart = chart_container_styles(colors.clone()).framed();
+    samples.push((
+        "chart",
+        [
+            chart.container_classes(),
+            chart.grid_classes(),
+            chart.axis_classes(),
+            chart.label_classes(),
+            chart.sparkline_line_classes(),
+            chart.sparkline_area_classes(),
+            chart.tooltip_classes(),
+            chart.tooltip_title_classes(),
+            chart.tooltip_value_classes(),
+        ]
+        .join(" "),
+    ));
+
+    for state in [
+        DragState::Lifted,
+        DragState::Placeholder,
+        DragState::DropTarget,
+        DragState::InvalidDrop,
+    ] {
+        let dnd = dnd_styles(colors.clone()).state(state);
+        samples.push(("dnd", format!("{} {}", dnd.classes(), dnd.handle_classes())));
+    }
+
+    let indicator = indicator_styles(colors.clone()).count();
+    samples.push((
+        "indicator",
+        format!("{} {}", indicator.classes(), indicator.ping_classes()),
+    ));
+    samples.push((
+        "indicator",
+        indicator_styles(colors.clone()).dot().classes(),
+    ));
+
+    let table = table_styles(colors.clone()).sticky_header();
+    samples.push((
+        "table",
+        [
+            table.root_classes(),
+            table.head_classes(),
+            table.header_cell_classes(),
+            table.row_classes(),
+            table.cell_classes(),
+        ]
+        .join(" "),
+    ));
+
+    for state in ["idle", "drag-over", "error", "uploading"] {
+        let dropzone = dropzone_styles(colors.clone()).state_str(state);
+        samples.push((
+            "upload",
+            [
+                dropzone.classes(),
+                dropzone.icon_classes(),
+                dropzone.text_classes(),
+                dropzone.hint_classes(),
+                dropzone.file_row_classes(),
+                dropzone.file_progress_bar_classes(),
+            ]
+            .join(" "),
+        ));
+    }
+
+    #[cfg(feature = "buttons")]
+    {
+        use crate::builders::button_styles;
+
+        for variant in [
+            "primary",
+            "secondary",
+            "success",
+            "warning",
+            "error",
+            "link",
+        ] {
+            samples.push((
+                "button",
+                button_styles(colors.clone()).variant_str(variant).classes(),
+            ));
+        }
+        samples.push(("button", button_styles(colors.clone()).ghost().classes()));
+        samples.push((
+            "button",
+            button_styles(colors.clone()).primary().disabled().classes(),
+        ));
+    }
+
+    #[cfg(feature = "cards")]
+    {
+        use crate::builders::card_styles;
+
+        for surface in [
+            "standard",
+            "elevated",
+            "overlay",
+            "branded",
+            "glass",
+            "glass-light",
+            "dark",
+        ] {
+            samples.push((
+                "card",
+                card_styles(colors.clone())
+                    .surface_str(surface)
+                    .selectable_interaction()
+                    .is_selected()
+                    .classes(),
+            ));
+        }
+    }
+
+    #[cfg(feature = "typography")]
+    {
+        use crate::builders::{callout_styles, text_styles};
+
+        for hierarchy in ["title", "body", "caption", "code"] {
+            samples.push((
+                "text",
+                text_styles(colors.clone())
+                    .hierarchy_str(hierarchy)
+                    .classes(),
+            ));
+        }
+        for color in [
+            "secondary",
+            "accent",
+            "muted",
+            "disabled",
+            "success",
+            "warning",
+            "error",
+            "info",
+        ] {
+            samples.push((
+                "text",
+                text_styles(colors.clone()).color_str(color).classes(),
+            ));
+        }
+
+        for kind in ["note", "tip", "success", "warning", "danger", "quote"] {
+            let callout = callout_styles(colors.clone()).kind_str(kind);
+            samples.push((
+                "callout",
+                [
+                    callout.classes(),
+                    callout.icon_classes(),
+                    callout.title_classes(),
+                    callout.body_classes(),
+                ]
+                .join(" "),
+            ));
+        }
+    }
+
+    #[cfg(feature = "layout")]
+    {
+        use crate::builders::layout_styles;
+
+        samples.push((
+            "layout",
+            layout_styles(colors.clone()).divider_bottom().classes(),
+        ));
+    }
+
+    #[cfg(feature = "selection")]
+    {
+        use crate::builders::selection_styles;
+
+        for display in ["button", "chip", "list", "card", "tab", "segment"] {
+            for state in ["unselected", "selected", "disabled"] {
+                samples.push((
+                    "selection",
+                    selection_styles(colors.clone())
+                        .display_str(display)
+                        .state_str(state)
+                        .item_classes(),
+                ));
+            }
+        }
+    }
+
+    #[cfg(feature = "states")]
+    {
+        use crate::builders::state_styles;
+        use crate::builders::status_indicator::{status_indicator_styles, ServiceStatus};
+
+        for intent in [
+            "informational",
+            "loading",
+            "success",
+            "warning",
+            "error",
+            "empty",
+        ] {
+            for display in ["section", "banner"] {
+                samples.push((
+                    "state",
+                    state_styles(colors.clone())
+                        .intent_str(intent)
+                        .display_str(display)
+                        .classes(),
+                ));
+            }
+        }
+
+        for status in ["operational", "maintenance", "degraded", "major-outage"] {
+            let service = status_indicator_styles(colors.clone()).status_str(status);
+            samples.push((
+                "status_indicator",
+                [
+                    service.row_classes(),
+                    service.dot_classes(),
+                    service.status_label_classes(),
+                    service.uptime_segment_classes(ServiceStatus::Unknown),
+                ]
+                .join(" "),
+            ));
+        }
+    }
+
+    #[cfg(feature = "product")]
+    {
+        use crate::builders::product_styles;
+
+        samples.push(("product", product_styles(colors.clone()).hero().classes()));
+        samples.push((
+            "product",
+            product_styles(colors.clone())
+                .out_of_stock()
+                .sale_badge()
+                .availability_badge_classes(),
+        ));
+    }
+
+    samples
+}
+
+#[cfg(test)]
+#[path = "theme_audit_test.rs"]
+mod theme_audit_test;
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::color::{Color, ColorPalette, ColorProvider};
+    use crate::themes::VibeColors;
+    use crate::utils::theme_audit::{token_name, Probe, ThemeAudit};
+    use crate::utils::theme_lint::COLORS;
+
+    #[test]
+    fn test_probe_gives_every_token_its_own_marker() {
+        let colors = VibeColors::default();
+        let probe = Probe::new(&colors);
+
+        assert_eq!(
+            probe.resolve_color(Color::TextInverse),
+            "theme-audit-text-inverse-500"
+        );
+        assert_ne!(
+            probe.resolve_color(Color::Surface),
+            probe.resolve_color(Color::SurfaceRaised)
+        );
+        // Scales still come from the audited theme
+        assert_eq!(probe.radius_scale(), colors.radius_scale());
+
+        let mut names: Vec<&str> = COLORS.iter().map(|c| token_name(*c)).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), COLORS.len());
+    }
+
+    #[test]
+    fn test_core_tokens_are_used() {
+        let audit = ThemeAudit::run(&VibeColors::default());
+
+        for color in [
+            Color::Primary,
+            Color::Border,
+            Color::Error,
+            Color::TextTertiary,
+        ] {
+            assert!(!audit.unused_tokens.contains(&color), "{color:?} unused");
+        }
+    }
+
+    #[test]
+    fn test_shipped_theme_has_no_findings() {
+        let audit = ThemeAudit::run(&VibeColors::default());
+
+        assert!(audit.hardcoded.is_empty(), "{audit}");
+        assert_eq!(audit.hardcoded_in("state").count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "states")]
+    fn test_hardcoded_colors_are_reported() {
+        /// Provider whose soft backgrounds bypass its palette
+        struct OffPalette(ColorPalette);
+
+        impl ColorProvider for OffPalette {
+            fn palette(&self) -> &ColorPalette {
+                &self.0
+            }
+
+            fn soft_bg(&self, _color: Color) -> String {
+                "lime-50".to_string()
+            }
+        }
+
+        let audit = ThemeAudit::run(&OffPalette(ColorPalette::default()));
+        let state: Vec<&str> = audit
+            .hardcoded_in("state")
+            .map(|finding| finding.color.class.as_str())
+            .collect();
+
+        assert_eq!(state, vec!["bg-lime-50"]);
+        assert!(!audit.is_clean());
+        assert!(audit
+            .to_string()
+            .contains("state: \"bg-lime-50\" uses lime-50"));
+    }
+
+    #[test]
+    fn test_findings_are_reported_once_per_builder() {
+        let audit = ThemeAudit::run(&VibeColors::default());
+
+        for (i, finding) in audit.hardcoded.iter().enumerate() {
+            assert!(
+                !audit.hardcoded[..i].contains(finding),
+                "{finding} repeated"
+            );
+        }
+    }
+
+    #[test]
+    fn test_report_lists_unused_tokens() {
+        let audit = ThemeAudit {
+            unused_tokens: vec![Color::Secondary, Color::SurfaceRaised],
+            hardcoded: Vec::new(),
+        };
+        assert!(!audit.is_clean());
+        assert_eq!(
+            audit.to_string(),
+            "unused palette tokens: secondary, surface-raised\n"
+        );
+
+        let clean = ThemeAudit {
+            unused_tokens: Vec::new(),
+            hardcoded: Vec::new(),
+        };
+        assert!(clean.is_clean());
+        assert_eq!(
+            clean.to_string(),
+            "every palette token is used and no builder hardcodes colors"
+        );
+    }
+}
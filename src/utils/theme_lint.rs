@@ -0,0 +1,246 @@
+//! Theme consistency linting for builder output
+//!
+//! Builders are supposed to take every color from the active
+//! [`ColorProvider`], so switching providers rebrands everything. A class
+//! like `bg-red-50` or `from-jupiter-navy-900` written into a builder
+//! silently survives a theme switch. [`ThemeLint`] collects the colors a
+//! provider actually supplies and flags color classes that use anything
+//! else.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::themes::VibeColors;
+//! use jupiter_design_system::utils::ThemeLint;
+//!
+//! let lint = ThemeLint::new(&VibeColors::water_wellness());
+//!
+//! assert!(lint.is_clean("px-4 bg-water-blue-500 text-white hover:bg-water-blue-600"));
+//!
+//! let findings = lint.check("bg-jupiter-blue-500 border-b-2");
+//! assert_eq!(findings.len(), 1);
+//! assert_eq!(findings[0].color, "jupiter-blue-500");
+//! ```
+
+use crate::core::color::{Color, ColorProvider};
+use crate::utils::css_budget::base_utility;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Every semantic color a provider resolves
+pub(crate) const COLORS: [Color; 21] = [
+    Color::Primary,
+    Color::Secondary,
+    Color::Accent,
+    Color::Success,
+    Color::Warning,
+    Color::Error,
+    Color::Info,
+    Color::Surface,
+    Color::SurfaceRaised,
+    Color::SurfaceOverlay,
+    Color::Background,
+    Color::Foreground,
+    Color::Border,
+    Color::TextPrimary,
+    Color::TextSecondary,
+    Color::TextTertiary,
+    Color::TextInverse,
+    Color::Interactive,
+    Color::InteractiveHover,
+    Color::InteractiveActive,
+    Color::InteractiveDisabled,
+];
+
+/// Utility prefixes that take a color, longest first so `ring-offset-`
+/// wins over `ring-`
+const COLOR_PREFIXES: [&str; 25] = [
+    "ring-offset-",
+    "placeholder-",
+    "decoration-",
+    "border-x-",
+    "border-y-",
+    "border-t-",
+    "border-r-",
+    "border-b-",
+    "border-l-",
+    "border-s-",
+    "border-e-",
+    "outline-",
+    "border-",
+    "divide-",
+    "accent-",
+    "shadow-",
+    "stroke-",
+    "caret-",
+    "text-",
+    "ring-",
+    "fill-",
+    "from-",
+    "bg-",
+    "via-",
+    "to-",
+];
+
+const SHADES: [&str; 11] = [
+    "50", "100", "200", "300", "400", "500", "600", "700", "800", "900", "950",
+];
+
+/// A color class that did not come from the active provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HardcodedColor {
+    /// The full class, including variants
+    pub class: String,
+    /// The color value inside it, e.g. `"red-50"`
+    pub color: String,
+}
+
+impl fmt::Display for HardcodedColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" uses {}, which the active theme does not provide",
+            self.class, self.color
+        )
+    }
+}
+
+/// Checks builder output against the colors of one provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeLint {
+    provided: BTreeSet<String>,
+    allow_shades: bool,
+}
+
+impl ThemeLint {
+    /// Collect the palette, custom status, custom intent and chart colors
+    /// of a provider
+    pub fn new<C: ColorProvider>(colors: &C) -> Self {
+        let mut provided: BTreeSet<String> = COLORS
+            .iter()
+            .map(|color| colors.resolve_color(*color).to_string())
+            .collect();
+        provided.extend(
+            colors
+                .palette()
+                .custom_statuses
+                .iter()
+                .map(|status| status.color.clone()),
+        );
+        provided.extend(
+            colors
+                .palette()
+                .custom_intents
+                .iter()
+                .map(|intent| intent.color.clone()),
+        );
+
+        let chart = colors.chart_palette();
+        provided.extend(
+            chart
+                .categorical
+                .into_iter()
+                .chain(chart.sequential)
+                .chain(chart.diverging)
+                .chain([chart.grid, chart.axis, chart.label]),
+        );
+
+        Self {
+            provided,
+            allow_shades: false,
+        }
+    }
+
+    /// Accept a color the provider does not supply, e.g. `"black"`
+    pub fn allow(mut self, color: impl Into<String>) -> Self {
+        self.provided.insert(color.into());
+        self
+    }
+
+    /// Accept other shades of a provided color family
+    ///
+    /// With `gray-200` provided, `gray-700` passes but `red-700` does not.
+    /// Builders that derive tints from a theme color (`-300` rings, `-50`
+    /// backgrounds) need this.
+    pub fn allow_shades(mut self) -> Self {
+        self.allow_shades = true;
+        self
+    }
+
+    /// Find every color class not supplied by the provider
+    pub fn check(&self, classes: &str) -> Vec<HardcodedColor> {
+        classes
+            .split_whitespace()
+            .filter_map(|class| {
+                let color = color_literal(class)?;
+                if self.is_provided(color) {
+                    None
+                } else {
+                    Some(HardcodedColor {
+                        class: class.to_string(),
+                        color: color.to_string(),
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Whether every color class comes from the provider
+    pub fn is_clean(&self, classes: &str) -> bool {
+        self.check(classes).is_empty()
+    }
+
+    fn is_provided(&self, color: &str) -> bool {
+        if self.provided.contains(color) {
+            return true;
+        }
+        if !self.allow_shades {
+            return false;
+        }
+        match color_family(color) {
+            Some(family) => self
+                .provided
+                .iter()
+                .any(|provided| color_family(provided) == Some(family)),
+            None => false,
+        }
+    }
+}
+
+/// Extract the color value of a class, if it is a color utility with a
+/// literal color (`bg-red-500/10` gives `red-500`)
+pub(crate) fn color_literal(class: &str) -> Option<&str> {
+    let utility = base_utility(class)
+        .trim_start_matches('!')
+        .trim_start_matches('-');
+    let value = COLOR_PREFIXES
+        .iter()
+        .find_map(|prefix| utility.strip_prefix(prefix))?;
+    let value = value.split('/').next().unwrap_or(value);
+
+    let is_color = match value {
+        "white" | "black" => true,
+        _ if value.starts_with("[#") || value.starts_with("[rgb") || value.starts_with("[hsl") => {
+            true
+        }
+        _ => color_family(value).is_some(),
+    };
+    is_color.then_some(value)
+}
+
+/// Family of a `family-shade` color (`jupiter-blue-500` gives `jupiter-blue`)
+pub(crate) fn color_family(color: &str) -> Option<&str> {
+    let (family, shade) = color.rsplit_once('-')?;
+    let is_family = !family.is_empty()
+        && family != "opacity"
+        && family
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch == '-');
+    (is_family && SHADES.contains(&shade)).then_some(family)
+}
+
+#[cfg(test)]
+#[path = "theme_lint_test.rs"]
+mod theme_lint_test;
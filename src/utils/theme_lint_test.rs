@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use crate::themes::VibeColors;
+    use crate::utils::theme_lint::ThemeLint;
+
+    #[test]
+    fn test_provided_colors_pass() {
+        let lint = ThemeLint::new(&VibeColors::default());
+
+        assert!(lint.is_clean(
+            "bg-jupiter-blue-500 text-white border-gray-200 hover:bg-jupiter-blue-600 ring-jupiter-blue-500/50"
+        ));
+        // Chart colors come from the provider too
+        assert!(lint.is_clean("fill-jupiter-blue-100"));
+    }
+
+    #[test]
+    fn test_non_color_utilities_are_ignored() {
+        let lint = ThemeLint::new(&VibeColors::default());
+
+        assert!(lint.is_clean(
+            "text-sm text-2xl border border-b-2 ring-2 ring-offset-2 bg-gradient-to-br shadow-md bg-transparent bg-opacity-50 divide-y-2 duration-200"
+        ));
+    }
+
+    #[test]
+    fn test_hardcoded_colors_are_flagged() {
+        let lint = ThemeLint::new(&VibeColors::default());
+        let findings =
+            lint.check("md:hover:bg-red-50 from-jupiter-navy-900/80 text-[#ff0000] px-4");
+
+        let colors: Vec<&str> = findings.iter().map(|f| f.color.as_str()).collect();
+        assert_eq!(colors, vec!["red-50", "jupiter-navy-900", "[#ff0000]"]);
+        assert_eq!(findings[0].class, "md:hover:bg-red-50");
+        assert_eq!(
+            findings[0].to_string(),
+            "\"md:hover:bg-red-50\" uses red-50, which the active theme does not provide"
+        );
+    }
+
+    #[test]
+    fn test_colors_follow_the_active_provider() {
+        let water = ThemeLint::new(&VibeColors::water_wellness());
+
+        assert!(water.is_clean("bg-water-blue-500"));
+        assert!(!water.is_clean("bg-jupiter-blue-500"));
+    }
+
+    #[test]
+    fn test_allow_and_shades() {
+        let colors = VibeColors::default().with_status("archived", "slate-400");
+
+        let strict = ThemeLint::new(&colors);
+        assert!(strict.is_clean("bg-slate-400"));
+        assert!(!strict.is_clean("border-gray-700"));
+        assert!(!strict.is_clean("bg-black/50"));
+
+        let relaxed = strict.allow("black").allow_shades();
+        assert!(relaxed.is_clean("border-gray-700 bg-black/50 ring-jupiter-blue-300"));
+        assert!(!relaxed.is_clean("bg-rose-700"));
+    }
+}
@@ -0,0 +1,124 @@
+//! Responsive visibility helpers
+//!
+//! Showing or hiding an element per breakpoint takes a `hidden` class plus
+//! a display class at the breakpoint where it reappears, and that display
+//! must match the element (`inline-flex` for buttons, `flex` for layouts).
+//! [`Visibility`] builds the right combination for any display.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use jupiter_design_system::core::Breakpoint;
+//! use jupiter_design_system::utils::visibility::{hidden_above, hidden_below, only_on};
+//!
+//! assert_eq!(hidden_below(Breakpoint::Desktop).classes(), "hidden lg:block");
+//! assert_eq!(hidden_above(Breakpoint::Mobile).classes(), "md:hidden");
+//! assert_eq!(
+//!     only_on(Breakpoint::Tablet).classes_with_display("flex"),
+//!     "hidden md:flex lg:hidden"
+//! );
+//! assert_eq!(
+//!     only_on(Breakpoint::Tablet).container_classes("flex"),
+//!     "hidden @sm:flex @lg:hidden"
+//! );
+//! ```
+
+use crate::core::{Breakpoint, BreakpointScale};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use serde::{Deserialize, Serialize};
+
+/// Breakpoint range an element is visible in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Visibility {
+    /// Visible at every width
+    #[default]
+    Always,
+    /// Hidden until the breakpoint starts
+    HiddenBelow(Breakpoint),
+    /// Hidden once the next breakpoint starts
+    HiddenAbove(Breakpoint),
+    /// Visible only within the breakpoint
+    OnlyOn(Breakpoint),
+}
+
+impl Visibility {
+    /// Build classes for a block element
+    pub fn classes(&self) -> String {
+        self.classes_with_display("block")
+    }
+
+    /// Build classes restoring `display` where the element becomes visible
+    pub fn classes_with_display(&self, display: &str) -> String {
+        self.classes_for_scale(display, &BreakpointScale::default())
+    }
+
+    /// Build classes against a theme's breakpoint widths
+    pub fn classes_for_scale(&self, display: &str, scale: &BreakpointScale) -> String {
+        self.classes_with_prefix(display, |breakpoint| scale.prefix(breakpoint))
+    }
+
+    /// Build classes with container query variants (`@sm:`), which respond
+    /// to the width of the nearest `@container` ancestor instead of the
+    /// viewport
+    pub fn container_classes(&self, display: &str) -> String {
+        self.classes_with_prefix(display, |breakpoint| {
+            breakpoint.container_prefix().to_string()
+        })
+    }
+
+    fn classes_with_prefix(&self, display: &str, prefix: impl Fn(Breakpoint) -> String) -> String {
+        let classes = match self {
+            Visibility::Always => Vec::new(),
+            Visibility::HiddenBelow(breakpoint) => show_from(*breakpoint, display, &prefix),
+            Visibility::HiddenAbove(breakpoint) => hide_after(*breakpoint, &prefix),
+            Visibility::OnlyOn(breakpoint) => {
+                let mut classes = show_from(*breakpoint, display, &prefix);
+                classes.extend(hide_after(*breakpoint, &prefix));
+                classes
+            }
+        };
+        classes.join(" ")
+    }
+}
+
+/// Hide an element below a breakpoint
+pub fn hidden_below(breakpoint: Breakpoint) -> Visibility {
+    Visibility::HiddenBelow(breakpoint)
+}
+
+/// Hide an element above a breakpoint
+pub fn hidden_above(breakpoint: Breakpoint) -> Visibility {
+    Visibility::HiddenAbove(breakpoint)
+}
+
+/// Show an element only within a breakpoint
+pub fn only_on(breakpoint: Breakpoint) -> Visibility {
+    Visibility::OnlyOn(breakpoint)
+}
+
+fn show_from(
+    breakpoint: Breakpoint,
+    display: &str,
+    prefix: &impl Fn(Breakpoint) -> String,
+) -> Vec<String> {
+    match breakpoint {
+        Breakpoint::Mobile => Vec::new(),
+        _ => vec![
+            "hidden".to_string(),
+            format!("{}{}", prefix(breakpoint), display),
+        ],
+    }
+}
+
+fn hide_after(breakpoint: Breakpoint, prefix: &impl Fn(Breakpoint) -> String) -> Vec<String> {
+    match breakpoint.next() {
+        Some(next) => vec![format!("{}hidden", prefix(next))],
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+#[path = "visibility_test.rs"]
+mod visibility_test;
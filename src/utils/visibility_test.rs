@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use crate::core::{Breakpoint, BreakpointScale};
+    use crate::utils::visibility::{hidden_above, hidden_below, only_on, Visibility};
+
+    #[test]
+    fn test_always_visible() {
+        assert_eq!(Visibility::default(), Visibility::Always);
+        assert_eq!(Visibility::Always.classes(), "");
+    }
+
+    #[test]
+    fn test_hidden_below() {
+        assert_eq!(hidden_below(Breakpoint::Mobile).classes(), "");
+        assert_eq!(
+            hidden_below(Breakpoint::Tablet).classes(),
+            "hidden md:block"
+        );
+        assert_eq!(
+            hidden_below(Breakpoint::Large).classes_with_display("inline-flex"),
+            "hidden xl:inline-flex"
+        );
+    }
+
+    #[test]
+    fn test_hidden_above() {
+        assert_eq!(hidden_above(Breakpoint::Mobile).classes(), "md:hidden");
+        assert_eq!(hidden_above(Breakpoint::Desktop).classes(), "xl:hidden");
+        assert_eq!(hidden_above(Breakpoint::Large).classes(), "");
+    }
+
+    #[test]
+    fn test_only_on() {
+        assert_eq!(only_on(Breakpoint::Mobile).classes(), "md:hidden");
+        assert_eq!(
+            only_on(Breakpoint::Desktop).classes(),
+            "hidden lg:block xl:hidden"
+        );
+        assert_eq!(only_on(Breakpoint::Large).classes(), "hidden xl:block");
+    }
+
+    #[test]
+    fn test_custom_breakpoint_scale() {
+        let scale = BreakpointScale {
+            tablet: 640,
+            ..BreakpointScale::default()
+        };
+
+        assert_eq!(
+            hidden_below(Breakpoint::Tablet).classes_for_scale("block", &scale),
+            "hidden min-[640px]:block"
+        );
+        assert_eq!(
+            only_on(Breakpoint::Tablet).classes_for_scale("flex", &scale),
+            "hidden min-[640px]:flex lg:hidden"
+        );
+        assert_eq!(
+            hidden_below(Breakpoint::Desktop).classes_for_scale("block", &scale),
+            hidden_below(Breakpoint::Desktop).classes()
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_widths() {
+        let scale = BreakpointScale::default();
+
+        assert_eq!(Breakpoint::Mobile.min_width(), 0);
+        assert_eq!(Breakpoint::Tablet.min_width(), 768);
+        assert_eq!(scale.min_width(Breakpoint::Large), 1280);
+        assert_eq!(
+            scale.media_query(Breakpoint::Desktop),
+            "(min-width: 1024px)"
+        );
+        assert_eq!(Breakpoint::Desktop.container_prefix(), "@lg:");
+    }
+}